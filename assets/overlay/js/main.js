@@ -1,18 +1,22 @@
 /**
  * Yambot Overlay - Main Script
- * Handles WebSocket connection and event processing
+ * Handles overlay events, the connection is managed by yambot-overlay.js
  */
 
 // Configuration
-const WS_URL = 'ws://localhost:3000/ws';
-const RECONNECT_INTERVAL = 3000; // 3 seconds
 const DEBUG_MODE = false; // Set to true to show debug panel
+const POLL_RESULT_DURATION = 10000; // How long final poll results stay visible
+const HYPE_TRAIN_END_DURATION = 8000; // How long the final hype train level stays visible
+const GOAL_END_DURATION = 10000; // How long a finished goal bar stays visible
+const NOTICE_DURATION = 6000; // How long a sub/raid notice stays visible
 
 // Global state
-let ws = null;
-let reconnectTimeout = null;
+let overlay = null;
 let wheel = null;
 let configMode = false;
+let pollHideTimeout = null;
+let hypeTrainHideTimeout = null;
+let adBreakInterval = null;
 
 // Drag state
 let dragElement = null;
@@ -41,8 +45,14 @@ document.addEventListener('DOMContentLoaded', () => {
         document.getElementById('debug-info').classList.remove('hidden');
     }
 
-    // Connect to WebSocket
-    connect();
+    // Connect to the overlay server
+    overlay = YambotOverlay.connect();
+    overlay.onStatus((status) => updateDebug('connection', status));
+    overlay.on('*', (event) => {
+        console.log('Received event:', event);
+        updateDebug('event', event.type || 'unknown');
+        handleEvent(event);
+    });
 
     // Listen for config mode toggle (Press 'C' key)
     document.addEventListener('keydown', (e) => {
@@ -58,68 +68,6 @@ document.addEventListener('DOMContentLoaded', () => {
     }
 });
 
-/**
- * Connect to the WebSocket server
- */
-function connect() {
-    console.log('Connecting to WebSocket...');
-    updateDebug('connection', 'Connecting...');
-
-    try {
-        ws = new WebSocket(WS_URL);
-
-        ws.onopen = () => {
-            console.log('WebSocket connected');
-            updateDebug('connection', 'Connected');
-
-            // Clear reconnect timeout
-            if (reconnectTimeout) {
-                clearTimeout(reconnectTimeout);
-                reconnectTimeout = null;
-            }
-
-            // Request current configuration
-            send({ type: 'request_config' });
-        };
-
-        ws.onmessage = (event) => {
-            try {
-                const data = JSON.parse(event.data);
-                console.log('Received event:', data);
-                updateDebug('event', data.type || 'unknown');
-                handleEvent(data);
-            } catch (error) {
-                console.error('Failed to parse message:', error);
-            }
-        };
-
-        ws.onerror = (error) => {
-            console.error('WebSocket error:', error);
-            updateDebug('connection', 'Error');
-        };
-
-        ws.onclose = () => {
-            console.log('WebSocket disconnected');
-            updateDebug('connection', 'Disconnected');
-            ws = null;
-
-            // Attempt to reconnect
-            reconnectTimeout = setTimeout(() => {
-                console.log('Attempting to reconnect...');
-                connect();
-            }, RECONNECT_INTERVAL);
-        };
-    } catch (error) {
-        console.error('Failed to connect:', error);
-        updateDebug('connection', 'Failed');
-
-        // Attempt to reconnect
-        reconnectTimeout = setTimeout(() => {
-            connect();
-        }, RECONNECT_INTERVAL);
-    }
-}
-
 /**
  * Handle incoming events from the server
  */
@@ -133,6 +81,39 @@ function handleEvent(event) {
             handleConfigUpdate(event);
             break;
 
+        case 'poll':
+            handlePoll(event);
+            break;
+
+        case 'hype_train':
+            handleHypeTrain(event);
+            break;
+
+        case 'goal':
+            handleGoal(event);
+            break;
+
+        case 'charity':
+            handleCharity(event);
+            break;
+
+        case 'ad_break':
+            handleAdBreak(event);
+            break;
+
+        case 'chat_notice':
+            handleChatNotice(event);
+            break;
+
+        case 'text_widget':
+            handleTextWidget(event);
+            break;
+
+        case 'stream_state':
+            // Expose the scene so it can be styled with body[data-scene="..."]
+            document.body.dataset.scene = event.scene;
+            break;
+
         case 'ping':
             // Just a keep-alive, no action needed
             break;
@@ -161,6 +142,234 @@ function handleTriggerAction(event) {
     }
 }
 
+/**
+ * Handle poll events (begin, progress and end)
+ */
+function handlePoll(event) {
+    const { title, status, choices } = event;
+    const container = document.getElementById('poll-container');
+    const choicesElement = document.getElementById('poll-choices');
+
+    if (pollHideTimeout) {
+        clearTimeout(pollHideTimeout);
+        pollHideTimeout = null;
+    }
+
+    // Archived polls are removed from the channel, hide immediately
+    if (status === 'archived') {
+        container.classList.add('hidden');
+        return;
+    }
+
+    const items = Array.isArray(choices) ? choices : [];
+    const totalVotes = items.reduce((sum, choice) => sum + (choice.votes || 0), 0);
+    const maxVotes = items.reduce((max, choice) => Math.max(max, choice.votes || 0), 0);
+    const finished = status !== 'active';
+
+    document.getElementById('poll-title').textContent = title;
+    choicesElement.innerHTML = '';
+
+    items.forEach(choice => {
+        const votes = choice.votes || 0;
+        const percent = totalVotes > 0 ? (votes / totalVotes) * 100 : 0;
+
+        const row = document.createElement('div');
+        row.className = 'poll-choice';
+        if (finished && votes > 0 && votes === maxVotes) {
+            row.classList.add('winner');
+        }
+
+        const bar = document.createElement('div');
+        bar.className = 'poll-choice-bar';
+        bar.style.width = `${percent}%`;
+
+        const label = document.createElement('div');
+        label.className = 'poll-choice-label';
+        const name = document.createElement('span');
+        name.textContent = choice.title;
+        const count = document.createElement('span');
+        count.textContent = `${votes} (${percent.toFixed(0)}%)`;
+        label.appendChild(name);
+        label.appendChild(count);
+
+        row.appendChild(bar);
+        row.appendChild(label);
+        choicesElement.appendChild(row);
+    });
+
+    container.classList.remove('hidden');
+
+    if (finished) {
+        pollHideTimeout = setTimeout(() => {
+            container.classList.add('hidden');
+            pollHideTimeout = null;
+        }, POLL_RESULT_DURATION);
+    }
+}
+
+/**
+ * Handle hype train events (begin, progress and end)
+ */
+function handleHypeTrain(event) {
+    const { status, level, progress, goal } = event;
+    const container = document.getElementById('hype-train-container');
+    const fill = document.getElementById('hype-train-fill');
+
+    if (hypeTrainHideTimeout) {
+        clearTimeout(hypeTrainHideTimeout);
+        hypeTrainHideTimeout = null;
+    }
+
+    if (status === 'end') {
+        document.getElementById('hype-train-level').textContent = `Finished at level ${level}`;
+        fill.style.width = '100%';
+        hypeTrainHideTimeout = setTimeout(() => {
+            container.classList.add('hidden');
+            hypeTrainHideTimeout = null;
+        }, HYPE_TRAIN_END_DURATION);
+    } else {
+        const percent = goal > 0 ? Math.min(100, (progress / goal) * 100) : 0;
+        document.getElementById('hype-train-level').textContent = `Level ${level}`;
+        fill.style.width = `${percent}%`;
+    }
+
+    container.classList.remove('hidden');
+}
+
+/**
+ * Handle creator goal events - one bar per goal, removed a while after it ends
+ */
+function handleGoal(event) {
+    const { goal_id, goal_type, description, status, current, target } = event;
+    const container = document.getElementById('goals-container');
+
+    let bar = document.getElementById(`goal-${goal_id}`);
+    if (!bar) {
+        bar = document.createElement('div');
+        bar.id = `goal-${goal_id}`;
+        bar.className = 'goal';
+        bar.innerHTML = `
+            <div class="goal-header">
+                <span class="goal-description"></span>
+                <span class="goal-amount"></span>
+            </div>
+            <div class="goal-bar"><div class="goal-fill"></div></div>
+        `;
+        container.appendChild(bar);
+    }
+
+    const percent = target > 0 ? Math.min(100, (current / target) * 100) : 0;
+    bar.querySelector('.goal-description').textContent = description || goalLabel(goal_type);
+    bar.querySelector('.goal-amount').textContent = `${current} / ${target}`;
+    bar.querySelector('.goal-fill').style.width = `${percent}%`;
+    bar.classList.toggle('achieved', status === 'achieved');
+
+    if (status !== 'active') {
+        setTimeout(() => bar.remove(), GOAL_END_DURATION);
+    }
+}
+
+/**
+ * Fallback title for goals without a description
+ */
+function goalLabel(goalType) {
+    switch (goalType) {
+        case 'follow':
+            return 'Follower goal';
+        case 'new_bit':
+        case 'new_cheerer':
+            return 'Bits goal';
+        default:
+            return 'Sub goal';
+    }
+}
+
+/**
+ * Handle charity campaign progress - the bar stays visible once the campaign has a total
+ */
+function handleCharity(event) {
+    const { charity_name, current, target, currency } = event;
+    const container = document.getElementById('charity-container');
+    const format = (amount) => amount.toLocaleString(undefined, { style: 'currency', currency });
+
+    const percent = target > 0 ? Math.min(100, (current / target) * 100) : 0;
+    document.getElementById('charity-name').textContent = charity_name;
+    document.getElementById('charity-amount').textContent = `${format(current)} / ${format(target)}`;
+    document.getElementById('charity-fill').style.width = `${percent}%`;
+
+    container.classList.remove('hidden');
+}
+
+/**
+ * Show sub, gift sub and raid notices, stacked until each one times out
+ */
+function handleChatNotice(event) {
+    const { notice_type, user_name, message } = event;
+    const container = document.getElementById('notices-container');
+
+    const notice = document.createElement('div');
+    notice.className = `notice notice-${notice_type}`;
+    notice.innerHTML = `
+        <div class="notice-user"></div>
+        <div class="notice-message"></div>
+    `;
+    notice.querySelector('.notice-user').textContent = user_name;
+    notice.querySelector('.notice-message').textContent = message;
+    container.appendChild(notice);
+
+    setTimeout(() => notice.remove(), NOTICE_DURATION);
+}
+
+/**
+ * Show a countdown until the ad break ends
+ */
+function handleAdBreak(event) {
+    const { duration_seconds, started_at } = event;
+    const container = document.getElementById('ad-break-container');
+    const countdown = document.getElementById('ad-break-countdown');
+
+    const startedAt = Date.parse(started_at);
+    const endsAt = (isNaN(startedAt) ? Date.now() : startedAt) + duration_seconds * 1000;
+
+    if (adBreakInterval) {
+        clearInterval(adBreakInterval);
+    }
+
+    const update = () => {
+        const remaining = Math.ceil((endsAt - Date.now()) / 1000);
+        if (remaining <= 0) {
+            container.classList.add('hidden');
+            clearInterval(adBreakInterval);
+            adBreakInterval = null;
+            return;
+        }
+        const minutes = Math.floor(remaining / 60);
+        const seconds = String(remaining % 60).padStart(2, '0');
+        countdown.textContent = `${minutes}:${seconds}`;
+    };
+
+    update();
+    container.classList.remove('hidden');
+    adBreakInterval = setInterval(update, 1000);
+}
+
+/**
+ * Handle text widget updates, e.g. the death counter. Each widget gets its own
+ * element that stays on screen once it has text.
+ */
+function handleTextWidget(event) {
+    const { widget, text } = event;
+    const container = document.getElementById('text-widgets-container');
+    let element = container.querySelector(`[data-widget="${CSS.escape(widget)}"]`);
+    if (!element) {
+        element = document.createElement('div');
+        element.className = 'text-widget';
+        element.dataset.widget = widget;
+        container.appendChild(element);
+    }
+    element.textContent = text;
+}
+
 /**
  * Handle configuration update from server
  */
@@ -497,10 +706,8 @@ function updateDebug(field, value) {
  * Send a message to the server
  */
 function send(data) {
-    if (ws && ws.readyState === WebSocket.OPEN) {
-        ws.send(JSON.stringify(data));
-    } else {
-        console.warn('WebSocket not connected, cannot send message');
+    if (overlay) {
+        overlay.send(data);
     }
 }
 
@@ -511,10 +718,7 @@ window.sendToBackend = send;
 
 // Cleanup on page unload
 window.addEventListener('beforeunload', () => {
-    if (ws) {
-        ws.close();
-    }
-    if (reconnectTimeout) {
-        clearTimeout(reconnectTimeout);
+    if (overlay) {
+        overlay.close();
     }
 });
@@ -216,7 +216,10 @@ class SpinningWheel {
                 window.sendToBackend({
                     type: 'wheel_result',
                     result: result,
-                    action: action
+                    action: action,
+                    wheel: wheelData?.name,
+                    user: wheelData?.user,
+                    segments: this.items.length
                 });
             }
         }
@@ -2,9 +2,19 @@ use crate::backend::tts::{TTSQueue, TTSQueueItem};
 use crate::ui::{BackendToFrontendMessage, TTSQueueItemUI};
 use log::{error, info};
 use rodio::{Decoder, OutputStream, Sink};
+use std::collections::VecDeque;
 use std::fs::File;
 use std::io::BufReader;
 use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// How often held playback checks whether the hold has expired
+const HOLD_POLL_INTERVAL: Duration = Duration::from_millis(250);
+// Maximum number of sound effects kept while playback is held, oldest are dropped first
+const MAX_HELD_SOUNDS: usize = 10;
+// How often the audio task tries to open the output device again while there is none
+const DEVICE_RETRY_INTERVAL: Duration = Duration::from_secs(5);
 
 // Audio playback request for SFX system
 #[derive(Debug, Clone)]
@@ -14,51 +24,176 @@ pub struct AudioPlaybackRequest {
     pub is_full_path: bool,
 }
 
+// Temporarily holds SFX/TTS playback (e.g. during ad breaks)
+// Held sounds are kept and played once the hold expires
+#[derive(Clone, Default)]
+pub struct PlaybackHold(Arc<Mutex<Option<Instant>>>);
+
+impl PlaybackHold {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Hold playback for the given duration, extending an existing hold if it ends later
+    pub fn hold_for(&self, duration: Duration) {
+        let until = Instant::now() + duration;
+        let mut current = self.0.lock().unwrap();
+        if current.is_none_or(|existing| existing < until) {
+            *current = Some(until);
+        }
+    }
+
+    pub fn is_held(&self) -> bool {
+        self.0
+            .lock()
+            .unwrap()
+            .is_some_and(|until| Instant::now() < until)
+    }
+}
+
+// Messages handled by the audio playback task
+#[derive(Debug, Clone)]
+pub enum AudioMessage {
+    Play(AudioPlaybackRequest),
+    // Try to open the output device again right away
+    RetryDevice,
+}
+
 // Channel for sending audio playback requests
 // Using std::sync::mpsc::Sender wrapped for compatibility with async code
 #[derive(Clone)]
-pub struct AudioPlaybackSender(pub std::sync::mpsc::Sender<AudioPlaybackRequest>);
+pub struct AudioPlaybackSender {
+    tx: std::sync::mpsc::Sender<AudioMessage>,
+    hold: PlaybackHold,
+}
 
 impl AudioPlaybackSender {
+    pub fn new(tx: std::sync::mpsc::Sender<AudioMessage>, hold: PlaybackHold) -> Self {
+        Self { tx, hold }
+    }
+
     pub fn send_sound(
         &self,
         sound: String,
         volume: f32,
-    ) -> Result<(), std::sync::mpsc::SendError<AudioPlaybackRequest>> {
-        self.0.send(AudioPlaybackRequest {
+    ) -> Result<(), std::sync::mpsc::SendError<AudioMessage>> {
+        self.tx.send(AudioMessage::Play(AudioPlaybackRequest {
             file_path: sound,
             volume,
             is_full_path: false,
-        })
+        }))
+    }
+
+    // Ask the audio task to open the output device again, used by the retry button
+    pub fn retry_device(&self) -> Result<(), std::sync::mpsc::SendError<AudioMessage>> {
+        self.tx.send(AudioMessage::RetryDevice)
+    }
+
+    // Playback hold shared with the audio and TTS player tasks
+    pub fn hold(&self) -> &PlaybackHold {
+        &self.hold
     }
 }
 
 // Dedicated audio playback task that owns the OutputStream
 // This solves the Send issue on macOS by keeping OutputStream in a single blocking thread
 // Handles both sound effects and TTS audio files
+// While playback is held, requests are kept and played once the hold ends
+// Without an output device sounds are dropped and the device is opened again
+// every few seconds or when the UI asks for it
 pub fn audio_playback_task(
-    rx: std::sync::mpsc::Receiver<AudioPlaybackRequest>,
-    stream: OutputStream,
+    rx: std::sync::mpsc::Receiver<AudioMessage>,
+    hold: PlaybackHold,
+    backend_tx: tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
 ) {
-    while let Ok(request) = rx.recv() {
-        let audio_path = if request.is_full_path {
-            request.file_path
-        } else {
-            "./assets/sounds/".to_string() + &request.file_path
-        };
+    let mut held: VecDeque<AudioPlaybackRequest> = VecDeque::new();
+    let mut stream = open_output_stream(&backend_tx, true);
+    let mut last_attempt = Instant::now();
 
-        if let Ok(file) = File::open(Path::new(&audio_path)) {
-            if let Ok(source) = Decoder::new(BufReader::new(file)) {
-                let sink = Sink::connect_new(stream.mixer());
-                sink.set_volume(request.volume);
-                sink.append(source);
-                sink.detach();
-            } else {
-                error!("Could not decode audio file: {}", audio_path);
+    loop {
+        match rx.recv_timeout(HOLD_POLL_INTERVAL) {
+            Ok(AudioMessage::Play(request)) if hold.is_held() => {
+                if held.len() >= MAX_HELD_SOUNDS {
+                    held.pop_front();
+                }
+                held.push_back(request);
+            }
+            Ok(AudioMessage::Play(request)) => match &stream {
+                Some(stream) => play_sound(stream, request),
+                None => info!("No audio device, skipping {}", request.file_path),
+            },
+            Ok(AudioMessage::RetryDevice) => {
+                if stream.is_none() {
+                    stream = open_output_stream(&backend_tx, true);
+                    last_attempt = Instant::now();
+                }
+            }
+            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {}
+            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+
+        if stream.is_none() && last_attempt.elapsed() >= DEVICE_RETRY_INTERVAL {
+            stream = open_output_stream(&backend_tx, false);
+            last_attempt = Instant::now();
+        }
+
+        if !held.is_empty() && !hold.is_held() {
+            info!("Playback hold ended, playing {} held sound(s)", held.len());
+            for request in held.drain(..) {
+                if let Some(stream) = &stream {
+                    play_sound(stream, request);
+                }
             }
+        }
+    }
+}
+
+// Open the default output device and tell the UI whether audio works.
+// Automatic retries only report the failure once, `report_failure` is set for the
+// first attempt and the ones asked for from the UI.
+fn open_output_stream(
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+    report_failure: bool,
+) -> Option<OutputStream> {
+    match rodio::OutputStreamBuilder::open_default_stream() {
+        Ok(stream) => {
+            info!("Opened default audio output device");
+            let _ = backend_tx.try_send(BackendToFrontendMessage::AudioDeviceChanged(None));
+            Some(stream)
+        }
+        Err(e) => {
+            if report_failure {
+                error!(
+                    "Failed to open default audio stream, audio is disabled: {}",
+                    e
+                );
+                let _ = backend_tx.try_send(BackendToFrontendMessage::AudioDeviceChanged(Some(
+                    e.to_string(),
+                )));
+            }
+            None
+        }
+    }
+}
+
+fn play_sound(stream: &OutputStream, request: AudioPlaybackRequest) {
+    let audio_path = if request.is_full_path {
+        request.file_path
+    } else {
+        format!("./assets/sounds/{}", request.file_path)
+    };
+
+    if let Ok(file) = File::open(Path::new(&audio_path)) {
+        if let Ok(source) = Decoder::new(BufReader::new(file)) {
+            let sink = Sink::connect_new(stream.mixer());
+            sink.set_volume(request.volume);
+            sink.append(source);
+            sink.detach();
         } else {
-            error!("Could not open audio file: {}", audio_path);
+            error!("Could not decode audio file: {}", audio_path);
         }
+    } else {
+        error!("Could not open audio file: {}", audio_path);
     }
 }
 
@@ -66,10 +201,17 @@ pub fn audio_playback_task(
 pub async fn tts_player_task(
     queue: TTSQueue,
     backend_tx: tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+    hold: PlaybackHold,
 ) {
     info!("TTS player task started");
 
     loop {
+        // Keep messages queued while playback is held
+        if hold.is_held() {
+            tokio::time::sleep(HOLD_POLL_INTERVAL).await;
+            continue;
+        }
+
         // Wait for an item in the queue
         if let Some(item) = queue.pop().await {
             // Check if user is ignored
@@ -0,0 +1,86 @@
+use serde::Serialize;
+use std::collections::VecDeque;
+
+use crate::backend::storage;
+
+/// Entries moved to the archive at once, so the file isn't written for every new entry
+const SPILL_BATCH: usize = 50;
+
+/// Ring buffer keeping the newest entries of a log in memory. Older entries are
+/// appended to `data/<name>.jsonl` in batches, so up to `SPILL_BATCH` more than
+/// `capacity` can be in memory.
+#[derive(Debug)]
+pub struct ArchivedBuffer<T> {
+    name: &'static str,
+    capacity: usize,
+    entries: VecDeque<T>,
+}
+
+impl<T: Serialize> ArchivedBuffer<T> {
+    pub fn new(name: &'static str, capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            name,
+            capacity,
+            entries: VecDeque::with_capacity(capacity + SPILL_BATCH),
+        }
+    }
+
+    /// Add an entry, archiving the oldest ones when the buffer is full
+    pub fn push(&mut self, entry: T) {
+        let spilled = self.push_back(entry);
+        if !spilled.is_empty() {
+            storage::append(self.name, &spilled);
+        }
+    }
+
+    /// Add an entry and return the ones that no longer fit, oldest first
+    fn push_back(&mut self, entry: T) -> Vec<T> {
+        self.entries.push_back(entry);
+        if self.entries.len() < self.capacity + SPILL_BATCH.min(self.capacity) {
+            return Vec::new();
+        }
+        let excess = self.entries.len() - self.capacity;
+        self.entries.drain(..excess).collect()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Entries in memory, oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.entries.iter()
+    }
+
+    pub fn retain(&mut self, keep: impl FnMut(&T) -> bool) {
+        self.entries.retain(keep);
+    }
+
+    /// Forget the entries in memory, archived ones are kept
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_back_spills_oldest() {
+        let mut buffer = ArchivedBuffer::new("test", 100);
+        for entry in 0..149 {
+            assert!(buffer.push_back(entry).is_empty());
+        }
+        assert_eq!(buffer.push_back(149), (0..50).collect::<Vec<_>>());
+        assert_eq!(buffer.iter().next(), Some(&50));
+        assert_eq!(buffer.iter().count(), 100);
+
+        let mut small = ArchivedBuffer::new("test", 2);
+        assert!(small.push_back(1).is_empty());
+        assert!(small.push_back(2).is_empty());
+        assert!(small.push_back(3).is_empty());
+        assert_eq!(small.push_back(4), vec![1, 2]);
+    }
+}
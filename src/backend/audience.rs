@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use std::sync::{LazyLock, Mutex};
+
+/// Viewer count and chatters, polled from Helix while connected
+static AUDIENCE: LazyLock<Mutex<Option<Audience>>> = LazyLock::new(|| Mutex::new(None));
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Audience {
+    /// Viewers of the live stream, `None` while offline
+    pub viewers: Option<u64>,
+    /// Logins of everyone connected to chat, sorted. Empty if the bot can't read chatters.
+    pub chatters: Vec<String>,
+    /// When the live stream started, `None` while offline
+    pub started_at: Option<DateTime<Utc>>,
+    /// Followers of the channel, `None` if they couldn't be loaded
+    pub followers: Option<u64>,
+}
+
+impl Audience {
+    /// Response of `!viewers`
+    pub fn describe(&self) -> String {
+        let chatters = match self.chatters.len() {
+            1 => "1 chatter".to_string(),
+            count => format!("{} chatters", count),
+        };
+        match self.viewers {
+            Some(1) => format!("1 viewer and {} in chat", chatters),
+            Some(viewers) => format!("{} viewers and {} in chat", viewers, chatters),
+            None => format!("The stream is offline, {} in chat", chatters),
+        }
+    }
+}
+
+/// Last polled audience, `None` until the first poll after connecting
+pub fn current() -> Option<Audience> {
+    AUDIENCE.lock().unwrap().clone()
+}
+
+pub fn update(audience: Option<Audience>) {
+    *AUDIENCE.lock().unwrap() = audience;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_audience() {
+        let mut audience = Audience {
+            viewers: Some(42),
+            chatters: vec!["a".to_string(), "b".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(audience.describe(), "42 viewers and 2 chatters in chat");
+        audience.chatters.truncate(1);
+        audience.viewers = None;
+        assert_eq!(
+            audience.describe(),
+            "The stream is offline, 1 chatter in chat"
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use std::sync::{LazyLock, Mutex};
+
+use crate::backend::archive::ArchivedBuffer;
+use crate::backend::config::load_config;
+use crate::backend::locale;
+use crate::backend::twitch::ChatMessageEvent;
+
+/// Storage name of the chat messages that no longer fit in memory
+const ARCHIVE_NAME: &str = "chat_archive";
+
+/// Most recent chat messages, oldest first. The UI asks for them when the chat panel
+/// opens so it isn't empty after switching tabs.
+static CHAT_HISTORY: LazyLock<Mutex<ArchivedBuffer<ChatMessage>>> = LazyLock::new(|| {
+    Mutex::new(ArchivedBuffer::new(
+        ARCHIVE_NAME,
+        load_config().ui.chat_history_size,
+    ))
+});
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ChatMessage {
+    pub message_id: String,
+    pub message_text: String,
+    pub badges: Vec<String>,
+    pub username: String,
+    pub user_id: String,
+    pub color: String,
+    /// When the bot received the message, formatted for display
+    pub timestamp: String,
+}
+
+impl From<ChatMessageEvent> for ChatMessage {
+    fn from(msg: ChatMessageEvent) -> Self {
+        let badges = msg
+            .badges
+            .into_iter()
+            .map(|badge| format!("{}-{}", badge.set_id, badge.id))
+            .collect();
+
+        ChatMessage {
+            message_id: msg.message_id,
+            message_text: msg.message.text,
+            badges,
+            username: msg.chatter_user_login,
+            user_id: msg.chatter_user_id,
+            color: msg.color,
+            timestamp: locale::timestamp(),
+        }
+    }
+}
+
+/// Add a message, archiving the oldest ones when the buffer is full
+pub fn push(message: ChatMessage) {
+    CHAT_HISTORY.lock().unwrap().push(message);
+}
+
+/// Number of chat messages kept in memory, set by `chat_history_size` in `[ui]`
+pub fn capacity() -> usize {
+    CHAT_HISTORY.lock().unwrap().capacity()
+}
+
+/// Copy of the buffered messages, oldest first
+pub fn recent() -> Vec<ChatMessage> {
+    CHAT_HISTORY.lock().unwrap().iter().cloned().collect()
+}
+
+/// Drop a deleted message
+pub fn remove_message(message_id: &str) {
+    CHAT_HISTORY
+        .lock()
+        .unwrap()
+        .retain(|message| message.message_id != message_id);
+}
+
+/// Drop all messages of a banned or timed out user
+pub fn remove_user_messages(user_login: &str) {
+    CHAT_HISTORY
+        .lock()
+        .unwrap()
+        .retain(|message| message.username != user_login);
+}
+
+pub fn clear() {
+    CHAT_HISTORY.lock().unwrap().clear();
+}
@@ -0,0 +1,198 @@
+use super::{
+    Command, CommandAction, CommandContext, CommandPermission, CommandRegistry, CommandResult,
+};
+
+const USAGE: &str = "Usage: !addcmd [--temp] <command> <response>";
+const EDIT_USAGE: &str = "Usage: !editcmd <command> <response>";
+const DELETE_USAGE: &str = "Usage: !delcmd <command>";
+
+/// Handle `!addcmd [--temp] <command> <response>` - lets moderators add a command from chat.
+/// With `--temp` the command only lives for this stream, it's removed when the bot
+/// disconnects and never written to commands.toml.
+///
+/// Returns `None` if the command isn't `!addcmd`, otherwise the result and whether
+/// the registry changed and should be saved.
+pub fn execute_add_command(
+    context: &CommandContext,
+    registry: &mut CommandRegistry,
+) -> Option<(CommandResult, bool)> {
+    if context.command_name != "addcmd" {
+        return None;
+    }
+    if !CommandPermission::Moderator.has_permission(context.identity()) {
+        return Some((CommandResult::PermissionDenied, false));
+    }
+
+    let mut args = context.args.as_slice();
+    let temporary = args.first().is_some_and(|arg| arg == "--temp");
+    if temporary {
+        args = &args[1..];
+    }
+
+    let (response, changed) = match args {
+        [trigger, response @ ..] if !response.is_empty() => {
+            let trigger = trigger.trim_start_matches('!').to_lowercase();
+            if registry.get(&trigger).is_some() {
+                (format!("!{} already exists", trigger), false)
+            } else {
+                let command = Command::new(
+                    trigger.clone(),
+                    format!("Added by {}", context.username()),
+                    CommandPermission::Everyone,
+                    CommandAction::SendMessage {
+                        message: response.join(" "),
+                    },
+                )
+                .with_temporary(temporary);
+                registry.register(command);
+                let response = if temporary {
+                    format!("Added !{} for this stream", trigger)
+                } else {
+                    format!("Added !{}", trigger)
+                };
+                (response, true)
+            }
+        }
+        _ => (USAGE.to_string(), false),
+    };
+
+    Some((reply(context, response), changed))
+}
+
+/// Handle `!editcmd <command> <response>` and `!delcmd <command>` - lets moderators change
+/// or remove commands from chat. Only commands that send a message can be managed this
+/// way, others have to be changed in the COMMANDS tab.
+///
+/// Returns `None` if the command isn't `!editcmd` or `!delcmd`, otherwise the result
+/// and whether the registry changed and should be saved.
+pub fn execute_edit_command(
+    context: &CommandContext,
+    registry: &mut CommandRegistry,
+) -> Option<(CommandResult, bool)> {
+    let deleting = match context.command_name.as_str() {
+        "editcmd" => false,
+        "delcmd" => true,
+        _ => return None,
+    };
+    if !CommandPermission::Moderator.has_permission(context.identity()) {
+        return Some((CommandResult::PermissionDenied, false));
+    }
+
+    let (response, changed) = match (deleting, context.args.as_slice()) {
+        (false, [trigger, response @ ..]) if !response.is_empty() => {
+            let trigger = trigger.trim_start_matches('!').to_lowercase();
+            match registry
+                .get_mut(&trigger)
+                .map(|command| &mut command.action)
+            {
+                Some(CommandAction::SendMessage { message } | CommandAction::Reply { message }) => {
+                    *message = response.join(" ");
+                    (format!("Updated !{}", trigger), true)
+                }
+                Some(_) => (not_simple(&trigger), false),
+                None => (format!("!{} doesn't exist", trigger), false),
+            }
+        }
+        (true, [trigger]) => {
+            let trigger = trigger.trim_start_matches('!').to_lowercase();
+            match registry.get(&trigger).map(|command| &command.action) {
+                Some(CommandAction::SendMessage { .. } | CommandAction::Reply { .. }) => {
+                    registry.unregister(&trigger);
+                    (format!("Removed !{}", trigger), true)
+                }
+                Some(_) => (not_simple(&trigger), false),
+                None => (format!("!{} doesn't exist", trigger), false),
+            }
+        }
+        (false, _) => (EDIT_USAGE.to_string(), false),
+        (true, _) => (DELETE_USAGE.to_string(), false),
+    };
+
+    Some((reply(context, response), changed))
+}
+
+fn reply(context: &CommandContext, response: String) -> CommandResult {
+    CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), response)))
+}
+
+fn not_simple(trigger: &str) -> String {
+    format!(
+        "!{} does more than send a message, change it in the COMMANDS tab",
+        trigger
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::roles::UserIdentity;
+
+    fn run(registry: &mut CommandRegistry, command: &str, args: &[&str]) -> (String, bool) {
+        let mut context = CommandContext::test(command, args);
+        context.identity = UserIdentity {
+            moderator: true,
+            ..Default::default()
+        };
+        let result = execute_add_command(&context, registry)
+            .or_else(|| execute_edit_command(&context, registry));
+        match result {
+            Some((CommandResult::Success(Some(reply)), changed)) => {
+                (reply.replacen("reply:m1:", "", 1), changed)
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_manage_commands_from_chat() {
+        let mut registry = CommandRegistry::new();
+        assert_eq!(
+            run(&mut registry, "addcmd", &["!Hi", "hello"]),
+            ("Added !hi".to_string(), true)
+        );
+        assert_eq!(
+            run(&mut registry, "addcmd", &["hi", "again"]),
+            ("!hi already exists".to_string(), false)
+        );
+        assert_eq!(
+            run(&mut registry, "editcmd", &["hi", "hello", "there"]),
+            ("Updated !hi".to_string(), true)
+        );
+        assert!(matches!(
+            &registry.get("hi").unwrap().action,
+            CommandAction::SendMessage { message } if message == "hello there"
+        ));
+        assert_eq!(
+            run(&mut registry, "editcmd", &["hi"]),
+            (EDIT_USAGE.to_string(), false)
+        );
+        assert_eq!(
+            run(&mut registry, "editcmd", &["bye", "ciao"]),
+            ("!bye doesn't exist".to_string(), false)
+        );
+
+        registry.register(Command::new(
+            "so".into(),
+            String::new(),
+            CommandPermission::Everyone,
+            CommandAction::Shoutout {
+                user: "{args}".to_string(),
+            },
+        ));
+        assert_eq!(
+            run(&mut registry, "delcmd", &["so"]),
+            (not_simple("so"), false)
+        );
+        assert_eq!(
+            run(&mut registry, "delcmd", &["!hi"]),
+            ("Removed !hi".to_string(), true)
+        );
+        assert!(registry.get("hi").is_none());
+
+        let viewer = CommandContext::test("delcmd", &["so"]);
+        assert!(matches!(
+            execute_edit_command(&viewer, &mut registry),
+            Some((CommandResult::PermissionDenied, false))
+        ));
+    }
+}
@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+
+use super::{CommandContext, CommandResult};
+use crate::backend::roles::UserRole;
+use crate::backend::twitch::AdSchedule;
+
+/// Trigger that pushes the next ad break back
+pub const SNOOZE_AD_COMMAND: &str = "snoozead";
+
+/// Check `!snoozead`. Broadcaster only, there are only a few snoozes.
+pub fn check_snooze_ad_command(context: &CommandContext) -> Result<(), CommandResult> {
+    if context.identity().role() < UserRole::Broadcaster {
+        return Err(CommandResult::PermissionDenied);
+    }
+    Ok(())
+}
+
+/// When the next ad break starts and the snoozes left,
+/// e.g. "Next ad in 12 min (90s), 2 snoozes left"
+pub fn format_next_ad(schedule: &AdSchedule, now: DateTime<Utc>) -> String {
+    let next_ad = match schedule.next_ad_at {
+        None => "No ad scheduled".to_string(),
+        Some(next_ad_at) => {
+            let minutes = (next_ad_at - now).num_minutes();
+            let when = if minutes < 1 {
+                "Next ad in less than a minute".to_string()
+            } else {
+                format!("Next ad in {} min", minutes)
+            };
+            if schedule.duration > 0 {
+                format!("{} ({}s)", when, schedule.duration)
+            } else {
+                when
+            }
+        }
+    };
+    let snoozes = match schedule.snooze_count {
+        1 => "1 snooze left".to_string(),
+        count => format!("{} snoozes left", count),
+    };
+    format!("{}, {}", next_ad, snoozes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_format_next_ad() {
+        let now = Utc::now();
+        let mut schedule = AdSchedule {
+            next_ad_at: None,
+            duration: 0,
+            snooze_count: 0,
+            snooze_refresh_at: None,
+        };
+        assert_eq!(
+            format_next_ad(&schedule, now),
+            "No ad scheduled, 0 snoozes left"
+        );
+
+        schedule.next_ad_at = Some(now + Duration::seconds(30));
+        schedule.snooze_count = 1;
+        assert_eq!(
+            format_next_ad(&schedule, now),
+            "Next ad in less than a minute, 1 snooze left"
+        );
+
+        schedule.next_ad_at = Some(now + Duration::seconds(12 * 60 + 5));
+        schedule.duration = 90;
+        schedule.snooze_count = 3;
+        assert_eq!(
+            format_next_ad(&schedule, now),
+            "Next ad in 12 min (90s), 3 snoozes left"
+        );
+    }
+}
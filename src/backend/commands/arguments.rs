@@ -0,0 +1,140 @@
+/// Placeholder for the arguments after the highest `{N}` of the template
+const REST: &str = "args:rest";
+
+/// Placeholder for all the arguments
+const ALL: &str = "args";
+
+/// A `{1}`, `{2|default}`, `{args:rest}` or `{args}` placeholder
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArgPlaceholder<'a> {
+    /// 1-based position of the argument
+    Position(usize, Option<&'a str>),
+    Rest(Option<&'a str>),
+    All(Option<&'a str>),
+}
+
+impl<'a> ArgPlaceholder<'a> {
+    /// Parse the text between the braces
+    fn parse(inner: &'a str) -> Option<Self> {
+        let (name, default) = match inner.split_once('|') {
+            Some((name, default)) => (name, Some(default)),
+            None => (inner, None),
+        };
+        if name == REST {
+            return Some(Self::Rest(default));
+        }
+        if name == ALL {
+            return Some(Self::All(default));
+        }
+        if !name.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        match name.parse::<usize>() {
+            Ok(position) if position > 0 => Some(Self::Position(position, default)),
+            _ => None,
+        }
+    }
+}
+
+/// Placeholders of a template with their byte range
+fn find_placeholders(template: &str) -> Vec<(usize, usize, ArgPlaceholder<'_>)> {
+    let mut placeholders = Vec::new();
+    let mut offset = 0;
+    while let Some(start) = template[offset..].find('{').map(|start| offset + start) {
+        let Some(len) = template[start..].find('}') else {
+            break;
+        };
+        let end = start + len + 1;
+        match ArgPlaceholder::parse(&template[start + 1..end - 1]) {
+            Some(placeholder) => {
+                placeholders.push((start, end, placeholder));
+                offset = end;
+            }
+            None => offset = start + 1,
+        }
+    }
+    placeholders
+}
+
+/// Number of arguments a template needs, the highest `{N}` without a default
+pub fn required_arg_count(template: &str) -> usize {
+    find_placeholders(template)
+        .into_iter()
+        .filter_map(|(_, _, placeholder)| match placeholder {
+            ArgPlaceholder::Position(position, None) => Some(position),
+            _ => None,
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+/// Replace `{1}`, `{2}`... with the arguments, `{args:rest}` with the ones after
+/// the highest position used and `{args}` with all of them. `{2|default}` is used
+/// when there's no second argument. Missing arguments without a default become empty.
+/// Done in one pass, so placeholders typed in the arguments are left as they are.
+pub fn replace_arg_placeholders(template: &str, args: &[String]) -> String {
+    let placeholders = find_placeholders(template);
+    if placeholders.is_empty() {
+        return template.to_string();
+    }
+    let highest = placeholders
+        .iter()
+        .filter_map(|(_, _, placeholder)| match placeholder {
+            ArgPlaceholder::Position(position, _) => Some(*position),
+            ArgPlaceholder::Rest(_) | ArgPlaceholder::All(_) => None,
+        })
+        .max()
+        .unwrap_or_default();
+
+    let mut result = String::with_capacity(template.len());
+    let mut last = 0;
+    for (start, end, placeholder) in placeholders {
+        result.push_str(&template[last..start]);
+        let (value, default) = match placeholder {
+            ArgPlaceholder::Position(position, default) => {
+                (args.get(position - 1).cloned(), default)
+            }
+            ArgPlaceholder::Rest(default) => {
+                let rest = args.get(highest..).unwrap_or_default();
+                ((!rest.is_empty()).then(|| rest.join(" ")), default)
+            }
+            ArgPlaceholder::All(default) => ((!args.is_empty()).then(|| args.join(" ")), default),
+        };
+        result.push_str(&value.unwrap_or_else(|| default.unwrap_or_default().to_string()));
+        last = end;
+    }
+    result.push_str(&template[last..]);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_replace_arg_placeholders() {
+        let template = "{1} hugs {2|everyone}: {args:rest|<3}";
+        assert_eq!(required_arg_count(template), 1);
+        assert_eq!(
+            replace_arg_placeholders(template, &args("yhn chat so much love")),
+            "yhn hugs chat: so much love"
+        );
+        assert_eq!(
+            replace_arg_placeholders(template, &args("yhn")),
+            "yhn hugs everyone: <3"
+        );
+        assert_eq!(
+            replace_arg_placeholders("{user} {0} {x} {3}", &args("a")),
+            "{user} {0} {x} "
+        );
+        assert_eq!(required_arg_count("{user} {0} {x} {3}"), 3);
+        assert_eq!(
+            replace_arg_placeholders("{args} / {1}", &args("{2} {args}")),
+            "{2} {args} / {2}"
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use super::{CommandContext, CommandResult};
+use crate::backend::roles::UserRole;
+use crate::backend::twitch::AutomodSettings;
+
+/// Trigger of the AutoMod command
+pub const AUTOMOD_COMMAND: &str = "automod";
+
+const USAGE: &str = "Usage: !automod [0-4] [minutes]";
+
+/// Highest AutoMod level, filters the most
+const MAX_LEVEL: u8 = 4;
+
+/// Longest a temporary level can be set for
+const MAX_MINUTES: u64 = 24 * 60;
+
+/// Settings to put back once a temporary level runs out, and when
+static PENDING_RESTORE: LazyLock<Mutex<Option<(AutomodSettings, Instant)>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+/// Action of `!automod`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AutomodCommand {
+    /// Reply with the current level
+    Show,
+    /// Set the overall level, only for `minutes` if given
+    SetLevel { level: u8, minutes: Option<u64> },
+}
+
+impl AutomodCommand {
+    /// Parse the arguments of `!automod`, returns the usage text if they don't match
+    pub fn parse(args: &[String]) -> Result<Self, &'static str> {
+        let (level, minutes) = match args {
+            [] => return Ok(Self::Show),
+            [level] => (level, None),
+            [level, minutes] => (level, Some(minutes)),
+            _ => return Err(USAGE),
+        };
+        let level = level
+            .parse::<u8>()
+            .ok()
+            .filter(|level| *level <= MAX_LEVEL)
+            .ok_or(USAGE)?;
+        let minutes = match minutes {
+            Some(minutes) => Some(
+                minutes
+                    .parse::<u64>()
+                    .ok()
+                    .filter(|minutes| (1..=MAX_MINUTES).contains(minutes))
+                    .ok_or(USAGE)?,
+            ),
+            None => None,
+        };
+        Ok(Self::SetLevel { level, minutes })
+    }
+}
+
+/// Check and parse `!automod`. Moderators only.
+pub fn parse_automod_command(context: &CommandContext) -> Result<AutomodCommand, CommandResult> {
+    if context.identity().role() < UserRole::Moderator {
+        return Err(CommandResult::PermissionDenied);
+    }
+
+    AutomodCommand::parse(&context.args).map_err(|usage| {
+        CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), usage)))
+    })
+}
+
+/// Describe the AutoMod level for chat
+pub fn describe_automod(settings: &AutomodSettings) -> String {
+    match settings.overall_level {
+        Some(0) => "AutoMod is off".to_string(),
+        Some(level) => format!("AutoMod level is {}", level),
+        None => "AutoMod uses custom levels per category".to_string(),
+    }
+}
+
+/// Put `previous` back after `duration`. While another temporary level is running
+/// the settings from before it are kept, so the original level comes back.
+pub fn schedule_automod_restore(previous: AutomodSettings, duration: Duration) {
+    let mut pending = PENDING_RESTORE.lock().unwrap();
+    let previous = pending
+        .take()
+        .map(|(settings, _)| settings)
+        .unwrap_or(previous);
+    *pending = Some((previous, Instant::now() + duration));
+}
+
+/// Forget the settings to restore, a level was set for good
+pub fn cancel_automod_restore() {
+    PENDING_RESTORE.lock().unwrap().take();
+}
+
+/// Take the settings to restore once the temporary level ran out
+pub fn take_due_automod_restore() -> Option<AutomodSettings> {
+    let mut pending = PENDING_RESTORE.lock().unwrap();
+    match &*pending {
+        Some((_, restore_at)) if *restore_at <= Instant::now() => {
+            pending.take().map(|(settings, _)| settings)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_automod_command() {
+        assert_eq!(AutomodCommand::parse(&args("")), Ok(AutomodCommand::Show));
+        assert_eq!(
+            AutomodCommand::parse(&args("3")),
+            Ok(AutomodCommand::SetLevel {
+                level: 3,
+                minutes: None
+            })
+        );
+        assert_eq!(
+            AutomodCommand::parse(&args("4 30")),
+            Ok(AutomodCommand::SetLevel {
+                level: 4,
+                minutes: Some(30)
+            })
+        );
+        assert!(AutomodCommand::parse(&args("5")).is_err());
+        assert!(AutomodCommand::parse(&args("high")).is_err());
+        assert!(AutomodCommand::parse(&args("4 0")).is_err());
+        assert!(AutomodCommand::parse(&args("4 30 extra")).is_err());
+    }
+}
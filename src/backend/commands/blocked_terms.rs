@@ -0,0 +1,53 @@
+use super::{CommandContext, CommandResult};
+use crate::backend::roles::UserRole;
+
+/// Trigger of the command that blocks a term
+pub const BLOCK_TERM_COMMAND: &str = "blockterm";
+/// Trigger of the command that removes a blocked term
+pub const UNBLOCK_TERM_COMMAND: &str = "unblockterm";
+
+/// Change to the channel's blocked terms, from chat or the MODERATION tab
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockedTermCommand {
+    Add(String),
+    Remove(String),
+}
+
+impl BlockedTermCommand {
+    /// Check the term of `!blockterm` or `!unblockterm`. Twitch accepts 2 to 500
+    /// characters and `*` as a wildcard.
+    pub fn parse(command: &str, text: &str) -> Result<Self, &'static str> {
+        let text = text.trim();
+        if !(2..=500).contains(&text.chars().count()) {
+            return Err("Usage: !blockterm <word or phrase> | !unblockterm <word or phrase>");
+        }
+
+        if command == UNBLOCK_TERM_COMMAND {
+            Ok(Self::Remove(text.to_string()))
+        } else {
+            Ok(Self::Add(text.to_string()))
+        }
+    }
+
+    /// Confirmation once the change went through
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Add(text) => format!("Blocked \"{}\"", text),
+            Self::Remove(text) => format!("Unblocked \"{}\"", text),
+        }
+    }
+}
+
+/// Check and parse `!blockterm` and `!unblockterm`. Returns the change to make,
+/// or the result to report when it can't run. Moderators only.
+pub fn parse_blocked_term_command(
+    context: &CommandContext,
+) -> Result<BlockedTermCommand, CommandResult> {
+    if context.identity().role() < UserRole::Moderator {
+        return Err(CommandResult::PermissionDenied);
+    }
+
+    BlockedTermCommand::parse(&context.command_name, &context.args.join(" ")).map_err(|usage| {
+        CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), usage)))
+    })
+}
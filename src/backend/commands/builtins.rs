@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use super::{CommandContext, CommandResult};
+use crate::backend::stats::{StatsCounters, STATS};
+
+/// How long a formatted response is reused before stats are read again
+const RESPONSE_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Number of entries shown in leaderboards
+const TOP_LIMIT: usize = 5;
+
+static RESPONSE_CACHE: LazyLock<Mutex<HashMap<String, (Instant, String)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Execute a built-in command (!stats, !topchatters, !topemotes, !viewers, !firsts).
+/// Returns `None` if the command isn't a builtin. Commands from commands.toml take precedence.
+pub fn execute_builtin(context: &CommandContext) -> Option<CommandResult> {
+    let response = match context.command_name.as_str() {
+        "stats" => cached("stats", format_stats),
+        "topchatters" => {
+            if context
+                .args
+                .first()
+                .is_some_and(|arg| arg.eq_ignore_ascii_case("all"))
+            {
+                cached("topchatters:all", || format_top_chatters(true))
+            } else {
+                cached("topchatters", || format_top_chatters(false))
+            }
+        }
+        "topemotes" => {
+            if context
+                .args
+                .first()
+                .is_some_and(|arg| arg.eq_ignore_ascii_case("all"))
+            {
+                cached("topemotes:all", || format_top_emotes(true))
+            } else {
+                cached("topemotes", || format_top_emotes(false))
+            }
+        }
+        "firsts" => format_top_firsts(),
+        "viewers" => match crate::backend::audience::current() {
+            Some(audience) => audience.describe(),
+            None => "Viewer count isn't available yet".to_string(),
+        },
+        _ => return None,
+    };
+
+    Some(CommandResult::Success(Some(format!(
+        "reply:{}:{}",
+        context.message_id(),
+        response
+    ))))
+}
+
+fn cached(key: &str, build: impl FnOnce() -> String) -> String {
+    let mut cache = RESPONSE_CACHE.lock().unwrap();
+    if let Some((built_at, response)) = cache.get(key) {
+        if built_at.elapsed() < RESPONSE_CACHE_TTL {
+            return response.clone();
+        }
+    }
+
+    let response = build();
+    cache.insert(key.to_string(), (Instant::now(), response.clone()));
+    response
+}
+
+fn format_stats() -> String {
+    let stats = STATS.lock().unwrap();
+    let mut parts = vec![
+        format!("Today: {} messages", stats.all_time.messages_today()),
+        format!(
+            "This stream: {} messages from {} chatters",
+            stats.session.total_messages,
+            stats.session.chatters.len()
+        ),
+        format!("All time: {} messages", stats.all_time.total_messages),
+    ];
+
+    if let Some((emote, count)) = StatsCounters::top(&stats.all_time.emotes, 1).first() {
+        parts.push(format!("Top emote: {} ({})", emote, count));
+    }
+    if let Some((sound, count)) = StatsCounters::top(&stats.all_time.sounds, 1).first() {
+        parts.push(format!("Top sound: !{} ({})", sound, count));
+    }
+
+    parts.join(" | ")
+}
+
+fn format_top_chatters(all_time: bool) -> String {
+    let stats = STATS.lock().unwrap();
+    let (label, counters) = if all_time {
+        ("of all time", &stats.all_time)
+    } else {
+        ("this stream", &stats.session)
+    };
+
+    let top = StatsCounters::top(&counters.chatters, TOP_LIMIT);
+    if top.is_empty() {
+        return format!("No chatters {} yet", label);
+    }
+
+    let ranking: Vec<String> = top
+        .iter()
+        .enumerate()
+        .map(|(i, (user, count))| format!("{}. {} ({})", i + 1, user, count))
+        .collect();
+
+    format!("Top chatters {}: {}", label, ranking.join(", "))
+}
+
+fn format_top_emotes(all_time: bool) -> String {
+    let stats = STATS.lock().unwrap();
+    let (label, counters) = if all_time {
+        ("of all time", &stats.all_time)
+    } else {
+        ("this stream", &stats.session)
+    };
+
+    let top = StatsCounters::top(&counters.emotes, TOP_LIMIT);
+    if top.is_empty() {
+        return format!("No emotes used {} yet", label);
+    }
+
+    let ranking: Vec<String> = top
+        .iter()
+        .enumerate()
+        .map(|(i, (emote, count))| format!("{}. {} ({})", i + 1, emote, count))
+        .collect();
+
+    format!("Top emotes {}: {}", label, ranking.join(", "))
+}
+
+fn format_top_firsts() -> String {
+    let top = crate::backend::firsts::leaderboard(TOP_LIMIT);
+    if top.is_empty() {
+        return "Nobody was first in chat yet".to_string();
+    }
+
+    let ranking: Vec<String> = top
+        .iter()
+        .enumerate()
+        .map(|(i, (user, count))| format!("{}. {} ({})", i + 1, user, count))
+        .collect();
+
+    format!("Most firsts: {}", ranking.join(", "))
+}
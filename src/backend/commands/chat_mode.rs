@@ -0,0 +1,168 @@
+use serde_json::json;
+
+use super::{CommandContext, CommandResult};
+use crate::backend::roles::UserRole;
+
+/// Triggers of the chat mode commands
+pub const CHAT_MODE_COMMANDS: [&str; 4] = ["slow", "emoteonly", "subonly", "followersonly"];
+
+/// Seconds between messages when `!slow` is used without a number
+const DEFAULT_SLOW_SECONDS: u32 = 30;
+/// Longest follow time Twitch accepts for followers-only mode (3 months)
+const MAX_FOLLOWER_MINUTES: u32 = 129_600;
+
+/// Chat setting changed by `!slow`, `!emoteonly`, `!subonly` or `!followersonly`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatModeCommand {
+    /// Seconds between messages, `None` turns slow mode off
+    Slow(Option<u32>),
+    EmoteOnly(bool),
+    SubOnly(bool),
+    /// Minutes viewers have to follow before chatting, `None` turns the mode off
+    FollowersOnly(Option<u32>),
+}
+
+impl ChatModeCommand {
+    /// Parse a chat mode command, returns the usage text if the arguments don't match
+    pub fn parse(command: &str, args: &[String]) -> Result<Self, &'static str> {
+        let arg = args.first().map(|arg| arg.to_lowercase());
+        let arg = arg.as_deref();
+
+        match command {
+            "slow" => match arg {
+                None | Some("on") => Ok(Self::Slow(Some(DEFAULT_SLOW_SECONDS))),
+                Some("off") => Ok(Self::Slow(None)),
+                Some(seconds) => match seconds.trim_end_matches('s').parse() {
+                    Ok(seconds) if (3..=120).contains(&seconds) => Ok(Self::Slow(Some(seconds))),
+                    _ => Err("Usage: !slow <3-120 seconds> | !slow off"),
+                },
+            },
+            "emoteonly" => parse_switch(arg)
+                .map(Self::EmoteOnly)
+                .ok_or("Usage: !emoteonly on|off"),
+            "subonly" => parse_switch(arg)
+                .map(Self::SubOnly)
+                .ok_or("Usage: !subonly on|off"),
+            "followersonly" => match arg {
+                None | Some("on") => Ok(Self::FollowersOnly(Some(0))),
+                Some("off") => Ok(Self::FollowersOnly(None)),
+                Some(duration) => parse_minutes(duration)
+                    .filter(|minutes| *minutes <= MAX_FOLLOWER_MINUTES)
+                    .map(|minutes| Self::FollowersOnly(Some(minutes)))
+                    .ok_or(
+                        "Usage: !followersonly <duration like 10m, 1h, 7d> | !followersonly off",
+                    ),
+            },
+            _ => Err("Unknown chat mode"),
+        }
+    }
+
+    /// Body of the Helix chat settings update
+    pub fn settings(&self) -> serde_json::Value {
+        match self {
+            Self::Slow(Some(seconds)) => {
+                json!({ "slow_mode": true, "slow_mode_wait_time": seconds })
+            }
+            Self::Slow(None) => json!({ "slow_mode": false }),
+            Self::EmoteOnly(enabled) => json!({ "emote_mode": enabled }),
+            Self::SubOnly(enabled) => json!({ "subscriber_mode": enabled }),
+            Self::FollowersOnly(Some(minutes)) => {
+                json!({ "follower_mode": true, "follower_mode_duration": minutes })
+            }
+            Self::FollowersOnly(None) => json!({ "follower_mode": false }),
+        }
+    }
+
+    /// Confirmation once the setting was changed
+    pub fn describe(&self) -> String {
+        let switch = |enabled: bool| if enabled { "on" } else { "off" };
+        match self {
+            Self::Slow(Some(seconds)) => format!("Slow mode is on ({}s)", seconds),
+            Self::Slow(None) => "Slow mode is off".to_string(),
+            Self::EmoteOnly(enabled) => format!("Emote-only mode is {}", switch(*enabled)),
+            Self::SubOnly(enabled) => format!("Subscriber-only mode is {}", switch(*enabled)),
+            Self::FollowersOnly(Some(0)) => "Followers-only mode is on".to_string(),
+            Self::FollowersOnly(Some(minutes)) => {
+                format!("Followers-only mode is on ({} minutes)", minutes)
+            }
+            Self::FollowersOnly(None) => "Followers-only mode is off".to_string(),
+        }
+    }
+}
+
+fn parse_switch(arg: Option<&str>) -> Option<bool> {
+    match arg? {
+        "on" => Some(true),
+        "off" => Some(false),
+        _ => None,
+    }
+}
+
+/// Parse a duration like "10m", "2h", "7d", "1w" or "30" (minutes) into minutes
+fn parse_minutes(duration: &str) -> Option<u32> {
+    let split = duration
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(duration.len());
+    let (number, unit) = duration.split_at(split);
+    let number: u32 = number.parse().ok()?;
+    let factor = match unit {
+        "" | "m" | "min" => 1,
+        "h" => 60,
+        "d" => 60 * 24,
+        "w" => 60 * 24 * 7,
+        _ => return None,
+    };
+    number.checked_mul(factor)
+}
+
+/// Check and parse a chat mode command. Returns the setting to change, or the result
+/// to report when it can't run. Moderators only.
+pub fn parse_chat_mode_command(context: &CommandContext) -> Result<ChatModeCommand, CommandResult> {
+    if context.identity().role() < UserRole::Moderator {
+        return Err(CommandResult::PermissionDenied);
+    }
+
+    ChatModeCommand::parse(&context.command_name, &context.args).map_err(|usage| {
+        CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), usage)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(command: &str, args: &str) -> Result<ChatModeCommand, &'static str> {
+        let args: Vec<String> = args.split_whitespace().map(String::from).collect();
+        ChatModeCommand::parse(command, &args)
+    }
+
+    #[test]
+    fn test_parse_chat_mode_command() {
+        assert_eq!(parse("slow", "45"), Ok(ChatModeCommand::Slow(Some(45))));
+        assert_eq!(
+            parse("slow", ""),
+            Ok(ChatModeCommand::Slow(Some(DEFAULT_SLOW_SECONDS)))
+        );
+        assert_eq!(parse("slow", "off"), Ok(ChatModeCommand::Slow(None)));
+        assert!(parse("slow", "500").is_err());
+        assert_eq!(
+            parse("emoteonly", "ON"),
+            Ok(ChatModeCommand::EmoteOnly(true))
+        );
+        assert_eq!(parse("subonly", "off"), Ok(ChatModeCommand::SubOnly(false)));
+        assert!(parse("subonly", "").is_err());
+        assert_eq!(
+            parse("followersonly", "10m"),
+            Ok(ChatModeCommand::FollowersOnly(Some(10)))
+        );
+        assert_eq!(
+            parse("followersonly", "1w"),
+            Ok(ChatModeCommand::FollowersOnly(Some(10080)))
+        );
+        assert_eq!(
+            parse("followersonly", "off"),
+            Ok(ChatModeCommand::FollowersOnly(None))
+        );
+        assert!(parse("followersonly", "1y").is_err());
+    }
+}
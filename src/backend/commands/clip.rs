@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use super::{CommandContext, CommandPermission, CommandResult};
+
+/// Trigger of the clip command
+pub const CLIP_COMMAND: &str = "clip";
+
+/// When the last clip was created, the cooldown is shared by everyone
+/// since clip creation is rate limited per channel
+static LAST_CLIP: LazyLock<Mutex<Option<Instant>>> = LazyLock::new(|| Mutex::new(None));
+
+/// Settings for the built-in `!clip` command
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ClipCommandConfig {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Who may create clips
+    #[serde(default = "default_permission")]
+    pub permission: CommandPermission,
+    /// Seconds between clips
+    #[serde(default = "default_cooldown")]
+    pub cooldown: u64,
+    /// Clip the delayed stream viewers see instead of the live feed
+    #[serde(default)]
+    pub has_delay: bool,
+    /// Reply once the clip is created, `{user}`, `{url}` and `{edit_url}` are replaced
+    #[serde(default = "default_message")]
+    pub message: String,
+}
+
+impl Default for ClipCommandConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            permission: default_permission(),
+            cooldown: default_cooldown(),
+            has_delay: false,
+            message: default_message(),
+        }
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_permission() -> CommandPermission {
+    CommandPermission::Subscriber
+}
+
+fn default_cooldown() -> u64 {
+    60
+}
+
+fn default_message() -> String {
+    "{user} clipped it: {url}".to_string()
+}
+
+/// Check whether `!clip` may run now and start its cooldown.
+/// Returns `NotFound` when the command is disabled so a sound or custom command can take over.
+pub fn check_clip_command(
+    context: &CommandContext,
+    config: &ClipCommandConfig,
+) -> Result<(), CommandResult> {
+    if !config.enabled {
+        return Err(CommandResult::NotFound);
+    }
+    if !config.permission.has_permission(context.identity()) {
+        return Err(CommandResult::PermissionDenied);
+    }
+
+    let mut last_clip = LAST_CLIP.lock().unwrap();
+    let cooldown = Duration::from_secs(config.cooldown);
+    if let Some(elapsed) = last_clip.map(|at| at.elapsed()) {
+        if elapsed < cooldown {
+            return Err(CommandResult::OnCooldown(
+                (cooldown - elapsed).as_secs().max(1),
+            ));
+        }
+    }
+    *last_clip = Some(Instant::now());
+    Ok(())
+}
+
+/// Let the next `!clip` run right away, e.g. after clip creation failed
+pub fn reset_clip_cooldown() {
+    *LAST_CLIP.lock().unwrap() = None;
+}
+
+/// Fill in the reply template
+pub fn format_clip_message(template: &str, user: &str, url: &str, edit_url: &str) -> String {
+    template
+        .replace("{user}", user)
+        .replace("{url}", url)
+        .replace("{edit_url}", edit_url)
+}
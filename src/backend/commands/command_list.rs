@@ -0,0 +1,94 @@
+use super::{CommandContext, CommandPermission, CommandRegistry, CommandResult};
+
+/// Trigger of the built-in command list
+pub const COMMANDS_COMMAND: &str = "commands";
+
+/// Longest list sent in one message, Twitch cuts chat messages at 500 characters
+const MAX_PAGE_LENGTH: usize = 400;
+
+/// Reply to `!commands [page]` with the commands the user is allowed to run.
+/// Follower-only commands are listed for everyone, looking up the follow date for
+/// every `!commands` isn't worth it.
+pub fn list_commands(context: &CommandContext, registry: &CommandRegistry) -> CommandResult {
+    let mut triggers: Vec<String> = registry
+        .list()
+        .into_iter()
+        .filter(|command| command.enabled && !command.pending_approval)
+        .filter(|command| {
+            command
+                .user_override(context.username())
+                .unwrap_or_else(|| {
+                    matches!(command.permission, CommandPermission::Follower { .. })
+                        || command.permission.has_permission(context.identity())
+                })
+        })
+        .map(|command| format!("!{}", command.trigger))
+        .collect();
+    triggers.sort();
+
+    let page = context
+        .args
+        .first()
+        .and_then(|arg| arg.parse::<usize>().ok())
+        .unwrap_or(1);
+    let message = format_page(&paginate(&triggers, MAX_PAGE_LENGTH), page);
+    CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), message)))
+}
+
+/// Split the triggers into pages of at most `max_length` characters
+fn paginate(triggers: &[String], max_length: usize) -> Vec<String> {
+    let mut pages: Vec<String> = Vec::new();
+    for trigger in triggers {
+        match pages.last_mut() {
+            Some(page) if page.len() + 2 + trigger.len() <= max_length => {
+                page.push_str(", ");
+                page.push_str(trigger);
+            }
+            _ => pages.push(trigger.clone()),
+        }
+    }
+    pages
+}
+
+/// Page `page` (starting at 1) of the list, with a hint how to get the next one
+fn format_page(pages: &[String], page: usize) -> String {
+    if pages.is_empty() {
+        return "There are no commands you can use".to_string();
+    }
+    let page = page.clamp(1, pages.len());
+    if pages.len() == 1 {
+        return format!("Commands: {}", pages[0]);
+    }
+
+    let mut message = format!("Commands ({}/{}): {}", page, pages.len(), pages[page - 1]);
+    if page < pages.len() {
+        message.push_str(&format!(" | !{} {} for more", COMMANDS_COMMAND, page + 1));
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paginate() {
+        let triggers: Vec<String> = ["!dice", "!hug", "!lurk", "!socials"]
+            .iter()
+            .map(|trigger| trigger.to_string())
+            .collect();
+        let pages = paginate(&triggers, 12);
+        assert_eq!(pages, vec!["!dice, !hug", "!lurk", "!socials"]);
+
+        assert_eq!(
+            format_page(&pages, 1),
+            "Commands (1/3): !dice, !hug | !commands 2 for more"
+        );
+        assert_eq!(format_page(&pages, 9), "Commands (3/3): !socials");
+        assert_eq!(
+            format_page(&paginate(&triggers, 100), 1),
+            "Commands: !dice, !hug, !lurk, !socials"
+        );
+        assert_eq!(format_page(&[], 1), "There are no commands you can use");
+    }
+}
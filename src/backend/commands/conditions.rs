@@ -0,0 +1,180 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+use super::parser::build_regex;
+use super::CommandContext;
+use crate::backend::counters;
+
+/// Check made by the If command action before picking which actions to run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Condition {
+    /// An argument matches a case-insensitive regex, `arg` 0 is all arguments
+    /// together. A missing argument never matches.
+    ArgMatches {
+        #[serde(default = "default_arg")]
+        arg: usize,
+        pattern: String,
+        /// Compiled regex, `None` if the pattern is invalid
+        #[serde(skip)]
+        compiled: OnceLock<Option<Regex>>,
+    },
+    /// The user has a chat badge, e.g. "subscriber", "vip", "moderator" or "founder"
+    HasBadge {
+        badge: String,
+    },
+    /// Compare a counter with a value
+    Counter {
+        name: String,
+        compare: Comparison,
+        value: i64,
+    },
+    Not(Box<Condition>),
+    /// Every condition is true
+    All(Vec<Condition>),
+    /// At least one condition is true
+    Any(Vec<Condition>),
+}
+
+fn default_arg() -> usize {
+    1
+}
+
+/// How a counter is compared in a condition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Comparison {
+    #[serde(rename = "==")]
+    Equal,
+    #[serde(rename = "!=")]
+    NotEqual,
+    #[serde(rename = "<")]
+    Less,
+    #[serde(rename = "<=")]
+    LessOrEqual,
+    #[serde(rename = ">")]
+    Greater,
+    #[serde(rename = ">=")]
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    pub fn compare(self, left: i64, right: i64) -> bool {
+        match self {
+            Comparison::Equal => left == right,
+            Comparison::NotEqual => left != right,
+            Comparison::Less => left < right,
+            Comparison::LessOrEqual => left <= right,
+            Comparison::Greater => left > right,
+            Comparison::GreaterOrEqual => left >= right,
+        }
+    }
+}
+
+impl Condition {
+    /// Check the condition for a command run
+    pub fn evaluate(&self, context: &CommandContext) -> bool {
+        let badges: Vec<&str> = context
+            .message
+            .badges
+            .iter()
+            .map(|badge| badge.set_id.as_str())
+            .collect();
+        self.evaluate_with(&context.args, &badges, &counters::get)
+    }
+
+    fn evaluate_with(
+        &self,
+        args: &[String],
+        badges: &[&str],
+        counter: &dyn Fn(&str) -> i64,
+    ) -> bool {
+        match self {
+            Condition::ArgMatches {
+                arg,
+                pattern,
+                compiled,
+            } => {
+                let text = match arg {
+                    0 if args.is_empty() => return false,
+                    0 => args.join(" "),
+                    n => match args.get(n - 1) {
+                        Some(arg) => arg.clone(),
+                        None => return false,
+                    },
+                };
+                compiled
+                    .get_or_init(|| build_regex(pattern).ok())
+                    .as_ref()
+                    .is_some_and(|regex| regex.is_match(&text))
+            }
+            Condition::HasBadge { badge } => badges
+                .iter()
+                .any(|set_id| set_id.eq_ignore_ascii_case(badge)),
+            Condition::Counter {
+                name,
+                compare,
+                value,
+            } => compare.compare(counter(name), *value),
+            Condition::Not(condition) => !condition.evaluate_with(args, badges, counter),
+            Condition::All(conditions) => conditions
+                .iter()
+                .all(|condition| condition.evaluate_with(args, badges, counter)),
+            Condition::Any(conditions) => conditions
+                .iter()
+                .any(|condition| condition.evaluate_with(args, badges, counter)),
+        }
+    }
+
+    /// Check the regexes, returns why the condition can't be used
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Condition::ArgMatches { pattern, .. } => build_regex(pattern)
+                .map(|_| ())
+                .map_err(|e| format!("Invalid regex \"{}\": {}", pattern, e)),
+            Condition::Not(condition) => condition.validate(),
+            Condition::All(conditions) | Condition::Any(conditions) => {
+                conditions.iter().try_for_each(Condition::validate)
+            }
+            Condition::HasBadge { .. } | Condition::Counter { .. } => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_conditions() {
+        let args = vec!["@friend".to_string(), "hi".to_string()];
+        let counter = |name: &str| if name == "deaths" { 10 } else { 0 };
+        let check = |toml: &str| {
+            let condition: Condition = toml::from_str::<toml::Table>(toml)
+                .unwrap()
+                .remove("condition")
+                .unwrap()
+                .try_into()
+                .unwrap();
+            condition.evaluate_with(&args, &["subscriber"], &counter)
+        };
+
+        assert!(check(
+            r#"condition = { ArgMatches = { pattern = "^@?\\w+$" } }"#
+        ));
+        assert!(!check(
+            r#"condition = { ArgMatches = { arg = 3, pattern = "" } }"#
+        ));
+        assert!(check(
+            r#"condition = { ArgMatches = { arg = 0, pattern = "friend hi" } }"#
+        ));
+        assert!(check(
+            r#"condition = { HasBadge = { badge = "Subscriber" } }"#
+        ));
+        assert!(check(
+            r#"condition = { Counter = { name = "deaths", compare = ">=", value = 10 } }"#
+        ));
+        assert!(check(
+            r#"condition = { Not = { Any = [{ HasBadge = { badge = "vip" } }, { Counter = { name = "wins", compare = "!=", value = 0 } }] } }"#
+        ));
+    }
+}
@@ -1,3 +1,7 @@
+use chrono::{DateTime, Utc};
+
+use super::{ShoutoutTarget, UserInfo, ACTION_SEPARATOR};
+use crate::backend::roles::UserIdentity;
 use crate::backend::twitch::ChatMessageEvent;
 
 /// Context provided to command execution
@@ -9,31 +13,68 @@ pub struct CommandContext {
     pub command_name: String,
     /// Arguments passed to the command
     pub args: Vec<String>,
+    /// Roles of the user who sent the command
+    pub identity: UserIdentity,
+    /// Triggered by a keyword in the message instead of the ! prefix
+    pub keyword: bool,
+    /// When the user followed the channel, only looked up for follower-only commands
+    pub followed_at: Option<DateTime<Utc>>,
+    /// Channel info of the user in the first argument, only looked up for commands
+    /// using `{target_game}` or `{target_clip}`
+    pub shoutout_target: Option<ShoutoutTarget>,
+    /// Pronouns, account and follow date of the user, only looked up for commands
+    /// using `{pronouns}`, `{accountage}` or `{followage}`
+    pub user_info: Option<UserInfo>,
 }
 
 impl CommandContext {
-    /// Create a new command context
-    pub fn new(message: ChatMessageEvent, command_name: String, args: Vec<String>) -> Self {
+    /// Create a new command context, arguments can't contain the action separator
+    pub fn new(
+        message: ChatMessageEvent,
+        command_name: String,
+        args: Vec<String>,
+        identity: UserIdentity,
+    ) -> Self {
+        let args = args
+            .into_iter()
+            .map(|arg| arg.replace(ACTION_SEPARATOR, ""))
+            .collect();
         Self {
             message,
             command_name,
             args,
+            identity,
+            keyword: false,
+            followed_at: None,
+            shoutout_target: None,
+            user_info: None,
         }
     }
 
+    /// Mark the command as triggered by a keyword
+    pub fn from_keyword(mut self) -> Self {
+        self.keyword = true;
+        self
+    }
+
     /// Get the username of the person who sent the command
     pub fn username(&self) -> &str {
         &self.message.chatter_user_login
     }
 
+    /// Nickname of the person who sent the command, or their login if they have none
+    pub fn display_name(&self) -> String {
+        crate::backend::users::display_name(self.username())
+    }
+
     /// Get the user ID of the person who sent the command
     pub fn user_id(&self) -> &str {
         &self.message.chatter_user_id
     }
 
-    /// Get the user's badges
-    pub fn badges(&self) -> &[crate::backend::twitch::Badge] {
-        &self.message.badges
+    /// Get the user's roles
+    pub fn identity(&self) -> &UserIdentity {
+        &self.identity
     }
 
     /// Get the message text
@@ -46,12 +87,60 @@ impl CommandContext {
         &self.message.message_id
     }
 
-    /// Replace placeholders in a string with context values
+    /// Whether the command was whispered to the bot instead of sent in chat
+    pub fn is_whisper(&self) -> bool {
+        self.message.message_type == "whisper"
+    }
+
+    /// Whether the command was run by redeeming a channel point reward
+    pub fn is_redemption(&self) -> bool {
+        self.message.message_type == "channel_points_redemption"
+    }
+
+    /// Replace placeholders in a string with context values.
+    /// `{json:...}` placeholders are resolved first and the arguments last, so
+    /// placeholders typed by the viewer are never replaced.
     pub fn replace_placeholders(&self, template: &str) -> String {
-        template
-            .replace("{user}", self.username())
+        let template = super::json_vars::replace_json_placeholders(template);
+        let template = match &self.shoutout_target {
+            Some(target) => target.replace_placeholders(&template),
+            None => template,
+        };
+        let template = match &self.user_info {
+            Some(info) => info.replace_placeholders(&template, Utc::now()),
+            None => template,
+        };
+        let template = template
+            .replace("{user}", &self.display_name())
             .replace("{userid}", self.user_id())
-            .replace("{args}", &self.args.join(" "))
             .replace("{command}", &self.command_name)
+            .replace(
+                "{first_chatter}",
+                &crate::backend::firsts::first_chatter().unwrap_or_else(|| "nobody".to_string()),
+            );
+        super::arguments::replace_arg_placeholders(&template, &self.args)
+    }
+}
+
+#[cfg(test)]
+impl CommandContext {
+    /// `!<command> <args>` sent by a viewer in chat, message ID "m1"
+    pub fn test(command: &str, args: &[&str]) -> Self {
+        let message: ChatMessageEvent = serde_json::from_value(serde_json::json!({
+            "broadcaster_user_id": "1",
+            "broadcaster_user_login": "streamer",
+            "broadcaster_user_name": "Streamer",
+            "chatter_user_id": "2",
+            "chatter_user_login": "viewer",
+            "chatter_user_name": "Viewer",
+            "message_id": "m1",
+            "message": { "text": format!("!{} {}", command, args.join(" ")), "fragments": [] },
+            "color": "",
+            "badges": [],
+            "message_type": "text"
+        }))
+        .unwrap();
+        let args = args.iter().map(|arg| arg.to_string()).collect();
+        Self::new(message, command.to_string(), args, UserIdentity::default())
     }
 }
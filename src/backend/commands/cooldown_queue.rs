@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use super::CommandContext;
+
+/// Commands used during a cooldown, waiting to run once it's over
+static QUEUE: LazyLock<Mutex<CommandQueue>> = LazyLock::new(Default::default);
+
+/// Cooldown shared by all commands from commands.toml, and what happens to commands
+/// used during a cooldown
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CommandCooldownConfig {
+    /// Seconds after any command before the next one can run (0 = no global cooldown).
+    /// Commands with `mods_bypass_cooldown` don't wait for moderators.
+    #[serde(default)]
+    pub global_cooldown: u64,
+    /// Run commands used during a cooldown once it's over instead of dropping them
+    #[serde(default)]
+    pub queue: bool,
+    /// Most commands waiting at once, more are dropped like without the queue
+    #[serde(default = "default_max_queued")]
+    pub max_queued: usize,
+}
+
+impl Default for CommandCooldownConfig {
+    fn default() -> Self {
+        Self {
+            global_cooldown: 0,
+            queue: false,
+            max_queued: default_max_queued(),
+        }
+    }
+}
+
+fn default_max_queued() -> usize {
+    10
+}
+
+/// Why a command used during a cooldown was dropped instead of queued
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QueueRejection {
+    /// `max_queued` commands are already waiting
+    Full,
+    /// The same viewer already has this command waiting
+    AlreadyQueued,
+}
+
+/// Commands waiting for their cooldown, run in the order they were used
+#[derive(Default)]
+pub struct CommandQueue {
+    waiting: VecDeque<(Instant, CommandContext)>,
+}
+
+impl CommandQueue {
+    /// Queue a command to run at `ready_at`, unless `max_queued` are waiting or the
+    /// viewer already queued it, so spamming a command runs it only once
+    pub fn push(
+        &mut self,
+        context: CommandContext,
+        ready_at: Instant,
+        max_queued: usize,
+    ) -> Result<(), QueueRejection> {
+        let queued = self.waiting.iter().any(|(_, waiting)| {
+            waiting.user_id() == context.user_id() && waiting.command_name == context.command_name
+        });
+        if queued {
+            return Err(QueueRejection::AlreadyQueued);
+        }
+        if self.waiting.len() >= max_queued {
+            return Err(QueueRejection::Full);
+        }
+        self.waiting.push_back((ready_at, context));
+        Ok(())
+    }
+
+    /// Take the commands whose cooldown is over
+    pub fn take_ready(&mut self, now: Instant) -> Vec<CommandContext> {
+        let (ready, waiting) = std::mem::take(&mut self.waiting)
+            .into_iter()
+            .partition(|(ready_at, _)| *ready_at <= now);
+        self.waiting = waiting;
+        ready.into_iter().map(|(_, context)| context).collect()
+    }
+}
+
+/// Queue a command until its cooldown of `remaining` seconds is over
+pub fn queue_command(
+    context: CommandContext,
+    remaining: u64,
+    max_queued: usize,
+) -> Result<(), QueueRejection> {
+    let ready_at = Instant::now() + Duration::from_secs(remaining);
+    QUEUE.lock().unwrap().push(context, ready_at, max_queued)
+}
+
+/// Queued commands whose cooldown is over, oldest first
+pub fn take_ready_commands() -> Vec<CommandContext> {
+    QUEUE.lock().unwrap().take_ready(Instant::now())
+}
+
+/// Drop the queued commands, e.g. when the bot disconnects
+pub fn clear_queued_commands() {
+    QUEUE.lock().unwrap().waiting.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(command: &str) -> CommandContext {
+        CommandContext::test(command, &[])
+    }
+
+    #[test]
+    fn test_command_queue() {
+        let now = Instant::now();
+        let mut queue = CommandQueue::default();
+        assert_eq!(
+            queue.push(context("a"), now + Duration::from_secs(5), 2),
+            Ok(())
+        );
+        assert_eq!(
+            queue.push(context("a"), now, 2),
+            Err(QueueRejection::AlreadyQueued)
+        );
+        assert_eq!(queue.push(context("b"), now, 2), Ok(()));
+        assert_eq!(queue.push(context("c"), now, 2), Err(QueueRejection::Full));
+
+        let ready = queue.take_ready(now);
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].command_name, "b");
+        assert_eq!(queue.waiting.len(), 1);
+
+        let ready = queue.take_ready(now + Duration::from_secs(5));
+        assert_eq!(ready[0].command_name, "a");
+        assert_eq!(queue.waiting.len(), 0);
+    }
+}
@@ -0,0 +1,55 @@
+use super::{CommandContext, CommandResult};
+use crate::backend::counters::CounterOp;
+use crate::backend::roles::UserRole;
+
+/// Trigger of the command adding a death
+pub const DEATH_ADD_COMMAND: &str = "death+";
+/// Trigger of the command showing the deaths
+pub const DEATHS_COMMAND: &str = "deaths";
+/// Trigger of the command resetting the deaths
+pub const RESET_DEATHS_COMMAND: &str = "resetdeaths";
+
+/// Overlay text widget showing the death count
+pub const DEATHS_WIDGET: &str = "deaths";
+
+/// Counter holding the deaths in a category, so every game keeps its own count.
+/// It can be shown in other commands with `{count:deaths:<category>}`.
+pub fn death_counter_name(game: &str) -> String {
+    format!("deaths:{}", game.trim().to_lowercase())
+}
+
+/// Check `!death+`, `!deaths` or `!resetdeaths`, returns the change to make or
+/// `None` to only show the count. Only moderators can change it.
+pub fn parse_death_command(context: &CommandContext) -> Result<Option<CounterOp>, CommandResult> {
+    let op = match context.command_name.as_str() {
+        DEATH_ADD_COMMAND => Some(CounterOp::Increment),
+        RESET_DEATHS_COMMAND => Some(CounterOp::Reset),
+        _ => None,
+    };
+    if op.is_some() && context.identity().role() < UserRole::Moderator {
+        return Err(CommandResult::PermissionDenied);
+    }
+    Ok(op)
+}
+
+/// Reply with the death count, e.g. "Deaths in Elden Ring: 12"
+pub fn format_deaths(game: &str, deaths: i64) -> String {
+    if game.trim().is_empty() {
+        format!("Deaths: {}", deaths)
+    } else {
+        format!("Deaths in {}: {}", game.trim(), deaths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_deaths() {
+        assert_eq!(death_counter_name(" Elden Ring "), "deaths:elden ring");
+        assert_eq!(death_counter_name(""), "deaths:");
+        assert_eq!(format_deaths("Elden Ring", 12), "Deaths in Elden Ring: 12");
+        assert_eq!(format_deaths("", 3), "Deaths: 3");
+    }
+}
@@ -1,4 +1,16 @@
-use super::{CommandAction, CommandContext, CommandRegistry};
+use super::process::ProcessRequest;
+use super::script::run_script;
+use super::variables::expand_variables;
+use super::{Command, CommandAction, CommandContext, CommandRegistry};
+use crate::backend::counters;
+
+/// Separates the outputs of commands running several actions, e.g. `send:...` and
+/// `sound:...`. It's removed from arguments and rendered templates, so user input
+/// can't add actions of its own.
+pub const ACTION_SEPARATOR: char = '\u{1e}';
+
+/// Language of text-to-speech from commands when none is given
+pub const DEFAULT_TTS_LANGUAGE: &str = "en";
 
 /// Result of a command execution
 #[derive(Debug, Clone)]
@@ -13,18 +25,31 @@ pub enum CommandResult {
     PermissionDenied,
     /// Command is on cooldown
     OnCooldown(u64), // remaining seconds
+    /// The user ran the command as often as it allows per stream
+    LimitReached(usize), // uses per stream
 }
 
 /// Executor for running commands
 #[derive(Debug)]
 pub struct CommandExecutor {
     registry: CommandRegistry,
+    /// Seconds after any command before the next one can run
+    global_cooldown: u64,
 }
 
 impl CommandExecutor {
     /// Create a new command executor
     pub fn new(registry: CommandRegistry) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            global_cooldown: 0,
+        }
+    }
+
+    /// Builder method to set the cooldown shared by all commands
+    pub fn with_global_cooldown(mut self, seconds: u64) -> Self {
+        self.global_cooldown = seconds;
+        self
     }
 
     /// Get a reference to the registry
@@ -39,75 +64,226 @@ impl CommandExecutor {
 
     /// Execute a command
     pub fn execute(&mut self, context: &CommandContext) -> CommandResult {
-        // Get the command
-        let command = match self.registry.get(&context.command_name) {
-            Some(cmd) => cmd,
-            None => return CommandResult::NotFound,
+        let command = match check_command(&self.registry, context, self.global_cooldown) {
+            Ok(command) => command,
+            Err(result) => return result,
         };
 
-        // Check if enabled
-        if !command.enabled {
-            return CommandResult::NotFound;
-        }
+        // Execute the action, `{count}` is the command's counter or the one named after it
+        let counter = command
+            .action
+            .counter()
+            .unwrap_or(&context.command_name)
+            .to_string();
+        let result = self.execute_action(&command.action, context, &counter);
 
-        // Check permissions
-        if !command.permission.has_permission(context.badges()) {
-            return CommandResult::PermissionDenied;
-        }
-
-        // Check cooldown
-        if self.registry.is_on_cooldown(&context.command_name) {
-            if let Some(remaining) = self.registry.remaining_cooldown(&context.command_name) {
-                return CommandResult::OnCooldown(remaining);
-            }
-        }
-
-        // Execute the action
-        let result = self.execute_action(&command.action, context);
-
-        // Update cooldown
+        // Update cooldown and the user's uses this stream
         if matches!(result, CommandResult::Success(_)) {
             self.registry.update_cooldown(&context.command_name);
+            self.registry
+                .record_stream_use(&context.command_name, context.username());
         }
 
         result
     }
 
     /// Execute a command action
-    fn execute_action(&self, action: &CommandAction, context: &CommandContext) -> CommandResult {
+    fn execute_action(
+        &self,
+        action: &CommandAction,
+        context: &CommandContext,
+        counter: &str,
+    ) -> CommandResult {
         match action {
             CommandAction::TextToSpeech { message } => {
-                let processed = context.replace_placeholders(message);
-                CommandResult::Success(Some(format!("tts:{}", processed)))
+                let processed = render(context, counter, message);
+                CommandResult::Success(Some(format!("tts:{}:{}", DEFAULT_TTS_LANGUAGE, processed)))
             }
             CommandAction::SendMessage { message } => {
-                let processed = context.replace_placeholders(message);
+                let processed = render(context, counter, message);
                 CommandResult::Success(Some(format!("send:{}", processed)))
             }
             CommandAction::Reply { message } => {
-                let processed = context.replace_placeholders(message);
+                let processed = render(context, counter, message);
                 CommandResult::Success(Some(format!(
                     "reply:{}:{}",
                     context.message_id(),
                     processed
                 )))
             }
-            CommandAction::Multiple { actions } => {
-                let mut results = Vec::new();
-                for action in actions {
-                    match self.execute_action(action, context) {
-                        CommandResult::Success(Some(msg)) => results.push(msg),
-                        CommandResult::Success(None) => {}
-                        CommandResult::Error(e) => return CommandResult::Error(e),
-                        other => return other,
-                    }
+            CommandAction::RandomReply { messages } => {
+                if messages.is_empty() {
+                    return CommandResult::Error("No responses to pick from".to_string());
                 }
-                if results.is_empty() {
-                    CommandResult::Success(None)
-                } else {
-                    CommandResult::Success(Some(results.join("|")))
+                let message = &messages[fastrand::usize(..messages.len())];
+                let processed = render(context, counter, message);
+                CommandResult::Success(Some(format!(
+                    "reply:{}:{}",
+                    context.message_id(),
+                    processed
+                )))
+            }
+            CommandAction::Announce { message, color } => {
+                let processed = render(context, counter, message);
+                CommandResult::Success(Some(format!("announce:{}:{}", color, processed)))
+            }
+            CommandAction::Shoutout { user } => {
+                let processed = render(context, counter, user);
+                // Only the first word, so "!so @name thanks for the raid" works
+                let login = processed
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or_default()
+                    .trim_start_matches('@')
+                    .to_lowercase();
+                if login.is_empty() {
+                    return CommandResult::Error("No channel to shout out".to_string());
                 }
+                CommandResult::Success(Some(format!("shoutout:{}", login)))
+            }
+            CommandAction::Whisper { message } => {
+                let processed = render(context, counter, message);
+                CommandResult::Success(Some(format!("whisper:{}:{}", context.user_id(), processed)))
+            }
+            CommandAction::Counter { name, op } => {
+                counters::update(name, *op);
+                CommandResult::Success(None)
+            }
+            CommandAction::PlaySound { sound } => {
+                CommandResult::Success(Some(format!("sound:{}", sound)))
+            }
+            CommandAction::RunProcess {
+                program,
+                args,
+                reply,
+            } => {
+                let request = ProcessRequest {
+                    program: program.clone(),
+                    args: args
+                        .iter()
+                        .map(|arg| render(context, counter, arg))
+                        .collect(),
+                    reply: *reply,
+                };
+                match serde_json::to_string(&request) {
+                    Ok(request) => CommandResult::Success(Some(format!("process:{}", request))),
+                    Err(e) => CommandResult::Error(e.to_string()),
+                }
+            }
+            CommandAction::Script { source } => run_script(source, context),
+            CommandAction::Multiple { actions } => self.execute_all(actions, context, counter),
+            CommandAction::If {
+                condition,
+                then,
+                otherwise,
+            } => {
+                let actions = if condition.evaluate(context) {
+                    then
+                } else {
+                    otherwise
+                };
+                self.execute_all(actions, context, counter)
             }
         }
     }
+
+    /// Execute actions in sequence, stopping at the first one that fails
+    fn execute_all(
+        &self,
+        actions: &[CommandAction],
+        context: &CommandContext,
+        counter: &str,
+    ) -> CommandResult {
+        let mut results = Vec::new();
+        for action in actions {
+            match self.execute_action(action, context, counter) {
+                CommandResult::Success(Some(msg)) => results.push(msg),
+                CommandResult::Success(None) => {}
+                CommandResult::Error(e) => return CommandResult::Error(e),
+                other => return other,
+            }
+        }
+        if results.is_empty() {
+            CommandResult::Success(None)
+        } else {
+            CommandResult::Success(Some(results.join(&ACTION_SEPARATOR.to_string())))
+        }
+    }
+}
+
+/// The command `context` asks for if the user may run it now, otherwise the result
+/// to answer with instead. Checked again before fetching a command's external data,
+/// so commands that won't run don't fetch anything.
+pub fn check_command<'a>(
+    registry: &'a CommandRegistry,
+    context: &CommandContext,
+    global_cooldown: u64,
+) -> Result<&'a Command, CommandResult> {
+    let command = registry
+        .get(&context.command_name)
+        .ok_or(CommandResult::NotFound)?;
+
+    // Check if enabled (viewer-owned commands also need approval)
+    if !command.enabled || command.pending_approval {
+        return Err(CommandResult::NotFound);
+    }
+
+    // Commands bound to a reward only run when it's redeemed
+    if command.reward_id.is_some() != context.is_redemption() {
+        return Err(CommandResult::NotFound);
+    }
+
+    // Check the user lists, then permissions
+    let allowed = command
+        .user_override(context.username())
+        .unwrap_or_else(|| {
+            command
+                .permission
+                .allows(context.identity(), context.followed_at, chrono::Utc::now())
+        });
+    if !allowed {
+        return Err(CommandResult::PermissionDenied);
+    }
+
+    // Check cooldown
+    if registry.is_on_cooldown(&context.command_name, context.identity()) {
+        if let Some(remaining) = registry.remaining_cooldown(&context.command_name) {
+            return Err(CommandResult::OnCooldown(remaining));
+        }
+    }
+    if let Some(remaining) = registry.remaining_global_cooldown(
+        &context.command_name,
+        context.identity(),
+        global_cooldown,
+    ) {
+        return Err(CommandResult::OnCooldown(remaining));
+    }
+
+    // Check the uses left this stream
+    if registry.stream_limit_reached(&context.command_name, context.username()) {
+        return Err(CommandResult::LimitReached(command.max_uses_per_stream));
+    }
+
+    // Answer with the usage instead of sending a message with missing arguments
+    if context.args.len() < command.min_args() {
+        if context.keyword {
+            return Err(CommandResult::Success(None));
+        }
+        return Err(CommandResult::Success(Some(format!(
+            "reply:{}:{}",
+            context.message_id(),
+            command.usage_response(context)
+        ))));
+    }
+
+    Ok(command)
+}
+
+/// Fill in the `$(...)` variables, the counters and then the context placeholders of
+/// a template. Variables go first so arguments can't add new ones.
+fn render(context: &CommandContext, counter: &str, template: &str) -> String {
+    let template = expand_variables(template, context);
+    context
+        .replace_placeholders(&counters::replace_count_placeholders(&template, counter))
+        .replace(ACTION_SEPARATOR, "")
 }
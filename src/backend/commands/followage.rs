@@ -0,0 +1,143 @@
+use chrono::{DateTime, Datelike, Months, Utc};
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use super::{CommandContext, CommandResult};
+
+/// Trigger of the built-in followage command
+pub const FOLLOWAGE_COMMAND: &str = "followage";
+
+/// How long a follow date looked up for a follower-only command is reused
+const FOLLOW_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Follow date of a user, `None` if they don't follow, and when it was looked up
+struct CachedFollow {
+    followed_at: Option<DateTime<Utc>>,
+    fetched: Instant,
+}
+
+/// Follow dates by user ID
+static FOLLOW_CACHE: LazyLock<Mutex<HashMap<String, CachedFollow>>> =
+    LazyLock::new(Default::default);
+
+/// Follow date of a user looked up in the last few minutes, `Some(None)` if they
+/// didn't follow then
+pub fn cached_followed_at(user_id: &str) -> Option<Option<DateTime<Utc>>> {
+    let cache = FOLLOW_CACHE.lock().unwrap();
+    let cached = cache.get(user_id)?;
+    (cached.fetched.elapsed() < FOLLOW_CACHE_TTL).then_some(cached.followed_at)
+}
+
+/// Remember a follow date looked up from Twitch
+pub fn cache_followed_at(user_id: &str, followed_at: Option<DateTime<Utc>>) {
+    let mut cache = FOLLOW_CACHE.lock().unwrap();
+    cache.retain(|_, cached| cached.fetched.elapsed() < FOLLOW_CACHE_TTL);
+    cache.insert(
+        user_id.to_string(),
+        CachedFollow {
+            followed_at,
+            fetched: Instant::now(),
+        },
+    );
+}
+
+const USAGE: &str = "Usage: !followage [user]";
+
+/// Parse the arguments of `!followage`, returns the login of the named user or
+/// `None` to look up whoever used the command
+pub fn parse_followage_args(args: &[String]) -> Result<Option<String>, &'static str> {
+    match args {
+        [] => Ok(None),
+        [user] => {
+            let login = user.trim_start_matches('@').to_lowercase();
+            if login.is_empty() {
+                Err(USAGE)
+            } else {
+                Ok(Some(login))
+            }
+        }
+        _ => Err(USAGE),
+    }
+}
+
+/// Check and parse `!followage`. Everyone can use it.
+pub fn parse_followage_command(context: &CommandContext) -> Result<Option<String>, CommandResult> {
+    parse_followage_args(&context.args).map_err(|usage| {
+        CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), usage)))
+    })
+}
+
+/// Reply to `!followage`, e.g. "viewer has been following for 1 year, 2 months and 3 days"
+pub fn format_followage(
+    name: &str,
+    followed_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> String {
+    let Some(followed_at) = followed_at else {
+        return format!("{} isn't following the channel", name);
+    };
+    format!(
+        "{} has been following for {}",
+        name,
+        format_duration_since(followed_at, now)
+    )
+}
+
+/// Time between `since` and `now` in calendar units, e.g. "1 year, 2 months and 3 days"
+pub fn format_duration_since(since: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    // Whole calendar months first, so a follow from the 15th is a month old on the next 15th
+    let mut months = (now.year() - since.year()) * 12 + now.month() as i32 - since.month() as i32;
+    let after = |months: i32| since + Months::new(months.max(0) as u32);
+    if months > 0 && after(months) > now {
+        months -= 1;
+    }
+    let months = months.max(0);
+    let days = (now - after(months)).num_days().max(0);
+
+    let parts: Vec<String> = [(months / 12) as i64, (months % 12) as i64, days]
+        .into_iter()
+        .zip(["year", "month", "day"])
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, unit)| format!("{} {}{}", count, unit, if count == 1 { "" } else { "s" }))
+        .collect();
+
+    match parts.as_slice() {
+        [] => "less than a day".to_string(),
+        [part] => part.clone(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_format_followage() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 18, 12, 0, 0).unwrap();
+        let at = |y, m, d| Some(Utc.with_ymd_and_hms(y, m, d, 12, 0, 0).unwrap());
+
+        assert_eq!(
+            format_followage("viewer", None, now),
+            "viewer isn't following the channel"
+        );
+        assert_eq!(
+            format_followage("viewer", at(2025, 3, 18), now),
+            "viewer has been following for less than a day"
+        );
+        assert_eq!(
+            format_followage("viewer", at(2025, 2, 18), now),
+            "viewer has been following for 1 month"
+        );
+        assert_eq!(
+            format_followage("viewer", at(2024, 1, 15), now),
+            "viewer has been following for 1 year, 2 months and 3 days"
+        );
+        assert_eq!(
+            format_followage("viewer", at(2025, 2, 20), now),
+            "viewer has been following for 26 days"
+        );
+    }
+}
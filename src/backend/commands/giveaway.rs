@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use super::{CommandContext, CommandResult};
+use crate::backend::roles::UserRole;
+
+/// Trigger of the command that starts and ends giveaway mode
+pub const GIVEAWAY_COMMAND: &str = "giveaway";
+
+const USAGE: &str = "Usage: !giveaway start | !giveaway end";
+
+/// Whether a giveaway is running, set with `!giveaway`
+static GIVEAWAY_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Settings for giveaway mode. While a giveaway runs, entry keywords like `!enter`
+/// don't play sounds or start TTS, so hundreds of entries don't flood the stream.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GiveawayConfig {
+    /// Commands viewers use to enter, without the `!`
+    #[serde(default = "default_entry_keywords")]
+    pub entry_keywords: Vec<String>,
+    /// Sent to chat when giveaway mode starts, `{keywords}` is replaced. Empty to disable.
+    #[serde(default = "default_start_message")]
+    pub start_message: String,
+}
+
+impl Default for GiveawayConfig {
+    fn default() -> Self {
+        Self {
+            entry_keywords: default_entry_keywords(),
+            start_message: default_start_message(),
+        }
+    }
+}
+
+fn default_entry_keywords() -> Vec<String> {
+    vec![
+        "enter".to_string(),
+        "join".to_string(),
+        "ticket".to_string(),
+    ]
+}
+
+fn default_start_message() -> String {
+    "Giveaway started! Type {keywords} to enter".to_string()
+}
+
+impl GiveawayConfig {
+    /// Whether sounds and TTS should ignore this trigger right now
+    pub fn suppresses(&self, trigger: &str) -> bool {
+        giveaway_active()
+            && self.entry_keywords.iter().any(|keyword| {
+                keyword
+                    .trim_start_matches('!')
+                    .eq_ignore_ascii_case(trigger)
+            })
+    }
+
+    /// Message announcing the giveaway, `None` if disabled
+    pub fn format_start_message(&self) -> Option<String> {
+        if self.start_message.is_empty() {
+            return None;
+        }
+        let keywords = self
+            .entry_keywords
+            .iter()
+            .map(|keyword| format!("!{}", keyword.trim_start_matches('!')))
+            .collect::<Vec<_>>()
+            .join(" or ");
+        Some(self.start_message.replace("{keywords}", &keywords))
+    }
+}
+
+pub fn giveaway_active() -> bool {
+    GIVEAWAY_ACTIVE.load(Ordering::Relaxed)
+}
+
+/// Start or end giveaway mode, returns whether it changed
+pub fn set_giveaway_active(active: bool) -> bool {
+    GIVEAWAY_ACTIVE.swap(active, Ordering::Relaxed) != active
+}
+
+/// Check and parse `!giveaway`, returns whether giveaway mode should be on or the
+/// result to report when it can't run. Moderators only.
+pub fn parse_giveaway_command(context: &CommandContext) -> Result<bool, CommandResult> {
+    if context.identity().role() < UserRole::Moderator {
+        return Err(CommandResult::PermissionDenied);
+    }
+
+    match context
+        .args
+        .first()
+        .map(|arg| arg.to_lowercase())
+        .as_deref()
+    {
+        Some("start") | Some("on") => Ok(true),
+        Some("end") | Some("off") => Ok(false),
+        _ => Err(CommandResult::Success(Some(format!(
+            "reply:{}:{}",
+            context.message_id(),
+            USAGE
+        )))),
+    }
+}
@@ -0,0 +1,111 @@
+use super::{CommandContext, CommandResult};
+use crate::backend::roles::UserRole;
+
+/// Trigger of the Guest Star management command
+pub const GUEST_STAR_COMMAND: &str = "gueststar";
+
+const USAGE: &str =
+    "Usage: !gueststar invite <user> | slot <user> <slot> | mute <slot> | unmute <slot>";
+
+/// Guest Star action, from `!gueststar` or the MODERATION tab
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GuestStarCommand {
+    Invite { user: String },
+    AssignSlot { user: String, slot_id: String },
+    SetAudio { slot_id: String, enabled: bool },
+}
+
+impl GuestStarCommand {
+    /// Parse the arguments of `!gueststar`, returns the usage text if they don't match
+    pub fn parse(args: &[String]) -> Result<Self, &'static str> {
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        let user = |name: &str| name.trim_start_matches('@').to_lowercase();
+        let slot = |slot: &str| {
+            (!slot.is_empty() && slot.chars().all(|c| c.is_ascii_digit()))
+                .then(|| slot.to_string())
+                .ok_or(USAGE)
+        };
+
+        match args.as_slice() {
+            ["invite", name] => Ok(Self::Invite { user: user(name) }),
+            ["slot", name, slot_id] => Ok(Self::AssignSlot {
+                user: user(name),
+                slot_id: slot(slot_id)?,
+            }),
+            ["mute", slot_id] => Ok(Self::SetAudio {
+                slot_id: slot(slot_id)?,
+                enabled: false,
+            }),
+            ["unmute", slot_id] => Ok(Self::SetAudio {
+                slot_id: slot(slot_id)?,
+                enabled: true,
+            }),
+            _ => Err(USAGE),
+        }
+    }
+
+    /// Confirmation once the action went through
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Invite { user } => format!("Invited {} to Guest Star", user),
+            Self::AssignSlot { user, slot_id } => format!("Moved {} to slot {}", user, slot_id),
+            Self::SetAudio {
+                slot_id,
+                enabled: true,
+            } => format!("Unmuted slot {}", slot_id),
+            Self::SetAudio {
+                slot_id,
+                enabled: false,
+            } => format!("Muted slot {}", slot_id),
+        }
+    }
+}
+
+/// Check and parse `!gueststar`. Returns the command to run against the Twitch API,
+/// or the result to report when it can't run. Moderators only.
+pub fn parse_guest_star_command(
+    context: &CommandContext,
+) -> Result<GuestStarCommand, CommandResult> {
+    if context.identity().role() < UserRole::Moderator {
+        return Err(CommandResult::PermissionDenied);
+    }
+
+    GuestStarCommand::parse(&context.args).map_err(|usage| {
+        CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), usage)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_guest_star_command() {
+        assert_eq!(
+            GuestStarCommand::parse(&args(&["invite", "@Guest"])),
+            Ok(GuestStarCommand::Invite {
+                user: "guest".to_string()
+            })
+        );
+        assert_eq!(
+            GuestStarCommand::parse(&args(&["slot", "guest", "2"])),
+            Ok(GuestStarCommand::AssignSlot {
+                user: "guest".to_string(),
+                slot_id: "2".to_string()
+            })
+        );
+        assert_eq!(
+            GuestStarCommand::parse(&args(&["mute", "1"])),
+            Ok(GuestStarCommand::SetAudio {
+                slot_id: "1".to_string(),
+                enabled: false
+            })
+        );
+        assert!(GuestStarCommand::parse(&args(&["mute", "one"])).is_err());
+        assert!(GuestStarCommand::parse(&args(&["invite"])).is_err());
+    }
+}
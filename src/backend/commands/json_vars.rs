@@ -0,0 +1,142 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+/// How long a fetched JSON document is reused before it's requested again
+const CACHE_TTL: Duration = Duration::from_secs(60);
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Fetched JSON documents keyed by URL
+static CACHE: LazyLock<Mutex<HashMap<String, (Instant, Value)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// A `{json:<url>:<pointer>}` placeholder found in a template
+struct JsonPlaceholder<'a> {
+    placeholder: &'a str,
+    url: &'a str,
+    pointer: &'a str,
+}
+
+/// Find all `{json:<url>:<pointer>}` placeholders in a template.
+/// The pointer is everything after the last `:`, e.g.
+/// `{json:https://api.github.com/repos/owner/repo:/stargazers_count}`
+fn find_placeholders(template: &str) -> Vec<JsonPlaceholder<'_>> {
+    let mut placeholders = Vec::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{json:") {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        let placeholder = &rest[start..start + len + 1];
+        let spec = &placeholder["{json:".len()..placeholder.len() - 1];
+        if let Some((url, pointer)) = spec.rsplit_once(':') {
+            placeholders.push(JsonPlaceholder {
+                placeholder,
+                url,
+                pointer,
+            });
+        }
+        rest = &rest[start + len + 1..];
+    }
+
+    placeholders
+}
+
+/// URLs of the JSON documents used by a template that aren't cached or have expired
+fn stale_urls(template: &str) -> Vec<String> {
+    let cache = CACHE.lock().unwrap();
+    find_placeholders(template)
+        .into_iter()
+        .filter(|p| {
+            cache
+                .get(p.url)
+                .is_none_or(|(fetched_at, _)| fetched_at.elapsed() >= CACHE_TTL)
+        })
+        .map(|p| p.url.to_string())
+        .collect()
+}
+
+/// Whether a template has JSON documents to fetch before it can be filled in
+pub fn needs_prefetch(template: &str) -> bool {
+    !stale_urls(template).is_empty()
+}
+
+/// Fetch the JSON documents used by a template, skipping ones that are still cached.
+/// Must run before the command executes so `replace_json_placeholders` has data.
+pub async fn prefetch_json_placeholders(template: &str) {
+    let stale = stale_urls(template);
+    if stale.is_empty() {
+        return;
+    }
+
+    let client = match reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(e) => {
+            log::error!("Failed to create HTTP client for JSON placeholders: {}", e);
+            return;
+        }
+    };
+
+    for url in stale {
+        match fetch_json(&client, &url).await {
+            Ok(json) => {
+                CACHE.lock().unwrap().insert(url, (Instant::now(), json));
+            }
+            // Keep serving the previous value if there is one
+            Err(e) => log::warn!("Failed to fetch JSON placeholder {}: {}", url, e),
+        }
+    }
+}
+
+async fn fetch_json(client: &reqwest::Client, url: &str) -> Result<Value, reqwest::Error> {
+    client
+        .get(url)
+        .header("User-Agent", "yambot")
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await
+}
+
+/// Replace `{json:<url>:<pointer>}` placeholders with cached values.
+/// Missing documents or pointers are replaced with an empty string.
+pub fn replace_json_placeholders(template: &str) -> String {
+    let placeholders = find_placeholders(template);
+    if placeholders.is_empty() {
+        return template.to_string();
+    }
+
+    let cache = CACHE.lock().unwrap();
+    let mut result = template.to_string();
+    for p in placeholders {
+        let value = cache
+            .get(p.url)
+            .and_then(|(_, json)| json.pointer(p.pointer))
+            .map(|value| match value {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            })
+            .unwrap_or_default();
+        result = result.replace(p.placeholder, &value);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_placeholders() {
+        let template = "Stars: {json:https://api.github.com/repos/a/b:/stargazers_count} ({user})";
+        let placeholders = find_placeholders(template);
+        assert_eq!(placeholders.len(), 1);
+        assert_eq!(placeholders[0].url, "https://api.github.com/repos/a/b");
+        assert_eq!(placeholders[0].pointer, "/stargazers_count");
+        assert!(find_placeholders("{user} {json:broken").is_empty());
+    }
+}
@@ -1,9 +1,109 @@
+mod add_command;
+mod ads;
+mod arguments;
+mod automod;
+mod blocked_terms;
+mod builtins;
+mod chat_mode;
+mod clip;
+mod command_list;
+mod conditions;
 mod context;
+mod cooldown_queue;
+mod deaths;
 mod executor;
+mod followage;
+mod giveaway;
+mod guest_star;
+mod json_vars;
+mod moderation;
+mod moderators;
+mod nickname;
+mod nuke;
 mod parser;
+mod poll;
+mod prediction;
+mod process;
+mod quotes;
+mod raid;
 mod registry;
+mod script;
+mod shield;
+mod shoutout;
+mod stream_info;
+mod transfer;
+mod uptime;
+mod usage;
+mod user_commands;
+mod user_info;
+mod variables;
+mod workers;
 
+pub use add_command::{execute_add_command, execute_edit_command};
+pub use ads::{check_snooze_ad_command, format_next_ad, SNOOZE_AD_COMMAND};
+pub use automod::{
+    cancel_automod_restore, describe_automod, parse_automod_command, schedule_automod_restore,
+    take_due_automod_restore, AutomodCommand, AUTOMOD_COMMAND,
+};
+pub use blocked_terms::{
+    parse_blocked_term_command, BlockedTermCommand, BLOCK_TERM_COMMAND, UNBLOCK_TERM_COMMAND,
+};
+pub use builtins::execute_builtin;
+pub use chat_mode::{parse_chat_mode_command, ChatModeCommand, CHAT_MODE_COMMANDS};
+pub use clip::{
+    check_clip_command, format_clip_message, reset_clip_cooldown, ClipCommandConfig, CLIP_COMMAND,
+};
+pub use command_list::{list_commands, COMMANDS_COMMAND};
+pub use conditions::{Comparison, Condition};
 pub use context::CommandContext;
-pub use executor::{CommandExecutor, CommandResult};
-pub use parser::CommandParser;
-pub use registry::{Command, CommandAction, CommandPermission, CommandRegistry};
+pub use cooldown_queue::{
+    clear_queued_commands, queue_command, take_ready_commands, CommandCooldownConfig,
+    QueueRejection,
+};
+pub use deaths::{
+    death_counter_name, format_deaths, parse_death_command, DEATHS_COMMAND, DEATHS_WIDGET,
+    DEATH_ADD_COMMAND, RESET_DEATHS_COMMAND,
+};
+pub use executor::{check_command, CommandExecutor, CommandResult, ACTION_SEPARATOR};
+pub use followage::{
+    cache_followed_at, cached_followed_at, format_followage, parse_followage_command,
+    FOLLOWAGE_COMMAND,
+};
+pub use giveaway::{
+    giveaway_active, parse_giveaway_command, set_giveaway_active, GiveawayConfig, GIVEAWAY_COMMAND,
+};
+pub use guest_star::{parse_guest_star_command, GuestStarCommand, GUEST_STAR_COMMAND};
+pub use json_vars::{needs_prefetch, prefetch_json_placeholders};
+pub use moderation::{
+    parse_moderation_command, ModerationCommand, BAN_COMMAND, TIMEOUT_COMMAND, UNBAN_COMMAND,
+};
+pub use moderators::{parse_moderator_command, ModeratorCommand, MOD_COMMAND, UNMOD_COMMAND};
+pub use nickname::{parse_nick_command, NickCommand, NICK_COMMAND};
+pub use nuke::{parse_nuke_command, NukeCommand, NUKE_COMMAND};
+pub use parser::{CommandParser, KeywordTrigger};
+pub use poll::{parse_poll_command, PollCommand, PollConfig, POLL_COMMAND};
+pub use prediction::{
+    parse_prediction_command, PredictionCommand, OUTCOME_COMMAND, PREDICTION_COMMAND,
+};
+pub use process::{ProcessConfig, ProcessRequest};
+pub use quotes::{
+    parse_quote_command, QuoteCommand, ADD_QUOTE_COMMAND, DEL_QUOTE_COMMAND, QUOTE_COMMAND,
+};
+pub use raid::{parse_raid_command, prepare_raid, take_pending_raid, RaidCommand, RAID_COMMAND};
+pub use registry::{
+    command_groups, Command, CommandAction, CommandCooldowns, CommandPermission, CommandRegistry,
+    FailureFeedback,
+};
+pub use script::check_script;
+pub use shield::{parse_shield_command, SHIELD_COMMAND};
+pub use shoutout::{record_shoutout, shoutout_cooldown, ShoutoutTarget};
+pub use stream_info::{parse_stream_info_command, StreamInfoCommand, STREAM_INFO_COMMANDS};
+pub use transfer::{CommandExport, ImportMode, ImportSummary, COMMANDS_EXPORT_FILE};
+pub use uptime::{format_uptime, UPTIME_COMMAND};
+pub use usage::{by_popularity, command_usage, record_command_usage, CommandUsage};
+pub use user_commands::{execute_user_command, UserCommandsConfig};
+pub use user_info::{
+    cache_created_at, cached_created_at, fetch_pronouns, UserInfo, ACCOUNT_AGE_PLACEHOLDER,
+    FOLLOW_AGE_PLACEHOLDER, PRONOUNS_PLACEHOLDER,
+};
+pub use workers::{CommandWorkers, FinishedWork};
@@ -0,0 +1,163 @@
+use super::{CommandContext, CommandResult};
+use crate::backend::roles::UserRole;
+
+/// Trigger that bans a user
+pub const BAN_COMMAND: &str = "ban";
+/// Trigger that times a user out
+pub const TIMEOUT_COMMAND: &str = "timeout";
+/// Trigger that lifts a ban or timeout
+pub const UNBAN_COMMAND: &str = "unban";
+
+/// Seconds a `!timeout` without a duration lasts, same as the Twitch chat
+const DEFAULT_TIMEOUT_SECONDS: u32 = 600;
+/// Longest timeout Twitch accepts (2 weeks)
+const MAX_TIMEOUT_SECONDS: u32 = 1_209_600;
+
+/// Action of `!ban`, `!timeout` or `!unban`, with the login of the user
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModerationCommand {
+    Ban {
+        login: String,
+        reason: String,
+    },
+    Timeout {
+        login: String,
+        seconds: u32,
+        reason: String,
+    },
+    Unban {
+        login: String,
+    },
+}
+
+impl ModerationCommand {
+    /// Parse the arguments of a moderation command, returns the usage text if they don't match
+    pub fn parse(command_name: &str, args: &[String]) -> Result<Self, &'static str> {
+        let usage = match command_name {
+            BAN_COMMAND => "Usage: !ban <user> [reason]",
+            TIMEOUT_COMMAND => "Usage: !timeout <user> [duration like 30s, 10m, 1h] [reason]",
+            UNBAN_COMMAND => "Usage: !unban <user>",
+            _ => return Err("Unknown moderation command"),
+        };
+        let Some((user, rest)) = args.split_first() else {
+            return Err(usage);
+        };
+        let login = user.trim_start_matches('@').to_lowercase();
+        if login.is_empty() {
+            return Err(usage);
+        }
+
+        match command_name {
+            BAN_COMMAND => Ok(Self::Ban {
+                login,
+                reason: rest.join(" "),
+            }),
+            TIMEOUT_COMMAND => {
+                // The duration is optional, anything that isn't one starts the reason
+                let (seconds, reason) = match rest.split_first() {
+                    Some((duration, reason)) if starts_with_digit(duration) => {
+                        let seconds = parse_seconds(duration)
+                            .filter(|seconds| (1..=MAX_TIMEOUT_SECONDS).contains(seconds))
+                            .ok_or(usage)?;
+                        (seconds, reason)
+                    }
+                    _ => (DEFAULT_TIMEOUT_SECONDS, rest),
+                };
+                Ok(Self::Timeout {
+                    login,
+                    seconds,
+                    reason: reason.join(" "),
+                })
+            }
+            _ if rest.is_empty() => Ok(Self::Unban { login }),
+            _ => Err(usage),
+        }
+    }
+
+    pub fn login(&self) -> &str {
+        match self {
+            Self::Ban { login, .. } | Self::Timeout { login, .. } | Self::Unban { login } => login,
+        }
+    }
+}
+
+fn starts_with_digit(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Parse a duration like "30s", "10m", "2h", "1d", "1w" or "600" (seconds) into seconds
+fn parse_seconds(duration: &str) -> Option<u32> {
+    let split = duration
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(duration.len());
+    let (number, unit) = duration.split_at(split);
+    let number: u32 = number.parse().ok()?;
+    let factor = match unit {
+        "" | "s" => 1,
+        "m" | "min" => 60,
+        "h" => 60 * 60,
+        "d" => 60 * 60 * 24,
+        "w" => 60 * 60 * 24 * 7,
+        _ => return None,
+    };
+    number.checked_mul(factor)
+}
+
+/// Check and parse `!ban`, `!timeout` or `!unban`. Moderators only.
+pub fn parse_moderation_command(
+    context: &CommandContext,
+) -> Result<ModerationCommand, CommandResult> {
+    if context.identity().role() < UserRole::Moderator {
+        return Err(CommandResult::PermissionDenied);
+    }
+
+    ModerationCommand::parse(&context.command_name, &context.args).map_err(|usage| {
+        CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), usage)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_moderation_command() {
+        assert_eq!(
+            ModerationCommand::parse(BAN_COMMAND, &args("@Spammer buying followers")),
+            Ok(ModerationCommand::Ban {
+                login: "spammer".to_string(),
+                reason: "buying followers".to_string(),
+            })
+        );
+        assert_eq!(
+            ModerationCommand::parse(TIMEOUT_COMMAND, &args("troll 10m calm down")),
+            Ok(ModerationCommand::Timeout {
+                login: "troll".to_string(),
+                seconds: 600,
+                reason: "calm down".to_string(),
+            })
+        );
+        assert_eq!(
+            ModerationCommand::parse(TIMEOUT_COMMAND, &args("troll spoilers")),
+            Ok(ModerationCommand::Timeout {
+                login: "troll".to_string(),
+                seconds: DEFAULT_TIMEOUT_SECONDS,
+                reason: "spoilers".to_string(),
+            })
+        );
+        assert_eq!(
+            ModerationCommand::parse(UNBAN_COMMAND, &args("troll")),
+            Ok(ModerationCommand::Unban {
+                login: "troll".to_string()
+            })
+        );
+        assert!(ModerationCommand::parse(BAN_COMMAND, &args("")).is_err());
+        assert!(ModerationCommand::parse(TIMEOUT_COMMAND, &args("troll 3w")).is_err());
+        assert!(ModerationCommand::parse(TIMEOUT_COMMAND, &args("troll 10x")).is_err());
+        assert!(ModerationCommand::parse(UNBAN_COMMAND, &args("troll sorry")).is_err());
+    }
+}
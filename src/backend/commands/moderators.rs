@@ -0,0 +1,74 @@
+use super::{CommandContext, CommandResult};
+use crate::backend::roles::UserRole;
+
+/// Trigger that makes a user a moderator
+pub const MOD_COMMAND: &str = "mod";
+/// Trigger that removes a user's moderator status
+pub const UNMOD_COMMAND: &str = "unmod";
+
+/// Action of `!mod` / `!unmod`, with the login of the user
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModeratorCommand {
+    Add(String),
+    Remove(String),
+}
+
+impl ModeratorCommand {
+    /// Parse the arguments of `!mod` or `!unmod`, returns the usage text if they don't match
+    pub fn parse(command_name: &str, args: &[String]) -> Result<Self, &'static str> {
+        let (usage, add) = if command_name == MOD_COMMAND {
+            ("Usage: !mod <user>", true)
+        } else {
+            ("Usage: !unmod <user>", false)
+        };
+        let [user] = args else {
+            return Err(usage);
+        };
+        let login = user.trim_start_matches('@').to_lowercase();
+        if login.is_empty() {
+            return Err(usage);
+        }
+        Ok(if add {
+            Self::Add(login)
+        } else {
+            Self::Remove(login)
+        })
+    }
+}
+
+/// Check and parse `!mod` / `!unmod`. Broadcaster only, like in the Twitch chat.
+pub fn parse_moderator_command(
+    context: &CommandContext,
+) -> Result<ModeratorCommand, CommandResult> {
+    if context.identity().role() < UserRole::Broadcaster {
+        return Err(CommandResult::PermissionDenied);
+    }
+
+    ModeratorCommand::parse(&context.command_name, &context.args).map_err(|usage| {
+        CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), usage)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_moderator_command() {
+        assert_eq!(
+            ModeratorCommand::parse(MOD_COMMAND, &args("@NewMod")),
+            Ok(ModeratorCommand::Add("newmod".to_string()))
+        );
+        assert_eq!(
+            ModeratorCommand::parse(UNMOD_COMMAND, &args("oldmod")),
+            Ok(ModeratorCommand::Remove("oldmod".to_string()))
+        );
+        assert!(ModeratorCommand::parse(MOD_COMMAND, &args("")).is_err());
+        assert!(ModeratorCommand::parse(MOD_COMMAND, &args("@")).is_err());
+        assert!(ModeratorCommand::parse(UNMOD_COMMAND, &args("two users")).is_err());
+    }
+}
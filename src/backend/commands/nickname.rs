@@ -0,0 +1,100 @@
+use super::{CommandContext, CommandResult};
+use crate::backend::roles::UserRole;
+use crate::backend::users::validate_nickname;
+
+/// Trigger of the nickname command
+pub const NICK_COMMAND: &str = "nick";
+
+const USAGE: &str = "Usage: !nick <nickname> | !nick clear | !nick @user <nickname>";
+
+/// Action of `!nick`, each names the login it applies to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NickCommand {
+    Show(String),
+    Set(String, String),
+    Clear(String),
+}
+
+impl NickCommand {
+    /// Parse the arguments of `!nick` sent by `sender`. A leading `@user` targets
+    /// someone else, otherwise the command applies to the sender.
+    pub fn parse(sender: &str, args: &[String]) -> Result<Self, &'static str> {
+        let (target, rest) = match args {
+            [first, rest @ ..] if first.starts_with('@') => {
+                let target = first.trim_start_matches('@').to_lowercase();
+                if target.is_empty() {
+                    return Err(USAGE);
+                }
+                (target, rest)
+            }
+            _ => (sender.to_lowercase(), args),
+        };
+
+        match rest {
+            [] => Ok(Self::Show(target)),
+            [arg] if arg.eq_ignore_ascii_case("clear") || arg.eq_ignore_ascii_case("off") => {
+                Ok(Self::Clear(target))
+            }
+            words => Ok(Self::Set(target, validate_nickname(&words.join(" "))?)),
+        }
+    }
+
+    pub fn target(&self) -> &str {
+        match self {
+            Self::Show(login) | Self::Set(login, _) | Self::Clear(login) => login,
+        }
+    }
+}
+
+/// Check and parse `!nick`. Everyone can change their own nickname,
+/// only moderators can change someone else's.
+pub fn parse_nick_command(context: &CommandContext) -> Result<NickCommand, CommandResult> {
+    let reply = |message: &str| {
+        CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), message)))
+    };
+    let command = NickCommand::parse(context.username(), &context.args).map_err(reply)?;
+
+    if !matches!(command, NickCommand::Show(_))
+        && !command.target().eq_ignore_ascii_case(context.username())
+        && context.identity().role() < UserRole::Moderator
+    {
+        return Err(CommandResult::PermissionDenied);
+    }
+    Ok(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<NickCommand, &'static str> {
+        let args: Vec<String> = args.split_whitespace().map(String::from).collect();
+        NickCommand::parse("Viewer", &args)
+    }
+
+    #[test]
+    fn test_parse_nick_command() {
+        assert_eq!(parse(""), Ok(NickCommand::Show("viewer".to_string())));
+        assert_eq!(
+            parse("Big Vee"),
+            Ok(NickCommand::Set(
+                "viewer".to_string(),
+                "Big Vee".to_string()
+            ))
+        );
+        assert_eq!(parse("clear"), Ok(NickCommand::Clear("viewer".to_string())));
+        assert_eq!(
+            parse("@Xx_Gamer_xX Gamer"),
+            Ok(NickCommand::Set(
+                "xx_gamer_xx".to_string(),
+                "Gamer".to_string()
+            ))
+        );
+        assert_eq!(
+            parse("@someone"),
+            Ok(NickCommand::Show("someone".to_string()))
+        );
+        assert!(parse("@ Gamer").is_err());
+        assert!(parse("{args}").is_err());
+    }
+}
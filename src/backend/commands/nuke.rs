@@ -0,0 +1,124 @@
+use super::{CommandContext, CommandResult};
+use crate::backend::chat_history::ChatMessage;
+use crate::backend::roles::UserRole;
+
+/// Trigger of the nuke command
+pub const NUKE_COMMAND: &str = "nuke";
+
+const USAGE: &str = "Usage: !nuke <phrase> [timeout minutes]";
+
+/// Longest timeout Twitch accepts (2 weeks)
+const MAX_TIMEOUT_MINUTES: u32 = 20_160;
+
+/// Messages to delete with `!nuke`, and how long to time out their senders
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NukeCommand {
+    /// Lowercase text the messages contain
+    pub phrase: String,
+    pub timeout_minutes: Option<u32>,
+}
+
+impl NukeCommand {
+    /// Parse the arguments of `!nuke`, returns the usage text if they don't match.
+    /// A number at the end is the timeout, unless it's the whole phrase.
+    pub fn parse(args: &[String]) -> Result<Self, &'static str> {
+        let (phrase, timeout_minutes) = match args {
+            [] => return Err(USAGE),
+            [phrase @ .., minutes]
+                if !phrase.is_empty() && minutes.chars().all(|c| c.is_ascii_digit()) =>
+            {
+                let minutes = minutes
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|minutes| (1..=MAX_TIMEOUT_MINUTES).contains(minutes))
+                    .ok_or(USAGE)?;
+                (phrase, Some(minutes))
+            }
+            phrase => (phrase, None),
+        };
+        Ok(Self {
+            phrase: phrase.join(" ").to_lowercase(),
+            timeout_minutes,
+        })
+    }
+
+    /// Buffered messages containing the phrase. Messages of moderators and the
+    /// broadcaster are left alone, including the `!nuke` itself.
+    pub fn targets<'a>(&self, messages: &'a [ChatMessage]) -> Vec<&'a ChatMessage> {
+        messages
+            .iter()
+            .filter(|message| {
+                !message.badges.iter().any(|badge| {
+                    badge.starts_with("broadcaster-") || badge.starts_with("moderator-")
+                })
+            })
+            .filter(|message| message.message_text.to_lowercase().contains(&self.phrase))
+            .collect()
+    }
+}
+
+/// Check and parse `!nuke`. Moderators only.
+pub fn parse_nuke_command(context: &CommandContext) -> Result<NukeCommand, CommandResult> {
+    if context.identity().role() < UserRole::Moderator {
+        return Err(CommandResult::PermissionDenied);
+    }
+
+    NukeCommand::parse(&context.args).map_err(|usage| {
+        CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), usage)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    fn message(id: &str, text: &str, badges: &[&str]) -> ChatMessage {
+        ChatMessage {
+            message_id: id.to_string(),
+            message_text: text.to_string(),
+            badges: badges.iter().map(|badge| badge.to_string()).collect(),
+            username: "viewer".to_string(),
+            user_id: "1".to_string(),
+            color: String::new(),
+            timestamp: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_nuke_command() {
+        assert_eq!(
+            NukeCommand::parse(&args("Big Follows 10")),
+            Ok(NukeCommand {
+                phrase: "big follows".to_string(),
+                timeout_minutes: Some(10),
+            })
+        );
+        assert_eq!(
+            NukeCommand::parse(&args("1337")),
+            Ok(NukeCommand {
+                phrase: "1337".to_string(),
+                timeout_minutes: None,
+            })
+        );
+        assert!(NukeCommand::parse(&args("")).is_err());
+        assert!(NukeCommand::parse(&args("spam 0")).is_err());
+
+        let nuke = NukeCommand::parse(&args("big follows")).unwrap();
+        let messages = [
+            message("1", "Get BIG FOLLOWS now", &[]),
+            message("2", "hello", &[]),
+            message("3", "!nuke big follows", &["moderator-1"]),
+            message("4", "big follows?", &["subscriber-12"]),
+        ];
+        let targets: Vec<&str> = nuke
+            .targets(&messages)
+            .into_iter()
+            .map(|message| message.message_id.as_str())
+            .collect();
+        assert_eq!(targets, vec!["1", "4"]);
+    }
+}
@@ -1,6 +1,86 @@
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
 use super::context::CommandContext;
+use super::CommandRegistry;
+use crate::backend::roles::UserIdentity;
 use crate::backend::twitch::ChatMessageEvent;
 
+/// Runs a command when a chat message contains a phrase or matches a regex,
+/// without the ! prefix
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeywordTrigger {
+    /// Text to find anywhere in the message (ignoring case), or a regex
+    pub pattern: String,
+    /// Treat `pattern` as a case-insensitive regex
+    #[serde(default)]
+    pub regex: bool,
+    /// Turn the trigger off without removing it, e.g. when it gets spammy
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Compiled regex, `None` if the pattern is invalid
+    #[serde(skip)]
+    compiled: OnceLock<Option<Regex>>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl KeywordTrigger {
+    pub fn new(pattern: String, regex: bool) -> Self {
+        Self {
+            pattern,
+            regex,
+            enabled: true,
+            compiled: OnceLock::new(),
+        }
+    }
+
+    /// Check the pattern, returns why it can't be used
+    pub fn validate(&self) -> Result<(), String> {
+        if self.pattern.trim().is_empty() {
+            return Err("The keyword is empty".to_string());
+        }
+        if self.regex {
+            build_regex(&self.pattern).map_err(|e| format!("Invalid regex: {}", e))?;
+        }
+        Ok(())
+    }
+
+    /// Match a message, returns the regex capture groups to use as `{args}`
+    pub fn captures(&self, text: &str) -> Option<Vec<String>> {
+        if !self.enabled || self.pattern.trim().is_empty() {
+            return None;
+        }
+        if !self.regex {
+            let found = text
+                .to_lowercase()
+                .contains(&self.pattern.trim().to_lowercase());
+            return found.then(Vec::new);
+        }
+
+        let regex = self
+            .compiled
+            .get_or_init(|| build_regex(&self.pattern).ok())
+            .as_ref()?;
+        let captures = regex.captures(text)?;
+        Some(
+            captures
+                .iter()
+                .skip(1)
+                .flatten()
+                .map(|capture| capture.as_str().to_string())
+                .collect(),
+        )
+    }
+}
+
+pub(super) fn build_regex(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern).case_insensitive(true).build()
+}
+
 /// Parser for extracting commands from chat messages
 #[derive(Debug, Default)]
 pub struct CommandParser {
@@ -26,8 +106,12 @@ impl CommandParser {
         message.trim().starts_with(&self.prefix)
     }
 
-    /// Parse a command from a chat message
-    pub fn parse(&self, message: ChatMessageEvent) -> Option<CommandContext> {
+    /// Parse a command from a chat message sent by a user with the given roles
+    pub fn parse(
+        &self,
+        message: ChatMessageEvent,
+        identity: UserIdentity,
+    ) -> Option<CommandContext> {
         let text = message.message.text.trim();
 
         if !self.is_command(text) {
@@ -47,7 +131,59 @@ impl CommandParser {
         let command_name = parts[0].to_lowercase();
         let args = parts[1..].iter().map(|s| s.to_string()).collect();
 
-        Some(CommandContext::new(message, command_name, args))
+        Some(CommandContext::new(message, command_name, args, identity))
+    }
+
+    /// Find the command bound to a redeemed channel point reward, the text entered by the
+    /// viewer becomes the arguments
+    pub fn parse_redemption(
+        &self,
+        message: ChatMessageEvent,
+        identity: UserIdentity,
+        registry: &CommandRegistry,
+    ) -> Option<CommandContext> {
+        let reward_id = message.channel_points_custom_reward_id.as_deref()?;
+        let command = registry
+            .list()
+            .into_iter()
+            .find(|command| command.reward_id.as_deref() == Some(reward_id))?;
+        let trigger = command.trigger.clone();
+        let args = message
+            .message
+            .text
+            .split_whitespace()
+            .map(String::from)
+            .collect();
+        Some(CommandContext::new(message, trigger, args, identity))
+    }
+
+    /// Find a command with a keyword trigger matching a message that isn't a ! command.
+    /// Commands are checked in trigger order and the first match wins.
+    pub fn parse_keyword(
+        &self,
+        message: ChatMessageEvent,
+        identity: UserIdentity,
+        registry: &CommandRegistry,
+    ) -> Option<CommandContext> {
+        let text = message.message.text.trim();
+        if self.is_command(text) {
+            return None;
+        }
+
+        let mut commands = registry.list();
+        commands.sort_by(|a, b| a.trigger.cmp(&b.trigger));
+        let (trigger, args) = commands
+            .into_iter()
+            .filter(|command| command.enabled && !command.pending_approval)
+            .find_map(|command| {
+                let args = command
+                    .keywords
+                    .iter()
+                    .find_map(|keyword| keyword.captures(text))?;
+                Some((command.trigger.clone(), args))
+            })?;
+
+        Some(CommandContext::new(message, trigger, args, identity).from_keyword())
     }
 }
 
@@ -63,4 +199,79 @@ mod tests {
         assert!(!parser.is_command("hello"));
         assert!(!parser.is_command("hello !world"));
     }
+
+    #[test]
+    fn test_keyword_captures() {
+        let phrase = KeywordTrigger::new("What game".to_string(), false);
+        assert_eq!(phrase.captures("so what GAME is this?"), Some(vec![]));
+        assert_eq!(phrase.captures("nice game"), None);
+
+        let regex = KeywordTrigger::new(r"^is (\w+) live\??$".to_string(), true);
+        assert_eq!(
+            regex.captures("Is Yhn live?"),
+            Some(vec!["Yhn".to_string()])
+        );
+        assert_eq!(regex.captures("yhn is live"), None);
+
+        let mut disabled = KeywordTrigger::new("hello".to_string(), false);
+        disabled.enabled = false;
+        assert_eq!(disabled.captures("hello"), None);
+
+        let invalid = KeywordTrigger::new("(unclosed".to_string(), true);
+        assert!(invalid.validate().is_err());
+        assert_eq!(invalid.captures("(unclosed"), None);
+    }
+
+    #[test]
+    fn test_parse_redemption() {
+        use crate::backend::commands::{
+            Command, CommandAction, CommandExecutor, CommandPermission, CommandResult,
+        };
+        use crate::backend::twitch::ChannelPointsRedemptionEvent;
+
+        let mut registry = CommandRegistry::new();
+        registry.register(
+            Command::new(
+                "hydrate".into(),
+                String::new(),
+                CommandPermission::Everyone,
+                CommandAction::SendMessage {
+                    message: "{user} says {args}".to_string(),
+                },
+            )
+            .with_reward_id(" reward-1 ".to_string()),
+        );
+        let redemption: ChannelPointsRedemptionEvent = serde_json::from_value(serde_json::json!({
+            "id": "r1",
+            "broadcaster_user_id": "1",
+            "broadcaster_user_login": "streamer",
+            "broadcaster_user_name": "Streamer",
+            "user_id": "2",
+            "user_login": "viewer",
+            "user_name": "Viewer",
+            "user_input": "drink water",
+            "status": "unfulfilled",
+            "reward": { "id": "reward-1", "title": "Hydrate", "cost": 100, "prompt": "" },
+            "redeemed_at": "2025-03-18T12:00:00Z"
+        }))
+        .unwrap();
+
+        let parser = CommandParser::with_default_prefix();
+        let context = parser
+            .parse_redemption(redemption.into(), UserIdentity::default(), &registry)
+            .unwrap();
+        assert_eq!(context.command_name, "hydrate");
+        assert_eq!(context.args, vec!["drink", "water"]);
+
+        let mut executor = CommandExecutor::new(registry);
+        assert!(matches!(
+            executor.execute(&context),
+            CommandResult::Success(Some(message)) if message == "send:viewer says drink water"
+        ));
+        // The chat trigger doesn't run commands bound to a reward
+        assert!(matches!(
+            executor.execute(&CommandContext::test("hydrate", &[])),
+            CommandResult::NotFound
+        ));
+    }
 }
@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CommandContext, CommandResult};
+use crate::backend::roles::UserRole;
+
+/// Trigger of the poll command
+pub const POLL_COMMAND: &str = "poll";
+
+const USAGE: &str = "Usage: !poll \"question\" option1|option2|option3 [seconds] | !poll end";
+
+const DEFAULT_DURATION: u32 = 60;
+
+/// Settings for polls, whether started with `!poll` or from the Twitch dashboard
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PollConfig {
+    /// Sent to chat when a poll ends, `{title}` and `{outcome}` are replaced. Empty to disable.
+    #[serde(default = "default_results_message")]
+    pub results_message: String,
+}
+
+impl Default for PollConfig {
+    fn default() -> Self {
+        Self {
+            results_message: default_results_message(),
+        }
+    }
+}
+
+fn default_results_message() -> String {
+    "Poll \"{title}\" ended: {outcome}".to_string()
+}
+
+/// Action of `!poll`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PollCommand {
+    Start {
+        title: String,
+        choices: Vec<String>,
+        /// Seconds
+        duration: u32,
+    },
+    /// End the running poll early
+    End,
+}
+
+impl PollCommand {
+    /// Parse the text after `!poll`, returns an error to reply with if it doesn't match.
+    /// Titles are limited to 60 characters and choices to 25, as on Twitch.
+    pub fn parse(text: &str) -> Result<Self, &'static str> {
+        if text.trim().eq_ignore_ascii_case("end") {
+            return Ok(Self::End);
+        }
+
+        let (title, choices, duration) = parse_question(text, DEFAULT_DURATION).ok_or(USAGE)?;
+        if title.chars().count() > 60 {
+            return Err("The question can be at most 60 characters");
+        }
+        if !(2..=5).contains(&choices.len()) {
+            return Err("A poll needs 2 to 5 options, separated by |");
+        }
+        if choices.iter().any(|choice| choice.chars().count() > 25) {
+            return Err("Options can be at most 25 characters");
+        }
+        if !(15..=1800).contains(&duration) {
+            return Err("The duration has to be between 15 and 1800 seconds");
+        }
+
+        Ok(Self::Start {
+            title,
+            choices,
+            duration,
+        })
+    }
+}
+
+/// Split `"question" choice1|choice2 [seconds]` into its parts, shared with `!prediction`.
+/// Returns `None` if the question isn't quoted or a part is empty.
+pub(super) fn parse_question(
+    text: &str,
+    default_duration: u32,
+) -> Option<(String, Vec<String>, u32)> {
+    let (title, rest) = text.trim().strip_prefix('"')?.split_once('"')?;
+    let title = title.trim();
+    let rest = rest.trim();
+
+    // A number after the last choice is the duration
+    let (choices, duration) = match rest.rsplit_once(' ') {
+        Some((choices, duration)) if duration.chars().all(|c| c.is_ascii_digit()) => {
+            (choices, duration.parse().ok()?)
+        }
+        _ => (rest, default_duration),
+    };
+    let choices: Vec<String> = choices
+        .split('|')
+        .map(|choice| choice.trim().to_string())
+        .collect();
+
+    if title.is_empty() || choices.iter().any(String::is_empty) {
+        return None;
+    }
+    Some((title.to_string(), choices, duration))
+}
+
+/// Check and parse `!poll`. Returns the command to run against the Twitch API,
+/// or the result to report when it can't run. Moderators only.
+pub fn parse_poll_command(context: &CommandContext) -> Result<PollCommand, CommandResult> {
+    if context.identity().role() < UserRole::Moderator {
+        return Err(CommandResult::PermissionDenied);
+    }
+
+    PollCommand::parse(&context.args.join(" ")).map_err(|error| {
+        CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), error)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_poll_command() {
+        assert_eq!(
+            PollCommand::parse("\"Best snack?\" Chips | Pretzels|Ice cream 120"),
+            Ok(PollCommand::Start {
+                title: "Best snack?".to_string(),
+                choices: vec![
+                    "Chips".to_string(),
+                    "Pretzels".to_string(),
+                    "Ice cream".to_string()
+                ],
+                duration: 120
+            })
+        );
+        assert_eq!(
+            PollCommand::parse("\"Which level?\" 1|2"),
+            Ok(PollCommand::Start {
+                title: "Which level?".to_string(),
+                choices: vec!["1".to_string(), "2".to_string()],
+                duration: DEFAULT_DURATION
+            })
+        );
+        assert_eq!(PollCommand::parse("end"), Ok(PollCommand::End));
+        assert!(PollCommand::parse("Best snack? Chips|Pretzels").is_err());
+        assert!(PollCommand::parse("\"Best snack?\" Chips").is_err());
+        assert!(PollCommand::parse("\"Best snack?\" Chips|Pretzels 5").is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use super::poll::parse_question;
+use super::{CommandContext, CommandResult};
+use crate::backend::roles::UserRole;
+
+/// Trigger of the command that starts, locks and cancels predictions
+pub const PREDICTION_COMMAND: &str = "prediction";
+/// Trigger of the command that picks the winning outcome
+pub const OUTCOME_COMMAND: &str = "outcome";
+
+const USAGE: &str =
+    "Usage: !prediction \"question\" outcome1|outcome2 [seconds] | !prediction lock | !prediction cancel";
+const OUTCOME_USAGE: &str = "Usage: !outcome <number or name of the winning outcome>";
+
+const DEFAULT_WINDOW: u32 = 120;
+
+/// Action of `!prediction` or `!outcome`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PredictionCommand {
+    Start {
+        title: String,
+        outcomes: Vec<String>,
+        /// Seconds viewers can make predictions
+        window: u32,
+    },
+    /// Stop taking predictions
+    Lock,
+    /// Cancel and refund all channel points
+    Cancel,
+    /// Pay out to the outcome with this number (starting at 1) or name
+    Resolve(String),
+}
+
+impl PredictionCommand {
+    /// Parse the text after `!prediction`, returns an error to reply with if it doesn't match.
+    /// Titles are limited to 45 characters and outcomes to 25, as on Twitch.
+    pub fn parse(text: &str) -> Result<Self, &'static str> {
+        match text.trim().to_lowercase().as_str() {
+            "lock" => return Ok(Self::Lock),
+            "cancel" => return Ok(Self::Cancel),
+            _ => {}
+        }
+
+        let (title, outcomes, window) = parse_question(text, DEFAULT_WINDOW).ok_or(USAGE)?;
+        if title.chars().count() > 45 {
+            return Err("The question can be at most 45 characters");
+        }
+        if !(2..=10).contains(&outcomes.len()) {
+            return Err("A prediction needs 2 to 10 outcomes, separated by |");
+        }
+        if outcomes.iter().any(|outcome| outcome.chars().count() > 25) {
+            return Err("Outcomes can be at most 25 characters");
+        }
+        if !(30..=1800).contains(&window) {
+            return Err("The prediction window has to be between 30 and 1800 seconds");
+        }
+
+        Ok(Self::Start {
+            title,
+            outcomes,
+            window,
+        })
+    }
+}
+
+/// Check and parse `!prediction` and `!outcome`. Returns the command to run against
+/// the Twitch API, or the result to report when it can't run. Moderators only.
+pub fn parse_prediction_command(
+    context: &CommandContext,
+) -> Result<PredictionCommand, CommandResult> {
+    if context.identity().role() < UserRole::Moderator {
+        return Err(CommandResult::PermissionDenied);
+    }
+
+    let text = context.args.join(" ");
+    let command = if context.command_name == OUTCOME_COMMAND {
+        let outcome = text.trim();
+        if outcome.is_empty() {
+            Err(OUTCOME_USAGE)
+        } else {
+            Ok(PredictionCommand::Resolve(outcome.to_string()))
+        }
+    } else {
+        PredictionCommand::parse(&text)
+    };
+
+    command.map_err(|error| {
+        CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), error)))
+    })
+}
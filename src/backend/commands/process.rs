@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use std::process::Stdio;
+use std::time::Duration;
+
+use super::CommandPermission;
+use crate::backend::roles::UserIdentity;
+
+/// Longest reply made from a program's output, Twitch allows 500 characters
+const MAX_OUTPUT_LENGTH: usize = 500;
+
+/// Settings for the RunProcess command action. Nothing is started unless the
+/// program is listed in `allowed_programs`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProcessConfig {
+    /// Programs commands may start, as written in the command's `program`
+    #[serde(default)]
+    pub allowed_programs: Vec<String>,
+    /// Who may trigger a program, on top of the command's own permission
+    #[serde(default = "default_permission")]
+    pub permission: CommandPermission,
+    /// Seconds before a program that hasn't finished is stopped. Programs run in the
+    /// background, so chat doesn't wait for them
+    #[serde(default = "default_timeout")]
+    pub timeout: u64,
+}
+
+impl Default for ProcessConfig {
+    fn default() -> Self {
+        Self {
+            allowed_programs: Vec::new(),
+            permission: default_permission(),
+            timeout: default_timeout(),
+        }
+    }
+}
+
+fn default_permission() -> CommandPermission {
+    CommandPermission::Broadcaster
+}
+
+fn default_timeout() -> u64 {
+    5
+}
+
+impl ProcessConfig {
+    /// Check that `program` may be started by a user with `identity`
+    pub fn check(&self, program: &str, identity: &UserIdentity) -> Result<(), String> {
+        if !self
+            .allowed_programs
+            .iter()
+            .any(|allowed| allowed == program)
+        {
+            return Err(format!(
+                "{} isn't in allowed_programs of [run_process]",
+                program
+            ));
+        }
+        if !self.permission.has_permission(identity) {
+            return Err(format!("Only {:?} can run {}", self.permission, program));
+        }
+        Ok(())
+    }
+}
+
+/// Program to start for a RunProcess action, with the placeholders filled in
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ProcessRequest {
+    pub program: String,
+    pub args: Vec<String>,
+    /// Reply with the program's output
+    pub reply: bool,
+}
+
+/// Start the program without a shell and wait for it, returns its output for chat
+pub async fn run_process(request: &ProcessRequest, timeout: Duration) -> Result<String, String> {
+    let child = tokio::process::Command::new(&request.program)
+        .args(&request.args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| format!("Failed to start {}: {}", request.program, e))?;
+
+    let output = tokio::time::timeout(timeout, child.wait_with_output())
+        .await
+        .map_err(|_| {
+            format!(
+                "{} didn't finish in {}s",
+                request.program,
+                timeout.as_secs()
+            )
+        })?
+        .map_err(|e| format!("Failed to run {}: {}", request.program, e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "{} exited with {}: {}",
+            request.program,
+            output.status,
+            format_output(&output.stderr)
+        ));
+    }
+    Ok(format_output(&output.stdout))
+}
+
+/// Output of a program as a single chat line
+fn format_output(output: &[u8]) -> String {
+    let text = String::from_utf8_lossy(output);
+    let line = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    line.chars().take(MAX_OUTPUT_LENGTH).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_config() {
+        let config = ProcessConfig {
+            allowed_programs: vec!["/usr/bin/np".to_string()],
+            ..Default::default()
+        };
+        let broadcaster = UserIdentity {
+            broadcaster: true,
+            ..Default::default()
+        };
+        let moderator = UserIdentity {
+            moderator: true,
+            ..Default::default()
+        };
+        assert!(config.check("/usr/bin/np", &broadcaster).is_ok());
+        assert!(config.check("/usr/bin/np", &moderator).is_err());
+        assert!(config.check("/bin/rm", &broadcaster).is_err());
+
+        assert_eq!(
+            format_output(b"  Now playing:\n\nSong  \r\n"),
+            "Now playing: Song"
+        );
+        assert_eq!(format_output(&[b'a'; 600]).len(), MAX_OUTPUT_LENGTH);
+    }
+}
@@ -0,0 +1,75 @@
+use super::{CommandContext, CommandResult};
+use crate::backend::quotes::validate_quote;
+use crate::backend::roles::UserRole;
+
+/// Trigger of the command showing a quote
+pub const QUOTE_COMMAND: &str = "quote";
+/// Trigger of the command adding a quote
+pub const ADD_QUOTE_COMMAND: &str = "addquote";
+/// Trigger of the command deleting a quote
+pub const DEL_QUOTE_COMMAND: &str = "delquote";
+
+/// Action of `!quote`, `!addquote` or `!delquote`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuoteCommand {
+    Random,
+    Show(u64),
+    Add(String),
+    Delete(u64),
+}
+
+impl QuoteCommand {
+    /// Parse the arguments of the quote command `command`
+    pub fn parse(command: &str, args: &[String]) -> Result<Self, &'static str> {
+        // IDs are shown as "#3", accept them either way
+        let id = |arg: &str| arg.trim_start_matches('#').parse::<u64>().ok();
+        match command {
+            ADD_QUOTE_COMMAND => Ok(Self::Add(validate_quote(&args.join(" "))?.to_string())),
+            DEL_QUOTE_COMMAND => match args {
+                [arg] => id(arg).map(Self::Delete).ok_or("Usage: !delquote <id>"),
+                _ => Err("Usage: !delquote <id>"),
+            },
+            _ => match args {
+                [] => Ok(Self::Random),
+                [arg] => id(arg).map(Self::Show).ok_or("Usage: !quote [id]"),
+                _ => Err("Usage: !quote [id]"),
+            },
+        }
+    }
+}
+
+/// Check and parse the quote commands. Everyone can show quotes, only moderators
+/// can add or delete them.
+pub fn parse_quote_command(context: &CommandContext) -> Result<QuoteCommand, CommandResult> {
+    if context.command_name != QUOTE_COMMAND && context.identity().role() < UserRole::Moderator {
+        return Err(CommandResult::PermissionDenied);
+    }
+
+    QuoteCommand::parse(&context.command_name, &context.args).map_err(|usage| {
+        CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), usage)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(command: &str, args: &str) -> Result<QuoteCommand, &'static str> {
+        let args: Vec<String> = args.split_whitespace().map(String::from).collect();
+        QuoteCommand::parse(command, &args)
+    }
+
+    #[test]
+    fn test_parse_quote_command() {
+        assert_eq!(parse("quote", ""), Ok(QuoteCommand::Random));
+        assert_eq!(parse("quote", "#12"), Ok(QuoteCommand::Show(12)));
+        assert!(parse("quote", "funny one").is_err());
+        assert_eq!(
+            parse("addquote", "I never miss"),
+            Ok(QuoteCommand::Add("I never miss".to_string()))
+        );
+        assert!(parse("addquote", "").is_err());
+        assert_eq!(parse("delquote", "3"), Ok(QuoteCommand::Delete(3)));
+        assert!(parse("delquote", "").is_err());
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use super::{CommandContext, CommandResult};
+use crate::backend::roles::UserRole;
+
+/// Trigger of the raid command
+pub const RAID_COMMAND: &str = "raid";
+
+const USAGE: &str = "Usage: !raid <channel> | !raid confirm | !raid cancel";
+
+/// Time to confirm a raid after naming the channel
+const CONFIRM_WINDOW: Duration = Duration::from_secs(30);
+
+/// Channel named with `!raid <channel>` that waits for `!raid confirm`
+static PENDING_RAID: LazyLock<Mutex<Option<(String, Instant)>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+/// Action of `!raid`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RaidCommand {
+    /// Name the channel to raid, the raid starts once confirmed
+    Prepare(String),
+    Confirm,
+    /// Drop the unconfirmed raid or cancel the raid countdown
+    Cancel,
+}
+
+impl RaidCommand {
+    /// Parse the arguments of `!raid`, returns the usage text if they don't match
+    pub fn parse(args: &[String]) -> Result<Self, &'static str> {
+        match args {
+            [arg] if arg.eq_ignore_ascii_case("confirm") => Ok(Self::Confirm),
+            [arg] if arg.eq_ignore_ascii_case("cancel") => Ok(Self::Cancel),
+            [channel] => {
+                let channel = channel.trim_start_matches('@').to_lowercase();
+                if channel.is_empty() {
+                    Err(USAGE)
+                } else {
+                    Ok(Self::Prepare(channel))
+                }
+            }
+            _ => Err(USAGE),
+        }
+    }
+}
+
+/// Check and parse `!raid`. Returns the command to run, or the result to report
+/// when it can't run. Broadcaster only, since raids take the whole audience along.
+pub fn parse_raid_command(context: &CommandContext) -> Result<RaidCommand, CommandResult> {
+    if context.identity().role() < UserRole::Broadcaster {
+        return Err(CommandResult::PermissionDenied);
+    }
+
+    RaidCommand::parse(&context.args).map_err(|usage| {
+        CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), usage)))
+    })
+}
+
+/// Remember the channel until the raid is confirmed, replacing an earlier one
+pub fn prepare_raid(channel: &str) {
+    *PENDING_RAID.lock().unwrap() = Some((channel.to_string(), Instant::now()));
+}
+
+/// Take the channel waiting for confirmation, `None` if there is none or it expired
+pub fn take_pending_raid() -> Option<String> {
+    PENDING_RAID
+        .lock()
+        .unwrap()
+        .take()
+        .filter(|(_, prepared_at)| prepared_at.elapsed() < CONFIRM_WINDOW)
+        .map(|(channel, _)| channel)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_raid_command() {
+        assert_eq!(
+            RaidCommand::parse(&args("@SomeStreamer")),
+            Ok(RaidCommand::Prepare("somestreamer".to_string()))
+        );
+        assert_eq!(
+            RaidCommand::parse(&args("Confirm")),
+            Ok(RaidCommand::Confirm)
+        );
+        assert_eq!(RaidCommand::parse(&args("cancel")), Ok(RaidCommand::Cancel));
+        assert!(RaidCommand::parse(&args("")).is_err());
+        assert!(RaidCommand::parse(&args("@")).is_err());
+        assert!(RaidCommand::parse(&args("two channels")).is_err());
+    }
+}
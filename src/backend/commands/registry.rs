@@ -1,6 +1,13 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::arguments::required_arg_count;
+use super::conditions::Condition;
+use super::{CommandContext, KeywordTrigger};
+use crate::backend::counters::CounterOp;
+use crate::backend::roles::{UserIdentity, UserRole};
+
 /// Permission level required to execute a command
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CommandPermission {
@@ -14,35 +21,61 @@ pub enum CommandPermission {
     Moderator,
     /// Only the broadcaster can use this command
     Broadcaster,
+    /// Only followers can use this command, optionally after following for a while
+    Follower {
+        #[serde(default)]
+        min_minutes: u64,
+    },
 }
 
 impl CommandPermission {
-    /// Check if user badges meet the permission requirement
+    /// Check if the user meets the permission requirement
     /// Implements a permission hierarchy: Broadcaster > Moderator > VIP > Subscriber > Everyone
-    pub fn has_permission(&self, badges: &[crate::backend::twitch::Badge]) -> bool {
-        // Check if user is broadcaster (has all permissions)
-        let is_broadcaster = badges.iter().any(|b| b.set_id == "broadcaster");
-        if is_broadcaster {
-            return true;
-        }
-
-        // Check if user is moderator (has all permissions except broadcaster-only)
-        let is_moderator = badges.iter().any(|b| b.set_id == "moderator");
-        if is_moderator && !matches!(self, CommandPermission::Broadcaster) {
-            return true;
+    pub fn has_permission(&self, identity: &UserIdentity) -> bool {
+        match identity.role() {
+            // Broadcaster has all permissions
+            UserRole::Broadcaster => return true,
+            // Moderators have all permissions except broadcaster-only
+            UserRole::Moderator if !matches!(self, CommandPermission::Broadcaster) => return true,
+            _ => {}
         }
 
         // Check specific permission level
         match self {
             CommandPermission::Everyone => true,
-            CommandPermission::Subscriber => {
-                badges.iter().any(|b| b.set_id == "subscriber" || b.set_id == "founder")
-            }
-            CommandPermission::Vip => badges.iter().any(|b| b.set_id == "vip"),
+            CommandPermission::Subscriber => identity.subscriber,
+            CommandPermission::Vip => identity.vip,
             CommandPermission::Moderator => false, // Already checked above
             CommandPermission::Broadcaster => false, // Already checked above
+            CommandPermission::Follower { .. } => false, // Needs the follow date, see `allows`
         }
     }
+
+    /// Like `has_permission`, with the date the user followed the channel for
+    /// `Follower` (`None` if they don't follow)
+    pub fn allows(
+        &self,
+        identity: &UserIdentity,
+        followed_at: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        match self {
+            CommandPermission::Follower { min_minutes }
+                if identity.role() < UserRole::Moderator =>
+            {
+                followed_at.is_some_and(|followed_at| {
+                    u64::try_from((now - followed_at).num_minutes())
+                        .is_ok_and(|minutes| minutes >= *min_minutes)
+                })
+            }
+            _ => self.has_permission(identity),
+        }
+    }
+
+    /// Whether the follow date of the user is needed to check this permission
+    pub fn needs_follow_date(&self) -> bool {
+        matches!(self, CommandPermission::Follower { .. })
+    }
 }
 
 /// Action to perform when a command is executed
@@ -54,10 +87,159 @@ pub enum CommandAction {
     SendMessage { message: String },
     /// Reply to the user who sent the command
     Reply { message: String },
+    /// Reply with one of the messages, picked at random every time
+    RandomReply { messages: Vec<String> },
+    /// Send a highlighted announcement to chat (the bot must be a moderator)
+    Announce {
+        message: String,
+        /// "blue", "green", "orange", "purple" or "primary" for the channel's accent color
+        #[serde(default = "default_announcement_color")]
+        color: String,
+    },
+    /// Give a channel a Twitch shoutout (the bot must be a moderator and the stream live)
+    Shoutout {
+        /// Login of the channel, usually `{args}`
+        #[serde(default = "default_shoutout_user")]
+        user: String,
+    },
+    /// Whisper the user who sent the command (the bot account needs a verified phone number)
+    Whisper { message: String },
+    /// Play a sound from assets/sounds at the SFX volume
+    PlaySound { sound: String },
+    /// Change a counter kept across restarts, shown with `{count}` or `{count:<name>}`
+    Counter { name: String, op: CounterOp },
+    /// Start a local program listed in `[run_process]` of config.toml, without a shell.
+    /// Placeholders work in `args`, each one is passed to the program as it is.
+    RunProcess {
+        program: String,
+        #[serde(default)]
+        args: Vec<String>,
+        /// Reply with what the program printed
+        #[serde(default)]
+        reply: bool,
+    },
+    /// Run a Rhai script, see script.rs for what it can do. Scripts can't read files
+    /// or run programs, and are stopped when they run too long.
+    Script { source: String },
     /// Multiple actions in sequence
     Multiple { actions: Vec<CommandAction> },
+    /// Run `then` when the condition holds and `else` otherwise, e.g. inside
+    /// Multiple to answer differently with and without arguments
+    If {
+        condition: Condition,
+        then: Vec<CommandAction>,
+        #[serde(default, rename = "else", skip_serializing_if = "Vec::is_empty")]
+        otherwise: Vec<CommandAction>,
+    },
     // Future actions can be added here:
-    // Ban, Timeout, RunScript, etc.
+    // Ban, Timeout, etc.
+}
+
+impl CommandAction {
+    /// Actions nested in Multiple or If
+    fn nested(&self) -> Vec<&CommandAction> {
+        match self {
+            CommandAction::Multiple { actions } => actions.iter().collect(),
+            CommandAction::If {
+                then, otherwise, ..
+            } => then.iter().chain(otherwise).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Message templates used by this action, including nested actions
+    pub fn templates(&self) -> Vec<&str> {
+        match self {
+            CommandAction::TextToSpeech { message }
+            | CommandAction::SendMessage { message }
+            | CommandAction::Reply { message }
+            | CommandAction::Announce { message, .. }
+            | CommandAction::Whisper { message } => vec![message.as_str()],
+            CommandAction::RandomReply { messages } => {
+                messages.iter().map(String::as_str).collect()
+            }
+            CommandAction::Shoutout { user } => vec![user.as_str()],
+            CommandAction::RunProcess { args, .. } => args.iter().map(String::as_str).collect(),
+            _ => self
+                .nested()
+                .into_iter()
+                .flat_map(|action| action.templates())
+                .collect(),
+        }
+    }
+
+    /// Name of the first counter this action changes, including nested actions
+    pub fn counter(&self) -> Option<&str> {
+        match self {
+            CommandAction::Counter { name, .. } => Some(name.as_str()),
+            _ => self
+                .nested()
+                .into_iter()
+                .find_map(|action| action.counter()),
+        }
+    }
+
+    /// Sounds played by this action, including nested actions
+    pub fn sounds(&self) -> Vec<&str> {
+        match self {
+            CommandAction::PlaySound { sound } => vec![sound.as_str()],
+            _ => self
+                .nested()
+                .into_iter()
+                .flat_map(|action| action.sounds())
+                .collect(),
+        }
+    }
+
+    /// Programs started by this action, including nested actions
+    pub fn programs(&self) -> Vec<&str> {
+        match self {
+            CommandAction::RunProcess { program, .. } => vec![program.as_str()],
+            _ => self
+                .nested()
+                .into_iter()
+                .flat_map(|action| action.programs())
+                .collect(),
+        }
+    }
+
+    /// Scripts run by this action, including nested actions
+    pub fn scripts(&self) -> Vec<&str> {
+        match self {
+            CommandAction::Script { source } => vec![source.as_str()],
+            _ => self
+                .nested()
+                .into_iter()
+                .flat_map(|action| action.scripts())
+                .collect(),
+        }
+    }
+
+    /// Conditions checked by this action, including nested actions
+    pub fn conditions(&self) -> Vec<&Condition> {
+        let nested = self
+            .nested()
+            .into_iter()
+            .flat_map(|action| action.conditions());
+        match self {
+            CommandAction::If { condition, .. } => {
+                std::iter::once(condition).chain(nested).collect()
+            }
+            _ => nested.collect(),
+        }
+    }
+}
+
+/// What happens when a user hits a cooldown or lacks permission for a command
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FailureFeedback {
+    /// Log permission denials to the bot log
+    #[default]
+    Log,
+    /// Ignore the failure without logging
+    Silent,
+    /// Whisper the reason to the user
+    Whisper,
 }
 
 /// A command definition
@@ -73,11 +255,64 @@ pub struct Command {
     pub action: CommandAction,
     /// Cooldown in seconds (0 = no cooldown)
     pub cooldown: u64,
+    /// Whether moderators and the broadcaster ignore the cooldown
+    #[serde(default = "default_bypass_cooldown")]
+    pub mods_bypass_cooldown: bool,
     /// Whether the command is enabled
     pub enabled: bool,
+    /// How cooldown and permission failures are reported
+    #[serde(default)]
+    pub failure_feedback: FailureFeedback,
+    /// Response when the command is on cooldown, supports `{remaining}`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cooldown_message: Option<String>,
+    /// Response when the user isn't allowed to use the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permission_denied_message: Option<String>,
+    /// Login of the viewer who owns this command (set by `!mycmd`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Viewer-owned commands stay inactive until a moderator approves them
+    #[serde(default)]
+    pub pending_approval: bool,
+    /// Only kept for the current stream, removed on disconnect and never saved
+    #[serde(default)]
+    pub temporary: bool,
+    /// Seconds to wait for external data like `{json:...}` before the command is dropped
+    #[serde(default = "default_timeout")]
+    pub timeout: u64,
+    /// Also run the command when a message contains one of these, without the ! prefix
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<KeywordTrigger>,
+    /// Times each user can run the command per stream (0 = no limit), counted since the
+    /// stream went live or the bot connected
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub max_uses_per_stream: usize,
+    /// Arguments the command needs, the usage is sent instead when fewer are given
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub required_args: usize,
+    /// Reply when arguments are missing, e.g. "Usage: !hug <user>"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<String>,
+    /// Group to enable or disable together with other commands, e.g. "minecraft"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// Logins that can always use the command, whatever the permission
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_users: Vec<String>,
+    /// Logins that can never use the command, even mods
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub denied_users: Vec<String>,
+    /// Channel point reward running the command instead of its chat trigger, the text
+    /// entered by the viewer becomes `{args}`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reward_id: Option<String>,
 }
 
 impl Command {
+    /// Seconds to wait for external data unless the command sets `timeout`
+    pub const DEFAULT_TIMEOUT: u64 = 5;
+
     /// Create a new command
     pub fn new(
         trigger: String,
@@ -91,7 +326,23 @@ impl Command {
             permission,
             action,
             cooldown: 0,
+            mods_bypass_cooldown: true,
             enabled: true,
+            failure_feedback: FailureFeedback::Log,
+            cooldown_message: None,
+            permission_denied_message: None,
+            owner: None,
+            pending_approval: false,
+            temporary: false,
+            timeout: default_timeout(),
+            keywords: Vec::new(),
+            max_uses_per_stream: 0,
+            required_args: 0,
+            usage: None,
+            group: None,
+            allowed_users: Vec::new(),
+            denied_users: Vec::new(),
+            reward_id: None,
         }
     }
 
@@ -101,19 +352,229 @@ impl Command {
         self
     }
 
+    /// Builder method to set whether mods and the broadcaster bypass the cooldown
+    pub fn with_mods_bypass_cooldown(mut self, bypass: bool) -> Self {
+        self.mods_bypass_cooldown = bypass;
+        self
+    }
+
     /// Builder method to set enabled state
     pub fn with_enabled(mut self, enabled: bool) -> Self {
         self.enabled = enabled;
         self
     }
+
+    /// Builder method to mark the command as only kept for the current stream
+    pub fn with_temporary(mut self, temporary: bool) -> Self {
+        self.temporary = temporary;
+        self
+    }
+
+    /// Builder method to set how long to wait for external data
+    pub fn with_timeout(mut self, timeout: u64) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Builder method to set the keyword triggers
+    pub fn with_keywords(mut self, keywords: Vec<KeywordTrigger>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Builder method to set the required arguments and the usage sent when they're
+    /// missing. An empty usage is treated as unset.
+    pub fn with_usage(mut self, required_args: usize, usage: String) -> Self {
+        self.required_args = required_args;
+        self.usage = Some(usage).filter(|usage| !usage.trim().is_empty());
+        self
+    }
+
+    /// Builder method to put the command in a group, an empty name is treated as unset
+    pub fn with_group(mut self, group: String) -> Self {
+        self.group = Some(group.trim().to_string()).filter(|group| !group.is_empty());
+        self
+    }
+
+    /// Builder method to limit how often each user can run the command per stream
+    pub fn with_max_uses_per_stream(mut self, uses: usize) -> Self {
+        self.max_uses_per_stream = uses;
+        self
+    }
+
+    /// Builder method to run the command when a channel point reward is redeemed, an
+    /// empty ID is treated as unset
+    pub fn with_reward_id(mut self, reward_id: String) -> Self {
+        self.reward_id = Some(reward_id.trim().to_string()).filter(|id| !id.is_empty());
+        self
+    }
+
+    /// Whether the command is in `group`, ignoring case
+    pub fn in_group(&self, group: &str) -> bool {
+        self.group
+            .as_ref()
+            .is_some_and(|name| name.eq_ignore_ascii_case(group))
+    }
+
+    /// Builder method to set the users that can always or never use the command.
+    /// Logins are stored lowercase without `@`.
+    pub fn with_user_lists(mut self, allowed: Vec<String>, denied: Vec<String>) -> Self {
+        let logins = |users: Vec<String>| {
+            users
+                .iter()
+                .map(|user| user.trim().trim_start_matches('@').to_lowercase())
+                .filter(|user| !user.is_empty())
+                .collect()
+        };
+        self.allowed_users = logins(allowed);
+        self.denied_users = logins(denied);
+        self
+    }
+
+    /// `Some(false)` if `login` is denied, `Some(true)` if allowed and `None` if the
+    /// permission decides. Denied wins when a user is on both lists.
+    pub fn user_override(&self, login: &str) -> Option<bool> {
+        let listed = |users: &[String]| users.iter().any(|user| user.eq_ignore_ascii_case(login));
+        if listed(&self.denied_users) {
+            Some(false)
+        } else if listed(&self.allowed_users) {
+            Some(true)
+        } else {
+            None
+        }
+    }
+
+    /// Builder method to set how cooldown and permission failures are reported
+    pub fn with_failure_feedback(mut self, feedback: FailureFeedback) -> Self {
+        self.failure_feedback = feedback;
+        self
+    }
+
+    /// Builder method to set custom cooldown and permission-denied responses.
+    /// Empty strings are treated as unset.
+    pub fn with_failure_messages(
+        mut self,
+        cooldown_message: String,
+        permission_denied_message: String,
+    ) -> Self {
+        self.cooldown_message = Some(cooldown_message).filter(|m| !m.trim().is_empty());
+        self.permission_denied_message =
+            Some(permission_denied_message).filter(|m| !m.trim().is_empty());
+        self
+    }
+
+    /// Custom cooldown response with placeholders filled in
+    pub fn cooldown_response(&self, context: &CommandContext, remaining: u64) -> Option<String> {
+        self.cooldown_message.as_ref().map(|message| {
+            context
+                .replace_placeholders(message)
+                .replace("{remaining}", &remaining.to_string())
+        })
+    }
+
+    /// Arguments the command needs: `required_args`, or more if a template uses
+    /// `{N}` without a default
+    pub fn min_args(&self) -> usize {
+        self.action
+            .templates()
+            .into_iter()
+            .map(required_arg_count)
+            .max()
+            .unwrap_or_default()
+            .max(self.required_args)
+    }
+
+    /// Response when arguments are missing, the custom usage or one listing the arguments
+    pub fn usage_response(&self, context: &CommandContext) -> String {
+        match &self.usage {
+            Some(usage) => context.replace_placeholders(usage),
+            None => {
+                let args: Vec<String> = (1..=self.min_args())
+                    .map(|position| format!("<arg{}>", position))
+                    .collect();
+                format!("Usage: !{} {}", self.trigger, args.join(" "))
+            }
+        }
+    }
+
+    /// Custom permission-denied response with placeholders filled in
+    pub fn permission_denied_response(&self, context: &CommandContext) -> Option<String> {
+        self.permission_denied_message
+            .as_ref()
+            .map(|message| context.replace_placeholders(message))
+    }
+
+    /// Builder method to make this a viewer-owned command awaiting approval
+    pub fn with_owner(mut self, owner: String) -> Self {
+        self.owner = Some(owner);
+        self.pending_approval = true;
+        self
+    }
+}
+
+/// Names of the groups of `commands`, sorted. Groups are matched ignoring case, so
+/// "Minecraft" and "minecraft" are listed once.
+pub fn command_groups<'a>(commands: impl IntoIterator<Item = &'a Command>) -> Vec<String> {
+    let mut groups: Vec<String> = commands
+        .into_iter()
+        .filter_map(|command| command.group.clone())
+        .collect();
+    groups.sort_by_key(|group| (group.to_lowercase(), group.clone()));
+    groups.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    groups
+}
+
+fn default_bypass_cooldown() -> bool {
+    true
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
+fn default_timeout() -> u64 {
+    Command::DEFAULT_TIMEOUT
+}
+
+fn default_announcement_color() -> String {
+    "primary".to_string()
+}
+
+fn default_shoutout_user() -> String {
+    "{args}".to_string()
+}
+
+/// When commands last ran, saved in data/command_cooldowns.json so cooldowns
+/// survive a restart of the bot
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommandCooldowns {
+    /// Last run of each command by trigger
+    #[serde(default)]
+    last_executed: HashMap<String, DateTime<Utc>>,
+    /// When any command last ran, for the global cooldown
+    #[serde(default)]
+    last_command: Option<DateTime<Utc>>,
+}
+
+/// Cooldowns shorter than this are over by the time the bot is back after a
+/// restart, so runs that only start those are not saved
+pub const MIN_SAVED_COOLDOWN: u64 = 60;
+
+/// Whole seconds since `since`, 0 if the clock went back
+fn seconds_since(since: DateTime<Utc>) -> u64 {
+    (Utc::now() - since).num_seconds().max(0) as u64
 }
 
 /// Registry for managing commands
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CommandRegistry {
+    #[serde(default)]
     commands: HashMap<String, Command>,
     #[serde(skip)]
-    last_executed: HashMap<String, std::time::Instant>,
+    cooldowns: CommandCooldowns,
+    /// Runs of each command by login this stream, for `max_uses_per_stream`
+    #[serde(skip)]
+    stream_uses: HashMap<String, HashMap<String, usize>>,
 }
 
 impl CommandRegistry {
@@ -129,7 +590,7 @@ impl CommandRegistry {
         // If this is an update (command already exists), clear its cooldown state
         // This ensures cooldown changes take effect immediately
         if self.commands.contains_key(&trigger) {
-            self.last_executed.remove(&trigger);
+            self.cooldowns.last_executed.remove(&trigger);
         }
 
         self.commands.insert(trigger, command);
@@ -138,7 +599,8 @@ impl CommandRegistry {
     /// Unregister a command
     pub fn unregister(&mut self, trigger: &str) -> Option<Command> {
         // Also remove cooldown state when unregistering
-        self.last_executed.remove(trigger);
+        self.cooldowns.last_executed.remove(trigger);
+        self.stream_uses.remove(trigger);
         self.commands.remove(trigger)
     }
 
@@ -157,16 +619,52 @@ impl CommandRegistry {
         self.commands.values().collect()
     }
 
-    /// Check if a command is on cooldown
-    pub fn is_on_cooldown(&self, trigger: &str) -> bool {
+    /// Remove the commands only kept for the current stream, returns their triggers
+    pub fn remove_temporary(&mut self) -> Vec<String> {
+        let triggers: Vec<String> = self
+            .commands
+            .values()
+            .filter(|command| command.temporary)
+            .map(|command| command.trigger.clone())
+            .collect();
+        for trigger in &triggers {
+            self.unregister(trigger);
+        }
+        triggers
+    }
+
+    /// Names of all command groups, sorted
+    pub fn groups(&self) -> Vec<String> {
+        command_groups(self.commands.values())
+    }
+
+    /// Enable or disable every command of a group (ignoring case), returns how many
+    /// commands changed
+    pub fn set_group_enabled(&mut self, group: &str, enabled: bool) -> usize {
+        let mut changed = 0;
+        for command in self.commands.values_mut() {
+            if command.in_group(group) && command.enabled != enabled {
+                command.enabled = enabled;
+                changed += 1;
+            }
+        }
+        changed
+    }
+
+    /// Check if a command is on cooldown for the given user
+    pub fn is_on_cooldown(&self, trigger: &str, identity: &UserIdentity) -> bool {
         if let Some(command) = self.get(trigger) {
             if command.cooldown == 0 {
                 return false;
             }
 
-            if let Some(last_time) = self.last_executed.get(trigger) {
-                let elapsed = last_time.elapsed().as_secs();
-                return elapsed < command.cooldown;
+            if command.mods_bypass_cooldown && CommandPermission::Moderator.has_permission(identity)
+            {
+                return false;
+            }
+
+            if let Some(last_time) = self.cooldowns.last_executed.get(trigger) {
+                return seconds_since(*last_time) < command.cooldown;
             }
         }
         false
@@ -179,8 +677,8 @@ impl CommandRegistry {
                 return None;
             }
 
-            if let Some(last_time) = self.last_executed.get(trigger) {
-                let elapsed = last_time.elapsed().as_secs();
+            if let Some(last_time) = self.cooldowns.last_executed.get(trigger) {
+                let elapsed = seconds_since(*last_time);
                 if elapsed < command.cooldown {
                     return Some(command.cooldown - elapsed);
                 }
@@ -189,16 +687,88 @@ impl CommandRegistry {
         None
     }
 
+    /// Seconds left on the cooldown shared by all commands, `None` if the command can
+    /// run. Moderators skip it for commands they can use during their own cooldown.
+    pub fn remaining_global_cooldown(
+        &self,
+        trigger: &str,
+        identity: &UserIdentity,
+        global_cooldown: u64,
+    ) -> Option<u64> {
+        let command = self.get(trigger)?;
+        if command.mods_bypass_cooldown && CommandPermission::Moderator.has_permission(identity) {
+            return None;
+        }
+
+        let elapsed = seconds_since(self.cooldowns.last_command?);
+        (elapsed < global_cooldown).then(|| global_cooldown - elapsed)
+    }
+
     /// Update the last execution time for a command
     pub fn update_cooldown(&mut self, trigger: &str) {
-        self.last_executed
-            .insert(trigger.to_string(), std::time::Instant::now());
+        let now = Utc::now();
+        self.cooldowns
+            .last_executed
+            .insert(trigger.to_string(), now);
+        self.cooldowns.last_command = Some(now);
+    }
+
+    /// Whether the user ran the command as often as it allows per stream
+    pub fn stream_limit_reached(&self, trigger: &str, login: &str) -> bool {
+        let Some(command) = self.get(trigger) else {
+            return false;
+        };
+        let uses = self
+            .stream_uses
+            .get(trigger)
+            .and_then(|uses| uses.get(&login.to_lowercase()))
+            .copied()
+            .unwrap_or(0);
+        command.max_uses_per_stream > 0 && uses >= command.max_uses_per_stream
+    }
+
+    /// Count a run of the command by the user this stream
+    pub fn record_stream_use(&mut self, trigger: &str, login: &str) {
+        *self
+            .stream_uses
+            .entry(trigger.to_string())
+            .or_default()
+            .entry(login.to_lowercase())
+            .or_insert(0) += 1;
+    }
+
+    /// Forget who ran which command, when a new stream starts or the bot connects
+    pub fn reset_stream_uses(&mut self) {
+        self.stream_uses.clear();
+    }
+
+    /// Last runs of the commands, to save them
+    pub fn cooldowns(&self) -> &CommandCooldowns {
+        &self.cooldowns
+    }
+
+    /// Whether running the command starts a cooldown worth saving
+    pub fn has_lasting_cooldown(&self, trigger: &str, global_cooldown: u64) -> bool {
+        global_cooldown >= MIN_SAVED_COOLDOWN
+            || self
+                .get(trigger)
+                .is_some_and(|command| command.cooldown >= MIN_SAVED_COOLDOWN)
+    }
+
+    /// Restore the last runs saved before a restart, skipping commands that no
+    /// longer exist
+    pub fn restore_cooldowns(&mut self, mut cooldowns: CommandCooldowns) {
+        cooldowns
+            .last_executed
+            .retain(|trigger, _| self.commands.contains_key(trigger));
+        self.cooldowns = cooldowns;
     }
 
     /// Clear all commands
     pub fn clear(&mut self) {
         self.commands.clear();
-        self.last_executed.clear();
+        self.cooldowns = CommandCooldowns::default();
+        self.stream_uses.clear();
     }
 
     /// Get the number of registered commands
@@ -211,3 +781,210 @@ impl CommandRegistry {
         self.commands.is_empty()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mods_bypass_cooldown() {
+        let action = CommandAction::Reply {
+            message: "hi".to_string(),
+        };
+        let mut registry = CommandRegistry::new();
+        registry.register(
+            Command::new(
+                "hi".into(),
+                String::new(),
+                CommandPermission::Everyone,
+                action.clone(),
+            )
+            .with_cooldown(60),
+        );
+        registry.register(
+            Command::new(
+                "strict".into(),
+                String::new(),
+                CommandPermission::Everyone,
+                action,
+            )
+            .with_cooldown(60)
+            .with_mods_bypass_cooldown(false),
+        );
+        registry.update_cooldown("hi");
+        registry.update_cooldown("strict");
+
+        let viewer = UserIdentity::default();
+        let moderator = UserIdentity {
+            moderator: true,
+            ..Default::default()
+        };
+        let broadcaster = UserIdentity {
+            broadcaster: true,
+            ..Default::default()
+        };
+        assert!(registry.is_on_cooldown("hi", &viewer));
+        assert!(!registry.is_on_cooldown("hi", &moderator));
+        assert!(!registry.is_on_cooldown("hi", &broadcaster));
+        assert!(registry.is_on_cooldown("strict", &moderator));
+    }
+
+    #[test]
+    fn test_global_cooldown() {
+        let mut registry = CommandRegistry::new();
+        for trigger in ["a", "b"] {
+            registry.register(Command::new(
+                trigger.into(),
+                String::new(),
+                CommandPermission::Everyone,
+                CommandAction::Reply {
+                    message: "hi".to_string(),
+                },
+            ));
+        }
+        let viewer = UserIdentity::default();
+        assert_eq!(registry.remaining_global_cooldown("b", &viewer, 10), None);
+
+        registry.update_cooldown("a");
+        assert!(registry
+            .remaining_global_cooldown("b", &viewer, 10)
+            .is_some());
+        assert_eq!(registry.remaining_global_cooldown("b", &viewer, 0), None);
+        let moderator = UserIdentity {
+            moderator: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            registry.remaining_global_cooldown("b", &moderator, 10),
+            None
+        );
+    }
+
+    #[test]
+    fn test_restore_cooldowns() {
+        let command = |trigger: &str| {
+            Command::new(
+                trigger.into(),
+                String::new(),
+                CommandPermission::Everyone,
+                CommandAction::Reply {
+                    message: "hi".to_string(),
+                },
+            )
+            .with_cooldown(3600)
+        };
+        let mut registry = CommandRegistry::new();
+        registry.register(command("giveaway"));
+        registry.register(command("removed"));
+        registry.update_cooldown("giveaway");
+        registry.update_cooldown("removed");
+        let saved = serde_json::to_string(registry.cooldowns()).unwrap();
+
+        let mut restarted = CommandRegistry::new();
+        restarted.register(command("giveaway"));
+        restarted.restore_cooldowns(serde_json::from_str(&saved).unwrap());
+        let viewer = UserIdentity::default();
+        assert!(restarted.is_on_cooldown("giveaway", &viewer));
+        assert!(restarted.remaining_cooldown("giveaway").unwrap() > 3590);
+        assert!(!restarted.cooldowns().last_executed.contains_key("removed"));
+        assert!(restarted
+            .remaining_global_cooldown("giveaway", &viewer, 10)
+            .is_some());
+    }
+
+    #[test]
+    fn test_max_uses_per_stream() {
+        let mut registry = CommandRegistry::new();
+        registry.register(
+            Command::new(
+                "meme".into(),
+                String::new(),
+                CommandPermission::Everyone,
+                CommandAction::Reply {
+                    message: "hi".to_string(),
+                },
+            )
+            .with_max_uses_per_stream(2),
+        );
+        registry.record_stream_use("meme", "Viewer");
+        assert!(!registry.stream_limit_reached("meme", "viewer"));
+        registry.record_stream_use("meme", "viewer");
+        assert!(registry.stream_limit_reached("meme", "viewer"));
+        assert!(!registry.stream_limit_reached("meme", "other"));
+
+        registry.reset_stream_uses();
+        assert!(!registry.stream_limit_reached("meme", "viewer"));
+    }
+
+    #[test]
+    fn test_set_group_enabled() {
+        let command = |trigger: &str, group: &str| {
+            Command::new(
+                trigger.into(),
+                String::new(),
+                CommandPermission::Everyone,
+                CommandAction::Reply {
+                    message: "hi".to_string(),
+                },
+            )
+            .with_group(group.to_string())
+        };
+        let mut registry = CommandRegistry::new();
+        registry.register(command("seed", "Minecraft"));
+        registry.register(command("deaths", "elden ring"));
+        registry.register(command("mods", "minecraft").with_enabled(false));
+        registry.register(command("hello", " "));
+
+        assert_eq!(registry.groups(), vec!["elden ring", "Minecraft"]);
+        assert_eq!(registry.set_group_enabled("MINECRAFT", false), 1);
+        assert!(!registry.get("seed").unwrap().enabled);
+        assert!(registry.get("deaths").unwrap().enabled);
+        assert_eq!(registry.set_group_enabled("minecraft", true), 2);
+        assert_eq!(registry.get("hello").unwrap().group, None);
+    }
+
+    #[test]
+    fn test_follower_permission() {
+        let now = Utc::now();
+        let permission = CommandPermission::Follower { min_minutes: 60 };
+        let viewer = UserIdentity::default();
+        let moderator = UserIdentity {
+            moderator: true,
+            ..Default::default()
+        };
+        let followed = |minutes| Some(now - chrono::Duration::minutes(minutes));
+
+        assert!(!permission.allows(&viewer, None, now));
+        assert!(!permission.allows(&viewer, followed(59), now));
+        assert!(permission.allows(&viewer, followed(60), now));
+        assert!(permission.allows(&moderator, None, now));
+        assert!(CommandPermission::Follower { min_minutes: 0 }.allows(&viewer, followed(0), now));
+        assert!(!permission.has_permission(&viewer));
+    }
+
+    #[test]
+    fn test_user_override() {
+        let command = Command::new(
+            "tts".to_string(),
+            String::new(),
+            CommandPermission::Broadcaster,
+            CommandAction::SendMessage {
+                message: String::new(),
+            },
+        )
+        .with_user_lists(
+            vec!["@Editor".to_string(), " ".to_string()],
+            vec![
+                "troll".to_string(),
+                "editor2".to_string(),
+                "Editor2".to_string(),
+            ],
+        );
+
+        assert_eq!(command.allowed_users, vec!["editor"]);
+        assert_eq!(command.user_override("editor"), Some(true));
+        assert_eq!(command.user_override("TROLL"), Some(false));
+        assert_eq!(command.user_override("editor2"), Some(false));
+        assert_eq!(command.user_override("viewer"), None);
+    }
+}
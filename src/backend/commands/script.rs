@@ -0,0 +1,189 @@
+use rhai::module_resolvers::DummyModuleResolver;
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Scope};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::executor::{ACTION_SEPARATOR, DEFAULT_TTS_LANGUAGE};
+use super::{CommandContext, CommandResult};
+
+/// Operations a script may run before it's stopped, so loops can't hang the bot
+const MAX_OPERATIONS: u64 = 100_000;
+
+/// Messages, sounds and TTS a script may send in one run
+const MAX_OUTPUTS: usize = 5;
+
+/// Longest string a script may build, Twitch cuts chat messages at 500 characters
+const MAX_STRING_SIZE: usize = 500;
+
+/// Engine without access to files or other modules, with limits on loops,
+/// recursion and sizes
+fn sandboxed_engine() -> Engine {
+    let mut engine = Engine::new();
+    engine
+        .set_module_resolver(DummyModuleResolver::new())
+        .disable_symbol("eval")
+        .set_max_operations(MAX_OPERATIONS)
+        .set_max_call_levels(16)
+        .set_max_expr_depths(32, 16)
+        .set_max_string_size(MAX_STRING_SIZE)
+        .set_max_array_size(100)
+        .set_max_map_size(100)
+        .on_print(|text| log::info!("Script: {}", text))
+        .on_debug(|text, _, _| log::debug!("Script: {}", text));
+    engine
+}
+
+/// Check a script for syntax errors without running it
+pub fn check_script(source: &str) -> Result<(), String> {
+    sandboxed_engine()
+        .compile(source)
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// Run a `Script` action. Scripts see `user`, `login`, `user_id`, `command`, `args`
+/// and `badges`, and can call `send`, `reply`, `play_sound`, `tts` and `random`. A
+/// value left at the end of the script is sent as a reply.
+pub fn run_script(source: &str, context: &CommandContext) -> CommandResult {
+    let outputs: Rc<RefCell<Vec<String>>> = Rc::default();
+    let mut engine = sandboxed_engine();
+
+    engine.register_fn("random", |min: i64, max: i64| {
+        fastrand::i64(min..=max.max(min))
+    });
+    let message_id = context.message_id().to_string();
+    let sink = outputs.clone();
+    engine.register_fn("send", move |text: &str| {
+        push_output(&sink, format!("send:{}", text))
+    });
+    let sink = outputs.clone();
+    engine.register_fn("reply", move |text: &str| {
+        push_output(&sink, format!("reply:{}:{}", message_id, text))
+    });
+    let sink = outputs.clone();
+    engine.register_fn("play_sound", move |sound: &str| {
+        push_output(&sink, format!("sound:{}", sound))
+    });
+    let sink = outputs.clone();
+    engine.register_fn("tts", move |text: &str| {
+        push_output(&sink, format!("tts:{}:{}", DEFAULT_TTS_LANGUAGE, text))
+    });
+    let sink = outputs.clone();
+    engine.register_fn("tts", move |language: &str, text: &str| {
+        push_output(&sink, format!("tts:{}:{}", language, text))
+    });
+
+    let identity = context.identity();
+    let mut scope = Scope::new();
+    scope
+        .push_constant("user", context.display_name())
+        .push_constant("login", context.username().to_string())
+        .push_constant("user_id", context.user_id().to_string())
+        .push_constant("command", context.command_name.clone())
+        .push_constant(
+            "args",
+            context
+                .args
+                .iter()
+                .cloned()
+                .map(Dynamic::from)
+                .collect::<Array>(),
+        )
+        .push_constant(
+            "badges",
+            context
+                .message
+                .badges
+                .iter()
+                .map(|badge| Dynamic::from(badge.set_id.clone()))
+                .collect::<Array>(),
+        )
+        .push_constant("is_broadcaster", identity.broadcaster)
+        .push_constant("is_mod", identity.moderator || identity.broadcaster)
+        .push_constant("is_vip", identity.vip)
+        .push_constant("is_sub", identity.subscriber);
+
+    let result = match engine.eval_with_scope::<Dynamic>(&mut scope, source) {
+        Ok(result) => result,
+        Err(e) => return CommandResult::Error(format!("Script error: {}", e)),
+    };
+
+    let mut outputs = outputs.take();
+    if !result.is_unit() {
+        outputs.push(format!(
+            "reply:{}:{}",
+            context.message_id(),
+            result.to_string().replace(ACTION_SEPARATOR, "")
+        ));
+    }
+
+    if outputs.is_empty() {
+        CommandResult::Success(None)
+    } else {
+        CommandResult::Success(Some(outputs.join(&ACTION_SEPARATOR.to_string())))
+    }
+}
+
+/// Collect an output of the script, failing the script once it sent too many
+fn push_output(outputs: &RefCell<Vec<String>>, output: String) -> Result<(), Box<EvalAltResult>> {
+    let mut outputs = outputs.borrow_mut();
+    if outputs.len() >= MAX_OUTPUTS {
+        return Err(format!(
+            "Scripts can send up to {} messages, sounds or TTS",
+            MAX_OUTPUTS
+        )
+        .into());
+    }
+    // One output is one action, even if the script built it from user input
+    outputs.push(output.replace(ACTION_SEPARATOR, ""));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_script() {
+        let context = CommandContext::test("dice", &["2"]);
+        let script = r#"
+            let sides = if args.len() > 0 { parse_int(args[0]) } else { 6 };
+            play_sound("roll");
+            tts("de", "Würfel");
+            let roll = random(1, sides);
+            if roll < 1 || roll > sides { throw "out of range"; }
+            "rolled " + sides
+        "#;
+        let CommandResult::Success(Some(outputs)) = run_script(script, &context) else {
+            panic!("script failed");
+        };
+        let outputs: Vec<&str> = outputs.split(ACTION_SEPARATOR).collect();
+        assert_eq!(
+            outputs,
+            vec!["sound:roll", "tts:de:Würfel", "reply:m1:rolled 2"]
+        );
+
+        assert!(matches!(
+            run_script("loop {}", &context),
+            CommandResult::Error(_)
+        ));
+        assert!(matches!(
+            run_script("for i in 0..10 { send(`${i}`); }", &context),
+            CommandResult::Error(_)
+        ));
+        assert!(matches!(
+            run_script("eval(\"1\")", &context),
+            CommandResult::Error(_)
+        ));
+        // Arguments and script strings can't add actions of their own
+        let context = CommandContext::test("say", &["hi\u{1e}sound:airhorn"]);
+        let CommandResult::Success(Some(outputs)) =
+            run_script(r#"send(args[0]); args[0] + "\u001etts:en:hi""#, &context)
+        else {
+            panic!("script failed");
+        };
+        assert_eq!(outputs.split(ACTION_SEPARATOR).count(), 2);
+        assert!(check_script("let x = ;").is_err());
+        assert!(check_script("reply(user)").is_ok());
+    }
+}
@@ -0,0 +1,45 @@
+use super::{CommandContext, CommandResult};
+use crate::backend::roles::UserRole;
+
+/// Trigger of the shield mode command
+pub const SHIELD_COMMAND: &str = "shield";
+
+const USAGE: &str = "Usage: !shield on | !shield off";
+
+/// Parse the arguments of `!shield` into the shield mode state to set
+pub fn parse_shield_args(args: &[String]) -> Result<bool, &'static str> {
+    match args {
+        [arg] if arg.eq_ignore_ascii_case("on") => Ok(true),
+        [arg] if arg.eq_ignore_ascii_case("off") => Ok(false),
+        _ => Err(USAGE),
+    }
+}
+
+/// Check and parse `!shield`, returns whether to turn shield mode on. Moderators only.
+pub fn parse_shield_command(context: &CommandContext) -> Result<bool, CommandResult> {
+    if context.identity().role() < UserRole::Moderator {
+        return Err(CommandResult::PermissionDenied);
+    }
+
+    parse_shield_args(&context.args).map_err(|usage| {
+        CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), usage)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_shield_args() {
+        assert_eq!(parse_shield_args(&args("on")), Ok(true));
+        assert_eq!(parse_shield_args(&args("OFF")), Ok(false));
+        assert!(parse_shield_args(&args("")).is_err());
+        assert!(parse_shield_args(&args("maybe")).is_err());
+        assert!(parse_shield_args(&args("on now")).is_err());
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+/// Twitch allows one shoutout every 2 minutes
+const SHOUTOUT_COOLDOWN: Duration = Duration::from_secs(120);
+/// and a shoutout to the same channel once an hour
+const TARGET_COOLDOWN: Duration = Duration::from_secs(3600);
+
+/// Shoutouts sent by the bot or from the Twitch dashboard. Checked before calling the API
+/// so a rate limited shoutout can be reported with the time left instead of failing.
+static SHOUTOUTS: LazyLock<Mutex<ShoutoutLimiter>> =
+    LazyLock::new(|| Mutex::new(ShoutoutLimiter::default()));
+
+#[derive(Debug, Default)]
+pub struct ShoutoutLimiter {
+    last: Option<Instant>,
+    /// Last shoutout per channel login
+    targets: HashMap<String, Instant>,
+}
+
+impl ShoutoutLimiter {
+    /// Seconds until `login` can get a shoutout, `None` if it can be sent now
+    pub fn remaining(&self, login: &str, now: Instant) -> Option<u64> {
+        let global = self
+            .last
+            .map(|at| SHOUTOUT_COOLDOWN.saturating_sub(now.duration_since(at)))
+            .unwrap_or_default();
+        let target = self
+            .targets
+            .get(&login.to_lowercase())
+            .map(|at| TARGET_COOLDOWN.saturating_sub(now.duration_since(*at)))
+            .unwrap_or_default();
+
+        let remaining = global.max(target);
+        (!remaining.is_zero()).then(|| remaining.as_secs().max(1))
+    }
+
+    pub fn record(&mut self, login: &str, now: Instant) {
+        self.targets
+            .retain(|_, at| now.duration_since(*at) < TARGET_COOLDOWN);
+        self.targets.insert(login.to_lowercase(), now);
+        self.last = Some(now);
+    }
+}
+
+/// Seconds until `login` can get a shoutout, `None` if it can be sent now
+pub fn shoutout_cooldown(login: &str) -> Option<u64> {
+    SHOUTOUTS.lock().unwrap().remaining(login, Instant::now())
+}
+
+/// Start the cooldowns after a shoutout was sent
+pub fn record_shoutout(login: &str) {
+    SHOUTOUTS.lock().unwrap().record(login, Instant::now());
+}
+
+/// Placeholders filled with the channel info of the shoutout target
+const TARGET_PLACEHOLDERS: [&str; 2] = ["{target_game}", "{target_clip}"];
+
+/// How long the channel info of a shoutout target is reused, failed lookups included,
+/// so repeating a command doesn't look the channel up every time
+const TARGET_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Channel info of shoutout targets by login, and when it was looked up
+static TARGET_CACHE: LazyLock<Mutex<HashMap<String, (ShoutoutTarget, Instant)>>> =
+    LazyLock::new(Default::default);
+
+/// Channel info of the user named in the first argument, for `{target_game}` and
+/// `{target_clip}` in shoutout messages
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShoutoutTarget {
+    pub login: String,
+    /// Category the channel last streamed, `None` if unknown
+    pub game: Option<String>,
+    /// Link to a recent clip
+    pub clip: Option<String>,
+}
+
+impl ShoutoutTarget {
+    /// Whether a template needs the target's channel info
+    pub fn is_used_in(template: &str) -> bool {
+        TARGET_PLACEHOLDERS
+            .iter()
+            .any(|placeholder| template.contains(placeholder))
+    }
+
+    /// Fill in `{target_game}` and `{target_clip}`. Without a category the game is
+    /// "something", without a clip the channel link is used.
+    pub fn replace_placeholders(&self, template: &str) -> String {
+        let game = self
+            .game
+            .as_deref()
+            .filter(|game| !game.is_empty())
+            .unwrap_or("something");
+        let clip = match &self.clip {
+            Some(clip) => clip.clone(),
+            None if self.login.is_empty() => String::new(),
+            None => format!("https://twitch.tv/{}", self.login),
+        };
+        template
+            .replace("{target_game}", game)
+            .replace("{target_clip}", &clip)
+    }
+
+    /// Channel info of `login` looked up in the last few minutes
+    pub fn cached(login: &str) -> Option<ShoutoutTarget> {
+        let cache = TARGET_CACHE.lock().unwrap();
+        let (target, fetched) = cache.get(login)?;
+        (fetched.elapsed() < TARGET_CACHE_TTL).then(|| target.clone())
+    }
+
+    /// Remember the channel info looked up from Twitch, or that the lookup failed
+    pub fn cache(&self) {
+        let mut cache = TARGET_CACHE.lock().unwrap();
+        cache.retain(|_, (_, fetched)| fetched.elapsed() < TARGET_CACHE_TTL);
+        cache.insert(self.login.clone(), (self.clone(), Instant::now()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shoutout_limiter() {
+        let mut limiter = ShoutoutLimiter::default();
+        let start = Instant::now();
+        assert_eq!(limiter.remaining("friend", start), None);
+
+        limiter.record("Friend", start);
+        let later = start + Duration::from_secs(30);
+        assert_eq!(limiter.remaining("other", later), Some(90));
+        assert_eq!(limiter.remaining("friend", later), Some(3570));
+
+        let later = start + Duration::from_secs(120);
+        assert_eq!(limiter.remaining("other", later), None);
+        assert!(limiter.remaining("friend", later).is_some());
+        assert_eq!(limiter.remaining("friend", start + TARGET_COOLDOWN), None);
+    }
+
+    #[test]
+    fn test_shoutout_target_placeholders() {
+        let template = "Go follow {1}, last seen playing {target_game}: {target_clip}";
+        assert!(ShoutoutTarget::is_used_in(template));
+        assert!(!ShoutoutTarget::is_used_in("Go follow {1}"));
+
+        let target = ShoutoutTarget {
+            login: "friend".to_string(),
+            game: Some("Celeste".to_string()),
+            clip: Some("https://clips.twitch.tv/abc".to_string()),
+        };
+        assert_eq!(
+            target.replace_placeholders("{target_game}: {target_clip}"),
+            "Celeste: https://clips.twitch.tv/abc"
+        );
+
+        let unknown = ShoutoutTarget {
+            login: "friend".to_string(),
+            game: Some(String::new()),
+            clip: None,
+        };
+        assert_eq!(
+            unknown.replace_placeholders("{target_game}: {target_clip}"),
+            "something: https://twitch.tv/friend"
+        );
+    }
+}
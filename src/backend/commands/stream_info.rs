@@ -0,0 +1,39 @@
+use super::{CommandContext, CommandResult};
+use crate::backend::roles::UserRole;
+
+/// Triggers of the commands that show or change the stream title and category
+pub const STREAM_INFO_COMMANDS: &[&str] = &["title", "game"];
+
+/// Action of `!title` or `!game`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamInfoCommand {
+    /// Reply with the current title, or category if `game` is set
+    Show {
+        game: bool,
+    },
+    SetTitle(String),
+    /// Category name, looked up on Twitch
+    SetGame(String),
+}
+
+/// Parse `!title [text]` or `!game [name]`. Anyone can see the current value,
+/// changing it is for moderators and the broadcaster.
+pub fn parse_stream_info_command(
+    context: &CommandContext,
+) -> Result<StreamInfoCommand, CommandResult> {
+    let game = context.command_name == "game";
+    let text = context.args.join(" ");
+    if text.is_empty() {
+        return Ok(StreamInfoCommand::Show { game });
+    }
+
+    if context.identity().role() < UserRole::Moderator {
+        return Err(CommandResult::PermissionDenied);
+    }
+
+    Ok(if game {
+        StreamInfoCommand::SetGame(text)
+    } else {
+        StreamInfoCommand::SetTitle(text)
+    })
+}
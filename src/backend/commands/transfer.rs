@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::{Command, CommandRegistry};
+
+/// File written next to commands.toml by "Export commands"
+pub const COMMANDS_EXPORT_FILE: &str = "commands-export.json";
+
+/// Commands in a file that can be moved to another bot, without cooldowns or
+/// temporary commands
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CommandExport {
+    pub commands: Vec<Command>,
+}
+
+/// What happens to the commands already registered when importing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportMode {
+    /// Add the imported commands, replacing ones with the same trigger
+    Merge,
+    /// Remove all saved commands first
+    Replace,
+}
+
+/// Number of commands changed by an import
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub added: usize,
+    pub updated: usize,
+    pub removed: usize,
+}
+
+impl CommandExport {
+    /// The saved commands of `registry`, sorted by trigger
+    pub fn from_registry(registry: &CommandRegistry) -> Self {
+        let mut commands: Vec<Command> = registry
+            .list()
+            .into_iter()
+            .filter(|command| !command.temporary)
+            .cloned()
+            .collect();
+        commands.sort_by(|a, b| a.trigger.cmp(&b.trigger));
+        Self { commands }
+    }
+
+    /// Write the export as TOML when `path` ends in .toml, as JSON otherwise
+    pub fn encode(&self, path: &Path) -> Result<String, String> {
+        if is_toml(path) {
+            toml::to_string_pretty(self).map_err(|e| e.to_string())
+        } else {
+            serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+        }
+    }
+
+    /// Read an export written by `encode`
+    pub fn decode(path: &Path, content: &str) -> Result<Self, String> {
+        let export: Self = if is_toml(path) {
+            toml::from_str(content).map_err(|e| e.to_string())?
+        } else {
+            serde_json::from_str(content).map_err(|e| e.to_string())?
+        };
+        if let Some(command) = export
+            .commands
+            .iter()
+            .find(|command| command.trigger.trim().is_empty())
+        {
+            return Err(format!(
+                "A command has no trigger ({})",
+                command.description
+            ));
+        }
+        Ok(export)
+    }
+
+    /// Register the imported commands, temporary commands are kept either way
+    pub fn import_into(self, registry: &mut CommandRegistry, mode: ImportMode) -> ImportSummary {
+        let mut summary = ImportSummary::default();
+        if mode == ImportMode::Replace {
+            // Ones that are imported again count as updated
+            let removed: Vec<String> = registry
+                .list()
+                .into_iter()
+                .filter(|command| !command.temporary)
+                .filter(|command| !self.commands.iter().any(|c| c.trigger == command.trigger))
+                .map(|command| command.trigger.clone())
+                .collect();
+            for trigger in &removed {
+                registry.unregister(trigger);
+            }
+            summary.removed = removed.len();
+        }
+
+        for command in self.commands {
+            if registry.get(&command.trigger).is_some() {
+                summary.updated += 1;
+            } else {
+                summary.added += 1;
+            }
+            registry.register(command);
+        }
+        summary
+    }
+}
+
+fn is_toml(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{CommandAction, CommandPermission};
+    use super::*;
+
+    fn command(trigger: &str, message: &str) -> Command {
+        Command::new(
+            trigger.to_string(),
+            String::new(),
+            CommandPermission::Everyone,
+            CommandAction::Reply {
+                message: message.to_string(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_import_commands() {
+        let mut registry = CommandRegistry::new();
+        registry.register(command("hello", "Hi!"));
+        registry.register(command("discord", "old link"));
+        registry.register(command("today", "Just chatting").with_temporary(true));
+
+        let export = CommandExport::from_registry(&registry);
+        let path = Path::new("commands.toml");
+        let text = export.encode(path).unwrap();
+        let mut export = CommandExport::decode(path, &text).unwrap();
+        assert_eq!(export.commands.len(), 2);
+        export.commands[0] = command("discord", "new link");
+        export.commands[1] = command("lurk", "Enjoy the lurk");
+
+        let summary = export
+            .clone()
+            .import_into(&mut registry.clone(), ImportMode::Merge);
+        assert_eq!(
+            summary,
+            ImportSummary {
+                added: 1,
+                updated: 1,
+                removed: 0
+            }
+        );
+
+        let summary = export.import_into(&mut registry, ImportMode::Replace);
+        assert_eq!(
+            summary,
+            ImportSummary {
+                added: 1,
+                updated: 1,
+                removed: 1
+            }
+        );
+        assert!(registry.get("hello").is_none());
+        assert!(registry.get("today").is_some());
+        assert!(matches!(
+            &registry.get("discord").unwrap().action,
+            CommandAction::Reply { message } if message == "new link"
+        ));
+        let empty = CommandExport {
+            commands: vec![command(" ", "")],
+        };
+        let path = Path::new("commands.json");
+        assert!(CommandExport::decode(path, &empty.encode(path).unwrap()).is_err());
+    }
+}
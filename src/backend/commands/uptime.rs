@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+
+/// Trigger of the built-in uptime command
+pub const UPTIME_COMMAND: &str = "uptime";
+
+/// Reply to `!uptime`, e.g. "The stream has been live for 2h 5m".
+/// `started_at` is `None` while the channel is offline.
+pub fn format_uptime(started_at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> String {
+    let Some(started_at) = started_at else {
+        return "The stream is offline".to_string();
+    };
+
+    format!(
+        "The stream has been live for {}",
+        format_live_time(started_at, now)
+    )
+}
+
+/// Time since the stream started, e.g. "2h 5m" or "less than a minute"
+pub fn format_live_time(started_at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let minutes = (now - started_at).num_minutes().max(0);
+    let (hours, minutes) = (minutes / 60, minutes % 60);
+    match (hours, minutes) {
+        (0, 0) => "less than a minute".to_string(),
+        (0, minutes) => format!("{}m", minutes),
+        (hours, minutes) => format!("{}h {}m", hours, minutes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_format_uptime() {
+        let now = Utc::now();
+        assert_eq!(format_uptime(None, now), "The stream is offline");
+        assert_eq!(
+            format_uptime(Some(now - Duration::seconds(30)), now),
+            "The stream has been live for less than a minute"
+        );
+        assert_eq!(
+            format_uptime(Some(now - Duration::minutes(42)), now),
+            "The stream has been live for 42m"
+        );
+        assert_eq!(
+            format_uptime(Some(now - Duration::minutes(125)), now),
+            "The stream has been live for 2h 5m"
+        );
+    }
+}
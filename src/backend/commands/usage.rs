@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use crate::backend::storage;
+
+const STORAGE_NAME: &str = "command_usage";
+
+/// Usage of every command by trigger, saved in data/command_usage.json
+static USAGE: LazyLock<Mutex<HashMap<String, CommandUsage>>> =
+    LazyLock::new(|| Mutex::new(storage::load(STORAGE_NAME)));
+
+/// How often and when a command was last used
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct CommandUsage {
+    pub count: u64,
+    pub last_used: Option<DateTime<Utc>>,
+}
+
+/// Count a successful run of a command and save the usage of all commands
+pub fn record_command_usage(trigger: &str) -> HashMap<String, CommandUsage> {
+    let mut usage = USAGE.lock().unwrap();
+    let command = usage.entry(trigger.to_lowercase()).or_default();
+    command.count += 1;
+    command.last_used = Some(Utc::now());
+    storage::save(STORAGE_NAME, &*usage);
+    usage.clone()
+}
+
+/// Usage of all commands that ran at least once
+pub fn command_usage() -> HashMap<String, CommandUsage> {
+    USAGE.lock().unwrap().clone()
+}
+
+/// Order of `triggers` from most to least used, ties go to the one used last
+pub fn by_popularity(triggers: &[&str], usage: &HashMap<String, CommandUsage>) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..triggers.len()).collect();
+    order.sort_by_key(|&idx| {
+        let usage = usage.get(&triggers[idx].to_lowercase());
+        let count = usage.map(|usage| usage.count).unwrap_or_default();
+        let last_used = usage.and_then(|usage| usage.last_used);
+        (std::cmp::Reverse(count), std::cmp::Reverse(last_used))
+    });
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_by_popularity() {
+        let used = |count, seconds| CommandUsage {
+            count,
+            last_used: DateTime::from_timestamp(seconds, 0),
+        };
+        let usage = HashMap::from([
+            ("hello".to_string(), used(3, 100)),
+            ("lurk".to_string(), used(10, 50)),
+            ("so".to_string(), used(3, 200)),
+        ]);
+        assert_eq!(
+            by_popularity(&["hello", "discord", "Lurk", "so"], &usage),
+            vec![2, 3, 0, 1]
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    Command, CommandAction, CommandContext, CommandPermission, CommandRegistry, CommandResult,
+};
+
+/// Settings for viewer-owned commands (`!mycmd`)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UserCommandsConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Who may own a personal command
+    #[serde(default = "default_permission")]
+    pub permission: CommandPermission,
+    /// Maximum number of viewer-owned commands in total
+    #[serde(default = "default_max_commands")]
+    pub max_commands: usize,
+    /// Maximum length of a command's response, in characters
+    #[serde(default = "default_max_length")]
+    pub max_length: usize,
+}
+
+impl Default for UserCommandsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            permission: default_permission(),
+            max_commands: default_max_commands(),
+            max_length: default_max_length(),
+        }
+    }
+}
+
+fn default_permission() -> CommandPermission {
+    CommandPermission::Vip
+}
+
+fn default_max_commands() -> usize {
+    20
+}
+
+fn default_max_length() -> usize {
+    200
+}
+
+/// Handle `!mycmd` - lets trusted viewers own a command named after their login.
+///
+/// - `!mycmd set <text>` creates or edits the caller's command, which then waits for approval
+/// - `!mycmd remove` deletes the caller's command
+/// - `!mycmd approve <user>` / `!mycmd reject <user>` / `!mycmd pending` are for moderators
+///
+/// Returns `None` if the command isn't `!mycmd` or the feature is disabled.
+/// The registry should be saved after a `Success` result.
+pub fn execute_user_command(
+    context: &CommandContext,
+    registry: &mut CommandRegistry,
+    config: &UserCommandsConfig,
+) -> Option<CommandResult> {
+    if context.command_name != "mycmd" || !config.enabled {
+        return None;
+    }
+
+    let subcommand = context
+        .args
+        .first()
+        .map(|arg| arg.to_lowercase())
+        .unwrap_or_default();
+
+    let response = match subcommand.as_str() {
+        "set" => set_command(context, registry, config),
+        "remove" => remove_command(context, registry),
+        "approve" | "reject" | "pending" => {
+            if !CommandPermission::Moderator.has_permission(context.identity()) {
+                return Some(CommandResult::PermissionDenied);
+            }
+            match subcommand.as_str() {
+                "approve" => approve_command(context, registry),
+                "reject" => reject_command(context, registry),
+                _ => list_pending(registry),
+            }
+        }
+        _ => "Usage: !mycmd set <text> | !mycmd remove".to_string(),
+    };
+
+    Some(CommandResult::Success(Some(format!(
+        "reply:{}:{}",
+        context.message_id(),
+        response
+    ))))
+}
+
+fn set_command(
+    context: &CommandContext,
+    registry: &mut CommandRegistry,
+    config: &UserCommandsConfig,
+) -> String {
+    if !config.permission.has_permission(context.identity()) {
+        return "You're not allowed to own a command".to_string();
+    }
+
+    let text = context.args[1..].join(" ");
+    if text.trim().is_empty() {
+        return "Usage: !mycmd set <text>".to_string();
+    }
+    if text.chars().count() > config.max_length {
+        return format!(
+            "Command text is too long (max {} characters)",
+            config.max_length
+        );
+    }
+
+    let trigger = context.username().to_lowercase();
+    match registry.get(&trigger) {
+        Some(existing) if existing.owner.as_deref() != Some(trigger.as_str()) => {
+            return format!("!{} is already taken", trigger);
+        }
+        Some(_) => {}
+        None => {
+            let owned = registry.list().iter().filter(|c| c.owner.is_some()).count();
+            if owned >= config.max_commands {
+                return "There are no free personal command slots left".to_string();
+            }
+        }
+    }
+
+    registry.register(
+        Command::new(
+            trigger.clone(),
+            format!("Personal command of {}", context.username()),
+            CommandPermission::Everyone,
+            CommandAction::Reply { message: text },
+        )
+        .with_owner(trigger.clone()),
+    );
+
+    format!(
+        "Your command !{} is waiting for moderator approval",
+        trigger
+    )
+}
+
+fn remove_command(context: &CommandContext, registry: &mut CommandRegistry) -> String {
+    let trigger = context.username().to_lowercase();
+    match registry.get(&trigger) {
+        Some(command) if command.owner.as_deref() == Some(trigger.as_str()) => {
+            registry.unregister(&trigger);
+            format!("Removed !{}", trigger)
+        }
+        _ => "You don't have a personal command".to_string(),
+    }
+}
+
+/// Target of a moderator subcommand, e.g. `!mycmd approve @someone`
+fn target_trigger(context: &CommandContext) -> Option<String> {
+    context
+        .args
+        .get(1)
+        .map(|user| user.trim_start_matches('@').to_lowercase())
+}
+
+fn approve_command(context: &CommandContext, registry: &mut CommandRegistry) -> String {
+    let Some(trigger) = target_trigger(context) else {
+        return "Usage: !mycmd approve <user>".to_string();
+    };
+
+    match registry.get_mut(&trigger) {
+        Some(command) if command.owner.is_some() => {
+            command.pending_approval = false;
+            format!("Approved !{}", trigger)
+        }
+        _ => format!("{} doesn't have a personal command", trigger),
+    }
+}
+
+fn reject_command(context: &CommandContext, registry: &mut CommandRegistry) -> String {
+    let Some(trigger) = target_trigger(context) else {
+        return "Usage: !mycmd reject <user>".to_string();
+    };
+
+    match registry.get(&trigger) {
+        Some(command) if command.owner.is_some() => {
+            registry.unregister(&trigger);
+            format!("Rejected and removed !{}", trigger)
+        }
+        _ => format!("{} doesn't have a personal command", trigger),
+    }
+}
+
+fn list_pending(registry: &CommandRegistry) -> String {
+    let mut pending: Vec<&str> = registry
+        .list()
+        .into_iter()
+        .filter(|c| c.owner.is_some() && c.pending_approval)
+        .map(|c| c.trigger.as_str())
+        .collect();
+
+    if pending.is_empty() {
+        return "No commands waiting for approval".to_string();
+    }
+
+    pending.sort_unstable();
+    format!("Waiting for approval: !{}", pending.join(", !"))
+}
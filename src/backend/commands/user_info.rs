@@ -0,0 +1,273 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use super::followage::format_duration_since;
+
+/// Pronouns of the user, from pronouns.alejo.io
+pub const PRONOUNS_PLACEHOLDER: &str = "{pronouns}";
+/// Age of the user's Twitch account
+pub const ACCOUNT_AGE_PLACEHOLDER: &str = "{accountage}";
+/// How long the user has been following the channel
+pub const FOLLOW_AGE_PLACEHOLDER: &str = "{followage}";
+
+const PRONOUNS_API_URL: &str = "https://api.pronouns.alejo.io/v1";
+
+/// How long the pronouns of a user are reused before they're requested again
+const PRONOUNS_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// How long a failed lookup is remembered, so a viewer spamming a command doesn't
+/// cause a request for every message while an API is down
+const FAILED_LOOKUP_TTL: Duration = Duration::from_secs(5 * 60);
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Pronouns of a user, `None` if they have none set or the lookup failed, and
+/// until when they're reused
+struct CachedPronouns {
+    pronouns: Option<String>,
+    expires: Instant,
+}
+
+/// Pronouns by login
+static PRONOUNS_CACHE: LazyLock<Mutex<HashMap<String, CachedPronouns>>> =
+    LazyLock::new(Default::default);
+
+/// Pronouns known to pronouns.alejo.io by ID, fetched once
+static PRONOUN_NAMES: LazyLock<Mutex<HashMap<String, Pronoun>>> = LazyLock::new(Default::default);
+
+/// Account creation dates by user ID, they never change
+static CREATED_AT_CACHE: LazyLock<Mutex<HashMap<String, DateTime<Utc>>>> =
+    LazyLock::new(Default::default);
+
+/// When looking up the account creation date of a user by ID last failed
+static FAILED_CREATED_AT: LazyLock<Mutex<HashMap<String, Instant>>> =
+    LazyLock::new(Default::default);
+
+#[derive(Debug, Clone, Deserialize)]
+struct Pronoun {
+    subject: String,
+    object: String,
+    /// Shown on its own, e.g. "Any"
+    #[serde(default)]
+    singular: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct PronounUser {
+    pronoun_id: String,
+    alt_pronoun_id: Option<String>,
+}
+
+/// Metadata of the user who ran the command, only looked up for commands using
+/// `{pronouns}`, `{accountage}` or `{followage}`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UserInfo {
+    /// e.g. "She/Her", `None` if the user has none set or the lookup failed
+    pub pronouns: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    /// `Some(None)` if the user doesn't follow, `None` if the lookup failed
+    pub followed_at: Option<Option<DateTime<Utc>>>,
+}
+
+impl UserInfo {
+    /// Whether a template uses any of the user metadata placeholders
+    pub fn is_used_in(template: &str) -> bool {
+        [
+            PRONOUNS_PLACEHOLDER,
+            ACCOUNT_AGE_PLACEHOLDER,
+            FOLLOW_AGE_PLACEHOLDER,
+        ]
+        .iter()
+        .any(|placeholder| template.contains(placeholder))
+    }
+
+    /// Fill in `{pronouns}`, `{accountage}` and `{followage}`, "unknown" when a
+    /// value couldn't be looked up
+    pub fn replace_placeholders(&self, template: &str, now: DateTime<Utc>) -> String {
+        let age = |since: Option<DateTime<Utc>>| match since {
+            Some(since) => format_duration_since(since, now),
+            None => "unknown".to_string(),
+        };
+        let followage = match self.followed_at {
+            Some(None) => "not following".to_string(),
+            followed_at => age(followed_at.flatten()),
+        };
+        template
+            .replace(
+                PRONOUNS_PLACEHOLDER,
+                self.pronouns.as_deref().unwrap_or("unknown"),
+            )
+            .replace(ACCOUNT_AGE_PLACEHOLDER, &age(self.created_at))
+            .replace(FOLLOW_AGE_PLACEHOLDER, &followage)
+    }
+}
+
+/// Account creation date of a user looked up before, `Some(None)` if looking it up
+/// failed in the last few minutes
+pub fn cached_created_at(user_id: &str) -> Option<Option<DateTime<Utc>>> {
+    if let Some(created_at) = CREATED_AT_CACHE.lock().unwrap().get(user_id) {
+        return Some(Some(*created_at));
+    }
+    let failed = FAILED_CREATED_AT.lock().unwrap();
+    let failed_at = failed.get(user_id)?;
+    (failed_at.elapsed() < FAILED_LOOKUP_TTL).then_some(None)
+}
+
+/// Remember an account creation date looked up from Twitch, `None` if the lookup failed
+pub fn cache_created_at(user_id: &str, created_at: Option<DateTime<Utc>>) {
+    match created_at {
+        Some(created_at) => {
+            CREATED_AT_CACHE
+                .lock()
+                .unwrap()
+                .insert(user_id.to_string(), created_at);
+        }
+        None => {
+            let mut failed = FAILED_CREATED_AT.lock().unwrap();
+            failed.retain(|_, failed_at| failed_at.elapsed() < FAILED_LOOKUP_TTL);
+            failed.insert(user_id.to_string(), Instant::now());
+        }
+    }
+}
+
+/// Pronouns of a user from pronouns.alejo.io, cached for an hour. Failed lookups
+/// are cached for a few minutes. `None` if they have none set or the API can't be reached.
+pub async fn fetch_pronouns(login: &str) -> Option<String> {
+    let login = login.to_lowercase();
+    if let Some(cached) = PRONOUNS_CACHE.lock().unwrap().get(&login) {
+        if Instant::now() < cached.expires {
+            return cached.pronouns.clone();
+        }
+    }
+
+    let (pronouns, ttl) = match lookup_pronouns(&login).await {
+        Ok(pronouns) => (pronouns, PRONOUNS_CACHE_TTL),
+        Err(e) => {
+            log::warn!("Failed to fetch the pronouns of {}: {}", login, e);
+            (None, FAILED_LOOKUP_TTL)
+        }
+    };
+
+    let now = Instant::now();
+    let mut cache = PRONOUNS_CACHE.lock().unwrap();
+    cache.retain(|_, cached| now < cached.expires);
+    cache.insert(
+        login,
+        CachedPronouns {
+            pronouns: pronouns.clone(),
+            expires: now + ttl,
+        },
+    );
+    pronouns
+}
+
+/// Request the pronouns of a user, and the list of pronouns the first time
+async fn lookup_pronouns(login: &str) -> Result<Option<String>, String> {
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    if PRONOUN_NAMES.lock().unwrap().is_empty() {
+        let url = format!("{}/pronouns", PRONOUNS_API_URL);
+        match fetch(&client, &url).await {
+            Ok(Some(names)) => *PRONOUN_NAMES.lock().unwrap() = names,
+            Ok(None) => {}
+            Err(e) => return Err(format!("Failed to fetch the list of pronouns: {}", e)),
+        }
+    }
+
+    let url = format!("{}/users/{}", PRONOUNS_API_URL, login);
+    let user = fetch::<PronounUser>(&client, &url)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(user.and_then(|user| format_pronouns(&PRONOUN_NAMES.lock().unwrap(), &user)))
+}
+
+/// Request a JSON document, `None` if it doesn't exist
+async fn fetch<T: serde::de::DeserializeOwned>(
+    client: &reqwest::Client,
+    url: &str,
+) -> Result<Option<T>, reqwest::Error> {
+    let response = client
+        .get(url)
+        .header("User-Agent", "yambot")
+        .send()
+        .await?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    response.error_for_status()?.json().await.map(Some)
+}
+
+/// Pronouns as shown on pronouns.alejo.io, e.g. "She/Her" or "He/They"
+fn format_pronouns(names: &HashMap<String, Pronoun>, user: &PronounUser) -> Option<String> {
+    let main = names.get(&user.pronoun_id)?;
+    let alt = user.alt_pronoun_id.as_ref().and_then(|id| names.get(id));
+    Some(match alt {
+        Some(alt) => format!("{}/{}", main.subject, alt.subject),
+        None if main.singular => main.subject.clone(),
+        None => format!("{}/{}", main.subject, main.object),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_format_pronouns() {
+        let names: HashMap<String, Pronoun> = serde_json::from_str(
+            r#"{
+                "sheher": {"name": "sheher", "subject": "She", "object": "Her", "singular": false},
+                "theythem": {"name": "theythem", "subject": "They", "object": "Them", "singular": false},
+                "any": {"name": "any", "subject": "Any", "object": "Any", "singular": true}
+            }"#,
+        )
+        .unwrap();
+        let user = |id: &str, alt: Option<&str>| PronounUser {
+            pronoun_id: id.to_string(),
+            alt_pronoun_id: alt.map(String::from),
+        };
+
+        assert_eq!(
+            format_pronouns(&names, &user("sheher", None)).as_deref(),
+            Some("She/Her")
+        );
+        assert_eq!(
+            format_pronouns(&names, &user("sheher", Some("theythem"))).as_deref(),
+            Some("She/They")
+        );
+        assert_eq!(
+            format_pronouns(&names, &user("any", None)).as_deref(),
+            Some("Any")
+        );
+        assert_eq!(format_pronouns(&names, &user("unknown", None)), None);
+    }
+
+    #[test]
+    fn test_user_info_placeholders() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 18, 12, 0, 0).unwrap();
+        let template = "{pronouns} | {accountage} | {followage}";
+        assert!(UserInfo::is_used_in(template));
+        assert!(!UserInfo::is_used_in("{user} {count}"));
+
+        let info = UserInfo {
+            pronouns: Some("She/Her".to_string()),
+            created_at: Some(Utc.with_ymd_and_hms(2023, 3, 18, 12, 0, 0).unwrap()),
+            followed_at: Some(None),
+        };
+        assert_eq!(
+            info.replace_placeholders(template, now),
+            "She/Her | 2 years | not following"
+        );
+        assert_eq!(
+            UserInfo::default().replace_placeholders(template, now),
+            "unknown | unknown | unknown"
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use super::uptime::format_live_time;
+use super::CommandContext;
+use crate::backend::{audience, config, locale};
+
+/// Computes a `$(name args)` variable, `args` is the text after the name.
+/// Returns `None` when there's no value, the variable is then left as it is.
+pub type VariableFn = fn(args: &str, context: &CommandContext) -> Option<String>;
+
+/// Range of `$(random)` without arguments
+const DEFAULT_RANDOM_RANGE: (i64, i64) = (1, 100);
+
+static VARIABLES: LazyLock<VariableRegistry> = LazyLock::new(VariableRegistry::with_builtins);
+
+/// Variables available in command responses as `$(name)` or `$(name args)`
+pub struct VariableRegistry {
+    variables: HashMap<String, VariableFn>,
+}
+
+impl VariableRegistry {
+    /// Registry with the built-in variables
+    pub fn with_builtins() -> Self {
+        let mut registry = Self {
+            variables: HashMap::new(),
+        };
+        registry.register("random", random);
+        registry.register("time", time);
+        registry.register("uptime", uptime);
+        registry.register("channel", channel);
+        registry.register("followcount", followcount);
+        registry.register("viewers", viewers);
+        registry.register("touser", touser);
+        registry
+    }
+
+    /// Add a variable, replacing one with the same name
+    pub fn register(&mut self, name: &str, variable: VariableFn) {
+        self.variables.insert(name.to_lowercase(), variable);
+    }
+
+    /// Expand the variables of a template, unknown ones are left as they are
+    pub fn expand(&self, template: &str, context: &CommandContext) -> String {
+        expand_with(template, |name, args| {
+            self.variables
+                .get(&name.to_lowercase())
+                .and_then(|variable| variable(args, context))
+        })
+    }
+}
+
+/// Expand `$(...)` variables with the built-in registry
+pub fn expand_variables(template: &str, context: &CommandContext) -> String {
+    VARIABLES.expand(template, context)
+}
+
+fn expand_with(template: &str, value: impl Fn(&str, &str) -> Option<String>) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("$(") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find(')') else {
+            rest = &rest[start..];
+            break;
+        };
+        let inner = after[..end].trim();
+        let (name, args) = inner.split_once(' ').unwrap_or((inner, ""));
+        match value(name, args.trim()) {
+            Some(value) => result.push_str(&value),
+            None => result.push_str(&rest[start..start + 2 + end + 1]),
+        }
+        rest = &after[end + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// `$(random)` 1-100, `$(random 5-10)` a number in the range or `$(random a,b,c)` one item
+fn random(args: &str, _context: &CommandContext) -> Option<String> {
+    if args.contains(',') {
+        let items: Vec<&str> = args
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .collect();
+        return (!items.is_empty()).then(|| items[fastrand::usize(..items.len())].to_string());
+    }
+    let (low, high) = parse_range(args)?;
+    Some(fastrand::i64(low..=high).to_string())
+}
+
+/// Bounds of a range like "1-100", in either order. Empty is the default range.
+fn parse_range(range: &str) -> Option<(i64, i64)> {
+    if range.is_empty() {
+        return Some(DEFAULT_RANDOM_RANGE);
+    }
+    // Split after the first character so a negative start like "-5-5" works
+    let split = range
+        .char_indices()
+        .skip(1)
+        .find(|(_, c)| *c == '-')
+        .map(|(index, _)| index)?;
+    let low: i64 = range[..split].trim().parse().ok()?;
+    let high: i64 = range[split + 1..].trim().parse().ok()?;
+    Some((low.min(high), low.max(high)))
+}
+
+/// `$(time)` in the configured timezone or `$(time Europe/Berlin)`
+fn time(args: &str, _context: &CommandContext) -> Option<String> {
+    let mut region = locale::region();
+    if !args.is_empty() {
+        region.timezone = Some(locale::parse_timezone(args).ok()??);
+    }
+    Some(region.format_time(chrono::Utc::now()))
+}
+
+/// `$(uptime)` like "2h 5m", or "offline"
+fn uptime(_args: &str, _context: &CommandContext) -> Option<String> {
+    let audience = audience::current()?;
+    Some(match audience.started_at {
+        Some(started_at) => format_live_time(started_at, chrono::Utc::now()),
+        None => "offline".to_string(),
+    })
+}
+
+fn channel(_args: &str, _context: &CommandContext) -> Option<String> {
+    Some(config::load_config().chatbot.channel_name)
+}
+
+fn followcount(_args: &str, _context: &CommandContext) -> Option<String> {
+    audience::current()?.followers.map(locale::format_number)
+}
+
+fn viewers(_args: &str, _context: &CommandContext) -> Option<String> {
+    let viewers = audience::current()?.viewers.unwrap_or_default();
+    Some(locale::format_number(viewers))
+}
+
+/// `$(touser)` the first argument without @, or the user who sent the command
+fn touser(_args: &str, context: &CommandContext) -> Option<String> {
+    Some(match context.args.first() {
+        Some(user) => user.trim_start_matches('@').to_string(),
+        None => context.display_name(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_with() {
+        let value = |name: &str, args: &str| match name {
+            "channel" => Some("yhn".to_string()),
+            "echo" => Some(args.to_string()),
+            _ => None,
+        };
+        assert_eq!(
+            expand_with("Welcome to $(channel)! $(echo  a b ) $(nope) $(", value),
+            "Welcome to yhn! a b $(nope) $("
+        );
+        assert_eq!(parse_range(""), Some((1, 100)));
+        assert_eq!(parse_range("10-5"), Some((5, 10)));
+        assert_eq!(parse_range("-5-5"), Some((-5, 5)));
+        assert_eq!(parse_range("five"), None);
+    }
+}
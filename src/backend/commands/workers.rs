@@ -0,0 +1,97 @@
+use std::future::Future;
+use std::time::Duration;
+use tokio::task::JoinSet;
+
+use super::process::{run_process, ProcessRequest};
+use super::CommandContext;
+
+/// Commands fetching external data at the same time, more are turned away
+const MAX_WORKERS: usize = 4;
+
+/// Programs of RunProcess actions running at the same time, more are turned away
+const MAX_PROCESSES: usize = 4;
+
+/// Work of a command that finished in the background
+pub enum FinishedWork {
+    /// The command's data is ready, `false` if fetching it timed out
+    Fetched(CommandContext, bool),
+    /// The command's program finished, with its output or why it failed
+    Process(CommandContext, ProcessRequest, Result<String, String>),
+}
+
+/// Commands waiting for external data like `{json:...}` placeholders and
+/// `{pronouns}`, or for a program they started. Both run off the event loop so a slow service or program
+/// doesn't hold up chat, and the command goes on once they're done. Dropping the
+/// pool cancels the waiting commands and stops their programs.
+#[derive(Default)]
+pub struct CommandWorkers {
+    jobs: JoinSet<(CommandContext, bool)>,
+    processes: JoinSet<(CommandContext, ProcessRequest, Result<String, String>)>,
+}
+
+impl CommandWorkers {
+    /// Run `fetch` for a command in the background, giving up after `timeout`. `fetch`
+    /// gets the command's context and returns it with the data filled in.
+    /// Returns false if all workers are busy.
+    pub fn spawn<F, Fut>(&mut self, context: CommandContext, fetch: F, timeout: Duration) -> bool
+    where
+        F: FnOnce(CommandContext) -> Fut,
+        Fut: Future<Output = CommandContext> + Send + 'static,
+    {
+        if self.jobs.len() >= MAX_WORKERS {
+            return false;
+        }
+
+        let waiting = context.clone();
+        let fetch = fetch(context);
+        self.jobs.spawn(async move {
+            match tokio::time::timeout(timeout, fetch).await {
+                Ok(context) => (context, true),
+                Err(_) => (waiting, false),
+            }
+        });
+        true
+    }
+
+    /// Start the program of a command in the background, stopping it after `timeout`.
+    /// Returns false if too many programs are running.
+    pub fn spawn_process(
+        &mut self,
+        context: CommandContext,
+        request: ProcessRequest,
+        timeout: Duration,
+    ) -> bool {
+        if self.processes.len() >= MAX_PROCESSES {
+            return false;
+        }
+
+        self.processes.spawn(async move {
+            let result = run_process(&request, timeout).await;
+            (context, request, result)
+        });
+        true
+    }
+
+    /// Next command whose data is ready or whose program finished. Never completes
+    /// while idle, so it can wait in a `select!` next to other events.
+    pub async fn next_finished(&mut self) -> FinishedWork {
+        tokio::select! {
+            (context, fetched) = join_next(&mut self.jobs) => {
+                FinishedWork::Fetched(context, fetched)
+            }
+            (context, request, result) = join_next(&mut self.processes) => {
+                FinishedWork::Process(context, request, result)
+            }
+        }
+    }
+}
+
+async fn join_next<T: 'static>(jobs: &mut JoinSet<T>) -> T {
+    loop {
+        match jobs.join_next().await {
+            Some(Ok(result)) => return result,
+            Some(Err(e)) => log::error!("Command worker failed: {}", e),
+            None => std::future::pending::<()>().await,
+        }
+    }
+}
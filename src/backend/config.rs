@@ -2,8 +2,20 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use std::sync::Mutex;
 
-use crate::backend::commands::CommandRegistry;
+use crate::backend::commands::{
+    ClipCommandConfig, CommandCooldownConfig, CommandCooldowns, CommandRegistry, GiveawayConfig,
+    PollConfig, ProcessConfig, UserCommandsConfig,
+};
+use crate::backend::engagement::EngagementConfig;
+use crate::backend::link_requests::LinkRequestsConfig;
+use crate::backend::obs::ObsConfig;
+use crate::backend::remote::RemoteConfig;
+use crate::backend::rewards::RewardsConfig;
+use crate::backend::schema::{self, Schema};
+use crate::backend::storage;
+use crate::backend::stream_state::StreamStatesConfig;
 use crate::ui::{ChatbotConfig, Config};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -15,26 +27,166 @@ pub struct AppConfig {
     pub tts: Config,
     #[serde(default)]
     pub overlay: OverlayConfig,
+    #[serde(default)]
+    pub ads: AdsConfig,
+    #[serde(default)]
+    pub charity: CharityConfig,
+    #[serde(default)]
+    pub stream_states: StreamStatesConfig,
+    #[serde(default)]
+    pub obs: ObsConfig,
+    #[serde(default)]
+    pub remote: RemoteConfig,
+    #[serde(default)]
+    pub command_cooldown: CommandCooldownConfig,
+    #[serde(default)]
+    pub user_commands: UserCommandsConfig,
+    #[serde(default)]
+    pub clip: ClipCommandConfig,
+    #[serde(default)]
+    pub poll: PollConfig,
+    #[serde(default)]
+    pub link_requests: LinkRequestsConfig,
+    #[serde(default)]
+    pub engagement: EngagementConfig,
+    #[serde(default)]
+    pub giveaway: GiveawayConfig,
+    #[serde(default)]
+    pub rewards: RewardsConfig,
+    #[serde(default)]
+    pub run_process: ProcessConfig,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct UiConfig {
     #[serde(default = "default_theme")]
     pub theme: String,
+    /// IANA timezone name like "Europe/Berlin" for timestamps, empty for the system timezone
+    #[serde(default)]
+    pub timezone: String,
+    /// Date, time and number format, e.g. "en-US" or "de-DE"
+    #[serde(default = "default_locale")]
+    pub locale: String,
+    /// SHA-256 of the editor mode PIN, empty if editor mode is disabled
+    #[serde(default)]
+    pub editor_pin_hash: String,
+    /// Start in editor mode, so restarting the bot doesn't unlock it
+    #[serde(default)]
+    pub editor_mode: bool,
+    /// Chat messages kept in memory, older ones are moved to data/chat_archive.jsonl
+    #[serde(default = "default_chat_history_size")]
+    pub chat_history_size: usize,
+    /// Bot log lines kept in memory, older ones are moved to data/log_archive.jsonl
+    #[serde(default = "default_log_history_size")]
+    pub log_history_size: usize,
 }
 
 impl Default for UiConfig {
     fn default() -> Self {
         Self {
             theme: default_theme(),
+            timezone: String::new(),
+            locale: default_locale(),
+            editor_pin_hash: String::new(),
+            editor_mode: false,
+            chat_history_size: default_chat_history_size(),
+            log_history_size: default_log_history_size(),
         }
     }
 }
 
+fn default_chat_history_size() -> usize {
+    200
+}
+
+fn default_log_history_size() -> usize {
+    500
+}
+
 fn default_theme() -> String {
     "Twilight".to_string()
 }
 
+fn default_locale() -> String {
+    "en-US".to_string()
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AdsConfig {
+    /// Hold SFX/TTS playback while an ad break is running
+    #[serde(default = "default_pause_playback")]
+    pub pause_playback: bool,
+    /// Chat message sent when an ad break starts, `{duration}` is replaced with
+    /// the length in seconds. Leave empty to disable.
+    #[serde(default = "default_ads_chat_message")]
+    pub chat_message: String,
+}
+
+impl Default for AdsConfig {
+    fn default() -> Self {
+        Self {
+            pause_playback: default_pause_playback(),
+            chat_message: default_ads_chat_message(),
+        }
+    }
+}
+
+fn default_pause_playback() -> bool {
+    true
+}
+
+fn default_ads_chat_message() -> String {
+    "Ads starting, back in {duration} seconds!".to_string()
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CharityConfig {
+    /// Chat message sent for each charity donation, `{user}`, `{amount}` and
+    /// `{charity}` are replaced. Leave empty to disable.
+    #[serde(default = "default_charity_thanks_message")]
+    pub thanks_message: String,
+    /// Read donations out with TTS
+    #[serde(default = "default_charity_tts")]
+    pub tts: bool,
+    /// Text read out for a donation, same placeholders as `thanks_message`
+    #[serde(default = "default_charity_tts_message")]
+    pub tts_message: String,
+    /// Language code of the TTS voice
+    #[serde(default = "default_charity_tts_language")]
+    pub tts_language: String,
+    /// Smallest donation read out, in the currency's major unit
+    #[serde(default)]
+    pub tts_min_amount: f64,
+}
+
+impl Default for CharityConfig {
+    fn default() -> Self {
+        Self {
+            thanks_message: default_charity_thanks_message(),
+            tts: default_charity_tts(),
+            tts_message: default_charity_tts_message(),
+            tts_language: default_charity_tts_language(),
+            tts_min_amount: 0.0,
+        }
+    }
+}
+
+fn default_charity_thanks_message() -> String {
+    "Thank you {user} for donating {amount} to {charity}!".to_string()
+}
+
+fn default_charity_tts() -> bool {
+    true
+}
+
+fn default_charity_tts_message() -> String {
+    "{user} donated {amount} to {charity}".to_string()
+}
+
+fn default_charity_tts_language() -> String {
+    "en".to_string()
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct OverlayConfig {
     #[serde(default = "default_overlay_enabled")]
@@ -116,60 +268,98 @@ fn default_overlay_port() -> u16 {
     3000
 }
 
-impl AppConfig {
-    fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
-        let content = fs::read_to_string(path)?;
-        let config: AppConfig = toml::from_str(&content)?;
-        Ok(config)
-    }
+/// Layout versions of config.toml, add a migration when renaming or moving settings
+const CONFIG_SCHEMA: Schema = Schema {
+    name: "config.toml",
+    migrations: &[schema::unversioned],
+};
 
-    fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
-        let content = toml::to_string(self)?;
-        fs::write(path, content)?;
-        Ok(())
-    }
-}
+/// Layout versions of commands.toml
+const COMMANDS_SCHEMA: Schema = Schema {
+    name: "commands.toml",
+    migrations: &[schema::unversioned],
+};
+
+/// config.toml as it was last loaded. While the bot runs, a broken config.toml
+/// keeps these settings instead of being replaced by the example.
+static LAST_CONFIG: Mutex<Option<AppConfig>> = Mutex::new(None);
 
 pub fn load_config() -> AppConfig {
     let project_root = project_root::get_project_root().unwrap();
     let config_path = project_root.join("config.toml");
-    let config: AppConfig = AppConfig::from_file(config_path).unwrap();
+    let mut last_config = LAST_CONFIG.lock().unwrap();
+    let config = match last_config.take() {
+        Some(last) => match read_config(&config_path) {
+            Ok(config) => config,
+            Err(e) => {
+                log::error!("{}, keeping the settings loaded before", e);
+                last
+            }
+        },
+        None => load_config_file(&project_root, &config_path),
+    };
+    *last_config = Some(config.clone());
+    config
+}
+
+/// Read config.toml without touching it, for reloading it while the bot runs
+fn read_config(config_path: &Path) -> Result<AppConfig, String> {
+    let content = fs::read_to_string(config_path)
+        .map_err(|e| format!("Failed to read config.toml: {}", e))?;
+    CONFIG_SCHEMA
+        .parse(&content)
+        .map(|(config, _)| config)
+        .map_err(|e| format!("Failed to parse config.toml: {}", e))
+}
 
-    return config;
+/// Load config.toml when the bot starts, migrating it or creating it from the example
+fn load_config_file(project_root: &Path, config_path: &Path) -> AppConfig {
+    if let Some(config) = CONFIG_SCHEMA
+        .load(config_path)
+        .unwrap_or_else(|e| panic!("{}", e))
+    {
+        return config;
+    }
+
+    // Missing or broken config, start over from the example. A broken config was
+    // backed up, so its tokens and settings can be copied back.
+    log::warn!("Creating config.toml from config.toml.example");
+    fs::copy(project_root.join("config.toml.example"), config_path)
+        .unwrap_or_else(|e| panic!("Failed to create config.toml: {}", e));
+    CONFIG_SCHEMA
+        .load(config_path)
+        .ok()
+        .flatten()
+        .expect("config.toml.example is broken")
 }
 
 pub fn save_config(config: &AppConfig) {
     let project_root = project_root::get_project_root().unwrap();
     let config_path = project_root.join("config.toml");
-    config.to_file(config_path).unwrap();
+    CONFIG_SCHEMA.save(&config_path, config);
 }
 
+/// Last runs of the commands, kept out of commands.toml since they change all the time
+const COOLDOWNS_STORAGE_NAME: &str = "command_cooldowns";
+
 pub fn load_commands() -> CommandRegistry {
     let project_root = project_root::get_project_root().unwrap();
     let commands_path = project_root.join("commands.toml");
 
-    // If file doesn't exist, return empty registry
-    if !commands_path.exists() {
-        return CommandRegistry::new();
-    }
-
-    match fs::read_to_string(&commands_path) {
-        Ok(content) => {
-            // If file is empty or only whitespace, return empty registry
-            if content.trim().is_empty() {
-                return CommandRegistry::new();
-            }
-
-            toml::from_str(&content).unwrap_or_else(|e| {
-                log::error!("Failed to parse commands.toml: {}", e);
-                log::error!("File content: {}", content);
-                CommandRegistry::new()
-            })
+    match COMMANDS_SCHEMA.load::<CommandRegistry>(&commands_path) {
+        Ok(Some(mut commands)) => {
+            commands.restore_cooldowns(storage::load(COOLDOWNS_STORAGE_NAME));
+            commands
         }
-        Err(e) => {
-            log::error!("Failed to read commands.toml: {}", e);
-            CommandRegistry::new()
+        Ok(None) => {
+            // Missing or broken (and backed up), write an empty registry in its place
+            let commands = CommandRegistry::new();
+            save_commands(&commands);
+            commands
         }
+        // Unreadable or written by a newer yambot, an empty registry would replace it
+        // on the next save
+        Err(e) => panic!("{}", e),
     }
 }
 
@@ -177,14 +367,14 @@ pub fn save_commands(commands: &CommandRegistry) {
     let project_root = project_root::get_project_root().unwrap();
     let commands_path = project_root.join("commands.toml");
 
-    match toml::to_string_pretty(commands) {
-        Ok(content) => {
-            if let Err(e) = fs::write(&commands_path, content) {
-                log::error!("Failed to write commands.toml: {}", e);
-            }
-        }
-        Err(e) => {
-            log::error!("Failed to serialize commands: {}", e);
-        }
-    }
+    // Temporary commands only live for the current stream
+    let mut commands = commands.clone();
+    commands.remove_temporary();
+
+    COMMANDS_SCHEMA.save(&commands_path, &commands);
+}
+
+/// Save when the commands last ran, so cooldowns continue after a restart
+pub fn save_cooldowns(cooldowns: &CommandCooldowns) {
+    storage::save(COOLDOWNS_STORAGE_NAME, cooldowns);
 }
@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{LazyLock, Mutex};
+
+use crate::backend::storage;
+
+const STORAGE_NAME: &str = "counters";
+
+/// Counters used by commands, by lowercase name. Kept across restarts.
+static COUNTERS: LazyLock<Mutex<BTreeMap<String, i64>>> =
+    LazyLock::new(|| Mutex::new(storage::load(STORAGE_NAME)));
+
+/// Change made to a counter by the Counter command action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CounterOp {
+    Increment,
+    Decrement,
+    Reset,
+}
+
+impl CounterOp {
+    pub fn apply(self, value: i64) -> i64 {
+        match self {
+            CounterOp::Increment => value.saturating_add(1),
+            CounterOp::Decrement => value.saturating_sub(1),
+            CounterOp::Reset => 0,
+        }
+    }
+}
+
+/// Current value of a counter, 0 if it was never changed
+pub fn get(name: &str) -> i64 {
+    COUNTERS
+        .lock()
+        .unwrap()
+        .get(&name.to_lowercase())
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Change a counter, save all counters and return the new value
+pub fn update(name: &str, op: CounterOp) -> i64 {
+    let mut counters = COUNTERS.lock().unwrap();
+    let value = counters.entry(name.to_lowercase()).or_default();
+    *value = op.apply(*value);
+    let value = *value;
+    storage::save(STORAGE_NAME, &*counters);
+    value
+}
+
+/// Replace `{count}` with the counter `default` and `{count:<name>}` with the counter `name`
+pub fn replace_count_placeholders(template: &str, default: &str) -> String {
+    replace_counts(template, default, get)
+}
+
+fn replace_counts(template: &str, default: &str, value: impl Fn(&str) -> i64) -> String {
+    const PREFIX: &str = "{count";
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find(PREFIX) {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + PREFIX.len()..];
+        let name = match after.find('}') {
+            Some(0) => Some((default, 1)),
+            Some(end) => after[..end]
+                .strip_prefix(':')
+                .filter(|name| !name.is_empty() && !name.contains('{'))
+                .map(|name| (name, end + 1)),
+            None => None,
+        };
+        match name {
+            Some((name, len)) => {
+                result.push_str(&value(name).to_string());
+                rest = &after[len..];
+            }
+            None => {
+                result.push_str(PREFIX);
+                rest = after;
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_counts() {
+        let value = |name: &str| match name {
+            "deaths" => 7,
+            "wins" => 2,
+            _ => 0,
+        };
+        assert_eq!(
+            replace_counts("Deaths: {count}, wins: {count:wins}", "deaths", value),
+            "Deaths: 7, wins: 2"
+        );
+        assert_eq!(
+            replace_counts("{counter} {count:} {count:x", "deaths", value),
+            "{counter} {count:} {count:x"
+        );
+        assert_eq!(CounterOp::Decrement.apply(0), -1);
+        assert_eq!(CounterOp::Reset.apply(12), 0);
+    }
+}
@@ -0,0 +1,109 @@
+use serde_json::Value;
+use std::collections::HashSet;
+use std::sync::{LazyLock, RwLock};
+use std::time::Duration;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Third-party (BTTV, FFZ, 7TV) emote codes available in the connected channel
+static THIRD_PARTY_EMOTES: LazyLock<RwLock<HashSet<String>>> =
+    LazyLock::new(|| RwLock::new(HashSet::new()));
+
+/// Emote lists to fetch: (url, JSON pointers to emote arrays, name field)
+fn emote_sources(broadcaster_id: &str) -> Vec<(String, &'static [&'static str], &'static str)> {
+    vec![
+        (
+            "https://api.betterttv.net/3/cached/emotes/global".to_string(),
+            &[""],
+            "code",
+        ),
+        (
+            format!(
+                "https://api.betterttv.net/3/cached/users/twitch/{}",
+                broadcaster_id
+            ),
+            &["/channelEmotes", "/sharedEmotes"],
+            "code",
+        ),
+        (
+            "https://api.betterttv.net/3/cached/frankerfacez/emotes/global".to_string(),
+            &[""],
+            "code",
+        ),
+        (
+            format!(
+                "https://api.betterttv.net/3/cached/frankerfacez/users/twitch/{}",
+                broadcaster_id
+            ),
+            &[""],
+            "code",
+        ),
+        (
+            "https://7tv.io/v3/emote-sets/global".to_string(),
+            &["/emotes"],
+            "name",
+        ),
+        (
+            format!("https://7tv.io/v3/users/twitch/{}", broadcaster_id),
+            &["/emote_set/emotes"],
+            "name",
+        ),
+    ]
+}
+
+/// Fetch global and channel emotes from BTTV, FFZ and 7TV.
+/// Providers the channel doesn't use are skipped. Returns the number of emotes loaded.
+pub async fn load_third_party_emotes(broadcaster_id: &str) -> usize {
+    let client = match reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(e) => {
+            log::error!("Failed to create HTTP client for emotes: {}", e);
+            return 0;
+        }
+    };
+
+    let mut emotes = HashSet::new();
+    for (url, pointers, field) in emote_sources(broadcaster_id) {
+        match fetch_json(&client, &url).await {
+            Ok(json) => {
+                for pointer in pointers {
+                    if let Some(list) = json.pointer(pointer).and_then(Value::as_array) {
+                        emotes.extend(
+                            list.iter()
+                                .filter_map(|emote| emote[field].as_str())
+                                .map(str::to_string),
+                        );
+                    }
+                }
+            }
+            Err(e) => log::debug!("Skipping emotes from {}: {}", url, e),
+        }
+    }
+
+    let count = emotes.len();
+    *THIRD_PARTY_EMOTES.write().unwrap() = emotes;
+    count
+}
+
+async fn fetch_json(client: &reqwest::Client, url: &str) -> Result<Value, reqwest::Error> {
+    client
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await
+}
+
+/// Third-party emotes used in a piece of chat text
+pub fn find_third_party_emotes(text: &str) -> Vec<String> {
+    let emotes = THIRD_PARTY_EMOTES.read().unwrap();
+    if emotes.is_empty() {
+        return Vec::new();
+    }
+
+    text.split_whitespace()
+        .filter(|word| emotes.contains(*word))
+        .map(str::to_string)
+        .collect()
+}
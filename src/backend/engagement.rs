@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// Settings for prompting chat when it goes quiet during a stream
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EngagementConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Minutes without chat messages before a prompt is sent
+    #[serde(default = "default_silence_minutes")]
+    pub silence_minutes: u64,
+    /// Minimum minutes between two prompts
+    #[serde(default = "default_cooldown_minutes")]
+    pub cooldown_minutes: u64,
+    /// Questions asked in chat, used in turn with the polls
+    #[serde(default)]
+    pub prompts: Vec<String>,
+    /// Quick polls, need the channel:manage:polls scope on the broadcaster's account
+    #[serde(default)]
+    pub polls: Vec<EngagementPoll>,
+    /// How long quick polls run, in seconds (15-1800)
+    #[serde(default = "default_poll_duration")]
+    pub poll_duration: u32,
+}
+
+impl Default for EngagementConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            silence_minutes: default_silence_minutes(),
+            cooldown_minutes: default_cooldown_minutes(),
+            prompts: Vec::new(),
+            polls: Vec::new(),
+            poll_duration: default_poll_duration(),
+        }
+    }
+}
+
+fn default_silence_minutes() -> u64 {
+    10
+}
+
+fn default_cooldown_minutes() -> u64 {
+    30
+}
+
+fn default_poll_duration() -> u32 {
+    60
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct EngagementPoll {
+    pub title: String,
+    /// 2-5 choices
+    pub choices: Vec<String>,
+}
+
+/// What to do about a quiet chat
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EngagementAction {
+    Question(String),
+    Poll(EngagementPoll),
+}
+
+/// Tracks chat activity during a stream and decides when chat needs a prompt
+#[derive(Debug, Default)]
+pub struct SilenceDetector {
+    /// Last chat message or prompt, unset until the first check of a stream
+    last_activity: Option<Instant>,
+    last_prompt: Option<Instant>,
+    /// Position in the prompt/poll rotation
+    next: usize,
+}
+
+impl SilenceDetector {
+    pub fn record_message(&mut self, now: Instant) {
+        self.last_activity = Some(now);
+    }
+
+    /// Returns the next prompt once chat has been quiet for `silence_minutes`
+    /// and the last prompt is at least `cooldown_minutes` ago
+    pub fn check(
+        &mut self,
+        config: &EngagementConfig,
+        live: bool,
+        now: Instant,
+    ) -> Option<EngagementAction> {
+        if !config.enabled || !live {
+            return None;
+        }
+
+        let last_activity = *self.last_activity.get_or_insert(now);
+        let silence = Duration::from_secs(config.silence_minutes * 60);
+        let cooldown = Duration::from_secs(config.cooldown_minutes * 60);
+        if now.duration_since(last_activity) < silence
+            || self
+                .last_prompt
+                .is_some_and(|prompt| now.duration_since(prompt) < cooldown)
+        {
+            return None;
+        }
+
+        let pool = config.prompts.len() + config.polls.len();
+        if pool == 0 {
+            return None;
+        }
+        let index = self.next % pool;
+        self.next = index + 1;
+        self.last_prompt = Some(now);
+        // Wait another full silence period before the next prompt
+        self.last_activity = Some(now);
+
+        Some(match config.prompts.get(index) {
+            Some(prompt) => EngagementAction::Question(prompt.clone()),
+            None => EngagementAction::Poll(config.polls[index - config.prompts.len()].clone()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_silence_detector() {
+        let config = EngagementConfig {
+            enabled: true,
+            silence_minutes: 5,
+            cooldown_minutes: 20,
+            prompts: vec!["What are you playing?".to_string()],
+            polls: vec![EngagementPoll {
+                title: "Next game?".to_string(),
+                choices: vec!["A".to_string(), "B".to_string()],
+            }],
+            ..Default::default()
+        };
+        let minutes = |m: u64| Duration::from_secs(m * 60);
+        let start = Instant::now();
+        let mut detector = SilenceDetector::default();
+
+        assert_eq!(detector.check(&config, true, start), None);
+        assert_eq!(detector.check(&config, false, start + minutes(6)), None);
+        detector.record_message(start + minutes(3));
+        assert_eq!(detector.check(&config, true, start + minutes(6)), None);
+        assert_eq!(
+            detector.check(&config, true, start + minutes(8)),
+            Some(EngagementAction::Question(
+                "What are you playing?".to_string()
+            ))
+        );
+        // Quiet again, but still on cooldown
+        assert_eq!(detector.check(&config, true, start + minutes(20)), None);
+        assert!(matches!(
+            detector.check(&config, true, start + minutes(28)),
+            Some(EngagementAction::Poll(_))
+        ));
+    }
+}
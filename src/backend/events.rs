@@ -0,0 +1,48 @@
+use std::sync::LazyLock;
+use tokio::sync::broadcast;
+
+use crate::backend::overlay::OverlayEvent;
+use crate::backend::twitch::ChatMessageEvent;
+
+/// Events a subscriber can fall behind by before it starts missing them
+const BUS_CAPACITY: usize = 256;
+
+static BUS: LazyLock<broadcast::Sender<BotEvent>> =
+    LazyLock::new(|| broadcast::channel(BUS_CAPACITY).0);
+
+/// Internal events published by the handlers.
+/// Subsystems (stats, webhooks, plugins) subscribe to these instead of being called
+/// directly from `handle_twitch_event`.
+#[derive(Debug, Clone)]
+pub enum BotEvent {
+    /// Chat message received, before commands and TTS are handled
+    ChatMessage(Box<ChatMessageEvent>),
+    /// A command ran successfully
+    CommandExecuted {
+        command: String,
+        user: String,
+    },
+    /// A sound command was played
+    SoundPlayed(String),
+    /// A TTS message was accepted and is being generated
+    TtsRequested {
+        user: String,
+        language: String,
+        text: String,
+    },
+    /// An alert was sent to the overlay
+    Alert(OverlayEvent),
+    StreamOnline,
+    StreamOffline,
+}
+
+/// Publish an event to all current subscribers
+pub fn publish(event: BotEvent) {
+    // Sending only fails when nobody is subscribed
+    let _ = BUS.send(event);
+}
+
+/// Receive events published from now on
+pub fn subscribe() -> broadcast::Receiver<BotEvent> {
+    BUS.subscribe()
+}
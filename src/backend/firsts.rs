@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::sync::{LazyLock, Mutex};
+
+use crate::backend::storage;
+
+const STORAGE_NAME: &str = "first_chatters";
+
+/// How many streams each user chatted first in, saved in data/first_chatters.json
+static FIRSTS: LazyLock<Mutex<FirstsBoard>> =
+    LazyLock::new(|| Mutex::new(storage::load(STORAGE_NAME)));
+
+/// Login of the first chatter since the stream went live or the bot connected
+static CURRENT: Mutex<Option<String>> = Mutex::new(None);
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct FirstsBoard {
+    /// Number of firsts by login
+    firsts: BTreeMap<String, u64>,
+}
+
+impl FirstsBoard {
+    /// Count a first for `login`, returns how many firsts they have now
+    pub fn record(&mut self, login: &str) -> u64 {
+        let count = self.firsts.entry(login.to_lowercase()).or_insert(0);
+        *count += 1;
+        *count
+    }
+
+    /// Users with the most firsts, ties sorted by login
+    pub fn top(&self, limit: usize) -> Vec<(String, u64)> {
+        let mut top: Vec<(String, u64)> = self
+            .firsts
+            .iter()
+            .map(|(login, count)| (login.clone(), *count))
+            .collect();
+        top.sort_by_key(|(_, count)| Reverse(*count));
+        top.truncate(limit);
+        top
+    }
+}
+
+/// Forget the current first chatter, the next one to chat takes the spot
+pub fn reset() {
+    *CURRENT.lock().unwrap() = None;
+}
+
+/// Login of the current first chatter, if anyone chatted yet
+pub fn first_chatter() -> Option<String> {
+    CURRENT.lock().unwrap().clone()
+}
+
+/// Make `login` the first chatter if nobody chatted yet. Returns their number of
+/// firsts including this one, `None` if someone else was first already.
+pub fn claim(login: &str) -> Option<u64> {
+    let mut current = CURRENT.lock().unwrap();
+    if current.is_some() {
+        return None;
+    }
+    *current = Some(login.to_lowercase());
+
+    let mut board = FIRSTS.lock().unwrap();
+    let count = board.record(login);
+    storage::save(STORAGE_NAME, &*board);
+    Some(count)
+}
+
+/// Users with the most firsts, for `!firsts`
+pub fn leaderboard(limit: usize) -> Vec<(String, u64)> {
+    FIRSTS.lock().unwrap().top(limit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_firsts_board() {
+        let mut board = FirstsBoard::default();
+        assert!(board.top(5).is_empty());
+
+        assert_eq!(board.record("Bob"), 1);
+        assert_eq!(board.record("alice"), 1);
+        assert_eq!(board.record("bob"), 2);
+        assert_eq!(board.record("carol"), 1);
+
+        assert_eq!(
+            board.top(2),
+            vec![("bob".to_string(), 2), ("alice".to_string(), 1)]
+        );
+    }
+}
@@ -0,0 +1,354 @@
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use crate::backend::commands::CommandPermission;
+use crate::backend::storage;
+
+const STORAGE_NAME: &str = "link_requests";
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+const YOUTUBE_VIDEOS_URL: &str = "https://www.googleapis.com/youtube/v3/videos";
+const YOUTUBE_OEMBED_URL: &str = "https://www.youtube.com/oembed";
+const SPOTIFY_OEMBED_URL: &str = "https://open.spotify.com/oembed";
+const STEAM_APP_DETAILS_URL: &str = "https://store.steampowered.com/api/appdetails";
+
+/// Requests waiting for approval and approved ones waiting to be played, oldest first
+pub static LINK_REQUESTS: LazyLock<Mutex<Vec<LinkRequest>>> =
+    LazyLock::new(|| Mutex::new(storage::load(STORAGE_NAME)));
+
+/// Settings for commands that only accept links, e.g. `!sr <youtube link>`
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LinkRequestsConfig {
+    /// YouTube Data API key, needed for video durations. Titles work without it.
+    #[serde(default)]
+    pub youtube_api_key: String,
+    #[serde(default)]
+    pub commands: Vec<LinkRequestCommand>,
+}
+
+impl LinkRequestsConfig {
+    pub fn command(&self, trigger: &str) -> Option<&LinkRequestCommand> {
+        self.commands
+            .iter()
+            .find(|command| command.trigger.eq_ignore_ascii_case(trigger))
+    }
+}
+
+/// A command that adds links to the request queue
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LinkRequestCommand {
+    /// The command trigger (without the ! prefix)
+    pub trigger: String,
+    /// Sites the command accepts links from
+    pub sources: Vec<LinkSource>,
+    #[serde(default = "default_permission")]
+    pub permission: CommandPermission,
+    /// Reply once a link is queued, `{title}` is replaced with the link's title
+    #[serde(default = "default_accepted_message")]
+    pub accepted_message: String,
+    /// Reply to anything that isn't an accepted link, `{sources}` lists the accepted sites
+    #[serde(default = "default_rejected_message")]
+    pub rejected_message: String,
+}
+
+fn default_permission() -> CommandPermission {
+    CommandPermission::Everyone
+}
+
+fn default_accepted_message() -> String {
+    "{user}, \"{title}\" was added to the request queue".to_string()
+}
+
+fn default_rejected_message() -> String {
+    "{user}, !{command} only accepts {sources} links".to_string()
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LinkSource {
+    Youtube,
+    Spotify,
+    Steam,
+}
+
+impl std::fmt::Display for LinkSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LinkSource::Youtube => write!(f, "YouTube"),
+            LinkSource::Spotify => write!(f, "Spotify"),
+            LinkSource::Steam => write!(f, "Steam"),
+        }
+    }
+}
+
+/// A validated link, reduced to its canonical form
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedLink {
+    pub source: LinkSource,
+    /// Video, track or app id
+    pub id: String,
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LinkRequest {
+    pub id: u64,
+    pub command: String,
+    pub user: String,
+    pub source: LinkSource,
+    pub url: String,
+    pub title: String,
+    /// Only known for YouTube videos when an API key is set
+    pub duration_seconds: Option<u64>,
+    pub approved: bool,
+    /// Time of the request in the configured timezone (YYYY-MM-DD HH:MM:SS)
+    pub timestamp: String,
+}
+
+/// Parse a link from one of the allowed sources. Anything else, including links to
+/// channels, playlists or other pages on an allowed site, returns `None`.
+pub fn parse_link(text: &str, sources: &[LinkSource]) -> Option<ParsedLink> {
+    let text = text.trim();
+    let url = Url::parse(text)
+        .or_else(|_| Url::parse(&format!("https://{}", text)))
+        .ok()?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+    let host = url.host_str()?.trim_start_matches("www.");
+    let segments: Vec<&str> = url.path_segments()?.filter(|s| !s.is_empty()).collect();
+
+    let (source, id, url) = match (host, segments.as_slice()) {
+        ("youtube.com" | "m.youtube.com" | "music.youtube.com", ["watch"]) => {
+            let id = url
+                .query_pairs()
+                .find(|(key, _)| key == "v")
+                .map(|(_, id)| id.into_owned())?;
+            youtube_link(id)?
+        }
+        ("youtube.com" | "m.youtube.com", ["shorts", id]) | ("youtu.be", [id]) => {
+            youtube_link(id.to_string())?
+        }
+        ("open.spotify.com", [.., kind @ ("track" | "album" | "episode"), id])
+            if id.len() == 22 && id.chars().all(|c| c.is_ascii_alphanumeric()) =>
+        {
+            (
+                LinkSource::Spotify,
+                id.to_string(),
+                format!("https://open.spotify.com/{}/{}", kind, id),
+            )
+        }
+        ("store.steampowered.com", ["app", id, ..])
+            if !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            (
+                LinkSource::Steam,
+                id.to_string(),
+                format!("https://store.steampowered.com/app/{}", id),
+            )
+        }
+        _ => return None,
+    };
+
+    sources
+        .contains(&source)
+        .then_some(ParsedLink { source, id, url })
+}
+
+fn youtube_link(id: String) -> Option<(LinkSource, String, String)> {
+    let valid = id.len() == 11
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    valid.then(|| {
+        let url = format!("https://www.youtube.com/watch?v={}", id);
+        (LinkSource::Youtube, id, url)
+    })
+}
+
+/// Parse an ISO 8601 duration as returned by the YouTube API, e.g. "PT1H2M3S"
+pub fn parse_iso8601_duration(duration: &str) -> Option<u64> {
+    let time = duration.strip_prefix("PT")?;
+    let mut seconds = 0;
+    let mut number = String::new();
+    for c in time.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let value: u64 = number.parse().ok()?;
+        number.clear();
+        seconds += value
+            * match c {
+                'H' => 3600,
+                'M' => 60,
+                'S' => 1,
+                _ => return None,
+            };
+    }
+    number.is_empty().then_some(seconds)
+}
+
+/// Look up the title and duration of a link. Falls back to the link itself
+/// as title when the site can't be reached.
+pub async fn fetch_metadata(link: &ParsedLink, youtube_api_key: &str) -> (String, Option<u64>) {
+    let client = match reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(e) => {
+            log::error!("Failed to create HTTP client for link metadata: {}", e);
+            return (link.url.clone(), None);
+        }
+    };
+
+    let result = match link.source {
+        LinkSource::Youtube if !youtube_api_key.is_empty() => fetch_json(
+            &client,
+            YOUTUBE_VIDEOS_URL,
+            &[
+                ("part", "snippet,contentDetails"),
+                ("id", &link.id),
+                ("key", youtube_api_key),
+            ],
+        )
+        .await
+        .map(|json| {
+            (
+                string_at(&json, "/items/0/snippet/title"),
+                json.pointer("/items/0/contentDetails/duration")
+                    .and_then(Value::as_str)
+                    .and_then(parse_iso8601_duration),
+            )
+        }),
+        LinkSource::Youtube => fetch_json(
+            &client,
+            YOUTUBE_OEMBED_URL,
+            &[("url", &link.url), ("format", "json")],
+        )
+        .await
+        .map(|json| (string_at(&json, "/title"), None)),
+        LinkSource::Spotify => fetch_json(&client, SPOTIFY_OEMBED_URL, &[("url", &link.url)])
+            .await
+            .map(|json| (string_at(&json, "/title"), None)),
+        LinkSource::Steam => fetch_json(&client, STEAM_APP_DETAILS_URL, &[("appids", &link.id)])
+            .await
+            .map(|json| (string_at(&json, &format!("/{}/data/name", link.id)), None)),
+    };
+
+    match result {
+        Ok((Some(title), duration)) => (title, duration),
+        Ok((None, _)) => (link.url.clone(), None),
+        Err(e) => {
+            log::warn!("Failed to fetch metadata for {}: {}", link.url, e);
+            (link.url.clone(), None)
+        }
+    }
+}
+
+async fn fetch_json(
+    client: &reqwest::Client,
+    url: &str,
+    query: &[(&str, &str)],
+) -> Result<Value, reqwest::Error> {
+    client
+        .get(url)
+        .query(query)
+        .header("User-Agent", "yambot")
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await
+}
+
+fn string_at(json: &Value, pointer: &str) -> Option<String> {
+    json.pointer(pointer)
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+/// Add a request to the queue, waiting for approval
+pub fn add_request(mut request: LinkRequest) -> LinkRequest {
+    let mut requests = LINK_REQUESTS.lock().unwrap();
+    request.id = requests.iter().map(|r| r.id).max().unwrap_or(0) + 1;
+    requests.push(request.clone());
+    storage::save(STORAGE_NAME, &*requests);
+    request
+}
+
+pub fn approve_request(id: u64) {
+    let mut requests = LINK_REQUESTS.lock().unwrap();
+    if let Some(request) = requests.iter_mut().find(|r| r.id == id) {
+        request.approved = true;
+        storage::save(STORAGE_NAME, &*requests);
+    }
+}
+
+/// Reject a pending request or remove a played one
+pub fn remove_request(id: u64) {
+    let mut requests = LINK_REQUESTS.lock().unwrap();
+    requests.retain(|r| r.id != id);
+    storage::save(STORAGE_NAME, &*requests);
+}
+
+/// Format a duration as M:SS or H:MM:SS
+pub fn format_duration(seconds: u64) -> String {
+    if seconds >= 3600 {
+        format!(
+            "{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds % 3600 / 60,
+            seconds % 60
+        )
+    } else {
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: &[LinkSource] = &[LinkSource::Youtube, LinkSource::Spotify, LinkSource::Steam];
+
+    #[test]
+    fn test_parse_link() {
+        let video = parse_link("https://youtu.be/dQw4w9WgXcQ?t=42", ALL).unwrap();
+        assert_eq!(video.source, LinkSource::Youtube);
+        assert_eq!(video.url, "https://www.youtube.com/watch?v=dQw4w9WgXcQ");
+        assert_eq!(
+            parse_link("youtube.com/watch?v=dQw4w9WgXcQ&list=abc", ALL),
+            Some(video)
+        );
+
+        let track = parse_link(
+            "https://open.spotify.com/intl-de/track/4uLU6hMCjMI75M1A2tKUQC?si=1",
+            ALL,
+        )
+        .unwrap();
+        assert_eq!(
+            track.url,
+            "https://open.spotify.com/track/4uLU6hMCjMI75M1A2tKUQC"
+        );
+
+        let game = parse_link("https://store.steampowered.com/app/620/Portal_2/", ALL).unwrap();
+        assert_eq!(game.id, "620");
+
+        assert!(parse_link("https://youtube.com/@channel", ALL).is_none());
+        assert!(parse_link("https://example.com/watch?v=dQw4w9WgXcQ", ALL).is_none());
+        assert!(parse_link("never gonna give you up", ALL).is_none());
+        assert!(parse_link("https://youtu.be/dQw4w9WgXcQ", &[LinkSource::Steam]).is_none());
+    }
+
+    #[test]
+    fn test_parse_iso8601_duration() {
+        assert_eq!(parse_iso8601_duration("PT1H2M3S"), Some(3723));
+        assert_eq!(parse_iso8601_duration("PT4M"), Some(240));
+        assert_eq!(parse_iso8601_duration("P1D"), None);
+        assert_eq!(format_duration(3723), "1:02:03");
+        assert_eq!(format_duration(61), "1:01");
+    }
+}
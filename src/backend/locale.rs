@@ -0,0 +1,269 @@
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, Utc};
+use chrono_tz::Tz;
+use std::sync::{LazyLock, RwLock};
+
+use crate::backend::config::{load_config, UiConfig};
+
+/// Format of timestamps written to storage, independent of the locale
+pub const STORAGE_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Timezone and locale used to show times and numbers, from the `[ui]` config section
+pub static REGION: LazyLock<RwLock<Region>> =
+    LazyLock::new(|| RwLock::new(Region::from_config(&load_config().ui)));
+
+/// Locales with their own date, time and number formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    EnUs,
+    EnGb,
+    De,
+    Fr,
+    Es,
+    PtBr,
+    Ru,
+    Ja,
+}
+
+impl Locale {
+    pub fn all() -> [Locale; 8] {
+        [
+            Locale::EnUs,
+            Locale::EnGb,
+            Locale::De,
+            Locale::Fr,
+            Locale::Es,
+            Locale::PtBr,
+            Locale::Ru,
+            Locale::Ja,
+        ]
+    }
+
+    /// Tag stored in config.toml
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Locale::EnUs => "en-US",
+            Locale::EnGb => "en-GB",
+            Locale::De => "de-DE",
+            Locale::Fr => "fr-FR",
+            Locale::Es => "es-ES",
+            Locale::PtBr => "pt-BR",
+            Locale::Ru => "ru-RU",
+            Locale::Ja => "ja-JP",
+        }
+    }
+
+    pub fn from_tag(tag: &str) -> Option<Locale> {
+        Locale::all()
+            .into_iter()
+            .find(|locale| locale.tag().eq_ignore_ascii_case(tag))
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Locale::EnUs => "English (US)",
+            Locale::EnGb => "English (UK)",
+            Locale::De => "Deutsch",
+            Locale::Fr => "Français",
+            Locale::Es => "Español",
+            Locale::PtBr => "Português (Brasil)",
+            Locale::Ru => "Русский",
+            Locale::Ja => "日本語",
+        }
+    }
+
+    fn date_format(&self) -> &'static str {
+        match self {
+            Locale::EnUs => "%m/%d/%Y",
+            Locale::De | Locale::Ru => "%d.%m.%Y",
+            Locale::Ja => "%Y/%m/%d",
+            _ => "%d/%m/%Y",
+        }
+    }
+
+    fn time_format(&self) -> &'static str {
+        match self {
+            Locale::EnUs => "%-I:%M:%S %p",
+            _ => "%H:%M:%S",
+        }
+    }
+
+    fn thousands_separator(&self) -> char {
+        match self {
+            Locale::EnUs | Locale::EnGb | Locale::Ja => ',',
+            Locale::De | Locale::Es | Locale::PtBr => '.',
+            Locale::Fr => '\u{202f}',
+            Locale::Ru => '\u{a0}',
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        match self {
+            Locale::EnUs | Locale::EnGb | Locale::Ja => '.',
+            _ => ',',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Region {
+    /// `None` uses the system timezone
+    pub timezone: Option<Tz>,
+    pub locale: Locale,
+}
+
+impl Region {
+    /// Read the region from config, falling back to the system timezone and en-US
+    pub fn from_config(config: &UiConfig) -> Self {
+        let timezone = parse_timezone(&config.timezone).unwrap_or_else(|e| {
+            log::warn!("{}, using the system timezone", e);
+            None
+        });
+        let locale = Locale::from_tag(&config.locale).unwrap_or_else(|| {
+            log::warn!("Unknown locale {:?}, using en-US", config.locale);
+            Locale::default()
+        });
+        Self { timezone, locale }
+    }
+
+    /// Convert a point in time to the configured timezone
+    pub fn local(&self, time: DateTime<Utc>) -> DateTime<FixedOffset> {
+        match self.timezone {
+            Some(tz) => time.with_timezone(&tz).fixed_offset(),
+            None => time.with_timezone(&Local).fixed_offset(),
+        }
+    }
+
+    pub fn format_datetime(&self, time: DateTime<Utc>) -> String {
+        let time = self.local(time);
+        format!(
+            "{} {}",
+            time.format(self.locale.date_format()),
+            time.format(self.locale.time_format())
+        )
+    }
+
+    pub fn format_time(&self, time: DateTime<Utc>) -> String {
+        self.local(time)
+            .format(self.locale.time_format())
+            .to_string()
+    }
+
+    pub fn format_number(&self, value: u64) -> String {
+        let digits = value.to_string();
+        let mut formatted = String::new();
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                formatted.push(self.locale.thousands_separator());
+            }
+            formatted.push(digit);
+        }
+        formatted
+    }
+
+    pub fn format_decimal(&self, value: f64, decimals: usize) -> String {
+        let formatted = format!("{:.*}", decimals, value.abs());
+        let (integer, fraction) = formatted
+            .split_once('.')
+            .unwrap_or((formatted.as_str(), ""));
+        // No minus sign when the value rounds to zero
+        let sign = if value < 0.0 && formatted.chars().any(|c| matches!(c, '1'..='9')) {
+            "-"
+        } else {
+            ""
+        };
+        let integer = self.format_number(integer.parse().unwrap_or(0));
+        if fraction.is_empty() {
+            format!("{}{}", sign, integer)
+        } else {
+            format!(
+                "{}{}{}{}",
+                sign,
+                integer,
+                self.locale.decimal_separator(),
+                fraction
+            )
+        }
+    }
+}
+
+/// Parse an IANA timezone name like "Europe/Berlin", an empty name means the system timezone
+pub fn parse_timezone(name: &str) -> Result<Option<Tz>, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Ok(None);
+    }
+    name.parse::<Tz>()
+        .map(Some)
+        .map_err(|_| format!("Unknown timezone \"{}\"", name))
+}
+
+pub fn region() -> Region {
+    *REGION.read().unwrap()
+}
+
+pub fn set_region(region: Region) {
+    *REGION.write().unwrap() = region;
+}
+
+/// Current time in the configured timezone
+pub fn now() -> DateTime<FixedOffset> {
+    region().local(Utc::now())
+}
+
+/// Current date and time, formatted for the log
+pub fn timestamp() -> String {
+    region().format_datetime(Utc::now())
+}
+
+/// Reformat a timestamp read from storage (see `STORAGE_FORMAT`) for display
+pub fn format_stored_timestamp(timestamp: &str) -> String {
+    match NaiveDateTime::parse_from_str(timestamp, STORAGE_FORMAT) {
+        Ok(time) => {
+            let locale = region().locale;
+            format!(
+                "{} {}",
+                time.format(locale.date_format()),
+                time.format(locale.time_format())
+            )
+        }
+        Err(_) => timestamp.to_string(),
+    }
+}
+
+pub fn format_number(value: u64) -> String {
+    region().format_number(value)
+}
+
+pub fn format_decimal(value: f64, decimals: usize) -> String {
+    region().format_decimal(value, decimals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_region_formatting() {
+        let region = Region {
+            timezone: parse_timezone("Europe/Berlin").unwrap(),
+            locale: Locale::De,
+        };
+        let time = Utc.with_ymd_and_hms(2024, 7, 1, 18, 5, 9).unwrap();
+        assert_eq!(region.format_datetime(time), "01.07.2024 20:05:09");
+        assert_eq!(region.format_number(1234567), "1.234.567");
+        assert_eq!(region.format_decimal(1234.5, 1), "1.234,5");
+
+        let region = Region {
+            timezone: parse_timezone("America/New_York").unwrap(),
+            locale: Locale::EnUs,
+        };
+        assert_eq!(region.format_datetime(time), "07/01/2024 2:05:09 PM");
+        assert_eq!(region.format_number(999), "999");
+        assert_eq!(region.format_decimal(-0.04, 1), "0.0");
+
+        assert!(parse_timezone("Mars/Olympus_Mons").is_err());
+        assert_eq!(parse_timezone("").unwrap(), None);
+    }
+}
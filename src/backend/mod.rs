@@ -1,6 +1,29 @@
+pub mod archive;
+pub mod audience;
+pub mod chat_history;
 pub mod commands;
 pub mod config;
+pub mod counters;
+pub mod emotes;
+pub mod engagement;
+pub mod events;
+pub mod firsts;
+pub mod link_requests;
+pub mod locale;
+pub mod obs;
 pub mod overlay;
+pub mod quotes;
+pub mod remote;
+pub mod rewards;
+pub mod roles;
+pub mod schema;
 pub mod sfx;
+pub mod stats;
+pub mod storage;
+pub mod stream_state;
+pub mod timers;
 pub mod tts;
 pub mod twitch;
+pub mod users;
+pub mod validation;
+pub mod wheel_history;
@@ -0,0 +1,197 @@
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use tokio::time::{sleep, Duration};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use super::set_current_scene;
+use crate::backend::overlay::{OverlayEvent, WebSocketState};
+use crate::ui::{BackendToFrontendMessage, LogLevel};
+
+const RECONNECT_DELAY: Duration = Duration::from_secs(10);
+
+// obs-websocket v5 opcodes
+const OP_HELLO: u64 = 0;
+const OP_IDENTIFY: u64 = 1;
+const OP_IDENTIFIED: u64 = 2;
+const OP_EVENT: u64 = 5;
+const OP_REQUEST: u64 = 6;
+const OP_REQUEST_RESPONSE: u64 = 7;
+
+// Event subscription bitmask for scene events
+const EVENT_SUBSCRIPTION_SCENES: u64 = 1 << 2;
+
+type ObsResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+/// Keep a connection to OBS open and track program scene changes.
+/// Reconnects automatically when OBS is closed or restarted.
+pub async fn run_obs_client(
+    backend_tx: tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+    overlay_ws_state: WebSocketState,
+) {
+    let mut failure_reported = false;
+
+    loop {
+        let config = crate::backend::config::load_config().obs;
+        let url = format!("ws://{}:{}", config.host, config.port);
+
+        match connect(&url, &config.password, &backend_tx, &overlay_ws_state).await {
+            Ok(()) => {
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::WARN,
+                        "Disconnected from OBS".to_string(),
+                    ))
+                    .await;
+                failure_reported = false;
+            }
+            Err(e) => {
+                // Only report the first failure, OBS may simply not be running yet
+                if !failure_reported {
+                    let _ = backend_tx
+                        .send(BackendToFrontendMessage::CreateLog(
+                            LogLevel::WARN,
+                            format!("Failed to connect to OBS: {}", e),
+                        ))
+                        .await;
+                    failure_reported = true;
+                }
+                log::debug!("Failed to connect to OBS at {}: {}", url, e);
+            }
+        }
+
+        set_current_scene(None);
+        sleep(RECONNECT_DELAY).await;
+    }
+}
+
+async fn connect(
+    url: &str,
+    password: &str,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+    overlay_ws_state: &WebSocketState,
+) -> ObsResult<()> {
+    let (ws_stream, _) = connect_async(url).await?;
+    let (mut write, mut read) = ws_stream.split();
+
+    while let Some(message) = read.next().await {
+        let text = match message? {
+            Message::Text(text) => text,
+            Message::Close(_) => break,
+            _ => continue,
+        };
+
+        let message: Value = serde_json::from_str(&text)?;
+        let data = &message["d"];
+
+        match message["op"].as_u64() {
+            Some(OP_HELLO) => {
+                let mut identify = json!({
+                    "rpcVersion": 1,
+                    "eventSubscriptions": EVENT_SUBSCRIPTION_SCENES,
+                });
+
+                if let Some(auth) = data.get("authentication") {
+                    if password.is_empty() {
+                        return Err(
+                            "OBS requires a password, set obs.password in config.toml".into()
+                        );
+                    }
+                    let challenge = auth["challenge"].as_str().unwrap_or_default();
+                    let salt = auth["salt"].as_str().unwrap_or_default();
+                    identify["authentication"] = json!(auth_response(password, salt, challenge));
+                }
+
+                send(&mut write, OP_IDENTIFY, identify).await?;
+            }
+            Some(OP_IDENTIFIED) => {
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        "Connected to OBS".to_string(),
+                    ))
+                    .await;
+
+                send(
+                    &mut write,
+                    OP_REQUEST,
+                    json!({
+                        "requestType": "GetCurrentProgramScene",
+                        "requestId": "current-scene",
+                    }),
+                )
+                .await?;
+            }
+            Some(OP_EVENT) if data["eventType"] == "CurrentProgramSceneChanged" => {
+                if let Some(scene) = data["eventData"]["sceneName"].as_str() {
+                    on_scene_changed(scene, backend_tx, overlay_ws_state).await;
+                }
+            }
+            Some(OP_REQUEST_RESPONSE) if data["requestType"] == "GetCurrentProgramScene" => {
+                if let Some(scene) = data["responseData"]["currentProgramSceneName"].as_str() {
+                    on_scene_changed(scene, backend_tx, overlay_ws_state).await;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+async fn send<S>(write: &mut S, op: u64, data: Value) -> ObsResult<()>
+where
+    S: SinkExt<Message> + Unpin,
+    S::Error: std::error::Error + Send + Sync + 'static,
+{
+    let message = json!({ "op": op, "d": data });
+    write
+        .send(Message::Text(message.to_string().into()))
+        .await?;
+    Ok(())
+}
+
+/// base64(sha256(base64(sha256(password + salt)) + challenge))
+fn auth_response(password: &str, salt: &str, challenge: &str) -> String {
+    let secret = BASE64.encode(Sha256::digest(format!("{}{}", password, salt)));
+    BASE64.encode(Sha256::digest(format!("{}{}", secret, challenge)))
+}
+
+async fn on_scene_changed(
+    scene: &str,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+    overlay_ws_state: &WebSocketState,
+) {
+    set_current_scene(Some(scene.to_string()));
+
+    let config = crate::backend::config::load_config();
+    let has_rule = config.obs.active_rule().is_some();
+    let features = crate::backend::stream_state::active_features(&config);
+
+    log::info!("OBS scene changed to {}", scene);
+
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(
+            LogLevel::INFO,
+            if has_rule {
+                format!(
+                    "OBS scene changed to {} - scene rule applied (TTS: {}, SFX: {}, alerts: {})",
+                    scene,
+                    if features.tts { "on" } else { "off" },
+                    if features.sfx { "on" } else { "off" },
+                    if features.alerts { "on" } else { "off" },
+                )
+            } else {
+                format!("OBS scene changed to {}", scene)
+            },
+        ))
+        .await;
+
+    overlay_ws_state
+        .broadcast(OverlayEvent::StreamState {
+            state: config.stream_states.current.label().to_string(),
+            scene: features.overlay_scene,
+        })
+        .await;
+}
@@ -0,0 +1,82 @@
+/// OBS integration via obs-websocket (v5 protocol)
+///
+/// Tracks the current program scene so features can be toggled per scene
+/// through rules configured in `[[obs.rules]]`.
+mod client;
+
+pub use client::run_obs_client;
+
+use serde::{Deserialize, Serialize};
+use std::sync::{LazyLock, Mutex};
+
+/// Current OBS program scene, `None` while disconnected
+static CURRENT_SCENE: LazyLock<Mutex<Option<String>>> = LazyLock::new(|| Mutex::new(None));
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ObsConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_obs_host")]
+    pub host: String,
+    #[serde(default = "default_obs_port")]
+    pub port: u16,
+    /// Leave empty if authentication is disabled in OBS
+    #[serde(default)]
+    pub password: String,
+    #[serde(default)]
+    pub rules: Vec<SceneRule>,
+}
+
+impl Default for ObsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: default_obs_host(),
+            port: default_obs_port(),
+            password: String::new(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+fn default_obs_host() -> String {
+    "localhost".to_string()
+}
+
+fn default_obs_port() -> u16 {
+    4455
+}
+
+/// Feature overrides applied while the given OBS scene is live.
+/// Unset fields keep the value from the current stream state.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SceneRule {
+    pub scene: String,
+    #[serde(default)]
+    pub tts: Option<bool>,
+    #[serde(default)]
+    pub sfx: Option<bool>,
+    #[serde(default)]
+    pub alerts: Option<bool>,
+    /// Overlay scene shown while this OBS scene is live
+    #[serde(default)]
+    pub overlay_scene: Option<String>,
+}
+
+impl ObsConfig {
+    /// Rule matching the current OBS scene, if any
+    pub fn active_rule(&self) -> Option<&SceneRule> {
+        let scene = current_scene()?;
+        self.rules
+            .iter()
+            .find(|rule| rule.scene.eq_ignore_ascii_case(&scene))
+    }
+}
+
+pub fn current_scene() -> Option<String> {
+    CURRENT_SCENE.lock().unwrap().clone()
+}
+
+fn set_current_scene(scene: Option<String>) {
+    *CURRENT_SCENE.lock().unwrap() = scene;
+}
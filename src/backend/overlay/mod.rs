@@ -1,3 +1,5 @@
+pub mod obs_export;
+pub mod sdk;
 pub mod server;
 pub mod websocket;
 
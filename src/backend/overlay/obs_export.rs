@@ -0,0 +1,180 @@
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+
+/// Name of the OBS scene holding the overlay sources, OBS needs it to differ from the sources
+const SCENE_NAME: &str = "Yambot";
+/// The bundled overlay is laid out for a 1080p canvas
+const OVERLAY_SIZE: (u32, u32) = (1920, 1080);
+/// File written next to config.toml, imported in OBS with Scene Collection > Import
+pub const EXPORT_FILE: &str = "yambot-obs-scenes.json";
+
+/// A browser source in the exported scene
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrowserSource {
+    pub name: String,
+    pub url: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Browser sources for the overlay server on `port`: the bundled overlay and every
+/// custom widget page in `overlay_dir`. Widgets are sized by their
+/// `<meta name="yambot:size" content="400x300">` tag, full canvas without one.
+pub fn browser_sources(overlay_dir: &Path, port: u16) -> Vec<BrowserSource> {
+    let base_url = format!("http://localhost:{}", port);
+    let mut sources = vec![BrowserSource {
+        name: "Yambot Overlay".to_string(),
+        url: format!("{}/", base_url),
+        width: OVERLAY_SIZE.0,
+        height: OVERLAY_SIZE.1,
+    }];
+
+    let mut widgets = widget_pages(overlay_dir);
+    widgets.sort();
+    for page in widgets {
+        let Ok(relative) = page.strip_prefix(overlay_dir) else {
+            continue;
+        };
+        let path = relative.to_string_lossy().replace('\\', "/");
+        // The bundled overlay is already the first source
+        if path == "index.html" {
+            continue;
+        }
+        let (width, height) = std::fs::read_to_string(&page)
+            .ok()
+            .and_then(|html| widget_size(&html))
+            .unwrap_or(OVERLAY_SIZE);
+        sources.push(BrowserSource {
+            name: format!("Yambot {}", path.trim_end_matches(".html")),
+            url: format!("{}/{}", base_url, path),
+            width,
+            height,
+        });
+    }
+    sources
+}
+
+/// HTML pages in a directory and its subdirectories
+fn widget_pages(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut pages = Vec::new();
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.is_dir() {
+            pages.extend(widget_pages(&path));
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("html"))
+        {
+            pages.push(path);
+        }
+    }
+    pages
+}
+
+/// Size from a `<meta name="yambot:size" content="WIDTHxHEIGHT">` tag
+fn widget_size(html: &str) -> Option<(u32, u32)> {
+    let tag_start = html.find("name=\"yambot:size\"")?;
+    let tag = &html[html[..tag_start].rfind('<')?..];
+    let tag = &tag[..tag.find('>')?];
+    let content = tag.split("content=\"").nth(1)?.split('"').next()?;
+    let (width, height) = content.split_once('x')?;
+    Some((width.trim().parse().ok()?, height.trim().parse().ok()?))
+}
+
+/// OBS scene collection with one scene holding all sources, stacked at the top left
+pub fn scene_collection(sources: &[BrowserSource]) -> Value {
+    let items: Vec<Value> = sources
+        .iter()
+        .enumerate()
+        .map(|(index, source)| {
+            json!({
+                "name": source.name,
+                "id": index + 1,
+                "visible": true,
+                "locked": false,
+                "pos": { "x": 0.0, "y": 0.0 },
+                "scale": { "x": 1.0, "y": 1.0 },
+                "rot": 0.0,
+                "align": 5,
+                "bounds_type": 0,
+            })
+        })
+        .collect();
+
+    let mut obs_sources: Vec<Value> = sources
+        .iter()
+        .map(|source| {
+            json!({
+                "id": "browser_source",
+                "versioned_id": "browser_source",
+                "name": source.name,
+                "enabled": true,
+                "settings": {
+                    "url": source.url,
+                    "width": source.width,
+                    "height": source.height,
+                    "shutdown": false,
+                    "restart_when_active": false,
+                },
+            })
+        })
+        .collect();
+    obs_sources.push(json!({
+        "id": "scene",
+        "versioned_id": "scene",
+        "name": SCENE_NAME,
+        "enabled": true,
+        "settings": {
+            "id_counter": items.len(),
+            "custom_size": false,
+            "items": items,
+        },
+    }));
+
+    json!({
+        "name": SCENE_NAME,
+        "current_scene": SCENE_NAME,
+        "current_program_scene": SCENE_NAME,
+        "scene_order": [{ "name": SCENE_NAME }],
+        "sources": obs_sources,
+        "groups": [],
+        "transitions": [],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_widget_size() {
+        let html =
+            r#"<head><meta charset="utf-8"><meta name="yambot:size" content="400x300"></head>"#;
+        assert_eq!(widget_size(html), Some((400, 300)));
+        assert_eq!(widget_size("<head></head>"), None);
+        assert_eq!(
+            widget_size(r#"<meta name="yambot:size" content="wide">"#),
+            None
+        );
+    }
+
+    #[test]
+    fn test_scene_collection() {
+        let sources = [BrowserSource {
+            name: "Yambot Overlay".to_string(),
+            url: "http://localhost:3000/".to_string(),
+            width: 1920,
+            height: 1080,
+        }];
+        let collection = scene_collection(&sources);
+        assert_eq!(collection["sources"][0]["settings"]["width"], 1920);
+        assert_eq!(collection["sources"][1]["id"], "scene");
+        assert_eq!(
+            collection["sources"][1]["settings"]["items"][0]["name"],
+            "Yambot Overlay"
+        );
+    }
+}
@@ -0,0 +1,201 @@
+/**
+ * Yambot Overlay client library v__VERSION__
+ * Generated by the Yambot overlay server - load it from http://localhost:<port>/yambot-overlay.js
+ *
+ * Usage:
+ *     const overlay = YambotOverlay.connect();
+ *     overlay.on('chat_notice', (event) => console.log(event.user_name, event.message));
+ *     overlay.onStatus((status) => console.log('Connection:', status));
+ */
+(function (global) {
+    'use strict';
+
+    const VERSION = '__VERSION__';
+
+    /** Event types the server can send (the `type` field of each event) */
+    const EVENTS = Object.freeze(__EVENT_TYPES__);
+
+    const DEFAULT_OPTIONS = {
+        // Derived from where this script was loaded from, see defaultUrl()
+        url: null,
+        // First reconnect delay, doubled after every failed attempt
+        reconnectDelay: 1000,
+        maxReconnectDelay: 30000,
+        // Request the overlay positions after connecting
+        requestConfig: true,
+    };
+
+    // Captured while the script runs, currentScript is null afterwards
+    const scriptSrc = document.currentScript ? document.currentScript.src : null;
+
+    /**
+     * WebSocket URL of the server this script was served by
+     */
+    function defaultUrl() {
+        const base = scriptSrc ? new URL(scriptSrc) : new URL(global.location.href);
+        const protocol = base.protocol === 'https:' ? 'wss:' : 'ws:';
+        const host = base.host || 'localhost:3000';
+        return `${protocol}//${host}/ws`;
+    }
+
+    class OverlayConnection {
+        constructor(options) {
+            this.options = Object.assign({}, DEFAULT_OPTIONS, options);
+            this.url = this.options.url || defaultUrl();
+            this.ws = null;
+            this.status = 'disconnected';
+            this.handlers = new Map();
+            this.statusHandlers = new Set();
+            this.reconnectTimeout = null;
+            this.reconnectAttempts = 0;
+            this.closed = false;
+
+            this.open();
+        }
+
+        /**
+         * Subscribe to an event type, or '*' for all events.
+         * Returns a function that removes the handler.
+         */
+        on(type, handler) {
+            if (type !== '*' && !EVENTS.includes(type)) {
+                console.warn(`[yambot-overlay] Unknown event type '${type}', known types:`, EVENTS);
+            }
+            if (!this.handlers.has(type)) {
+                this.handlers.set(type, new Set());
+            }
+            this.handlers.get(type).add(handler);
+            return () => this.off(type, handler);
+        }
+
+        off(type, handler) {
+            const handlers = this.handlers.get(type);
+            if (handlers) {
+                handlers.delete(handler);
+            }
+        }
+
+        /**
+         * Subscribe to connection status changes: 'connecting', 'connected' or 'disconnected'.
+         * The handler is called with the current status right away.
+         */
+        onStatus(handler) {
+            this.statusHandlers.add(handler);
+            handler(this.status);
+            return () => this.statusHandlers.delete(handler);
+        }
+
+        /**
+         * Send a message to the server, returns false while disconnected
+         */
+        send(message) {
+            if (this.ws && this.ws.readyState === WebSocket.OPEN) {
+                this.ws.send(JSON.stringify(message));
+                return true;
+            }
+            console.warn('[yambot-overlay] Not connected, cannot send message');
+            return false;
+        }
+
+        /**
+         * Close the connection for good
+         */
+        close() {
+            this.closed = true;
+            if (this.reconnectTimeout) {
+                clearTimeout(this.reconnectTimeout);
+                this.reconnectTimeout = null;
+            }
+            if (this.ws) {
+                this.ws.close();
+            }
+        }
+
+        open() {
+            this.setStatus('connecting');
+
+            try {
+                this.ws = new WebSocket(this.url);
+            } catch (error) {
+                console.error('[yambot-overlay] Failed to connect:', error);
+                this.scheduleReconnect();
+                return;
+            }
+
+            this.ws.onopen = () => {
+                this.reconnectAttempts = 0;
+                this.setStatus('connected');
+                if (this.options.requestConfig) {
+                    this.send({ type: 'request_config' });
+                }
+            };
+
+            this.ws.onmessage = (message) => {
+                let event;
+                try {
+                    event = JSON.parse(message.data);
+                } catch (error) {
+                    console.error('[yambot-overlay] Failed to parse message:', error);
+                    return;
+                }
+                this.dispatch(event);
+            };
+
+            this.ws.onclose = () => {
+                this.ws = null;
+                this.setStatus('disconnected');
+                this.scheduleReconnect();
+            };
+        }
+
+        dispatch(event) {
+            for (const type of [event.type, '*']) {
+                const handlers = this.handlers.get(type);
+                if (!handlers) {
+                    continue;
+                }
+                for (const handler of handlers) {
+                    try {
+                        handler(event);
+                    } catch (error) {
+                        console.error(`[yambot-overlay] '${type}' handler failed:`, error);
+                    }
+                }
+            }
+        }
+
+        scheduleReconnect() {
+            if (this.closed || this.reconnectTimeout) {
+                return;
+            }
+
+            // Exponential backoff with jitter, so many widgets don't reconnect at once
+            const delay = Math.min(
+                this.options.reconnectDelay * 2 ** this.reconnectAttempts,
+                this.options.maxReconnectDelay
+            );
+            this.reconnectAttempts++;
+
+            this.reconnectTimeout = setTimeout(() => {
+                this.reconnectTimeout = null;
+                this.open();
+            }, delay * (0.5 + Math.random() / 2));
+        }
+
+        setStatus(status) {
+            if (this.status === status) {
+                return;
+            }
+            this.status = status;
+            for (const handler of this.statusHandlers) {
+                handler(status);
+            }
+        }
+    }
+
+    global.YambotOverlay = Object.freeze({
+        VERSION,
+        EVENTS,
+        connect: (options) => new OverlayConnection(options),
+    });
+})(window);
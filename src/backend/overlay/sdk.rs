@@ -0,0 +1,62 @@
+use axum::{http::header, response::IntoResponse};
+
+/// Version of the overlay client library, bump when its API changes
+pub const SDK_VERSION: &str = "1.0.0";
+
+const SDK_TEMPLATE: &str = include_str!("sdk.js");
+
+/// `type` values of `OverlayEvent`, exposed to widgets as `YambotOverlay.EVENTS`
+const EVENT_TYPES: &[&str] = &[
+    "command_executed",
+    "tts_message",
+    "sound_played",
+    "trigger_action",
+    "poll",
+    "hype_train",
+    "goal",
+    "charity",
+    "chat_notice",
+    "ad_break",
+    "stream_state",
+    "text_widget",
+    "ping",
+    "config_update",
+];
+
+/// Fill in the version and event types of the client library
+pub fn generate_sdk() -> String {
+    let event_types = serde_json::to_string(EVENT_TYPES).unwrap_or_else(|_| "[]".to_string());
+    SDK_TEMPLATE
+        .replace("__VERSION__", SDK_VERSION)
+        .replace("__EVENT_TYPES__", &event_types)
+}
+
+/// Serve `yambot-overlay.js` for the bundled and custom widgets
+pub async fn sdk_handler() -> impl IntoResponse {
+    (
+        [
+            (
+                header::CONTENT_TYPE,
+                "application/javascript; charset=utf-8",
+            ),
+            (header::CACHE_CONTROL, "no-cache"),
+        ],
+        generate_sdk(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::overlay::OverlayEvent;
+
+    #[test]
+    fn test_generate_sdk() {
+        let sdk = generate_sdk();
+        assert!(!sdk.contains("__VERSION__") && !sdk.contains("__EVENT_TYPES__"));
+        assert!(sdk.contains(&format!("const VERSION = '{}';", SDK_VERSION)));
+
+        let ping = serde_json::to_value(OverlayEvent::Ping).unwrap();
+        assert!(EVENT_TYPES.contains(&ping["type"].as_str().unwrap()));
+    }
+}
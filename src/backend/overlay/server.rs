@@ -9,6 +9,7 @@ use std::path::PathBuf;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::services::ServeDir;
 
+use crate::backend::overlay::sdk::sdk_handler;
 use crate::backend::overlay::websocket::websocket_handler;
 use crate::backend::overlay::WebSocketState;
 
@@ -54,6 +55,7 @@ fn create_router(overlay_dir: PathBuf, ws_state: WebSocketState) -> Router {
     Router::new()
         .route("/health", get(health_check))
         .route("/ws", get(websocket_handler))
+        .route("/yambot-overlay.js", get(sdk_handler))
         .nest_service("/", ServeDir::new(overlay_dir))
         .layer(cors)
         .with_state(ws_state)
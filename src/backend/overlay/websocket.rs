@@ -99,6 +99,70 @@ pub enum OverlayEvent {
         action_type: String,
         data: serde_json::Value,
     },
+    /// Live poll state - sent when a poll starts, when votes change and when it ends
+    Poll {
+        poll_id: String,
+        title: String,
+        /// "active" while running, otherwise the end status from Twitch
+        status: String,
+        choices: serde_json::Value,
+        ends_at: Option<String>,
+    },
+    /// Hype train state - sent when a hype train starts, progresses and ends
+    HypeTrain {
+        /// "begin", "progress" or "end"
+        status: String,
+        level: u32,
+        total: u64,
+        /// Points towards the next level (0 once the train has ended)
+        progress: u64,
+        /// Points required for the next level (0 once the train has ended)
+        goal: u64,
+        /// When the train expires, or when the cooldown ends once it has ended
+        expires_at: String,
+    },
+    /// Creator goal state - sent when a goal starts, progresses and ends
+    Goal {
+        goal_id: String,
+        /// Twitch goal type, e.g. "follow" or "subscription"
+        goal_type: String,
+        description: String,
+        /// "active", "achieved" or "ended"
+        status: String,
+        current: i64,
+        target: i64,
+    },
+    /// Charity campaign total - sent whenever the total changes
+    Charity {
+        charity_name: String,
+        /// Amounts in the currency's major unit
+        current: f64,
+        target: f64,
+        currency: String,
+    },
+    /// Sub, gift sub or raid notice from chat
+    ChatNotice {
+        /// Twitch notice type, e.g. "sub", "resub", "sub_gift", "community_sub_gift" or "raid"
+        notice_type: String,
+        user_name: String,
+        /// Text Twitch shows for the notice
+        message: String,
+    },
+    /// An ad break started - show a countdown
+    AdBreak {
+        duration_seconds: u32,
+        started_at: String,
+    },
+    /// Stream state changed - switch the overlay scene
+    StreamState {
+        state: String,
+        scene: String,
+    },
+    /// Text shown by a widget that stays on screen, e.g. the death counter
+    TextWidget {
+        widget: String,
+        text: String,
+    },
     /// Ping to keep connection alive
     Ping,
     /// Configuration update - send overlay positions to client
@@ -115,6 +179,15 @@ pub enum OverlayClientMessage {
     WheelResult {
         result: String,
         action: Option<WheelAction>,
+        /// Name of the wheel, from the spin_wheel data
+        #[serde(default)]
+        wheel: Option<String>,
+        /// User who triggered the spin, from the spin_wheel data
+        #[serde(default)]
+        user: Option<String>,
+        /// Number of segments on the wheel
+        #[serde(default)]
+        segments: usize,
     },
     /// Overlay position update
     PositionUpdate {
@@ -138,6 +211,20 @@ pub enum WheelAction {
     Nothing,
 }
 
+impl std::fmt::Display for WheelAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WheelAction::Ban { username, .. } => write!(f, "ban {}", username),
+            WheelAction::Timeout {
+                username, duration, ..
+            } => write!(f, "timeout {} for {}s", username, duration),
+            WheelAction::Unban { username } => write!(f, "unban {}", username),
+            WheelAction::RunCommand { command } => write!(f, "run {}", command),
+            WheelAction::Nothing => write!(f, "nothing"),
+        }
+    }
+}
+
 /// WebSocket handler for overlay connections
 pub async fn websocket_handler(
     ws: WebSocketUpgrade,
@@ -0,0 +1,144 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::{LazyLock, Mutex};
+
+use crate::backend::storage;
+
+const STORAGE_NAME: &str = "quotes";
+
+/// Longest quote accepted, so `!quote` always fits in one chat message
+pub const MAX_QUOTE_LENGTH: usize = 400;
+
+/// Quotes added with `!addquote` or in the QUOTES tab, saved in data/quotes.json
+static QUOTES: LazyLock<Mutex<QuoteBook>> =
+    LazyLock::new(|| Mutex::new(storage::load(STORAGE_NAME)));
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Quote {
+    /// Number used by `!quote <id>` and `!delquote <id>`, never reused
+    pub id: u64,
+    pub text: String,
+    /// Login of whoever added the quote
+    pub added_by: String,
+    pub added_at: DateTime<Utc>,
+}
+
+impl Quote {
+    /// Chat form of the quote, e.g. `#3: "Never again" (2026-03-01)`
+    pub fn format(&self) -> String {
+        format!(
+            "#{}: \"{}\" ({})",
+            self.id,
+            self.text,
+            self.added_at.format("%Y-%m-%d")
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct QuoteBook {
+    quotes: Vec<Quote>,
+    /// ID of the last quote added, deleted IDs aren't given out again
+    #[serde(default)]
+    last_id: u64,
+}
+
+impl QuoteBook {
+    pub fn add(&mut self, text: &str, added_by: &str, now: DateTime<Utc>) -> Quote {
+        self.last_id = self
+            .last_id
+            .max(self.quotes.iter().map(|quote| quote.id).max().unwrap_or(0))
+            + 1;
+        let quote = Quote {
+            id: self.last_id,
+            text: text.trim().to_string(),
+            added_by: added_by.to_lowercase(),
+            added_at: now,
+        };
+        self.quotes.push(quote.clone());
+        quote
+    }
+
+    pub fn remove(&mut self, id: u64) -> Option<Quote> {
+        let idx = self.quotes.iter().position(|quote| quote.id == id)?;
+        Some(self.quotes.remove(idx))
+    }
+
+    pub fn get(&self, id: u64) -> Option<&Quote> {
+        self.quotes.iter().find(|quote| quote.id == id)
+    }
+
+    pub fn random(&self) -> Option<&Quote> {
+        if self.quotes.is_empty() {
+            return None;
+        }
+        self.quotes.get(fastrand::usize(..self.quotes.len()))
+    }
+}
+
+/// Check a quote before it is stored
+pub fn validate_quote(text: &str) -> Result<&str, &'static str> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("The quote is empty");
+    }
+    if text.chars().count() > MAX_QUOTE_LENGTH {
+        return Err("Quotes can be up to 400 characters long");
+    }
+    Ok(text)
+}
+
+/// All quotes, oldest first
+pub fn list() -> Vec<Quote> {
+    QUOTES.lock().unwrap().quotes.clone()
+}
+
+pub fn get(id: u64) -> Option<Quote> {
+    QUOTES.lock().unwrap().get(id).cloned()
+}
+
+pub fn random() -> Option<Quote> {
+    QUOTES.lock().unwrap().random().cloned()
+}
+
+/// Add a quote and save the quotes
+pub fn add(text: &str, added_by: &str) -> Quote {
+    let mut book = QUOTES.lock().unwrap();
+    let quote = book.add(text, added_by, Utc::now());
+    storage::save(STORAGE_NAME, &*book);
+    quote
+}
+
+/// Delete a quote and save the quotes, `None` if there is no quote with that ID
+pub fn remove(id: u64) -> Option<Quote> {
+    let mut book = QUOTES.lock().unwrap();
+    let quote = book.remove(id)?;
+    storage::save(STORAGE_NAME, &*book);
+    Some(quote)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_book() {
+        let now = Utc::now();
+        let mut book = QuoteBook::default();
+        assert!(book.random().is_none());
+
+        assert_eq!(book.add(" First ", "Mod", now).id, 1);
+        assert_eq!(book.add("Second", "mod", now).id, 2);
+        assert_eq!(book.get(1).unwrap().text, "First");
+        assert_eq!(book.get(1).unwrap().added_by, "mod");
+
+        assert!(book.remove(2).is_some());
+        assert!(book.remove(2).is_none());
+        assert_eq!(book.add("Third", "mod", now).id, 3);
+        assert!(book.random().is_some());
+
+        assert!(validate_quote("  ").is_err());
+        assert!(validate_quote(&"a".repeat(401)).is_err());
+        assert_eq!(validate_quote(" ok "), Ok("ok"));
+    }
+}
@@ -0,0 +1,85 @@
+mod server;
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+pub use server::start_remote_server;
+
+/// Web dashboard for moderators, served separately from the overlay so it can be
+/// reached from other devices on the network
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RemoteConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_remote_port")]
+    pub port: u16,
+    /// Asked for by the browser, any user name is accepted. The dashboard doesn't start without one.
+    #[serde(default)]
+    pub password: String,
+    #[serde(default)]
+    pub actions: Vec<RemoteAction>,
+}
+
+impl Default for RemoteConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: default_remote_port(),
+            password: String::new(),
+            actions: Vec::new(),
+        }
+    }
+}
+
+fn default_remote_port() -> u16 {
+    3001
+}
+
+/// Button on the dashboard that sends a chat message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RemoteAction {
+    pub label: String,
+    pub message: String,
+    /// Announcement color like "primary" or "purple", empty sends a normal message
+    #[serde(default)]
+    pub announce: String,
+}
+
+/// Check the `Authorization` header of a request against the dashboard password.
+/// Only HTTP basic auth is supported, the user name is ignored.
+pub fn is_authorized(header: Option<&str>, password: &str) -> bool {
+    let Some(encoded) = header.and_then(|header| header.strip_prefix("Basic ")) else {
+        return false;
+    };
+    let Ok(decoded) = base64::engine::general_purpose::STANDARD.decode(encoded.trim()) else {
+        return false;
+    };
+    let decoded = String::from_utf8_lossy(&decoded);
+    let Some((_, given)) = decoded.split_once(':') else {
+        return false;
+    };
+    // Compare digests so the comparison doesn't depend on where the passwords differ
+    !password.is_empty() && Sha256::digest(given.as_bytes()) == Sha256::digest(password.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_authorized() {
+        let header = |credentials: &str| {
+            format!(
+                "Basic {}",
+                base64::engine::general_purpose::STANDARD.encode(credentials)
+            )
+        };
+        assert!(is_authorized(Some(&header("mod:hunter2")), "hunter2"));
+        assert!(is_authorized(Some(&header(":hunter2")), "hunter2"));
+        assert!(!is_authorized(Some(&header("mod:hunter")), "hunter2"));
+        assert!(!is_authorized(Some(&header("mod:")), ""));
+        assert!(!is_authorized(Some("Bearer hunter2"), "hunter2"));
+        assert!(!is_authorized(None, "hunter2"));
+    }
+}
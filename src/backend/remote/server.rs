@@ -0,0 +1,187 @@
+use axum::{
+    extract::{Request, State},
+    http::{header, StatusCode},
+    middleware::{self, Next},
+    response::{Html, IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+
+use crate::backend::config::load_config;
+use crate::backend::remote::is_authorized;
+use crate::backend::tts::TTSQueue;
+use crate::ui::FrontendToBackendMessage;
+
+const DASHBOARD: &str = include_str!("index.html");
+
+/// Shared state of the dashboard routes
+#[derive(Clone)]
+struct RemoteState {
+    password: String,
+    tts_queue: TTSQueue,
+    /// Requests from the dashboard are handled like the ones from the app window
+    frontend_tx: tokio::sync::mpsc::Sender<FrontendToBackendMessage>,
+}
+
+#[derive(Serialize)]
+struct DashboardState {
+    tts: Vec<TtsItem>,
+    sfx_paused: bool,
+    actions: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct TtsItem {
+    id: String,
+    username: String,
+    text: String,
+    playing: bool,
+}
+
+#[derive(Deserialize)]
+struct SkipRequest {
+    /// Message to skip, the one playing if missing
+    id: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PauseRequest {
+    paused: bool,
+}
+
+/// Sent as JSON like the other requests, so other sites can't post it with a plain form
+#[derive(Deserialize)]
+struct ActionRequest {
+    /// Position of the action in [remote] of config.toml
+    index: usize,
+}
+
+/// Start the moderator dashboard, reachable from other devices on the network
+pub async fn start_remote_server(
+    port: u16,
+    password: String,
+    tts_queue: TTSQueue,
+    frontend_tx: tokio::sync::mpsc::Sender<FrontendToBackendMessage>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if password.is_empty() {
+        return Err("set a password in [remote] to use the moderator dashboard".into());
+    }
+
+    let state = RemoteState {
+        password,
+        tts_queue,
+        frontend_tx,
+    };
+    let app = Router::new()
+        .route("/", get(dashboard))
+        .route("/api/state", get(dashboard_state))
+        .route("/api/tts/skip", post(skip_tts))
+        .route("/api/sfx/pause", post(pause_sfx))
+        .route("/api/actions/run", post(run_action))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_password,
+        ))
+        .with_state(state);
+
+    let addr = SocketAddr::from(([0, 0, 0, 0], port));
+    log::info!("Starting moderator dashboard on http://{}", addr);
+
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, app).await?;
+
+    Ok(())
+}
+
+/// Ask the browser for the password on every route
+async fn require_password(
+    State(state): State<RemoteState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let header = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+    if !is_authorized(header, &state.password) {
+        return (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Basic realm=\"Yambot\"")],
+            "Password required",
+        )
+            .into_response();
+    }
+    next.run(request).await
+}
+
+async fn dashboard() -> impl IntoResponse {
+    Html(DASHBOARD)
+}
+
+async fn dashboard_state(State(state): State<RemoteState>) -> Json<DashboardState> {
+    let current = state.tts_queue.get_currently_playing().await;
+    let queued = state.tts_queue.get_all().await;
+    let tts = current
+        .iter()
+        .map(|item| (item, true))
+        .chain(queued.iter().map(|item| (item, false)))
+        .map(|(item, playing)| TtsItem {
+            id: item.request.id.clone(),
+            username: item.request.username.clone(),
+            text: item.request.text.clone(),
+            playing,
+        })
+        .collect();
+
+    Json(DashboardState {
+        tts,
+        sfx_paused: crate::backend::sfx::is_paused(),
+        actions: load_config()
+            .remote
+            .actions
+            .into_iter()
+            .map(|action| action.label)
+            .collect(),
+    })
+}
+
+async fn skip_tts(
+    State(state): State<RemoteState>,
+    Json(request): Json<SkipRequest>,
+) -> StatusCode {
+    let message = match request.id {
+        Some(id) => FrontendToBackendMessage::SkipTTSMessage(id),
+        None => FrontendToBackendMessage::SkipCurrentTTS,
+    };
+    forward(&state, message).await
+}
+
+async fn pause_sfx(
+    State(state): State<RemoteState>,
+    Json(request): Json<PauseRequest>,
+) -> StatusCode {
+    forward(
+        &state,
+        FrontendToBackendMessage::SetSfxPaused(request.paused),
+    )
+    .await
+}
+
+async fn run_action(
+    State(state): State<RemoteState>,
+    Json(request): Json<ActionRequest>,
+) -> StatusCode {
+    let Some(action) = load_config().remote.actions.into_iter().nth(request.index) else {
+        return StatusCode::NOT_FOUND;
+    };
+    forward(&state, FrontendToBackendMessage::RunRemoteAction(action)).await
+}
+
+async fn forward(state: &RemoteState, message: FrontendToBackendMessage) -> StatusCode {
+    match state.frontend_tx.send(message).await {
+        Ok(()) => StatusCode::NO_CONTENT,
+        Err(_) => StatusCode::SERVICE_UNAVAILABLE,
+    }
+}
@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+
+use crate::backend::twitch::{CustomRewardData, CustomRewardSettings};
+
+/// Channel point rewards the bot creates and keeps up to date, so rewards used in
+/// `overlay.reward_bindings` don't have to be set up on Twitch by hand
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RewardsConfig {
+    /// Sync the rewards every time the bot connects
+    #[serde(default)]
+    pub sync_on_connect: bool,
+    #[serde(default)]
+    pub rewards: Vec<ManagedReward>,
+}
+
+/// A reward owned by the bot, matched to the channel's rewards by title
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ManagedReward {
+    pub title: String,
+    pub cost: u64,
+    /// Description shown to viewers
+    #[serde(default)]
+    pub prompt: String,
+    /// Ask viewers for a message when redeeming
+    #[serde(default)]
+    pub user_input: bool,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl ManagedReward {
+    pub fn settings(&self) -> CustomRewardSettings {
+        CustomRewardSettings {
+            title: self.title.clone(),
+            cost: self.cost,
+            prompt: self.prompt.clone(),
+            is_enabled: self.enabled,
+            is_user_input_required: self.user_input,
+        }
+    }
+}
+
+/// Change needed to bring the channel's rewards in line with the config
+#[derive(Debug, Clone, PartialEq)]
+pub enum RewardChange {
+    Create(CustomRewardSettings),
+    Update {
+        id: String,
+        settings: CustomRewardSettings,
+    },
+    /// Created by the bot but no longer in the config
+    Delete {
+        id: String,
+        title: String,
+    },
+}
+
+/// Compare the configured rewards with the ones the bot created earlier (`existing`)
+pub fn plan_sync(wanted: &[ManagedReward], existing: &[CustomRewardData]) -> Vec<RewardChange> {
+    let mut changes = Vec::new();
+
+    for reward in wanted {
+        let settings = reward.settings();
+        let current = existing
+            .iter()
+            .find(|current| current.title.eq_ignore_ascii_case(&reward.title));
+        match current {
+            None => changes.push(RewardChange::Create(settings)),
+            Some(current) => {
+                let unchanged = current.title == settings.title
+                    && current.cost == settings.cost
+                    && current.prompt == settings.prompt
+                    && current.is_enabled == settings.is_enabled
+                    && current.is_user_input_required == settings.is_user_input_required;
+                if !unchanged {
+                    changes.push(RewardChange::Update {
+                        id: current.id.clone(),
+                        settings,
+                    });
+                }
+            }
+        }
+    }
+
+    for current in existing {
+        let wanted = wanted
+            .iter()
+            .any(|reward| reward.title.eq_ignore_ascii_case(&current.title));
+        if !wanted {
+            changes.push(RewardChange::Delete {
+                id: current.id.clone(),
+                title: current.title.clone(),
+            });
+        }
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn managed(title: &str, cost: u64) -> ManagedReward {
+        ManagedReward {
+            title: title.to_string(),
+            cost,
+            prompt: String::new(),
+            user_input: false,
+            enabled: true,
+        }
+    }
+
+    fn existing(id: &str, title: &str, cost: u64) -> CustomRewardData {
+        CustomRewardData {
+            id: id.to_string(),
+            title: title.to_string(),
+            cost,
+            prompt: String::new(),
+            is_enabled: true,
+            is_user_input_required: false,
+        }
+    }
+
+    #[test]
+    fn test_plan_sync() {
+        let wanted = [
+            managed("Spin the wheel", 500),
+            managed("Hydrate", 100),
+            managed("Skip TTS", 1000),
+        ];
+        let current = [
+            existing("1", "spin the wheel", 500),
+            existing("2", "Hydrate", 100),
+            existing("3", "Old reward", 50),
+        ];
+
+        assert_eq!(
+            plan_sync(&wanted, &current),
+            vec![
+                RewardChange::Update {
+                    id: "1".to_string(),
+                    settings: wanted[0].settings(),
+                },
+                RewardChange::Create(wanted[2].settings()),
+                RewardChange::Delete {
+                    id: "3".to_string(),
+                    title: "Old reward".to_string(),
+                },
+            ]
+        );
+        assert!(plan_sync(&wanted[1..2], &current[1..2]).is_empty());
+    }
+}
@@ -0,0 +1,92 @@
+use crate::backend::twitch::Badge;
+use crate::ui::PermitedRoles;
+
+/// Highest role a chatter has in the channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum UserRole {
+    Viewer,
+    Subscriber,
+    Vip,
+    Moderator,
+    Broadcaster,
+}
+
+/// Roles of the user who sent a chat message, resolved once from its badges
+/// and used by all permission checks (commands, TTS, sounds)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UserIdentity {
+    pub broadcaster: bool,
+    pub moderator: bool,
+    pub vip: bool,
+    /// Current or founding subscriber
+    pub subscriber: bool,
+}
+
+impl UserIdentity {
+    pub fn from_badges(badges: &[Badge]) -> Self {
+        let mut identity = Self::default();
+        for badge in badges {
+            match badge.set_id.as_str() {
+                "broadcaster" => identity.broadcaster = true,
+                "moderator" => identity.moderator = true,
+                "vip" => identity.vip = true,
+                "subscriber" | "founder" => identity.subscriber = true,
+                _ => {}
+            }
+        }
+        identity
+    }
+
+    /// Highest role of the user
+    pub fn role(&self) -> UserRole {
+        if self.broadcaster {
+            UserRole::Broadcaster
+        } else if self.moderator {
+            UserRole::Moderator
+        } else if self.vip {
+            UserRole::Vip
+        } else if self.subscriber {
+            UserRole::Subscriber
+        } else {
+            UserRole::Viewer
+        }
+    }
+
+    /// Check the TTS/SFX role settings. The broadcaster is always allowed,
+    /// `mods_only` ignores the subscriber and VIP settings (e.g. during shield mode)
+    pub fn is_permitted(&self, roles: &PermitedRoles, mods_only: bool) -> bool {
+        self.broadcaster
+            || self.moderator && roles.mods
+            || !mods_only && (self.subscriber && roles.subs || self.vip && roles.vips)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn badge(set_id: &str) -> Badge {
+        Badge {
+            set_id: set_id.to_string(),
+            id: "1".to_string(),
+            info: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_identity_from_badges() {
+        let identity = UserIdentity::from_badges(&[badge("vip"), badge("founder")]);
+        assert!(identity.vip && identity.subscriber);
+        assert_eq!(identity.role(), UserRole::Vip);
+        assert_eq!(UserIdentity::from_badges(&[]).role(), UserRole::Viewer);
+
+        let roles = PermitedRoles {
+            subs: true,
+            vips: false,
+            mods: true,
+        };
+        assert!(identity.is_permitted(&roles, false));
+        assert!(!identity.is_permitted(&roles, true));
+        assert!(UserIdentity::from_badges(&[badge("moderator")]).is_permitted(&roles, true));
+    }
+}
@@ -0,0 +1,198 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Key holding the schema version at the top of each versioned file
+const VERSION_KEY: &str = "schema_version";
+
+/// Upgrades a document by one schema version
+pub type Migration = fn(&mut toml::Table);
+
+/// Layout of a persisted TOML file. Files written before schema versioning have no
+/// `schema_version` and count as version 0.
+pub struct Schema {
+    /// File name used in log messages
+    pub name: &'static str,
+    /// `migrations[n]` upgrades a document from version n to n + 1,
+    /// so the current version is the number of migrations
+    pub migrations: &'static [Migration],
+}
+
+impl Schema {
+    pub fn version(&self) -> u32 {
+        self.migrations.len() as u32
+    }
+
+    /// Parse a document, migrating it if an older version wrote it.
+    /// Returns the value and whether it was migrated.
+    pub fn parse<T: DeserializeOwned>(&self, content: &str) -> Result<(T, bool), String> {
+        let mut table: toml::Table = content.parse().map_err(|e| format!("{}", e))?;
+        let version = match table.remove(VERSION_KEY) {
+            None => 0,
+            Some(toml::Value::Integer(version)) if version >= 0 => version as u32,
+            Some(other) => return Err(format!("Invalid {}: {}", VERSION_KEY, other)),
+        };
+        if version > self.version() {
+            return Err(self.newer_version_error(version));
+        }
+
+        for migration in &self.migrations[version as usize..] {
+            migration(&mut table);
+        }
+        let value = toml::Value::Table(table)
+            .try_into()
+            .map_err(|e| format!("{}", e))?;
+        Ok((value, version < self.version()))
+    }
+
+    /// Serialize a document with the current schema version on top
+    pub fn to_string<T: Serialize>(&self, value: &T) -> Result<String, toml::ser::Error> {
+        Ok(format!(
+            "{} = {}\n\n{}",
+            VERSION_KEY,
+            self.version(),
+            toml::to_string_pretty(value)?
+        ))
+    }
+
+    /// Load a file, returns `Ok(None)` if it doesn't exist or is broken so the caller
+    /// can regenerate it. Broken files are moved to a backup first, migrated files are
+    /// saved in the current version right away. Errors if the file can't be read or a
+    /// newer version of yambot wrote it, those are left alone.
+    pub fn load<T: DeserializeOwned + Serialize>(&self, path: &Path) -> Result<Option<T>, String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(format!("Failed to read {}: {}", self.name, e)),
+        };
+        if let Some(version) = file_version(&content).filter(|version| *version > self.version()) {
+            return Err(self.newer_version_error(version));
+        }
+
+        match self.parse(&content) {
+            Ok((value, migrated)) => {
+                if migrated {
+                    log::info!("Updated {} to schema version {}", self.name, self.version());
+                    self.save(path, &value);
+                }
+                Ok(Some(value))
+            }
+            Err(e) => {
+                log::error!("Failed to parse {}: {}", self.name, e);
+                let backup_path = backup(path)?;
+                log::error!(
+                    "{} was moved to {} and will be regenerated",
+                    self.name,
+                    backup_path.display()
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    fn newer_version_error(&self, version: u32) -> String {
+        format!(
+            "{} has schema version {}, newer than this version of yambot supports ({})",
+            self.name,
+            version,
+            self.version()
+        )
+    }
+
+    /// Save a file in the current schema version. Writes to a temporary file first
+    /// so a crash can't leave a half-written file behind.
+    pub fn save<T: Serialize>(&self, path: &Path, value: &T) {
+        let result = (|| -> Result<(), Box<dyn std::error::Error>> {
+            let tmp_path = path.with_extension("toml.tmp");
+            fs::write(&tmp_path, self.to_string(value)?)?;
+            fs::rename(&tmp_path, path)?;
+            Ok(())
+        })();
+
+        if let Err(e) = result {
+            log::error!("Failed to save {}: {}", self.name, e);
+        }
+    }
+}
+
+/// Schema version written in a document, `None` if it has none or can't be parsed
+fn file_version(content: &str) -> Option<u32> {
+    let table: toml::Table = content.parse().ok()?;
+    match table.get(VERSION_KEY)? {
+        toml::Value::Integer(version) => u32::try_from(*version).ok(),
+        _ => None,
+    }
+}
+
+/// Move a file next to itself as `<name>.<date>.bak`, returns the backup path
+pub fn backup(path: &Path) -> Result<PathBuf, String> {
+    let mut backup_name = path.file_name().unwrap_or_default().to_os_string();
+    backup_name.push(format!(
+        ".{}.bak",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    let backup_path = path.with_file_name(backup_name);
+
+    fs::rename(path, &backup_path)
+        .map(|_| backup_path)
+        .map_err(|e| format!("Failed to back up {}: {}", path.display(), e))
+}
+
+/// Files from before schema versioning only get the version added
+pub fn unversioned(_: &mut toml::Table) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Document {
+        name: String,
+    }
+
+    fn rename_title(table: &mut toml::Table) {
+        if let Some(title) = table.remove("title") {
+            table.insert("name".to_string(), title);
+        }
+    }
+
+    const SCHEMA: Schema = Schema {
+        name: "test.toml",
+        migrations: &[unversioned, rename_title],
+    };
+
+    #[test]
+    fn test_schema_migration() {
+        let expected = Document {
+            name: "yambot".to_string(),
+        };
+        assert_eq!(
+            SCHEMA.parse::<Document>("title = \"yambot\"").unwrap(),
+            (
+                Document {
+                    name: "yambot".to_string()
+                },
+                true
+            )
+        );
+
+        let current = SCHEMA.to_string(&expected).unwrap();
+        assert!(current.starts_with("schema_version = 2"));
+        assert_eq!(
+            SCHEMA.parse::<Document>(&current).unwrap(),
+            (expected, false)
+        );
+
+        assert!(SCHEMA
+            .parse::<Document>("schema_version = 3\nname = \"yambot\"")
+            .is_err());
+        assert!(SCHEMA.parse::<Document>("name = ").is_err());
+        assert_eq!(
+            file_version("schema_version = 3\nname = \"yambot\""),
+            Some(3)
+        );
+        assert_eq!(file_version("name = "), None);
+    }
+}
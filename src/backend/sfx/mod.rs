@@ -2,12 +2,13 @@ mod sounds;
 mod watcher;
 use serde::{Deserialize, Serialize};
 
-use rodio::OutputStream;
-
 use std::{
     collections::HashSet,
     path::PathBuf,
-    sync::{LazyLock, Mutex},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        LazyLock, Mutex,
+    },
 };
 
 use tokio::sync::mpsc;
@@ -20,6 +21,17 @@ static SOUNDS_DIRECTORY: &str = "./assets/sounds/";
 
 pub static FILES: LazyLock<Mutex<HashSet<String>>> = LazyLock::new(|| Mutex::new(HashSet::new()));
 
+/// Sound commands are ignored while paused, unlike `enabled` in the config this isn't saved
+static PAUSED: AtomicBool = AtomicBool::new(false);
+
+pub fn is_paused() -> bool {
+    PAUSED.load(Ordering::Relaxed)
+}
+
+pub fn set_paused(paused: bool) {
+    PAUSED.store(paused, Ordering::Relaxed);
+}
+
 #[derive(PartialEq, Serialize, Deserialize, Debug, Clone)]
 pub enum Format {
     Wav,
@@ -36,8 +48,6 @@ pub struct SoundsManager {
     soundlist: Soundlist,
     #[allow(dead_code)] // Reserved for filtering unwanted sounds
     ignore_list: HashSet<String>,
-
-    stream: OutputStream,
 }
 
 impl SoundsManager {
@@ -53,15 +63,11 @@ impl SoundsManager {
 
         let soundlist = Soundlist::serve().await?;
 
-        let stream = rodio::OutputStreamBuilder::open_default_stream()
-            .expect("Failed to open default audio stream");
-
         Ok(Self {
             sounds_path,
             watcher,
             soundlist,
             ignore_list: HashSet::new(),
-            stream,
         })
     }
 
@@ -72,8 +78,4 @@ impl SoundsManager {
     pub fn watcher(&self) -> &Watcher {
         &self.watcher
     }
-
-    pub fn get_stream(self) -> OutputStream {
-        self.stream
-    }
 }
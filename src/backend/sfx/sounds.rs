@@ -1,12 +1,24 @@
 use serde::{Deserialize, Serialize};
-use std::{collections::HashSet, path::PathBuf};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
 
 use super::{Format, FILES};
 use crate::backend::config;
+use crate::backend::schema::{self, Schema};
 
-const SOUNDLIST_PATH: &str = "./assets/soundlist.json";
+const SOUNDLIST_PATH: &str = "./assets/soundlist.toml";
+/// Soundlist written by versions before the TOML manifest
+const LEGACY_SOUNDLIST_PATH: &str = "./assets/soundlist.json";
 const SOUNDS_DIRECTORY: &str = "./assets/sounds/";
 
+/// Layout versions of soundlist.toml
+const SOUNDLIST_SCHEMA: Schema = Schema {
+    name: "soundlist.toml",
+    migrations: &[schema::unversioned],
+};
+
 #[non_exhaustive]
 #[derive(Serialize, Deserialize, Default)]
 pub struct Soundlist {
@@ -15,28 +27,67 @@ pub struct Soundlist {
 
 impl Soundlist {
     pub async fn serve() -> Result<Self, Box<dyn std::error::Error>> {
-        if !tokio::fs::try_exists(SOUNDLIST_PATH).await? {
-            let default = serde_json::to_vec(&Self::default())?;
-            tokio::fs::write(SOUNDLIST_PATH, default).await?;
+        let mut sounds = match SOUNDLIST_SCHEMA.load(Path::new(SOUNDLIST_PATH))? {
+            Some(sounds) => sounds,
+            None => Self::load_legacy().await.unwrap_or_default(),
         };
 
-        let soundlist_json = tokio::fs::read(SOUNDLIST_PATH).await?;
-        let mut sounds: Self = serde_json::from_reader(&*soundlist_json)?;
-
         sounds.sync_files()?;
 
-        sounds.save().await?;
+        sounds.save();
         Ok(sounds)
     }
 
+    /// Read the old soundlist.json and move it to a backup, it's replaced by soundlist.toml
+    async fn load_legacy() -> Option<Self> {
+        let soundlist_json = tokio::fs::read(LEGACY_SOUNDLIST_PATH).await.ok()?;
+        let sounds = serde_json::from_slice(&soundlist_json)
+            .map_err(|e| log::error!("Failed to parse soundlist.json: {}", e))
+            .ok();
+        match schema::backup(Path::new(LEGACY_SOUNDLIST_PATH)) {
+            Ok(_) => log::info!("Moved soundlist.json to soundlist.toml"),
+            Err(e) => log::error!("{}", e),
+        }
+        sounds
+    }
+
     pub fn is_soundfile(file: &PathBuf) -> Option<(&str, &str)> {
-        let sound_format = Self::get_format();
-        match (file.file_stem(), file.extension()) {
-            (Some(filename), Some(extension)) if extension == sound_format => {
-                Some((filename.to_str()?, extension.to_str()?))
-            }
-            _ => None,
+        is_sound_name(file, Self::get_format())
+    }
+
+    /// File name of the sound `name` inside the sounds folder. The extension can be in
+    /// any case, so `airhorn.MP3` is found as well.
+    pub fn file_name(name: &str) -> Option<String> {
+        if !FILES.lock().unwrap().contains(name) {
+            return None;
+        }
+        let file_name = format!("{}.{}", name, Self::get_format());
+        if Path::new(SOUNDS_DIRECTORY).join(&file_name).exists() {
+            return Some(file_name);
         }
+
+        std::fs::read_dir(SOUNDS_DIRECTORY)
+            .ok()?
+            .flatten()
+            .map(|entry| entry.path())
+            .find(|file| matches!(Self::is_soundfile(file), Some((stem, _)) if stem == name))
+            .and_then(|file| Some(file.file_name()?.to_str()?.to_string()))
+    }
+
+    /// Names of the sounds in the sounds folder, read from disk without touching the
+    /// list the watcher keeps
+    pub fn names_on_disk() -> HashSet<String> {
+        std::fs::read_dir(SOUNDS_DIRECTORY)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .filter_map(|entry| {
+                        let file = entry.path();
+                        Self::is_soundfile(&file).map(|(name, _)| name.to_string())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
     }
 
     pub fn get_format() -> &'static str {
@@ -53,6 +104,7 @@ impl Soundlist {
         match std::fs::read_dir(SOUNDS_DIRECTORY) {
             Ok(entries) => {
                 self.sounds.clear();
+                lock.clear();
                 for entry in entries {
                     match entry {
                         Ok(entry) => {
@@ -74,19 +126,65 @@ impl Soundlist {
         }
     }
 
-    async fn save(&self) -> Result<(), std::io::Error> {
-        let sounds = serde_json::to_vec(self)?;
-        tokio::fs::write(SOUNDLIST_PATH, &sounds).await?;
-        Ok(())
+    /// Read the sounds folder again and save the list, for changes the watcher missed.
+    /// Returns the number of sounds found.
+    pub fn rescan() -> Result<usize, String> {
+        let mut soundlist = Soundlist::default();
+        soundlist.sync_files().map_err(|e| e.to_string())?;
+        soundlist.save();
+        Ok(soundlist.sounds.len())
     }
 
-    /// Save the current FILES HashSet to soundlist.json
-    pub async fn save_from_files() -> Result<(), std::io::Error> {
+    fn save(&self) {
+        SOUNDLIST_SCHEMA.save(Path::new(SOUNDLIST_PATH), self);
+    }
+
+    /// Save the current FILES HashSet to soundlist.toml
+    pub fn save_from_files() {
         let sounds = {
             let lock = FILES.lock().unwrap();
             lock.clone()
         };
         let soundlist = Soundlist { sounds };
-        soundlist.save().await
+        soundlist.save()
+    }
+}
+
+/// Files to skip even with the right extension: hidden files and the lock or backup
+/// files editors put next to the original (`.airhorn.mp3`, `~$airhorn.mp3`,
+/// `#airhorn.mp3`). Partial downloads like `airhorn.mp3.part` already fail the
+/// extension check.
+fn is_hidden_or_temp(file_name: &str) -> bool {
+    file_name.starts_with(['.', '~', '#'])
+}
+
+/// Stem and extension of a sound file in `format`, the extension is matched in any case
+fn is_sound_name<'a>(file: &'a Path, format: &str) -> Option<(&'a str, &'a str)> {
+    if is_hidden_or_temp(file.file_name()?.to_str()?) {
+        return None;
+    }
+    let extension = file.extension()?.to_str()?;
+    if !extension.eq_ignore_ascii_case(format) {
+        return None;
+    }
+    Some((file.file_stem()?.to_str()?, extension))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_sound_name() {
+        fn stem(name: &str) -> Option<&str> {
+            is_sound_name(Path::new(name), "mp3").map(|(stem, _)| stem)
+        }
+        assert_eq!(stem("./assets/sounds/airhorn.mp3"), Some("airhorn"));
+        assert_eq!(stem("./assets/sounds/airhorn.MP3"), Some("airhorn"));
+        assert_eq!(stem("./assets/sounds/airhorn.wav"), None);
+        assert_eq!(stem("./assets/sounds/.airhorn.mp3"), None);
+        assert_eq!(stem("./assets/sounds/~$airhorn.mp3"), None);
+        assert_eq!(stem("./assets/sounds/airhorn.mp3.part"), None);
+        assert_eq!(stem("./assets/sounds/airhorn.mp3~"), None);
     }
 }
@@ -1,17 +1,14 @@
-use notify::{
-    event::{EventKind, ModifyKind, RenameMode},
-    RecommendedWatcher, Watcher as _Watcher,
-};
+use notify::{event::EventKind, RecommendedWatcher, Watcher as _Watcher};
 use std::{
     collections::HashSet,
     path::{Path, PathBuf},
     time::Duration,
 };
-use tokio::{
-    pin,
-    sync::{mpsc, watch},
-};
-use tokio_stream::{wrappers::UnboundedReceiverStream, StreamExt};
+use tokio::sync::{mpsc, watch};
+
+/// Quiet time after the last file event before the sound list is updated, so a file
+/// that is still being copied or saved is only picked up once
+const DEBOUNCE: Duration = Duration::from_millis(500);
 
 pub struct Watcher {
     in_tx: watch::Sender<PathBuf>,
@@ -38,42 +35,22 @@ impl Watcher {
 
         let handler = RecommendedWatcher::new(
             move |res: notify::Result<notify::Event>| {
-                let Ok(mut event) = res else {
+                let Ok(event) = res else {
                     log::error!("Watcher Error: {:?}", res);
                     return;
                 };
 
-                // pop removes first parent
-                let file = event.paths.pop().expect("No Such file exists");
-
-                log::debug!("File watcher event: {:?} for file: {}", event.kind, file.display());
-
-                // Check if file exists to determine if it's a creation or deletion
-                let file_exists = file.exists();
-
                 match event.kind {
-                    EventKind::Create(_) | EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
-                        log::info!("Detected sound file creation: {}", file.display());
-                        let event = SoundEvent::Add(file);
-                        out_tx.send(event).unwrap();
-                    }
-                    EventKind::Remove(_)
-                    | EventKind::Modify(ModifyKind::Name(RenameMode::From)) => {
-                        log::info!("Detected sound file removal: {}", file.display());
-                        let event = SoundEvent::Remove(file);
-                        out_tx.send(event).unwrap();
-                    }
-                    EventKind::Modify(ModifyKind::Name(_)) => {
-                        // On macOS, file deletions can show up as Modify(Name(Any))
-                        // Check if the file still exists to determine if it was deleted
-                        if file_exists {
-                            log::info!("Detected sound file creation (via name modify): {}", file.display());
-                            let event = SoundEvent::Add(file);
-                            out_tx.send(event).unwrap();
-                        } else {
-                            log::info!("Detected sound file removal (via name modify): {}", file.display());
-                            let event = SoundEvent::Remove(file);
-                            out_tx.send(event).unwrap();
+                    // Whether a file was added or removed is decided once the folder is
+                    // quiet, renames and editor saves send several events per file
+                    EventKind::Create(_) | EventKind::Remove(_) | EventKind::Modify(_) => {
+                        for file in event.paths {
+                            log::debug!(
+                                "File watcher event: {:?} for file: {}",
+                                event.kind,
+                                file.display()
+                            );
+                            let _ = out_tx.send(file);
                         }
                     }
                     _ => {
@@ -145,9 +122,7 @@ impl Watcher {
 
                 // Save the updated soundlist to file and notify UI if there were changes
                 if has_changes {
-                    if let Err(e) = Soundlist::save_from_files().await {
-                        log::error!("Failed to save soundlist: {}", e);
-                    }
+                    Soundlist::save_from_files();
                     // Notify the UI that the sound list has been updated
                     if let Err(e) = backend_tx
                         .send(crate::ui::BackendToFrontendMessage::SFXListUpdated)
@@ -177,15 +152,28 @@ impl Watcher {
         }
     }
 
+    /// Collect changed paths until no event came for `DEBOUNCE`, then report each
+    /// path once as added or removed depending on whether it still exists
     async fn fan_out(
-        rx: mpsc::UnboundedReceiver<SoundEvent>,
+        mut rx: mpsc::UnboundedReceiver<PathBuf>,
         tx: watch::Sender<HashSet<SoundEvent>>,
     ) {
-        let rx = UnboundedReceiverStream::new(rx).chunks_timeout(1000, Duration::from_millis(100));
-        pin!(rx);
+        while let Some(file) = rx.recv().await {
+            let mut files = HashSet::from([file]);
+            while let Ok(Some(file)) = tokio::time::timeout(DEBOUNCE, rx.recv()).await {
+                files.insert(file);
+            }
 
-        while let Some(chunk) = rx.next().await {
-            let events: HashSet<_> = chunk.into_iter().collect();
+            let events: HashSet<_> = files
+                .into_iter()
+                .map(|file| {
+                    if file.exists() {
+                        SoundEvent::Add(file)
+                    } else {
+                        SoundEvent::Remove(file)
+                    }
+                })
+                .collect();
             let _ = tx.send(events);
         }
     }
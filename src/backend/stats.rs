@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+
+use crate::backend::emotes;
+use crate::backend::events::BotEvent;
+use crate::backend::locale;
+use crate::backend::storage;
+use crate::backend::twitch::ChatMessageEvent;
+
+const STORAGE_NAME: &str = "chat_stats";
+
+/// Persist stats after this many recorded messages
+const SAVE_EVERY: u64 = 25;
+
+/// Chat statistics, tracked for the current session and persisted across sessions
+pub static STATS: LazyLock<Mutex<ChatStats>> = LazyLock::new(|| {
+    Mutex::new(ChatStats {
+        all_time: storage::load(STORAGE_NAME),
+        session: StatsCounters::default(),
+        unsaved: 0,
+    })
+});
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct StatsCounters {
+    pub total_messages: u64,
+    /// Messages per day, keyed by date (YYYY-MM-DD) in the configured timezone
+    #[serde(default)]
+    pub daily_messages: HashMap<String, u64>,
+    /// Messages per chatter login
+    #[serde(default)]
+    pub chatters: HashMap<String, u64>,
+    /// Uses per emote name
+    #[serde(default)]
+    pub emotes: HashMap<String, u64>,
+    /// Plays per sound name
+    #[serde(default)]
+    pub sounds: HashMap<String, u64>,
+}
+
+impl StatsCounters {
+    pub fn messages_today(&self) -> u64 {
+        self.daily_messages.get(&today()).copied().unwrap_or(0)
+    }
+
+    /// Entries with the highest counts, most used first
+    pub fn top(counts: &HashMap<String, u64>, limit: usize) -> Vec<(&str, u64)> {
+        let mut entries: Vec<(&str, u64)> = counts.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+        entries.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        entries.truncate(limit);
+        entries
+    }
+
+    fn record_message(&mut self, msg: &ChatMessageEvent) {
+        self.total_messages += 1;
+        *self.daily_messages.entry(today()).or_default() += 1;
+        *self
+            .chatters
+            .entry(msg.chatter_user_login.clone())
+            .or_default() += 1;
+
+        for fragment in &msg.message.fragments {
+            match fragment.fragment_type.as_str() {
+                "emote" => *self.emotes.entry(fragment.text.clone()).or_default() += 1,
+                "text" => {
+                    for emote in emotes::find_third_party_emotes(&fragment.text) {
+                        *self.emotes.entry(emote).or_default() += 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn record_sound(&mut self, sound: &str) {
+        *self.sounds.entry(sound.to_string()).or_default() += 1;
+    }
+}
+
+#[derive(Debug)]
+pub struct ChatStats {
+    pub all_time: StatsCounters,
+    /// Counters since the bot started or the stream last went live
+    pub session: StatsCounters,
+    unsaved: u64,
+}
+
+impl ChatStats {
+    fn mark_dirty(&mut self) {
+        self.unsaved += 1;
+        if self.unsaved >= SAVE_EVERY {
+            self.save();
+        }
+    }
+
+    fn save(&mut self) {
+        storage::save(STORAGE_NAME, &self.all_time);
+        self.unsaved = 0;
+    }
+}
+
+fn today() -> String {
+    locale::now().format("%Y-%m-%d").to_string()
+}
+
+fn record_message(msg: &ChatMessageEvent) {
+    let mut stats = STATS.lock().unwrap();
+    stats.all_time.record_message(msg);
+    stats.session.record_message(msg);
+    stats.mark_dirty();
+}
+
+fn record_sound(sound: &str) {
+    let mut stats = STATS.lock().unwrap();
+    stats.all_time.record_sound(sound);
+    stats.session.record_sound(sound);
+    stats.mark_dirty();
+}
+
+/// Reset session counters, e.g. when the stream goes live
+fn start_session() {
+    let mut stats = STATS.lock().unwrap();
+    stats.session = StatsCounters::default();
+}
+
+/// Write pending all-time stats to disk
+pub fn flush() {
+    let mut stats = STATS.lock().unwrap();
+    if stats.unsaved > 0 {
+        stats.save();
+    }
+}
+
+/// Record stats from the event bus until it closes
+pub async fn run_recorder(mut events: Receiver<BotEvent>) {
+    loop {
+        match events.recv().await {
+            Ok(BotEvent::ChatMessage(msg)) => record_message(&msg),
+            Ok(BotEvent::SoundPlayed(sound)) => record_sound(&sound),
+            Ok(BotEvent::StreamOnline) => start_session(),
+            Ok(BotEvent::StreamOffline) => flush(),
+            Ok(_) => {}
+            Err(RecvError::Lagged(missed)) => {
+                log::warn!("Chat stats fell behind, {} events were not counted", missed)
+            }
+            Err(RecvError::Closed) => break,
+        }
+    }
+}
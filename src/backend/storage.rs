@@ -0,0 +1,152 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+use super::schema::backup;
+
+/// Directory (relative to the project root) where persistent bot data is kept
+const DATA_DIR: &str = "data";
+
+/// Version of the data files written by `save`. Files from before versioning hold
+/// the bare value and count as version 0.
+const VERSION: u64 = 1;
+
+/// Key holding the version next to the value in each data file
+const VERSION_KEY: &str = "schema_version";
+
+/// Layout of a data file, the value under `data` and its version next to it
+#[derive(Serialize)]
+struct Versioned<'a, T> {
+    schema_version: u64,
+    data: &'a T,
+}
+
+fn data_path(name: &str) -> PathBuf {
+    let project_root = project_root::get_project_root().unwrap();
+    project_root.join(DATA_DIR).join(format!("{}.json", name))
+}
+
+/// Load a JSON document from `data/<name>.json`.
+/// Returns the default value if the file doesn't exist or can't be read. A file that
+/// can't be parsed is moved to a backup first, so the next save doesn't overwrite it.
+pub fn load<T: DeserializeOwned + Default>(name: &str) -> T {
+    let path = data_path(name);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return T::default(),
+        Err(e) => {
+            log::error!("Failed to read {}: {}", path.display(), e);
+            return T::default();
+        }
+    };
+
+    parse(&content).unwrap_or_else(|e| {
+        log::error!("Failed to parse {}: {}", path.display(), e);
+        match backup(&path) {
+            Ok(backup_path) => log::error!(
+                "{} was moved to {} and will be regenerated",
+                path.display(),
+                backup_path.display()
+            ),
+            Err(e) => log::error!("{}", e),
+        }
+        T::default()
+    })
+}
+
+/// Parse a data file, taking the value out of its versioned layout if it has one
+fn parse<T: DeserializeOwned>(content: &str) -> Result<T, String> {
+    let mut value: serde_json::Value =
+        serde_json::from_str(content).map_err(|e| format!("{}", e))?;
+    let versioned = value
+        .as_object()
+        .is_some_and(|object| object.len() == 2 && object.contains_key("data"));
+    if let Some(version) = value.get(VERSION_KEY).filter(|_| versioned) {
+        match version.as_u64() {
+            Some(version) if version <= VERSION => value = value["data"].take(),
+            Some(version) => {
+                return Err(format!(
+                    "Version {} is newer than this version of yambot supports ({})",
+                    version, VERSION
+                ))
+            }
+            None => return Err(format!("Invalid {}: {}", VERSION_KEY, version)),
+        }
+    }
+    serde_json::from_value(value).map_err(|e| format!("{}", e))
+}
+
+/// Save a JSON document to `data/<name>.json`, creating the data directory if needed.
+/// Writes to a temporary file first so a crash can't leave a half-written file behind.
+pub fn save<T: Serialize>(name: &str, value: &T) {
+    let path = data_path(name);
+    let result = (|| -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let tmp_path = path.with_extension("json.tmp");
+        let versioned = Versioned {
+            schema_version: VERSION,
+            data: value,
+        };
+        fs::write(&tmp_path, serde_json::to_string_pretty(&versioned)?)?;
+        fs::rename(&tmp_path, &path)?;
+        Ok(())
+    })();
+
+    if let Err(e) = result {
+        log::error!("Failed to save {}: {}", path.display(), e);
+    }
+}
+
+/// Append entries as JSON lines to `data/<name>.jsonl`, for logs that are only ever added to
+pub fn append<T: Serialize>(name: &str, entries: &[T]) {
+    let path = data_path(name).with_extension("jsonl");
+    let result = (|| -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut lines = String::new();
+        for entry in entries {
+            lines.push_str(&serde_json::to_string(entry)?);
+            lines.push('\n');
+        }
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        file.write_all(lines.as_bytes())?;
+        Ok(())
+    })();
+
+    if let Err(e) = result {
+        log::error!("Failed to append to {}: {}", path.display(), e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_versions() {
+        let expected = HashMap::from([("viewer".to_string(), 3u32)]);
+        assert_eq!(
+            parse::<HashMap<String, u32>>(r#"{"viewer": 3}"#),
+            Ok(expected.clone())
+        );
+
+        let current = serde_json::to_string(&Versioned {
+            schema_version: VERSION,
+            data: &expected,
+        })
+        .unwrap();
+        assert_eq!(parse::<HashMap<String, u32>>(&current), Ok(expected));
+
+        assert!(parse::<HashMap<String, u32>>(r#"{"schema_version": 9, "data": {}}"#).is_err());
+        assert!(parse::<HashMap<String, u32>>(r#"{"viewer": "#).is_err());
+    }
+}
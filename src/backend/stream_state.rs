@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::backend::config::AppConfig;
+
+/// Whether shield mode is active on the channel, updated from EventSub
+static SHIELD_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Stream states selectable from the UI, each with its own feature profile
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StreamState {
+    Starting,
+    #[default]
+    Live,
+    Brb,
+    Ending,
+}
+
+impl StreamState {
+    pub const ALL: [StreamState; 4] = [
+        StreamState::Starting,
+        StreamState::Live,
+        StreamState::Brb,
+        StreamState::Ending,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StreamState::Starting => "Starting",
+            StreamState::Live => "Live",
+            StreamState::Brb => "BRB",
+            StreamState::Ending => "Ending",
+        }
+    }
+}
+
+/// Features that are active while a stream state is selected
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StreamStateProfile {
+    pub tts: bool,
+    pub sfx: bool,
+    /// Overlay alerts (polls, hype trains)
+    pub alerts: bool,
+    /// Scene name sent to the overlay, exposed there as `body[data-scene]`
+    pub overlay_scene: String,
+}
+
+impl StreamStateProfile {
+    fn new(tts: bool, sfx: bool, alerts: bool, overlay_scene: &str) -> Self {
+        Self {
+            tts,
+            sfx,
+            alerts,
+            overlay_scene: overlay_scene.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StreamStatesConfig {
+    #[serde(default)]
+    pub current: StreamState,
+    #[serde(default = "default_starting")]
+    pub starting: StreamStateProfile,
+    #[serde(default = "default_live")]
+    pub live: StreamStateProfile,
+    #[serde(default = "default_brb")]
+    pub brb: StreamStateProfile,
+    #[serde(default = "default_ending")]
+    pub ending: StreamStateProfile,
+}
+
+impl StreamStatesConfig {
+    pub fn profile(&self, state: StreamState) -> &StreamStateProfile {
+        match state {
+            StreamState::Starting => &self.starting,
+            StreamState::Live => &self.live,
+            StreamState::Brb => &self.brb,
+            StreamState::Ending => &self.ending,
+        }
+    }
+
+    /// Profile of the currently selected stream state
+    pub fn active(&self) -> &StreamStateProfile {
+        self.profile(self.current)
+    }
+}
+
+impl Default for StreamStatesConfig {
+    fn default() -> Self {
+        Self {
+            current: StreamState::default(),
+            starting: default_starting(),
+            live: default_live(),
+            brb: default_brb(),
+            ending: default_ending(),
+        }
+    }
+}
+
+fn default_starting() -> StreamStateProfile {
+    StreamStateProfile::new(false, true, true, "starting")
+}
+
+fn default_live() -> StreamStateProfile {
+    StreamStateProfile::new(true, true, true, "live")
+}
+
+fn default_brb() -> StreamStateProfile {
+    StreamStateProfile::new(false, false, true, "brb")
+}
+
+fn default_ending() -> StreamStateProfile {
+    StreamStateProfile::new(false, false, false, "ending")
+}
+
+/// Features that are currently active
+#[derive(Debug, Clone)]
+pub struct ActiveFeatures {
+    pub tts: bool,
+    pub sfx: bool,
+    pub alerts: bool,
+    pub overlay_scene: String,
+}
+
+/// Combine the current stream state profile with the rule for the current OBS scene
+pub fn active_features(config: &AppConfig) -> ActiveFeatures {
+    let profile = config.stream_states.active();
+    let rule = config.obs.active_rule();
+
+    ActiveFeatures {
+        tts: rule.and_then(|r| r.tts).unwrap_or(profile.tts),
+        sfx: rule.and_then(|r| r.sfx).unwrap_or(profile.sfx),
+        alerts: rule.and_then(|r| r.alerts).unwrap_or(profile.alerts),
+        overlay_scene: rule
+            .and_then(|r| r.overlay_scene.clone())
+            .unwrap_or_else(|| profile.overlay_scene.clone()),
+    }
+}
+
+pub fn set_shield_mode(active: bool) {
+    SHIELD_MODE.store(active, Ordering::Relaxed);
+}
+
+/// Whether TTS and sounds are limited to moderators because shield mode is active
+pub fn shield_mode_restricts(config: &AppConfig) -> bool {
+    config.chatbot.shield_mode_mods_only && SHIELD_MODE.load(Ordering::Relaxed)
+}
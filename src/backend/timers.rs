@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::backend::storage;
+
+const STORAGE_NAME: &str = "timers";
+
+/// Timed messages set up in the TIMERS tab, saved in data/timers.json
+static TIMERS: LazyLock<Mutex<Vec<Timer>>> =
+    LazyLock::new(|| Mutex::new(storage::load(STORAGE_NAME)));
+
+/// A message posted to chat again and again while the stream is live, e.g. a
+/// Discord link or a sponsor
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Timer {
+    /// Shown in the UI, each timer needs its own name
+    pub name: String,
+    pub message: String,
+    /// Minutes between two posts
+    pub interval_minutes: u64,
+    /// Chat messages needed since the last post, so a quiet chat isn't flooded
+    #[serde(default)]
+    pub min_lines: u64,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl Timer {
+    pub fn new(name: String, message: String) -> Self {
+        Self {
+            name,
+            message,
+            interval_minutes: 15,
+            min_lines: 5,
+            enabled: true,
+        }
+    }
+}
+
+/// All timers, in the order they were added
+pub fn list() -> Vec<Timer> {
+    TIMERS.lock().unwrap().clone()
+}
+
+/// Replace all timers and save them
+pub fn set_timers(timers: Vec<Timer>) {
+    let mut lock = TIMERS.lock().unwrap();
+    *lock = timers;
+    storage::save(STORAGE_NAME, &*lock);
+}
+
+/// When a timer last posted
+#[derive(Debug, Clone, Copy)]
+struct Posted {
+    at: Instant,
+    /// Chat messages counted at that point
+    lines: u64,
+}
+
+/// Decides which timer posts next during a stream
+#[derive(Debug, Default)]
+pub struct TimerScheduler {
+    /// Chat messages since the scheduler was created
+    lines: u64,
+    /// Last post by timer name, the first check of a stream counts as one
+    posted: HashMap<String, Posted>,
+}
+
+impl TimerScheduler {
+    pub fn record_message(&mut self) {
+        self.lines += 1;
+    }
+
+    /// Returns the message of the timer that's waited longest past its interval,
+    /// at most one per check so timers take turns instead of posting all at once
+    pub fn check(&mut self, timers: &[Timer], live: bool, now: Instant) -> Option<String> {
+        if !live {
+            return None;
+        }
+
+        let lines = self.lines;
+        let due = timers
+            .iter()
+            .filter(|timer| timer.enabled && !timer.message.trim().is_empty())
+            .filter_map(|timer| {
+                let posted = *self
+                    .posted
+                    .entry(timer.name.clone())
+                    .or_insert(Posted { at: now, lines });
+                let interval = Duration::from_secs(timer.interval_minutes.max(1) * 60);
+                let waited = now.duration_since(posted.at);
+                (waited >= interval && lines - posted.lines >= timer.min_lines)
+                    .then(|| (waited - interval, timer))
+            })
+            .max_by_key(|(overdue, _)| *overdue)
+            .map(|(_, timer)| timer)?;
+
+        self.posted
+            .insert(due.name.clone(), Posted { at: now, lines });
+        Some(due.message.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timer_scheduler() {
+        let timer = |name: &str, interval_minutes, min_lines| Timer {
+            interval_minutes,
+            min_lines,
+            ..Timer::new(name.to_string(), format!("{} message", name))
+        };
+        let timers = [timer("discord", 10, 2), timer("socials", 15, 0)];
+        let minutes = |m: u64| Duration::from_secs(m * 60);
+        let start = Instant::now();
+        let mut scheduler = TimerScheduler::default();
+
+        assert_eq!(scheduler.check(&timers, true, start), None);
+        assert_eq!(scheduler.check(&timers, true, start + minutes(12)), None);
+        scheduler.record_message();
+        scheduler.record_message();
+        assert_eq!(scheduler.check(&timers, false, start + minutes(12)), None);
+        assert_eq!(
+            scheduler.check(&timers, true, start + minutes(12)),
+            Some("discord message".to_string())
+        );
+        // Discord posted 8 minutes ago
+        assert_eq!(
+            scheduler.check(&timers, true, start + minutes(20)),
+            Some("socials message".to_string())
+        );
+        scheduler.record_message();
+        scheduler.record_message();
+        assert_eq!(
+            scheduler.check(&timers, true, start + minutes(22)),
+            Some("discord message".to_string())
+        );
+        assert_eq!(scheduler.check(&timers, true, start + minutes(23)), None);
+    }
+}
@@ -72,6 +72,51 @@ impl TTSQueue {
         }
     }
 
+    /// Remove every queued chunk generated from a chat message and skip it if it is playing.
+    /// Returns the number of removed/skipped items.
+    pub async fn purge_message(&self, message_id: &str) -> usize {
+        self.purge_where(|item| Self::belongs_to_message(item, message_id))
+            .await
+    }
+
+    /// Remove every queued item requested by a user and skip it if it is playing.
+    /// Returns the number of removed/skipped items.
+    pub async fn purge_user(&self, username: &str) -> usize {
+        self.purge_where(|item| item.request.username.eq_ignore_ascii_case(username))
+            .await
+    }
+
+    async fn purge_where<F>(&self, matches: F) -> usize
+    where
+        F: Fn(&TTSQueueItem) -> bool,
+    {
+        let mut removed = {
+            let mut queue = self.queue.lock().await;
+            let before = queue.len();
+            queue.retain(|item| !matches(item));
+            before - queue.len()
+        };
+
+        if let Some(current) = self.get_currently_playing().await {
+            if matches(&current) {
+                self.skip_current().await;
+                removed += 1;
+            }
+        }
+
+        removed
+    }
+
+    /// Long messages are split into chunks with IDs of the form `{message_id}-{index}`
+    fn belongs_to_message(item: &TTSQueueItem, message_id: &str) -> bool {
+        item.request.id == message_id
+            || item
+                .request
+                .id
+                .strip_prefix(message_id)
+                .is_some_and(|rest| rest.starts_with('-'))
+    }
+
     pub async fn skip_current(&self) {
         self.skip_current.store(true, Ordering::SeqCst);
     }
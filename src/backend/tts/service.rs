@@ -1,16 +1,38 @@
 use super::queue::{TTSAudioChunk, TTSQueue, TTSRequest};
 use log::info;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::task::AbortHandle;
 use urlencoding::encode;
 
 const MAX_TEXT_LENGTH: usize = 200;
+/// Maximum number of TTS requests being generated at the same time
+const MAX_CONCURRENT_GENERATIONS: usize = 3;
+/// Maximum time a single chunk may take to download before it is abandoned
+const GENERATION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A TTS request whose audio is still being generated
+struct PendingGeneration {
+    username: String,
+    abort_handle: AbortHandle,
+}
 
 pub struct TTSService {
     queue: TTSQueue,
+    generation_permits: Arc<Semaphore>,
+    pending_generations: Mutex<HashMap<String, PendingGeneration>>,
 }
 
 impl TTSService {
     pub fn new(queue: TTSQueue) -> Self {
-        Self { queue }
+        Self {
+            queue,
+            generation_permits: Arc::new(Semaphore::new(MAX_CONCURRENT_GENERATIONS)),
+            pending_generations: Mutex::new(HashMap::new()),
+        }
     }
 
     /// Fetch TTS audio data as bytes from Google Translate API
@@ -44,6 +66,76 @@ impl TTSService {
         Ok(bytes.to_vec())
     }
 
+    /// Fetch TTS audio, giving up after `GENERATION_TIMEOUT`
+    pub async fn fetch_tts_audio_with_timeout(
+        &self,
+        text: &str,
+        language: &str,
+    ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        match tokio::time::timeout(GENERATION_TIMEOUT, self.fetch_tts_audio(text, language)).await
+        {
+            Ok(result) => result,
+            Err(_) => Err(format!(
+                "TTS generation timed out after {}s",
+                GENERATION_TIMEOUT.as_secs()
+            )
+            .into()),
+        }
+    }
+
+    /// Wait for a free generation slot; the slot is released when the permit is dropped
+    pub async fn acquire_generation_permit(&self) -> Option<OwnedSemaphorePermit> {
+        self.generation_permits.clone().acquire_owned().await.ok()
+    }
+
+    /// Spawn a generation task and remember it so it can be cancelled before playback
+    pub fn track_generation<F>(&self, request_id: String, username: String, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        // Hold the lock while spawning so a fast task can't finish before it is registered
+        let mut pending = self.pending_generations.lock().unwrap();
+        let handle = tokio::spawn(task);
+        pending.insert(
+            request_id,
+            PendingGeneration {
+                username,
+                abort_handle: handle.abort_handle(),
+            },
+        );
+    }
+
+    /// Mark a generation as finished (called by the generation task itself)
+    pub fn finish_generation(&self, request_id: &str) {
+        self.pending_generations.lock().unwrap().remove(request_id);
+    }
+
+    /// Abort the generation started by the given chat message, if it is still running
+    pub fn cancel_generation(&self, request_id: &str) -> bool {
+        match self.pending_generations.lock().unwrap().remove(request_id) {
+            Some(pending) => {
+                pending.abort_handle.abort();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Abort all running generations requested by a user, returning how many were cancelled
+    pub fn cancel_user_generations(&self, username: &str) -> usize {
+        let mut pending = self.pending_generations.lock().unwrap();
+        let before = pending.len();
+        pending.retain(|_, generation| {
+            if generation.username.eq_ignore_ascii_case(username) {
+                generation.abort_handle.abort();
+                false
+            } else {
+                true
+            }
+        });
+        before - pending.len()
+    }
+
 
     /// Split text into chunks if longer than MAX_TEXT_LENGTH
     pub fn split_text(&self, text: &str) -> Vec<String> {
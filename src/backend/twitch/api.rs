@@ -5,6 +5,7 @@ use tokio::sync::{mpsc, RwLock};
 
 use super::auth;
 use super::error::{Result, TwitchError};
+use super::rate_limit::SendRateLimited;
 
 const CHAT_MESSAGES_URL: &str = "https://api.twitch.tv/helix/chat/messages";
 const MODERATION_CHAT_URL: &str = "https://api.twitch.tv/helix/moderation/chat";
@@ -12,6 +13,31 @@ const MODERATION_BANS_URL: &str = "https://api.twitch.tv/helix/moderation/bans";
 #[allow(dead_code)] // Reserved for future chat settings management
 const CHAT_SETTINGS_URL: &str = "https://api.twitch.tv/helix/chat/settings";
 const USERS_URL: &str = "https://api.twitch.tv/helix/users";
+const WHISPERS_URL: &str = "https://api.twitch.tv/helix/whispers";
+const ANNOUNCEMENTS_URL: &str = "https://api.twitch.tv/helix/chat/announcements";
+const SHOUTOUTS_URL: &str = "https://api.twitch.tv/helix/chat/shoutouts";
+const CLIPS_URL: &str = "https://api.twitch.tv/helix/clips";
+const POLLS_URL: &str = "https://api.twitch.tv/helix/polls";
+const PREDICTIONS_URL: &str = "https://api.twitch.tv/helix/predictions";
+const RAIDS_URL: &str = "https://api.twitch.tv/helix/raids";
+const MODERATORS_URL: &str = "https://api.twitch.tv/helix/moderation/moderators";
+const CHANNEL_FOLLOWERS_URL: &str = "https://api.twitch.tv/helix/channels/followers";
+const AD_SCHEDULE_URL: &str = "https://api.twitch.tv/helix/channels/ads";
+const AD_SNOOZE_URL: &str = "https://api.twitch.tv/helix/channels/ads/schedule/snooze";
+const CHANNELS_URL: &str = "https://api.twitch.tv/helix/channels";
+const GAMES_URL: &str = "https://api.twitch.tv/helix/games";
+const SEARCH_CATEGORIES_URL: &str = "https://api.twitch.tv/helix/search/categories";
+const UNBAN_REQUESTS_URL: &str = "https://api.twitch.tv/helix/moderation/unban_requests";
+const BLOCKED_TERMS_URL: &str = "https://api.twitch.tv/helix/moderation/blocked_terms";
+const SHIELD_MODE_URL: &str = "https://api.twitch.tv/helix/moderation/shield_mode";
+const AUTOMOD_SETTINGS_URL: &str = "https://api.twitch.tv/helix/moderation/automod/settings";
+const GUEST_STAR_SESSION_URL: &str = "https://api.twitch.tv/helix/guest_star/session";
+const GUEST_STAR_INVITES_URL: &str = "https://api.twitch.tv/helix/guest_star/invites";
+const GUEST_STAR_SLOT_URL: &str = "https://api.twitch.tv/helix/guest_star/slot";
+const GUEST_STAR_SLOT_SETTINGS_URL: &str = "https://api.twitch.tv/helix/guest_star/slot_settings";
+const CHATTERS_URL: &str = "https://api.twitch.tv/helix/chat/chatters";
+const STREAMS_URL: &str = "https://api.twitch.tv/helix/streams";
+const CUSTOM_REWARDS_URL: &str = "https://api.twitch.tv/helix/channel_points/custom_rewards";
 
 /// Response from sending a chat message
 #[derive(Debug, Clone, Deserialize)]
@@ -54,6 +80,324 @@ pub struct BanData {
     pub end_time: Option<String>,
 }
 
+/// Unban requests response
+#[derive(Debug, Clone, Deserialize)]
+pub struct UnbanRequestsResponse {
+    pub data: Vec<UnbanRequestData>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UnbanRequestData {
+    pub id: String,
+    pub user_name: String,
+    pub text: String,
+    pub created_at: String,
+}
+
+/// Blocked terms response
+#[derive(Debug, Clone, Deserialize)]
+pub struct BlockedTermsResponse {
+    pub data: Vec<BlockedTermData>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BlockedTermData {
+    pub id: String,
+    pub text: String,
+}
+
+/// Cursor of paginated responses, `None` on the last page
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Pagination {
+    pub cursor: Option<String>,
+}
+
+/// Chatters response
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChattersResponse {
+    pub data: Vec<ChatterData>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChatterData {
+    pub user_login: String,
+}
+
+/// Streams response, empty while the channel is offline
+#[derive(Debug, Clone, Deserialize)]
+pub struct StreamsResponse {
+    pub data: Vec<StreamData>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StreamData {
+    pub viewer_count: u64,
+    /// When the stream went live
+    pub started_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChannelFollowersResponse {
+    pub data: Vec<ChannelFollowerData>,
+    /// Number of followers of the channel
+    #[serde(default)]
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChannelFollowerData {
+    pub followed_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AdScheduleResponse {
+    pub data: Vec<AdSchedule>,
+}
+
+/// Upcoming ad break of a channel, also returned when snoozing it
+#[derive(Debug, Clone, Deserialize)]
+pub struct AdSchedule {
+    /// `None` while offline or when no ad is scheduled
+    #[serde(default, deserialize_with = "deserialize_ad_time")]
+    pub next_ad_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Length of the next ad break in seconds, not part of the snooze response
+    #[serde(default)]
+    pub duration: u32,
+    /// Snoozes left
+    pub snooze_count: u32,
+    /// When another snooze is earned
+    #[serde(default, deserialize_with = "deserialize_ad_time")]
+    pub snooze_refresh_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Ad times come as RFC 3339 strings or unix timestamps, empty or 0 when not set
+fn deserialize_ad_time<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<chrono::DateTime<chrono::Utc>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(time) => chrono::DateTime::parse_from_rfc3339(&time)
+            .ok()
+            .map(|time| time.to_utc()),
+        serde_json::Value::Number(time) => time
+            .as_i64()
+            .filter(|time| *time > 0)
+            .and_then(|time| chrono::DateTime::from_timestamp(time, 0)),
+        _ => None,
+    })
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AutomodSettingsResponse {
+    pub data: Vec<AutomodSettings>,
+}
+
+/// AutoMod levels of a channel, 0 (off) to 4 (most filtering)
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AutomodSettings {
+    /// Set when all categories use the same level, `None` for custom levels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overall_level: Option<u8>,
+    pub disability: u8,
+    pub aggression: u8,
+    pub sexuality_sex_or_gender: u8,
+    pub misogyny: u8,
+    pub bullying: u8,
+    pub swearing: u8,
+    pub race_ethnicity_or_religion: u8,
+    pub sex_based_terms: u8,
+}
+
+/// Shield mode status response
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShieldModeResponse {
+    pub data: Vec<ShieldModeData>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShieldModeData {
+    pub is_active: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateClipResponse {
+    pub data: Vec<ClipData>,
+}
+
+/// A clip being created, it takes a few seconds until it can be viewed
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClipData {
+    pub id: String,
+    pub edit_url: String,
+}
+
+impl ClipData {
+    /// Public link to the clip
+    pub fn url(&self) -> String {
+        format!("https://clips.twitch.tv/{}", self.id)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClipsResponse {
+    pub data: Vec<ClipInfo>,
+}
+
+/// A published clip of a channel
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClipInfo {
+    pub id: String,
+    pub url: String,
+    pub title: String,
+    pub view_count: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PollsResponse {
+    pub data: Vec<PollData>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PollData {
+    pub id: String,
+    pub title: String,
+    /// "ACTIVE" while the poll is running
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PredictionsResponse {
+    pub data: Vec<PredictionData>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PredictionData {
+    pub id: String,
+    pub title: String,
+    /// "ACTIVE" while taking predictions, "LOCKED" until it's resolved
+    pub status: String,
+    pub outcomes: Vec<PredictionOutcome>,
+}
+
+impl PredictionData {
+    /// Find an outcome by its number (starting at 1) or name
+    pub fn outcome(&self, outcome: &str) -> Option<&PredictionOutcome> {
+        match outcome.parse::<usize>() {
+            Ok(number) => self.outcomes.get(number.checked_sub(1)?),
+            Err(_) => self
+                .outcomes
+                .iter()
+                .find(|o| o.title.eq_ignore_ascii_case(outcome)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PredictionOutcome {
+    pub id: String,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RaidsResponse {
+    pub data: Vec<RaidData>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RaidData {
+    pub created_at: String,
+    /// Whether the raided channel is for mature audiences
+    pub is_mature: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChannelInformationResponse {
+    pub data: Vec<ChannelInformation>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChannelInformation {
+    pub broadcaster_id: String,
+    pub title: String,
+    pub game_id: String,
+    /// Category name, empty if no category is set
+    pub game_name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustomRewardsResponse {
+    pub data: Vec<CustomRewardData>,
+}
+
+/// Custom channel point reward of a channel
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustomRewardData {
+    pub id: String,
+    pub title: String,
+    pub cost: u64,
+    pub prompt: String,
+    pub is_enabled: bool,
+    pub is_user_input_required: bool,
+}
+
+/// Settings sent when creating or updating a custom reward
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CustomRewardSettings {
+    pub title: String,
+    pub cost: u64,
+    pub prompt: String,
+    pub is_enabled: bool,
+    pub is_user_input_required: bool,
+}
+
+/// Response of the games and category search endpoints
+#[derive(Debug, Clone, Deserialize)]
+pub struct GamesResponse {
+    pub data: Vec<GameData>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GameData {
+    pub id: String,
+    pub name: String,
+}
+
+/// Guest Star session response, empty while no session is running
+#[derive(Debug, Clone, Deserialize)]
+pub struct GuestStarSessionResponse {
+    pub data: Vec<GuestStarSession>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GuestStarSession {
+    pub id: String,
+    /// Slot "0" is the host
+    pub guests: Vec<GuestStarGuest>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GuestStarGuest {
+    pub slot_id: String,
+    pub is_live: bool,
+    pub user_id: String,
+    pub user_display_name: String,
+    pub audio_settings: GuestStarMediaSettings,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GuestStarMediaSettings {
+    /// Enabled by the host, false when the slot is muted
+    pub is_host_enabled: bool,
+}
+
 /// User info response
 #[derive(Debug, Clone, Deserialize)]
 pub struct UsersResponse {
@@ -63,9 +407,7 @@ pub struct UsersResponse {
 #[derive(Debug, Clone, Deserialize)]
 pub struct UserData {
     pub id: String,
-    #[allow(dead_code)] // Part of Twitch API response
     pub login: String,
-    #[allow(dead_code)] // Part of Twitch API response
     pub display_name: String,
     #[allow(dead_code)] // Part of Twitch API response
     pub broadcaster_type: String,
@@ -75,12 +417,10 @@ pub struct UserData {
     pub profile_image_url: String,
     #[allow(dead_code)] // Part of Twitch API response
     pub offline_image_url: String,
-    #[allow(dead_code)] // Part of Twitch API response
-    pub created_at: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
 /// Chat settings response
-#[allow(dead_code)] // Reserved for future chat settings management
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ChatSettingsResponse {
     pub data: Vec<ChatSettings>,
@@ -99,7 +439,8 @@ pub struct ChatSettings {
     pub unique_chat_mode: bool,
 }
 
-/// Twitch API client for HTTP operations
+/// Twitch API client for HTTP operations. Clones share the tokens.
+#[derive(Clone)]
 pub struct TwitchApi {
     client: reqwest::Client,
     access_token: Arc<RwLock<String>>,
@@ -169,7 +510,7 @@ impl TwitchApi {
             .get(&url)
             .header("Authorization", format!("Bearer {}", *access_token))
             .header("Client-Id", auth::CLIENT_ID)
-            .send()
+            .send_rate_limited()
             .await?;
 
         if !response.status().is_success() {
@@ -206,7 +547,7 @@ impl TwitchApi {
             .get(USERS_URL)
             .header("Authorization", format!("Bearer {}", *access_token))
             .header("Client-Id", auth::CLIENT_ID)
-            .send()
+            .send_rate_limited()
             .await?;
 
         if !response.status().is_success() {
@@ -256,7 +597,7 @@ impl TwitchApi {
             .header("Client-Id", auth::CLIENT_ID)
             .header("Content-Type", "application/json")
             .json(&body)
-            .send()
+            .send_rate_limited()
             .await?;
 
         if !response.status().is_success() {
@@ -280,31 +621,31 @@ impl TwitchApi {
         Ok(send_response)
     }
 
-    /// Reply to a chat message
-    pub async fn reply_to_message(
+    /// Send a whisper (requires user:manage:whispers scope and a verified phone number)
+    pub async fn send_whisper(
         &self,
-        broadcaster_id: &str,
-        sender_id: &str,
+        from_user_id: &str,
+        to_user_id: &str,
         message: &str,
-        reply_parent_message_id: &str,
-    ) -> Result<SendMessageResponse> {
+    ) -> Result<()> {
+        let url = format!(
+            "{}?from_user_id={}&to_user_id={}",
+            WHISPERS_URL, from_user_id, to_user_id
+        );
         let body = json!({
-            "broadcaster_id": broadcaster_id,
-            "sender_id": sender_id,
-            "message": message,
-            "reply_parent_message_id": reply_parent_message_id
+            "message": message
         });
 
         let access_token = self.access_token.read().await;
 
         let response = self
             .client
-            .post(CHAT_MESSAGES_URL)
+            .post(&url)
             .header("Authorization", format!("Bearer {}", *access_token))
             .header("Client-Id", auth::CLIENT_ID)
             .header("Content-Type", "application/json")
             .json(&body)
-            .send()
+            .send_rate_limited()
             .await?;
 
         if !response.status().is_success() {
@@ -312,13 +653,7 @@ impl TwitchApi {
             if status.as_u16() == 401 {
                 drop(access_token);
                 self.refresh_token().await?;
-                return Box::pin(self.reply_to_message(
-                    broadcaster_id,
-                    sender_id,
-                    message,
-                    reply_parent_message_id,
-                ))
-                .await;
+                return Box::pin(self.send_whisper(from_user_id, to_user_id, message)).await;
             }
             let error_text = response
                 .text()
@@ -330,30 +665,37 @@ impl TwitchApi {
             )));
         }
 
-        let send_response = response.json::<SendMessageResponse>().await?;
-        Ok(send_response)
+        Ok(())
     }
 
-    /// Delete a chat message (requires moderator:manage:chat_messages scope)
-    pub async fn delete_message(
+    /// Send a highlighted announcement to chat (requires moderator permissions).
+    /// `color` is "blue", "green", "orange", "purple" or "primary" for the channel's accent color
+    pub async fn send_announcement(
         &self,
         broadcaster_id: &str,
         moderator_id: &str,
-        message_id: &str,
+        message: &str,
+        color: &str,
     ) -> Result<()> {
         let url = format!(
-            "{}?broadcaster_id={}&moderator_id={}&message_id={}",
-            MODERATION_CHAT_URL, broadcaster_id, moderator_id, message_id
+            "{}?broadcaster_id={}&moderator_id={}",
+            ANNOUNCEMENTS_URL, broadcaster_id, moderator_id
         );
+        let body = json!({
+            "message": message,
+            "color": color
+        });
 
         let access_token = self.access_token.read().await;
 
         let response = self
             .client
-            .delete(&url)
+            .post(&url)
             .header("Authorization", format!("Bearer {}", *access_token))
             .header("Client-Id", auth::CLIENT_ID)
-            .send()
+            .header("Content-Type", "application/json")
+            .json(&body)
+            .send_rate_limited()
             .await?;
 
         if !response.status().is_success() {
@@ -361,8 +703,13 @@ impl TwitchApi {
             if status.as_u16() == 401 {
                 drop(access_token);
                 self.refresh_token().await?;
-                return Box::pin(self.delete_message(broadcaster_id, moderator_id, message_id))
-                    .await;
+                return Box::pin(self.send_announcement(
+                    broadcaster_id,
+                    moderator_id,
+                    message,
+                    color,
+                ))
+                .await;
             }
             let error_text = response
                 .text()
@@ -377,26 +724,18 @@ impl TwitchApi {
         Ok(())
     }
 
-    /// Ban a user permanently (requires moderator:manage:banned_users scope)
-    pub async fn ban_user(
+    /// Send a Twitch shoutout (requires moderator permissions and a live stream)
+    pub async fn send_shoutout(
         &self,
-        broadcaster_id: &str,
+        from_broadcaster_id: &str,
+        to_broadcaster_id: &str,
         moderator_id: &str,
-        user_id: &str,
-        reason: &str,
-    ) -> Result<BanResponse> {
+    ) -> Result<()> {
         let url = format!(
-            "{}?broadcaster_id={}&moderator_id={}",
-            MODERATION_BANS_URL, broadcaster_id, moderator_id
+            "{}?from_broadcaster_id={}&to_broadcaster_id={}&moderator_id={}",
+            SHOUTOUTS_URL, from_broadcaster_id, to_broadcaster_id, moderator_id
         );
 
-        let body = json!({
-            "data": {
-                "user_id": user_id,
-                "reason": reason
-            }
-        });
-
         let access_token = self.access_token.read().await;
 
         let response = self
@@ -404,9 +743,7 @@ impl TwitchApi {
             .post(&url)
             .header("Authorization", format!("Bearer {}", *access_token))
             .header("Client-Id", auth::CLIENT_ID)
-            .header("Content-Type", "application/json")
-            .json(&body)
-            .send()
+            .send_rate_limited()
             .await?;
 
         if !response.status().is_success() {
@@ -414,8 +751,17 @@ impl TwitchApi {
             if status.as_u16() == 401 {
                 drop(access_token);
                 self.refresh_token().await?;
-                return Box::pin(self.ban_user(broadcaster_id, moderator_id, user_id, reason))
-                    .await;
+                return Box::pin(self.send_shoutout(
+                    from_broadcaster_id,
+                    to_broadcaster_id,
+                    moderator_id,
+                ))
+                .await;
+            }
+            if status.as_u16() == 429 {
+                return Err(TwitchError::HttpError(
+                    "Shoutout rate limited, wait before sending another one".to_string(),
+                ));
             }
             let error_text = response
                 .text()
@@ -427,42 +773,34 @@ impl TwitchApi {
             )));
         }
 
-        let ban_response = response.json::<BanResponse>().await?;
-        Ok(ban_response)
+        Ok(())
     }
 
-    /// Timeout a user (requires moderator:manage:banned_users scope)
-    pub async fn timeout_user(
+    /// Reply to a chat message
+    pub async fn reply_to_message(
         &self,
         broadcaster_id: &str,
-        moderator_id: &str,
-        user_id: &str,
-        duration: u32,
-        reason: &str,
-    ) -> Result<BanResponse> {
-        let url = format!(
-            "{}?broadcaster_id={}&moderator_id={}",
-            MODERATION_BANS_URL, broadcaster_id, moderator_id
-        );
-
+        sender_id: &str,
+        message: &str,
+        reply_parent_message_id: &str,
+    ) -> Result<SendMessageResponse> {
         let body = json!({
-            "data": {
-                "user_id": user_id,
-                "duration": duration,
-                "reason": reason
-            }
+            "broadcaster_id": broadcaster_id,
+            "sender_id": sender_id,
+            "message": message,
+            "reply_parent_message_id": reply_parent_message_id
         });
 
         let access_token = self.access_token.read().await;
 
         let response = self
             .client
-            .post(&url)
+            .post(CHAT_MESSAGES_URL)
             .header("Authorization", format!("Bearer {}", *access_token))
             .header("Client-Id", auth::CLIENT_ID)
             .header("Content-Type", "application/json")
             .json(&body)
-            .send()
+            .send_rate_limited()
             .await?;
 
         if !response.status().is_success() {
@@ -470,12 +808,11 @@ impl TwitchApi {
             if status.as_u16() == 401 {
                 drop(access_token);
                 self.refresh_token().await?;
-                return Box::pin(self.timeout_user(
+                return Box::pin(self.reply_to_message(
                     broadcaster_id,
-                    moderator_id,
-                    user_id,
-                    duration,
-                    reason,
+                    sender_id,
+                    message,
+                    reply_parent_message_id,
                 ))
                 .await;
             }
@@ -489,30 +826,1791 @@ impl TwitchApi {
             )));
         }
 
-        let ban_response = response.json::<BanResponse>().await?;
-        Ok(ban_response)
+        let send_response = response.json::<SendMessageResponse>().await?;
+        Ok(send_response)
+    }
+
+    /// Delete a chat message (requires moderator:manage:chat_messages scope)
+    pub async fn delete_message(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+        message_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "{}?broadcaster_id={}&moderator_id={}&message_id={}",
+            MODERATION_CHAT_URL, broadcaster_id, moderator_id, message_id
+        );
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .delete(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.delete_message(broadcaster_id, moderator_id, message_id))
+                    .await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Ban a user permanently (requires moderator:manage:banned_users scope)
+    pub async fn ban_user(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+        user_id: &str,
+        reason: &str,
+    ) -> Result<BanResponse> {
+        let url = format!(
+            "{}?broadcaster_id={}&moderator_id={}",
+            MODERATION_BANS_URL, broadcaster_id, moderator_id
+        );
+
+        let body = json!({
+            "data": {
+                "user_id": user_id,
+                "reason": reason
+            }
+        });
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .header("Content-Type", "application/json")
+            .json(&body)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.ban_user(broadcaster_id, moderator_id, user_id, reason))
+                    .await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let ban_response = response.json::<BanResponse>().await?;
+        Ok(ban_response)
+    }
+
+    /// Timeout a user (requires moderator:manage:banned_users scope)
+    pub async fn timeout_user(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+        user_id: &str,
+        duration: u32,
+        reason: &str,
+    ) -> Result<BanResponse> {
+        let url = format!(
+            "{}?broadcaster_id={}&moderator_id={}",
+            MODERATION_BANS_URL, broadcaster_id, moderator_id
+        );
+
+        let body = json!({
+            "data": {
+                "user_id": user_id,
+                "duration": duration,
+                "reason": reason
+            }
+        });
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .header("Content-Type", "application/json")
+            .json(&body)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.timeout_user(
+                    broadcaster_id,
+                    moderator_id,
+                    user_id,
+                    duration,
+                    reason,
+                ))
+                .await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let ban_response = response.json::<BanResponse>().await?;
+        Ok(ban_response)
+    }
+
+    /// Unban a user (requires moderator:manage:banned_users scope)
+    pub async fn unban_user(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+        user_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "{}?broadcaster_id={}&moderator_id={}&user_id={}",
+            MODERATION_BANS_URL, broadcaster_id, moderator_id, user_id
+        );
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .delete(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.unban_user(broadcaster_id, moderator_id, user_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Get all pending unban requests (requires moderator:read:unban_requests scope)
+    pub async fn get_pending_unban_requests(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+    ) -> Result<Vec<UnbanRequestData>> {
+        let mut requests = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let mut url = format!(
+                "{}?broadcaster_id={}&moderator_id={}&status=pending&first=100",
+                UNBAN_REQUESTS_URL, broadcaster_id, moderator_id
+            );
+            if let Some(cursor) = &cursor {
+                url.push_str(&format!("&after={}", urlencoding::encode(cursor)));
+            }
+
+            let access_token = self.access_token.read().await;
+
+            let response = self
+                .client
+                .get(&url)
+                .header("Authorization", format!("Bearer {}", *access_token))
+                .header("Client-Id", auth::CLIENT_ID)
+                .send_rate_limited()
+                .await?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                if status.as_u16() == 401 {
+                    drop(access_token);
+                    self.refresh_token().await?;
+                    return Box::pin(self.get_pending_unban_requests(broadcaster_id, moderator_id))
+                        .await;
+                }
+                let error_text = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "Unknown error".to_string());
+                return Err(TwitchError::HttpError(format!(
+                    "HTTP {}: {}",
+                    status, error_text
+                )));
+            }
+
+            let requests_response = response.json::<UnbanRequestsResponse>().await?;
+            requests.extend(requests_response.data);
+            cursor = requests_response.pagination.cursor;
+            if cursor.is_none() {
+                return Ok(requests);
+            }
+        }
+    }
+
+    /// Approve or deny an unban request (requires moderator:manage:unban_requests scope)
+    pub async fn resolve_unban_request(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+        unban_request_id: &str,
+        approve: bool,
+        resolution_text: &str,
+    ) -> Result<()> {
+        let status = if approve { "approved" } else { "denied" };
+        let mut url = reqwest::Url::parse(UNBAN_REQUESTS_URL)
+            .map_err(|e| TwitchError::HttpError(e.to_string()))?;
+        url.query_pairs_mut()
+            .append_pair("broadcaster_id", broadcaster_id)
+            .append_pair("moderator_id", moderator_id)
+            .append_pair("unban_request_id", unban_request_id)
+            .append_pair("status", status);
+        if !resolution_text.is_empty() {
+            url.query_pairs_mut()
+                .append_pair("resolution_text", resolution_text);
+        }
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .patch(url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.resolve_unban_request(
+                    broadcaster_id,
+                    moderator_id,
+                    unban_request_id,
+                    approve,
+                    resolution_text,
+                ))
+                .await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Get all blocked terms of a channel (requires moderator:read:blocked_terms scope)
+    pub async fn get_blocked_terms(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+    ) -> Result<Vec<BlockedTermData>> {
+        let mut terms = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let mut url = format!(
+                "{}?broadcaster_id={}&moderator_id={}&first=100",
+                BLOCKED_TERMS_URL, broadcaster_id, moderator_id
+            );
+            if let Some(cursor) = &cursor {
+                url.push_str(&format!("&after={}", urlencoding::encode(cursor)));
+            }
+
+            let access_token = self.access_token.read().await;
+
+            let response = self
+                .client
+                .get(&url)
+                .header("Authorization", format!("Bearer {}", *access_token))
+                .header("Client-Id", auth::CLIENT_ID)
+                .send_rate_limited()
+                .await?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                if status.as_u16() == 401 {
+                    drop(access_token);
+                    self.refresh_token().await?;
+                    return Box::pin(self.get_blocked_terms(broadcaster_id, moderator_id)).await;
+                }
+                let error_text = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "Unknown error".to_string());
+                return Err(TwitchError::HttpError(format!(
+                    "HTTP {}: {}",
+                    status, error_text
+                )));
+            }
+
+            let terms_response = response.json::<BlockedTermsResponse>().await?;
+            terms.extend(terms_response.data);
+            cursor = terms_response.pagination.cursor;
+            if cursor.is_none() {
+                return Ok(terms);
+            }
+        }
+    }
+
+    /// Get the logins of everyone connected to chat (requires moderator:read:chatters scope)
+    pub async fn get_chatters(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+    ) -> Result<Vec<ChatterData>> {
+        let mut chatters = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let mut url = format!(
+                "{}?broadcaster_id={}&moderator_id={}&first=1000",
+                CHATTERS_URL, broadcaster_id, moderator_id
+            );
+            if let Some(cursor) = &cursor {
+                url.push_str(&format!("&after={}", urlencoding::encode(cursor)));
+            }
+
+            let access_token = self.access_token.read().await;
+
+            let response = self
+                .client
+                .get(&url)
+                .header("Authorization", format!("Bearer {}", *access_token))
+                .header("Client-Id", auth::CLIENT_ID)
+                .send_rate_limited()
+                .await?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                if status.as_u16() == 401 {
+                    drop(access_token);
+                    self.refresh_token().await?;
+                    return Box::pin(self.get_chatters(broadcaster_id, moderator_id)).await;
+                }
+                let error_text = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "Unknown error".to_string());
+                return Err(TwitchError::HttpError(format!(
+                    "HTTP {}: {}",
+                    status, error_text
+                )));
+            }
+
+            let chatters_response = response.json::<ChattersResponse>().await?;
+            chatters.extend(chatters_response.data);
+            cursor = chatters_response.pagination.cursor;
+            if cursor.is_none() {
+                return Ok(chatters);
+            }
+        }
+    }
+
+    /// Get the live stream of a channel, `None` while it's offline
+    pub async fn get_streams(&self, broadcaster_id: &str) -> Result<Option<StreamData>> {
+        let url = format!("{}?user_id={}", STREAMS_URL, broadcaster_id);
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.get_streams(broadcaster_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let streams_response = response.json::<StreamsResponse>().await?;
+        Ok(streams_response.data.into_iter().next())
+    }
+
+    /// Block a word or phrase in chat (requires moderator:manage:blocked_terms scope)
+    pub async fn add_blocked_term(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+        text: &str,
+    ) -> Result<BlockedTermData> {
+        let url = format!(
+            "{}?broadcaster_id={}&moderator_id={}",
+            BLOCKED_TERMS_URL, broadcaster_id, moderator_id
+        );
+        let body = json!({ "text": text });
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .header("Content-Type", "application/json")
+            .json(&body)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.add_blocked_term(broadcaster_id, moderator_id, text)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let terms_response = response.json::<BlockedTermsResponse>().await?;
+        terms_response
+            .data
+            .into_iter()
+            .next()
+            .ok_or_else(|| TwitchError::HttpError("No blocked term in response".to_string()))
+    }
+
+    /// Remove a blocked term by its ID (requires moderator:manage:blocked_terms scope)
+    pub async fn remove_blocked_term(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+        term_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "{}?broadcaster_id={}&moderator_id={}&id={}",
+            BLOCKED_TERMS_URL, broadcaster_id, moderator_id, term_id
+        );
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .delete(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.remove_blocked_term(broadcaster_id, moderator_id, term_id))
+                    .await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Get the AutoMod levels (requires moderator:read:automod_settings scope)
+    pub async fn get_automod_settings(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+    ) -> Result<AutomodSettings> {
+        let url = format!(
+            "{}?broadcaster_id={}&moderator_id={}",
+            AUTOMOD_SETTINGS_URL, broadcaster_id, moderator_id
+        );
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.get_automod_settings(broadcaster_id, moderator_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let settings_response = response.json::<AutomodSettingsResponse>().await?;
+        settings_response
+            .data
+            .into_iter()
+            .next()
+            .ok_or_else(|| TwitchError::HttpError("No AutoMod settings in response".to_string()))
+    }
+
+    /// Change the AutoMod levels, either the overall level or all categories
+    /// (requires moderator:manage:automod_settings scope). Returns the new settings.
+    pub async fn update_automod_settings(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+        settings: &AutomodSettings,
+    ) -> Result<AutomodSettings> {
+        let url = format!(
+            "{}?broadcaster_id={}&moderator_id={}",
+            AUTOMOD_SETTINGS_URL, broadcaster_id, moderator_id
+        );
+        // Twitch rejects the overall level together with the category levels
+        let body = match settings.overall_level {
+            Some(level) => json!({ "overall_level": level }),
+            None => serde_json::to_value(settings)?,
+        };
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .put(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .header("Content-Type", "application/json")
+            .json(&body)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.update_automod_settings(
+                    broadcaster_id,
+                    moderator_id,
+                    settings,
+                ))
+                .await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let settings_response = response.json::<AutomodSettingsResponse>().await?;
+        settings_response
+            .data
+            .into_iter()
+            .next()
+            .ok_or_else(|| TwitchError::HttpError("No AutoMod settings in response".to_string()))
+    }
+
+    /// Check whether shield mode is active (requires moderator:read:shield_mode scope)
+    pub async fn get_shield_mode_status(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "{}?broadcaster_id={}&moderator_id={}",
+            SHIELD_MODE_URL, broadcaster_id, moderator_id
+        );
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.get_shield_mode_status(broadcaster_id, moderator_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let shield_response = response.json::<ShieldModeResponse>().await?;
+        Ok(shield_response
+            .data
+            .first()
+            .is_some_and(|status| status.is_active))
+    }
+
+    /// Turn shield mode on or off (requires moderator:manage:shield_mode scope).
+    /// Returns whether shield mode is active afterwards.
+    pub async fn update_shield_mode_status(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+        is_active: bool,
+    ) -> Result<bool> {
+        let url = format!(
+            "{}?broadcaster_id={}&moderator_id={}",
+            SHIELD_MODE_URL, broadcaster_id, moderator_id
+        );
+        let body = json!({ "is_active": is_active });
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .put(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .header("Content-Type", "application/json")
+            .json(&body)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.update_shield_mode_status(
+                    broadcaster_id,
+                    moderator_id,
+                    is_active,
+                ))
+                .await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let shield_response = response.json::<ShieldModeResponse>().await?;
+        Ok(shield_response
+            .data
+            .first()
+            .is_some_and(|status| status.is_active))
+    }
+
+    /// Create a clip of the live stream (requires clips:edit scope).
+    /// With `has_delay` the clip is taken from the delayed stream viewers see.
+    pub async fn create_clip(&self, broadcaster_id: &str, has_delay: bool) -> Result<ClipData> {
+        let url = format!(
+            "{}?broadcaster_id={}&has_delay={}",
+            CLIPS_URL, broadcaster_id, has_delay
+        );
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.create_clip(broadcaster_id, has_delay)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let clip_response = response.json::<CreateClipResponse>().await?;
+        clip_response
+            .data
+            .into_iter()
+            .next()
+            .ok_or_else(|| TwitchError::HttpError("No clip in response".to_string()))
+    }
+
+    /// Most viewed clips of a channel created since `started_at`, at most `first`
+    pub async fn get_clips(
+        &self,
+        broadcaster_id: &str,
+        started_at: chrono::DateTime<chrono::Utc>,
+        first: u32,
+    ) -> Result<Vec<ClipInfo>> {
+        let url = format!(
+            "{}?broadcaster_id={}&started_at={}&first={}",
+            CLIPS_URL,
+            broadcaster_id,
+            started_at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            first
+        );
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.get_clips(broadcaster_id, started_at, first)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        Ok(response.json::<ClipsResponse>().await?.data)
+    }
+
+    /// Start a poll (requires channel:manage:polls scope on the broadcaster's token).
+    /// `duration` is in seconds, Twitch accepts 15 to 1800.
+    pub async fn create_poll(
+        &self,
+        broadcaster_id: &str,
+        title: &str,
+        choices: &[String],
+        duration: u32,
+    ) -> Result<PollData> {
+        let body = json!({
+            "broadcaster_id": broadcaster_id,
+            "title": title,
+            "choices": choices
+                .iter()
+                .map(|choice| json!({ "title": choice }))
+                .collect::<Vec<_>>(),
+            "duration": duration.clamp(15, 1800)
+        });
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .post(POLLS_URL)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .header("Content-Type", "application/json")
+            .json(&body)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.create_poll(broadcaster_id, title, choices, duration)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let poll_response = response.json::<PollsResponse>().await?;
+        poll_response
+            .data
+            .into_iter()
+            .next()
+            .ok_or_else(|| TwitchError::HttpError("No poll in response".to_string()))
+    }
+
+    /// Get the most recent poll of a channel, `None` if it never had one
+    pub async fn get_latest_poll(&self, broadcaster_id: &str) -> Result<Option<PollData>> {
+        let url = format!("{}?broadcaster_id={}&first=1", POLLS_URL, broadcaster_id);
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.get_latest_poll(broadcaster_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let poll_response = response.json::<PollsResponse>().await?;
+        Ok(poll_response.data.into_iter().next())
+    }
+
+    /// End a running poll early. "TERMINATED" keeps the results visible on the channel,
+    /// "ARCHIVED" hides them.
+    pub async fn end_poll(&self, broadcaster_id: &str, poll_id: &str, status: &str) -> Result<()> {
+        let body = json!({
+            "broadcaster_id": broadcaster_id,
+            "id": poll_id,
+            "status": status
+        });
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .patch(POLLS_URL)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .header("Content-Type", "application/json")
+            .json(&body)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status_code = response.status();
+            if status_code.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.end_poll(broadcaster_id, poll_id, status)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status_code, error_text
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Start a prediction (requires channel:manage:predictions scope on the broadcaster's token).
+    /// `prediction_window` is in seconds, Twitch accepts 30 to 1800.
+    pub async fn create_prediction(
+        &self,
+        broadcaster_id: &str,
+        title: &str,
+        outcomes: &[String],
+        prediction_window: u32,
+    ) -> Result<PredictionData> {
+        let body = json!({
+            "broadcaster_id": broadcaster_id,
+            "title": title,
+            "outcomes": outcomes
+                .iter()
+                .map(|outcome| json!({ "title": outcome }))
+                .collect::<Vec<_>>(),
+            "prediction_window": prediction_window.clamp(30, 1800)
+        });
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .post(PREDICTIONS_URL)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .header("Content-Type", "application/json")
+            .json(&body)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.create_prediction(
+                    broadcaster_id,
+                    title,
+                    outcomes,
+                    prediction_window,
+                ))
+                .await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let prediction_response = response.json::<PredictionsResponse>().await?;
+        prediction_response
+            .data
+            .into_iter()
+            .next()
+            .ok_or_else(|| TwitchError::HttpError("No prediction in response".to_string()))
+    }
+
+    /// Get the most recent prediction of a channel, `None` if it never had one
+    pub async fn get_latest_prediction(
+        &self,
+        broadcaster_id: &str,
+    ) -> Result<Option<PredictionData>> {
+        let url = format!(
+            "{}?broadcaster_id={}&first=1",
+            PREDICTIONS_URL, broadcaster_id
+        );
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.get_latest_prediction(broadcaster_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let prediction_response = response.json::<PredictionsResponse>().await?;
+        Ok(prediction_response.data.into_iter().next())
+    }
+
+    /// Lock, cancel or resolve a prediction. `status` is "LOCKED", "CANCELED" or
+    /// "RESOLVED", resolving needs the id of the winning outcome.
+    pub async fn end_prediction(
+        &self,
+        broadcaster_id: &str,
+        prediction_id: &str,
+        status: &str,
+        winning_outcome_id: Option<&str>,
+    ) -> Result<()> {
+        let mut body = json!({
+            "broadcaster_id": broadcaster_id,
+            "id": prediction_id,
+            "status": status
+        });
+        if let Some(outcome_id) = winning_outcome_id {
+            body["winning_outcome_id"] = json!(outcome_id);
+        }
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .patch(PREDICTIONS_URL)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .header("Content-Type", "application/json")
+            .json(&body)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status_code = response.status();
+            if status_code.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.end_prediction(
+                    broadcaster_id,
+                    prediction_id,
+                    status,
+                    winning_outcome_id,
+                ))
+                .await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status_code, error_text
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Start a raid, Twitch sends the viewers over after a 90 second countdown
+    /// (requires the broadcaster's token)
+    pub async fn start_raid(
+        &self,
+        from_broadcaster_id: &str,
+        to_broadcaster_id: &str,
+    ) -> Result<RaidData> {
+        let url = format!(
+            "{}?from_broadcaster_id={}&to_broadcaster_id={}",
+            RAIDS_URL, from_broadcaster_id, to_broadcaster_id
+        );
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.start_raid(from_broadcaster_id, to_broadcaster_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let raid_response = response.json::<RaidsResponse>().await?;
+        raid_response
+            .data
+            .into_iter()
+            .next()
+            .ok_or_else(|| TwitchError::HttpError("No raid in response".to_string()))
+    }
+
+    /// Cancel a raid during its countdown
+    pub async fn cancel_raid(&self, broadcaster_id: &str) -> Result<()> {
+        let url = format!("{}?broadcaster_id={}", RAIDS_URL, broadcaster_id);
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .delete(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.cancel_raid(broadcaster_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Make a user a moderator of the channel, needs the broadcaster's token
+    pub async fn add_channel_moderator(&self, broadcaster_id: &str, user_id: &str) -> Result<()> {
+        let url = format!(
+            "{}?broadcaster_id={}&user_id={}",
+            MODERATORS_URL, broadcaster_id, user_id
+        );
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.add_channel_moderator(broadcaster_id, user_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Remove a user's moderator status, needs the broadcaster's token
+    pub async fn remove_channel_moderator(
+        &self,
+        broadcaster_id: &str,
+        user_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "{}?broadcaster_id={}&user_id={}",
+            MODERATORS_URL, broadcaster_id, user_id
+        );
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .delete(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.remove_channel_moderator(broadcaster_id, user_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Get the custom rewards of a channel. With `only_manageable` only the ones created
+    /// by this app are returned, the others can't be changed by it.
+    /// Requires channel:read:redemptions or channel:manage:redemptions on the broadcaster's token.
+    pub async fn get_custom_rewards(
+        &self,
+        broadcaster_id: &str,
+        only_manageable: bool,
+    ) -> Result<Vec<CustomRewardData>> {
+        let url = format!(
+            "{}?broadcaster_id={}&only_manageable_rewards={}",
+            CUSTOM_REWARDS_URL, broadcaster_id, only_manageable
+        );
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.get_custom_rewards(broadcaster_id, only_manageable)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let rewards_response = response.json::<CustomRewardsResponse>().await?;
+        Ok(rewards_response.data)
+    }
+
+    /// Create a custom reward (requires channel:manage:redemptions scope on the broadcaster's token)
+    pub async fn create_custom_reward(
+        &self,
+        broadcaster_id: &str,
+        settings: &CustomRewardSettings,
+    ) -> Result<CustomRewardData> {
+        let url = format!("{}?broadcaster_id={}", CUSTOM_REWARDS_URL, broadcaster_id);
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .header("Content-Type", "application/json")
+            .json(settings)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.create_custom_reward(broadcaster_id, settings)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let rewards_response = response.json::<CustomRewardsResponse>().await?;
+        rewards_response
+            .data
+            .into_iter()
+            .next()
+            .ok_or_else(|| TwitchError::HttpError("No reward in response".to_string()))
+    }
+
+    /// Change a custom reward created by this app (requires channel:manage:redemptions scope)
+    pub async fn update_custom_reward(
+        &self,
+        broadcaster_id: &str,
+        reward_id: &str,
+        settings: &CustomRewardSettings,
+    ) -> Result<CustomRewardData> {
+        let url = format!(
+            "{}?broadcaster_id={}&id={}",
+            CUSTOM_REWARDS_URL, broadcaster_id, reward_id
+        );
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .patch(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .header("Content-Type", "application/json")
+            .json(settings)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.update_custom_reward(broadcaster_id, reward_id, settings))
+                    .await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let rewards_response = response.json::<CustomRewardsResponse>().await?;
+        rewards_response
+            .data
+            .into_iter()
+            .next()
+            .ok_or_else(|| TwitchError::HttpError("No reward in response".to_string()))
+    }
+
+    /// Delete a custom reward created by this app (requires channel:manage:redemptions scope)
+    pub async fn delete_custom_reward(&self, broadcaster_id: &str, reward_id: &str) -> Result<()> {
+        let url = format!(
+            "{}?broadcaster_id={}&id={}",
+            CUSTOM_REWARDS_URL, broadcaster_id, reward_id
+        );
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .delete(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.delete_custom_reward(broadcaster_id, reward_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Check whether a user follows a channel, `None` if they don't. Requires
+    /// moderator:read:followers and the token's user being a moderator of the channel.
+    pub async fn get_channel_follower(
+        &self,
+        broadcaster_id: &str,
+        user_id: &str,
+    ) -> Result<Option<ChannelFollowerData>> {
+        let url = format!(
+            "{}?broadcaster_id={}&user_id={}",
+            CHANNEL_FOLLOWERS_URL, broadcaster_id, user_id
+        );
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.get_channel_follower(broadcaster_id, user_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let followers_response = response.json::<ChannelFollowersResponse>().await?;
+        Ok(followers_response.data.into_iter().next())
     }
 
-    /// Unban a user (requires moderator:manage:banned_users scope)
-    pub async fn unban_user(
+    /// Get the number of followers of a channel. Works without moderator:read:followers,
+    /// which is only needed to list the followers themselves.
+    pub async fn get_follower_count(&self, broadcaster_id: &str) -> Result<u64> {
+        let url = format!(
+            "{}?broadcaster_id={}&first=1",
+            CHANNEL_FOLLOWERS_URL, broadcaster_id
+        );
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.get_follower_count(broadcaster_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let followers_response = response.json::<ChannelFollowersResponse>().await?;
+        Ok(followers_response.total)
+    }
+
+    /// Get the next scheduled ad break (requires channel:read:ads scope on the broadcaster's token)
+    pub async fn get_ad_schedule(&self, broadcaster_id: &str) -> Result<AdSchedule> {
+        let url = format!("{}?broadcaster_id={}", AD_SCHEDULE_URL, broadcaster_id);
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.get_ad_schedule(broadcaster_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let schedule_response = response.json::<AdScheduleResponse>().await?;
+        schedule_response
+            .data
+            .into_iter()
+            .next()
+            .ok_or_else(|| TwitchError::HttpError("No ad schedule in response".to_string()))
+    }
+
+    /// Push the next ad break back by 5 minutes, uses up a snooze
+    /// (requires channel:manage:ads scope on the broadcaster's token)
+    pub async fn snooze_next_ad(&self, broadcaster_id: &str) -> Result<AdSchedule> {
+        let url = format!("{}?broadcaster_id={}", AD_SNOOZE_URL, broadcaster_id);
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.snooze_next_ad(broadcaster_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let snooze_response = response.json::<AdScheduleResponse>().await?;
+        snooze_response
+            .data
+            .into_iter()
+            .next()
+            .ok_or_else(|| TwitchError::HttpError("No ad schedule in response".to_string()))
+    }
+
+    /// Get the title and category of a channel
+    pub async fn get_channel_information(
         &self,
         broadcaster_id: &str,
-        moderator_id: &str,
-        user_id: &str,
+    ) -> Result<ChannelInformation> {
+        let url = format!("{}?broadcaster_id={}", CHANNELS_URL, broadcaster_id);
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.get_channel_information(broadcaster_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let channel_response = response.json::<ChannelInformationResponse>().await?;
+        channel_response
+            .data
+            .into_iter()
+            .next()
+            .ok_or_else(|| TwitchError::HttpError("Channel not found".to_string()))
+    }
+
+    /// Update the title and/or category of a channel (requires channel:manage:broadcast
+    /// scope, so the bot has to be authorized as the broadcaster)
+    pub async fn modify_channel_information(
+        &self,
+        broadcaster_id: &str,
+        title: Option<&str>,
+        game_id: Option<&str>,
     ) -> Result<()> {
+        let url = format!("{}?broadcaster_id={}", CHANNELS_URL, broadcaster_id);
+        let mut body = serde_json::Map::new();
+        if let Some(title) = title {
+            body.insert("title".to_string(), json!(title));
+        }
+        if let Some(game_id) = game_id {
+            body.insert("game_id".to_string(), json!(game_id));
+        }
+
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .patch(&url)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .header("Content-Type", "application/json")
+            .json(&body)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.modify_channel_information(broadcaster_id, title, game_id))
+                    .await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Find a game or category by name. Exact names are looked up first,
+    /// otherwise the best category search result is used.
+    pub async fn find_game(&self, name: &str) -> Result<GameData> {
+        if let Some(game) = self.get_games(GAMES_URL, &[("name", name)]).await? {
+            return Ok(game);
+        }
+        self.get_games(SEARCH_CATEGORIES_URL, &[("query", name), ("first", "1")])
+            .await?
+            .ok_or_else(|| TwitchError::HttpError(format!("Category '{}' not found", name)))
+    }
+
+    async fn get_games(&self, url: &str, query: &[(&str, &str)]) -> Result<Option<GameData>> {
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .get(url)
+            .query(query)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.get_games(url, query)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let games_response = response.json::<GamesResponse>().await?;
+        Ok(games_response.data.into_iter().next())
+    }
+
+    /// Get the running Guest Star session (requires channel:read:guest_star or
+    /// moderator:read:guest_star scope)
+    pub async fn get_guest_star_session(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+    ) -> Result<Option<GuestStarSession>> {
         let url = format!(
-            "{}?broadcaster_id={}&moderator_id={}&user_id={}",
-            MODERATION_BANS_URL, broadcaster_id, moderator_id, user_id
+            "{}?broadcaster_id={}&moderator_id={}",
+            GUEST_STAR_SESSION_URL, broadcaster_id, moderator_id
         );
 
         let access_token = self.access_token.read().await;
 
         let response = self
             .client
-            .delete(&url)
+            .get(&url)
             .header("Authorization", format!("Bearer {}", *access_token))
             .header("Client-Id", auth::CLIENT_ID)
-            .send()
+            .send_rate_limited()
             .await?;
 
         if !response.status().is_success() {
@@ -520,7 +2618,47 @@ impl TwitchApi {
             if status.as_u16() == 401 {
                 drop(access_token);
                 self.refresh_token().await?;
-                return Box::pin(self.unban_user(broadcaster_id, moderator_id, user_id)).await;
+                return Box::pin(self.get_guest_star_session(broadcaster_id, moderator_id)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::HttpError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let session_response = response.json::<GuestStarSessionResponse>().await?;
+        Ok(session_response.data.into_iter().next())
+    }
+
+    /// Send, assign or update a Guest Star slot. Only the query differs between these
+    /// endpoints and none of them returns a body.
+    async fn send_guest_star_request(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        query: &[(&str, &str)],
+    ) -> Result<()> {
+        let access_token = self.access_token.read().await;
+
+        let response = self
+            .client
+            .request(method.clone(), url)
+            .query(query)
+            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send_rate_limited()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 {
+                drop(access_token);
+                self.refresh_token().await?;
+                return Box::pin(self.send_guest_star_request(method, url, query)).await;
             }
             let error_text = response
                 .text()
@@ -535,6 +2673,74 @@ impl TwitchApi {
         Ok(())
     }
 
+    /// Invite a user to the Guest Star session (requires channel:manage:guest_star or
+    /// moderator:manage:guest_star scope)
+    pub async fn send_guest_star_invite(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+        session_id: &str,
+        guest_id: &str,
+    ) -> Result<()> {
+        self.send_guest_star_request(
+            reqwest::Method::POST,
+            GUEST_STAR_INVITES_URL,
+            &[
+                ("broadcaster_id", broadcaster_id),
+                ("moderator_id", moderator_id),
+                ("session_id", session_id),
+                ("guest_id", guest_id),
+            ],
+        )
+        .await
+    }
+
+    /// Put an invited guest into a slot
+    pub async fn assign_guest_star_slot(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+        session_id: &str,
+        guest_id: &str,
+        slot_id: &str,
+    ) -> Result<()> {
+        self.send_guest_star_request(
+            reqwest::Method::POST,
+            GUEST_STAR_SLOT_URL,
+            &[
+                ("broadcaster_id", broadcaster_id),
+                ("moderator_id", moderator_id),
+                ("session_id", session_id),
+                ("guest_id", guest_id),
+                ("slot_id", slot_id),
+            ],
+        )
+        .await
+    }
+
+    /// Mute or unmute a slot
+    pub async fn set_guest_star_slot_audio(
+        &self,
+        broadcaster_id: &str,
+        moderator_id: &str,
+        session_id: &str,
+        slot_id: &str,
+        enabled: bool,
+    ) -> Result<()> {
+        self.send_guest_star_request(
+            reqwest::Method::PATCH,
+            GUEST_STAR_SLOT_SETTINGS_URL,
+            &[
+                ("broadcaster_id", broadcaster_id),
+                ("moderator_id", moderator_id),
+                ("session_id", session_id),
+                ("slot_id", slot_id),
+                ("is_audio_enabled", if enabled { "true" } else { "false" }),
+            ],
+        )
+        .await
+    }
+
     /// Get chat settings (requires moderator:read:chat_settings scope)
     #[allow(dead_code)] // Reserved for future chat settings management
     pub async fn get_chat_settings(
@@ -554,7 +2760,7 @@ impl TwitchApi {
             .get(&url)
             .header("Authorization", format!("Bearer {}", *access_token))
             .header("Client-Id", auth::CLIENT_ID)
-            .send()
+            .send_rate_limited()
             .await?;
 
         if !response.status().is_success() {
@@ -583,7 +2789,6 @@ impl TwitchApi {
     }
 
     /// Update chat settings (requires moderator:manage:chat_settings scope)
-    #[allow(dead_code)] // Reserved for future chat settings management
     pub async fn update_chat_settings(
         &self,
         broadcaster_id: &str,
@@ -604,7 +2809,7 @@ impl TwitchApi {
             .header("Client-Id", auth::CLIENT_ID)
             .header("Content-Type", "application/json")
             .json(&settings)
-            .send()
+            .send_rate_limited()
             .await?;
 
         if !response.status().is_success() {
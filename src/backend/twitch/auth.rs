@@ -52,6 +52,42 @@ pub async fn refresh_access_token(refresh_token: &str) -> Result<TokenResponse>
     Ok(token_response)
 }
 
+/// Response from the client credentials grant
+#[derive(Debug, Clone, Deserialize)]
+struct AppTokenResponse {
+    access_token: String,
+}
+
+/// Get an app access token using the client credentials grant.
+/// Conduit and webhook subscriptions can only be created with an app token.
+pub async fn get_app_access_token() -> Result<String> {
+    let client = reqwest::Client::new();
+
+    let params = [
+        ("client_id", CLIENT_ID),
+        ("client_secret", CLIENT_SECRET),
+        ("grant_type", "client_credentials"),
+    ];
+
+    let response = client.post(TOKEN_URL).form(&params).send().await?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(TwitchError::AuthError(format!(
+            "App token request failed: HTTP {} - {}",
+            status, error_text
+        )));
+    }
+
+    let token_response = response.json::<AppTokenResponse>().await?;
+
+    Ok(token_response.access_token)
+}
+
 /// Validate the current access token
 ///
 /// # Arguments
@@ -1,9 +1,15 @@
+use chrono::{DateTime, Utc};
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 
-use super::api::TwitchApi;
+use super::api::{
+    AdSchedule, AutomodSettings, BlockedTermData, ChannelInformation, ClipData, CustomRewardData,
+    CustomRewardSettings, GuestStarSession, PollData, PredictionData, RaidData, StreamData,
+    TwitchApi, UnbanRequestData,
+};
 use super::error::{Result, TwitchError};
-use super::eventsub::EventSubManager;
+use super::eventsub::{EventSubManager, EventSubTransport, SubscriptionList};
+use super::irc::{run_irc_fallback, IrcChannel};
 use super::messages::TwitchEvent;
 use super::websocket::{
     reconnect_with_backoff, ConnectionState, WebSocketHandler, WebSocketMessage,
@@ -15,6 +21,27 @@ pub struct TwitchConfig {
     pub channel_name: String,
     pub auth_token: String,
     pub refresh_token: String,
+    /// Read chat over IRC when the EventSub chat subscription is unavailable
+    pub irc_fallback: bool,
+    /// Where EventSub subscriptions deliver events
+    pub eventsub_transport: EventSubTransport,
+}
+
+/// Transport chat messages are currently received over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatTransport {
+    EventSub,
+    /// Read-only IRC fallback
+    Irc,
+}
+
+impl ChatTransport {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChatTransport::EventSub => "EventSub",
+            ChatTransport::Irc => "IRC (fallback)",
+        }
+    }
 }
 
 /// Public events from the Twitch client
@@ -32,6 +59,9 @@ pub enum TwitchClientEvent {
     /// Tokens were refreshed (access_token, refresh_token)
     TokensRefreshed(String, String),
 
+    /// Chat messages are now received over a different transport
+    TransportChanged(ChatTransport),
+
     /// Warning occurred (non-fatal)
     Warning(String),
 
@@ -59,7 +89,8 @@ impl TwitchClient {
             std::sync::Arc::new(tokio::sync::RwLock::new(config.refresh_token.clone()));
 
         let api = TwitchApi::new(config.auth_token.clone(), config.refresh_token.clone());
-        let eventsub = EventSubManager::new(access_token, refresh_token);
+        let mut eventsub = EventSubManager::new(access_token, refresh_token);
+        eventsub.set_transport(config.eventsub_transport.clone());
 
         Self {
             config,
@@ -138,9 +169,18 @@ impl TwitchClient {
             }
         };
 
+        self.eventsub.prepare_transport(&session_id).await?;
+
+        // Remove subscriptions of previous sessions before creating new ones
+        match self.eventsub.delete_stale_subscriptions(&session_id).await {
+            Ok(0) => {}
+            Ok(deleted) => log::info!("Deleted {} stale EventSub subscriptions", deleted),
+            Err(e) => log::warn!("Failed to clean up stale EventSub subscriptions: {}", e),
+        }
+
         // Create EventSub subscriptions
         log::info!("Setting up EventSub subscriptions...");
-        let (success_count, failed_count, warnings) = self
+        let (success_count, failed_count, warnings, chat_subscribed) = self
             .eventsub
             .subscribe_to_all_events(&session_id, &broadcaster.id, &bot_user.id)
             .await?;
@@ -179,10 +219,30 @@ impl TwitchClient {
         log::info!("EventSub setup complete - bot is ready");
         let _ = event_tx.send(TwitchClientEvent::Connected).await;
 
+        let irc_channel = self.config.irc_fallback.then(|| IrcChannel {
+            user_id: broadcaster.id.clone(),
+            login: broadcaster.login.clone(),
+            name: broadcaster.display_name.clone(),
+        });
+
+        // Fall back to IRC right away if chat messages can't be received over EventSub
+        let transport = match &irc_channel {
+            Some(channel) if !chat_subscribed => {
+                log::warn!("Chat subscription failed, reading chat over IRC instead");
+                tokio::spawn(run_irc_fallback(channel.clone(), event_tx.clone()));
+                ChatTransport::Irc
+            }
+            _ => ChatTransport::EventSub,
+        };
+        let _ = event_tx
+            .send(TwitchClientEvent::TransportChanged(transport))
+            .await;
+
         // Spawn event processing task with reconnection handling
         let event_tx_clone = event_tx.clone();
         let ws_tx_clone = ws_tx.clone();
         let mut reconnect_handler = ws_handler.clone();
+        let mut irc_channel = irc_channel.filter(|_| transport == ChatTransport::EventSub);
 
         tokio::spawn(async move {
             while let Some(msg) = ws_rx.recv().await {
@@ -209,6 +269,16 @@ impl TwitchClient {
                                         e
                                     )))
                                     .await;
+
+                                // Keep chat commands working while EventSub is down
+                                if let Some(channel) = irc_channel.take() {
+                                    tokio::spawn(run_irc_fallback(channel, event_tx_clone.clone()));
+                                    let _ = event_tx_clone
+                                        .send(TwitchClientEvent::TransportChanged(
+                                            ChatTransport::Irc,
+                                        ))
+                                        .await;
+                                }
                                 break;
                             }
                         }
@@ -320,6 +390,99 @@ impl TwitchClient {
         Ok(())
     }
 
+    /// Whisper a user from the bot account
+    pub async fn send_whisper(&self, to_user_id: &str, message: &str) -> Result<()> {
+        let bot_user_id = self
+            .bot_user_id
+            .as_ref()
+            .ok_or_else(|| TwitchError::ConfigError("Not connected".to_string()))?;
+
+        self.api
+            .send_whisper(bot_user_id, to_user_id, message)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Send a highlighted announcement to chat (requires moderator permissions)
+    pub async fn send_announcement(&self, message: &str, color: &str) -> Result<()> {
+        let (broadcaster_id, bot_user_id) = self.channel_ids()?;
+        self.api
+            .send_announcement(broadcaster_id, bot_user_id, message, color)
+            .await
+    }
+
+    /// Change chat modes like slow or emote-only mode (requires moderator permissions)
+    pub async fn update_chat_settings(&self, settings: serde_json::Value) -> Result<()> {
+        let (broadcaster_id, bot_user_id) = self.channel_ids()?;
+        self.api
+            .update_chat_settings(broadcaster_id, bot_user_id, settings)
+            .await?;
+        Ok(())
+    }
+
+    /// Blocked terms of the channel, sorted by text
+    pub async fn get_blocked_terms(&self) -> Result<Vec<BlockedTermData>> {
+        let (broadcaster_id, bot_user_id) = self.channel_ids()?;
+        let mut terms = self
+            .api
+            .get_blocked_terms(broadcaster_id, bot_user_id)
+            .await?;
+        terms.sort_by_key(|term| term.text.to_lowercase());
+        Ok(terms)
+    }
+
+    /// Logins of everyone connected to chat, sorted
+    pub async fn get_chatters(&self) -> Result<Vec<String>> {
+        let (broadcaster_id, bot_user_id) = self.channel_ids()?;
+        let mut chatters: Vec<String> = self
+            .api
+            .get_chatters(broadcaster_id, bot_user_id)
+            .await?
+            .into_iter()
+            .map(|chatter| chatter.user_login)
+            .collect();
+        chatters.sort();
+        Ok(chatters)
+    }
+
+    /// The channel's live stream, `None` while offline
+    pub async fn get_stream(&self) -> Result<Option<StreamData>> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        self.api.get_streams(broadcaster_id).await
+    }
+
+    /// Block a word or phrase in chat (requires moderator permissions)
+    pub async fn add_blocked_term(&self, text: &str) -> Result<BlockedTermData> {
+        let (broadcaster_id, bot_user_id) = self.channel_ids()?;
+        self.api
+            .add_blocked_term(broadcaster_id, bot_user_id, text)
+            .await
+    }
+
+    /// Remove a blocked term, given by its text
+    pub async fn remove_blocked_term(&self, text: &str) -> Result<()> {
+        let (broadcaster_id, bot_user_id) = self.channel_ids()?;
+        let term = self
+            .get_blocked_terms()
+            .await?
+            .into_iter()
+            .find(|term| term.text.eq_ignore_ascii_case(text))
+            .ok_or_else(|| TwitchError::ConfigError(format!("\"{}\" is not blocked", text)))?;
+        self.api
+            .remove_blocked_term(broadcaster_id, bot_user_id, &term.id)
+            .await
+    }
+
+    /// Give another channel a Twitch shoutout
+    pub async fn send_shoutout(&self, user_login: &str) -> Result<()> {
+        let (broadcaster_id, bot_user_id) = self.channel_ids()?;
+        let target = self.api.get_user_by_login(user_login).await?;
+        self.api
+            .send_shoutout(broadcaster_id, &target.id, bot_user_id)
+            .await
+    }
+
     /// Delete a chat message (requires moderator permissions)
     pub async fn delete_message(&self, message_id: &str) -> Result<()> {
         let broadcaster_id = self
@@ -396,6 +559,418 @@ impl TwitchClient {
         Ok(())
     }
 
+    /// Get pending unban requests for the channel
+    pub async fn get_pending_unban_requests(&self) -> Result<Vec<UnbanRequestData>> {
+        let broadcaster_id = self
+            .broadcaster_id
+            .as_ref()
+            .ok_or_else(|| TwitchError::ConfigError("Not connected".to_string()))?;
+
+        let bot_user_id = self
+            .bot_user_id
+            .as_ref()
+            .ok_or_else(|| TwitchError::ConfigError("Not connected".to_string()))?;
+
+        self.api
+            .get_pending_unban_requests(broadcaster_id, bot_user_id)
+            .await
+    }
+
+    /// Approve or deny an unban request (requires moderator permissions)
+    pub async fn resolve_unban_request(
+        &self,
+        unban_request_id: &str,
+        approve: bool,
+        resolution_text: &str,
+    ) -> Result<()> {
+        let broadcaster_id = self
+            .broadcaster_id
+            .as_ref()
+            .ok_or_else(|| TwitchError::ConfigError("Not connected".to_string()))?;
+
+        let bot_user_id = self
+            .bot_user_id
+            .as_ref()
+            .ok_or_else(|| TwitchError::ConfigError("Not connected".to_string()))?;
+
+        self.api
+            .resolve_unban_request(
+                broadcaster_id,
+                bot_user_id,
+                unban_request_id,
+                approve,
+                resolution_text,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Check whether shield mode is active on the channel
+    pub async fn get_shield_mode_status(&self) -> Result<bool> {
+        let broadcaster_id = self
+            .broadcaster_id
+            .as_ref()
+            .ok_or_else(|| TwitchError::ConfigError("Not connected".to_string()))?;
+
+        let bot_user_id = self
+            .bot_user_id
+            .as_ref()
+            .ok_or_else(|| TwitchError::ConfigError("Not connected".to_string()))?;
+
+        self.api
+            .get_shield_mode_status(broadcaster_id, bot_user_id)
+            .await
+    }
+
+    /// Turn shield mode on or off, returns whether it is active afterwards
+    pub async fn set_shield_mode(&self, active: bool) -> Result<bool> {
+        let (broadcaster_id, bot_user_id) = self.channel_ids()?;
+        self.api
+            .update_shield_mode_status(broadcaster_id, bot_user_id, active)
+            .await
+    }
+
+    /// Get the channel's AutoMod levels
+    pub async fn get_automod_settings(&self) -> Result<AutomodSettings> {
+        let (broadcaster_id, bot_user_id) = self.channel_ids()?;
+        self.api
+            .get_automod_settings(broadcaster_id, bot_user_id)
+            .await
+    }
+
+    /// Change the channel's AutoMod levels, returns the new settings
+    pub async fn update_automod_settings(
+        &self,
+        settings: &AutomodSettings,
+    ) -> Result<AutomodSettings> {
+        let (broadcaster_id, bot_user_id) = self.channel_ids()?;
+        self.api
+            .update_automod_settings(broadcaster_id, bot_user_id, settings)
+            .await
+    }
+
+    /// Broadcaster and bot user IDs, known once connected
+    fn channel_ids(&self) -> Result<(&str, &str)> {
+        match (&self.broadcaster_id, &self.bot_user_id) {
+            (Some(broadcaster_id), Some(bot_user_id)) => Ok((broadcaster_id, bot_user_id)),
+            _ => Err(TwitchError::ConfigError("Not connected".to_string())),
+        }
+    }
+
+    /// Create a clip of the live stream
+    pub async fn create_clip(&self, has_delay: bool) -> Result<ClipData> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        self.api.create_clip(broadcaster_id, has_delay).await
+    }
+
+    /// Start a poll in the broadcaster's channel
+    pub async fn create_poll(
+        &self,
+        title: &str,
+        choices: &[String],
+        duration: u32,
+    ) -> Result<PollData> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        self.api
+            .create_poll(broadcaster_id, title, choices, duration)
+            .await
+    }
+
+    /// End the running poll and show its results, returns the poll that was ended
+    pub async fn end_poll(&self) -> Result<PollData> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        let poll = self
+            .api
+            .get_latest_poll(broadcaster_id)
+            .await?
+            .filter(|poll| poll.status == "ACTIVE")
+            .ok_or_else(|| TwitchError::ConfigError("No poll is running".to_string()))?;
+        self.api
+            .end_poll(broadcaster_id, &poll.id, "TERMINATED")
+            .await?;
+        Ok(poll)
+    }
+
+    /// Start a prediction in the broadcaster's channel
+    pub async fn create_prediction(
+        &self,
+        title: &str,
+        outcomes: &[String],
+        prediction_window: u32,
+    ) -> Result<PredictionData> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        self.api
+            .create_prediction(broadcaster_id, title, outcomes, prediction_window)
+            .await
+    }
+
+    /// Lock or cancel the running prediction, returns the prediction
+    pub async fn end_prediction(&self, status: &str) -> Result<PredictionData> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        let prediction = self.unresolved_prediction().await?;
+        self.api
+            .end_prediction(broadcaster_id, &prediction.id, status, None)
+            .await?;
+        Ok(prediction)
+    }
+
+    /// Pay out the running prediction to an outcome, given by its number (starting at 1)
+    /// or name. Returns the prediction and the winning outcome's name.
+    pub async fn resolve_prediction(&self, outcome: &str) -> Result<(PredictionData, String)> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        let prediction = self.unresolved_prediction().await?;
+        let winner = prediction
+            .outcome(outcome)
+            .ok_or_else(|| {
+                TwitchError::ConfigError(format!("No outcome \"{}\" in this prediction", outcome))
+            })?
+            .clone();
+        self.api
+            .end_prediction(broadcaster_id, &prediction.id, "RESOLVED", Some(&winner.id))
+            .await?;
+        Ok((prediction, winner.title))
+    }
+
+    /// The latest prediction if it's still taking predictions or locked
+    async fn unresolved_prediction(&self) -> Result<PredictionData> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        self.api
+            .get_latest_prediction(broadcaster_id)
+            .await?
+            .filter(|prediction| matches!(prediction.status.as_str(), "ACTIVE" | "LOCKED"))
+            .ok_or_else(|| TwitchError::ConfigError("No prediction is running".to_string()))
+    }
+
+    /// Start a raid to another channel
+    pub async fn start_raid(&self, user_login: &str) -> Result<RaidData> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        let target = self.api.get_user_by_login(user_login).await?;
+        self.api.start_raid(broadcaster_id, &target.id).await
+    }
+
+    /// Cancel the raid that is counting down
+    pub async fn cancel_raid(&self) -> Result<()> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        self.api.cancel_raid(broadcaster_id).await
+    }
+
+    /// Make a user a moderator, returns their display name
+    pub async fn add_moderator(&self, user_login: &str) -> Result<String> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        let user = self.api.get_user_by_login(user_login).await?;
+        self.api
+            .add_channel_moderator(broadcaster_id, &user.id)
+            .await?;
+        Ok(user.display_name)
+    }
+
+    /// Remove a user's moderator status, returns their display name
+    pub async fn remove_moderator(&self, user_login: &str) -> Result<String> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        let user = self.api.get_user_by_login(user_login).await?;
+        self.api
+            .remove_channel_moderator(broadcaster_id, &user.id)
+            .await?;
+        Ok(user.display_name)
+    }
+
+    /// Ban a user by login, or time them out for `duration` seconds. Returns their display name.
+    pub async fn ban_user_by_login(
+        &self,
+        user_login: &str,
+        duration: Option<u32>,
+        reason: &str,
+    ) -> Result<String> {
+        let user = self.api.get_user_by_login(user_login).await?;
+        match duration {
+            Some(duration) => self.timeout_user(&user.id, duration, reason).await?,
+            None => self.ban_user(&user.id, reason).await?,
+        }
+        Ok(user.display_name)
+    }
+
+    /// Lift a user's ban or timeout by login, returns their display name
+    pub async fn unban_user_by_login(&self, user_login: &str) -> Result<String> {
+        let user = self.api.get_user_by_login(user_login).await?;
+        self.unban_user(&user.id).await?;
+        Ok(user.display_name)
+    }
+
+    /// Number of followers of the channel
+    pub async fn get_follower_count(&self) -> Result<u64> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        self.api.get_follower_count(broadcaster_id).await
+    }
+
+    /// When a user followed the channel, `None` if they don't follow it
+    pub async fn get_followed_at(&self, user_id: &str) -> Result<Option<DateTime<Utc>>> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        let follower = self
+            .api
+            .get_channel_follower(broadcaster_id, user_id)
+            .await?;
+        Ok(follower.map(|follower| follower.followed_at))
+    }
+
+    /// When a user followed the channel, looked up by login. Returns their display name too.
+    pub async fn get_followed_at_by_login(
+        &self,
+        user_login: &str,
+    ) -> Result<(String, Option<DateTime<Utc>>)> {
+        let user = self.api.get_user_by_login(user_login).await?;
+        let followed_at = self.get_followed_at(&user.id).await?;
+        Ok((user.display_name, followed_at))
+    }
+
+    /// When a user created their Twitch account
+    pub async fn get_account_created_at(&self, user_login: &str) -> Result<DateTime<Utc>> {
+        let user = self.api.get_user_by_login(user_login).await?;
+        Ok(user.created_at)
+    }
+
+    /// Category of another channel and its most viewed clip of the last 30 days,
+    /// for shoutout messages. The clip is `None` if the channel has no recent clips.
+    pub async fn get_shoutout_info(&self, user_login: &str) -> Result<(String, Option<String>)> {
+        let user = self.api.get_user_by_login(user_login).await?;
+        let channel = self.api.get_channel_information(&user.id).await?;
+        let since = Utc::now() - chrono::Duration::days(30);
+        let clip = self
+            .api
+            .get_clips(&user.id, since, 1)
+            .await?
+            .into_iter()
+            .next()
+            .map(|clip| clip.url);
+        Ok((channel.game_name, clip))
+    }
+
+    /// Get the next scheduled ad break
+    pub async fn get_ad_schedule(&self) -> Result<AdSchedule> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        self.api.get_ad_schedule(broadcaster_id).await
+    }
+
+    /// Push the next ad break back, returns the new schedule
+    pub async fn snooze_next_ad(&self) -> Result<AdSchedule> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        self.api.snooze_next_ad(broadcaster_id).await
+    }
+
+    /// Get the current stream title and category
+    pub async fn get_channel_information(&self) -> Result<ChannelInformation> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        self.api.get_channel_information(broadcaster_id).await
+    }
+
+    /// Change the stream title and/or category, the category is looked up by name.
+    /// Returns the name of the category that was set.
+    pub async fn update_channel_information(
+        &self,
+        title: Option<&str>,
+        game: Option<&str>,
+    ) -> Result<Option<String>> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        let game = match game {
+            Some(name) => Some(self.api.find_game(name).await?),
+            None => None,
+        };
+
+        self.api
+            .modify_channel_information(
+                broadcaster_id,
+                title,
+                game.as_ref().map(|game| game.id.as_str()),
+            )
+            .await?;
+        Ok(game.map(|game| game.name))
+    }
+
+    /// Custom rewards created by the bot, only these can be changed by it
+    pub async fn get_managed_rewards(&self) -> Result<Vec<CustomRewardData>> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        self.api.get_custom_rewards(broadcaster_id, true).await
+    }
+
+    /// Create a custom channel point reward
+    pub async fn create_reward(&self, settings: &CustomRewardSettings) -> Result<CustomRewardData> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        self.api
+            .create_custom_reward(broadcaster_id, settings)
+            .await
+    }
+
+    /// Change a custom reward created by the bot
+    pub async fn update_reward(
+        &self,
+        reward_id: &str,
+        settings: &CustomRewardSettings,
+    ) -> Result<CustomRewardData> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        self.api
+            .update_custom_reward(broadcaster_id, reward_id, settings)
+            .await
+    }
+
+    /// Delete a custom reward created by the bot
+    pub async fn delete_reward(&self, reward_id: &str) -> Result<()> {
+        let (broadcaster_id, _) = self.channel_ids()?;
+        self.api
+            .delete_custom_reward(broadcaster_id, reward_id)
+            .await
+    }
+
+    /// Get the running Guest Star session, if any
+    pub async fn get_guest_star_session(&self) -> Result<Option<GuestStarSession>> {
+        let (broadcaster_id, bot_user_id) = self.channel_ids()?;
+        self.api
+            .get_guest_star_session(broadcaster_id, bot_user_id)
+            .await
+    }
+
+    async fn require_guest_star_session(&self) -> Result<GuestStarSession> {
+        self.get_guest_star_session()
+            .await?
+            .ok_or_else(|| TwitchError::ConfigError("No Guest Star session running".to_string()))
+    }
+
+    /// Invite a user to the running Guest Star session
+    pub async fn invite_guest(&self, user_login: &str) -> Result<()> {
+        let (broadcaster_id, bot_user_id) = self.channel_ids()?;
+        let session = self.require_guest_star_session().await?;
+        let guest = self.api.get_user_by_login(user_login).await?;
+
+        self.api
+            .send_guest_star_invite(broadcaster_id, bot_user_id, &session.id, &guest.id)
+            .await
+    }
+
+    /// Move an invited guest into a slot
+    pub async fn assign_guest_slot(&self, user_login: &str, slot_id: &str) -> Result<()> {
+        let (broadcaster_id, bot_user_id) = self.channel_ids()?;
+        let session = self.require_guest_star_session().await?;
+        let guest = self.api.get_user_by_login(user_login).await?;
+
+        self.api
+            .assign_guest_star_slot(broadcaster_id, bot_user_id, &session.id, &guest.id, slot_id)
+            .await
+    }
+
+    /// Mute or unmute a Guest Star slot
+    pub async fn set_guest_slot_audio(&self, slot_id: &str, enabled: bool) -> Result<()> {
+        let (broadcaster_id, bot_user_id) = self.channel_ids()?;
+        let session = self.require_guest_star_session().await?;
+
+        self.api
+            .set_guest_star_slot_audio(broadcaster_id, bot_user_id, &session.id, slot_id, enabled)
+            .await
+    }
+
+    /// List EventSub subscriptions with their cost
+    pub async fn list_eventsub_subscriptions(&self) -> Result<SubscriptionList> {
+        self.eventsub.list_subscriptions().await
+    }
+
     /// Get the current access token (may have been refreshed)
     pub async fn get_access_token(&self) -> String {
         self.api.get_access_token().await
@@ -445,6 +1020,20 @@ impl TwitchClient {
     pub fn api(&self) -> &TwitchApi {
         &self.api
     }
+
+    /// Copy of the client for Helix requests made off the event loop. It shares the
+    /// tokens and channel but not the chat connection, which stays with this client.
+    pub fn detached(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            api: self.api.clone(),
+            eventsub: self.eventsub.clone(),
+            ws_handler: None,
+            ws_task: None,
+            broadcaster_id: self.broadcaster_id.clone(),
+            bot_user_id: self.bot_user_id.clone(),
+        }
+    }
 }
 
 impl Drop for TwitchClient {
@@ -7,6 +7,7 @@ use super::auth;
 use super::error::{Result, TwitchError};
 
 const EVENTSUB_API_URL: &str = "https://api.twitch.tv/helix/eventsub/subscriptions";
+const CONDUIT_SHARDS_URL: &str = "https://api.twitch.tv/helix/eventsub/conduits/shards";
 
 /// EventSub subscription request
 #[derive(Debug, Clone, Serialize)]
@@ -19,9 +20,45 @@ pub struct SubscriptionRequest {
 }
 
 #[derive(Debug, Clone, Serialize)]
-pub struct Transport {
-    pub method: String,
-    pub session_id: String,
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum Transport {
+    Websocket { session_id: String },
+    Conduit { conduit_id: String },
+    Webhook { callback: String, secret: String },
+}
+
+/// Where subscriptions deliver their events, selected in config.toml
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum EventSubTransport {
+    /// The bot's own WebSocket session, subscriptions are recreated on every connect
+    #[default]
+    Websocket,
+    /// A conduit created for the client. The bot's WebSocket session is assigned to one of
+    /// its shards, so subscriptions outlive the session
+    Conduit {
+        conduit_id: String,
+        #[serde(default = "default_shard_id")]
+        shard_id: String,
+    },
+    /// A webhook of a hosted receiver. Events go to the callback instead of the bot
+    Webhook { callback: String, secret: String },
+}
+
+fn default_shard_id() -> String {
+    "0".to_string()
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ConduitShardsResponse {
+    #[serde(default)]
+    errors: Vec<ConduitShardError>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ConduitShardError {
+    id: String,
+    message: String,
 }
 
 /// EventSub subscription response
@@ -53,18 +90,44 @@ pub struct SubscriptionData {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct TransportData {
-    #[allow(dead_code)]
     pub method: String,
-    #[allow(dead_code)]
-    pub session_id: String,
+    /// Only set for websocket transports
+    pub session_id: Option<String>,
+}
+
+/// Page of existing subscriptions
+#[derive(Debug, Clone, Deserialize)]
+struct SubscriptionListResponse {
+    data: Vec<SubscriptionData>,
+    total_cost: u32,
+    max_total_cost: u32,
+    #[serde(default)]
+    pagination: Pagination,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct Pagination {
+    cursor: Option<String>,
+}
+
+/// All subscriptions of the client and user, with the cost budget they use
+#[derive(Debug, Clone)]
+pub struct SubscriptionList {
+    pub subscriptions: Vec<SubscriptionData>,
+    pub total_cost: u32,
+    pub max_total_cost: u32,
 }
 
 /// EventSub manager for creating and managing subscriptions
+#[derive(Clone)]
 pub struct EventSubManager {
     client: reqwest::Client,
     access_token: Arc<RwLock<String>>,
     refresh_token: Arc<RwLock<String>>,
     token_refresh_tx: Option<mpsc::UnboundedSender<(String, String)>>,
+    transport: EventSubTransport,
+    /// Conduit and webhook subscriptions need an app access token, fetched on first use
+    app_access_token: Arc<RwLock<Option<String>>>,
 }
 
 impl EventSubManager {
@@ -74,7 +137,144 @@ impl EventSubManager {
             access_token,
             refresh_token,
             token_refresh_tx: None,
+            transport: EventSubTransport::default(),
+            app_access_token: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Select where new subscriptions deliver their events
+    pub fn set_transport(&mut self, transport: EventSubTransport) {
+        self.transport = transport;
+    }
+
+    /// Transport of a new subscription
+    fn transport(&self, session_id: &str) -> Transport {
+        match &self.transport {
+            EventSubTransport::Websocket => Transport::Websocket {
+                session_id: session_id.to_string(),
+            },
+            EventSubTransport::Conduit { conduit_id, .. } => Transport::Conduit {
+                conduit_id: conduit_id.clone(),
+            },
+            EventSubTransport::Webhook { callback, secret } => Transport::Webhook {
+                callback: callback.clone(),
+                secret: secret.clone(),
+            },
+        }
+    }
+
+    /// Token to create subscriptions with - the user token for WebSocket subscriptions,
+    /// an app token for conduits and webhooks
+    async fn subscription_token(&self) -> Result<String> {
+        if self.transport == EventSubTransport::Websocket {
+            return Ok(self.access_token.read().await.clone());
+        }
+
+        if let Some(token) = self.app_access_token.read().await.as_ref() {
+            return Ok(token.clone());
+        }
+        let token = auth::get_app_access_token().await?;
+        *self.app_access_token.write().await = Some(token.clone());
+        Ok(token)
+    }
+
+    /// Get a new subscription token after it was rejected. User tokens are refreshed,
+    /// app tokens can't be so a new one is fetched on next use.
+    async fn renew_subscription_token(&self) -> Result<()> {
+        if self.transport == EventSubTransport::Websocket {
+            self.refresh_token().await
+        } else {
+            *self.app_access_token.write().await = None;
+            Ok(())
+        }
+    }
+
+    /// Get the transport ready for a new WebSocket session.
+    /// For conduits this points the configured shard at the session.
+    pub async fn prepare_transport(&self, session_id: &str) -> Result<()> {
+        match &self.transport {
+            EventSubTransport::Websocket => Ok(()),
+            EventSubTransport::Conduit {
+                conduit_id,
+                shard_id,
+            } => {
+                self.assign_conduit_shard(conduit_id, shard_id, session_id, true)
+                    .await
+            }
+            EventSubTransport::Webhook { callback, .. } => {
+                log::warn!(
+                    "EventSub webhook transport selected, events are delivered to {} instead of the bot",
+                    callback
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// Point a conduit shard at the session. `retry_unauthorized` gets a new app
+    /// token and tries once more if the current one is rejected.
+    async fn assign_conduit_shard(
+        &self,
+        conduit_id: &str,
+        shard_id: &str,
+        session_id: &str,
+        retry_unauthorized: bool,
+    ) -> Result<()> {
+        let access_token = self.subscription_token().await?;
+        let body = json!({
+            "conduit_id": conduit_id,
+            "shards": [{
+                "id": shard_id,
+                "transport": {
+                    "method": "websocket",
+                    "session_id": session_id
+                }
+            }]
+        });
+
+        let response = self
+            .client
+            .patch(CONDUIT_SHARDS_URL)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .json(&body)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 && retry_unauthorized {
+                // App tokens can't be refreshed, get a new one
+                *self.app_access_token.write().await = None;
+                return Box::pin(
+                    self.assign_conduit_shard(conduit_id, shard_id, session_id, false),
+                )
+                .await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::SubscriptionError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        let shards = response.json::<ConduitShardsResponse>().await?;
+        if let Some(error) = shards.errors.first() {
+            return Err(TwitchError::SubscriptionError(format!(
+                "Failed to assign conduit shard {}: {}",
+                error.id, error.message
+            )));
         }
+
+        log::info!(
+            "Assigned conduit {} shard {} to this session",
+            conduit_id,
+            shard_id
+        );
+        Ok(())
     }
 
     /// Set a channel to receive notifications when tokens are refreshed
@@ -114,12 +314,22 @@ impl EventSubManager {
         &self,
         request: SubscriptionRequest,
     ) -> Result<SubscriptionResponse> {
-        let access_token = self.access_token.read().await;
+        self.send_subscription(request, true).await
+    }
+
+    /// Send a subscription request. `retry_unauthorized` gets a new token and tries
+    /// once more if the current one is rejected.
+    async fn send_subscription(
+        &self,
+        request: SubscriptionRequest,
+        retry_unauthorized: bool,
+    ) -> Result<SubscriptionResponse> {
+        let access_token = self.subscription_token().await?;
 
         let response = self
             .client
             .post(EVENTSUB_API_URL)
-            .header("Authorization", format!("Bearer {}", *access_token))
+            .header("Authorization", format!("Bearer {}", access_token))
             .header("Client-Id", auth::CLIENT_ID)
             .header("Content-Type", "application/json")
             .json(&request)
@@ -129,12 +339,22 @@ impl EventSubManager {
         if !response.status().is_success() {
             let status = response.status();
 
-            // Handle 401 by refreshing token and retrying
-            if status.as_u16() == 401 {
-                drop(access_token); // Release the lock before refreshing
+            // Handle 401 by refreshing token and retrying once
+            if status.as_u16() == 401 && retry_unauthorized {
                 log::warn!("EventSub subscription got 401, refreshing token and retrying...");
-                self.refresh_token().await?;
-                return Box::pin(self.create_subscription(request)).await; // Retry with new token
+                self.renew_subscription_token().await?;
+                return Box::pin(self.send_subscription(request, false)).await; // Retry with new token
+            }
+
+            // Conduit and webhook subscriptions outlive the bot, they already exist after a restart
+            if status.as_u16() == 409 && self.transport != EventSubTransport::Websocket {
+                log::debug!("{} subscription already exists", request.subscription_type);
+                return Ok(SubscriptionResponse {
+                    data: Vec::new(),
+                    total: 0,
+                    total_cost: 0,
+                    max_total_cost: 0,
+                });
             }
 
             let error_text = response
@@ -151,6 +371,123 @@ impl EventSubManager {
         Ok(subscription_response)
     }
 
+    /// List all subscriptions, following pagination
+    pub async fn list_subscriptions(&self) -> Result<SubscriptionList> {
+        let mut page = self.list_subscriptions_page(None, true).await?;
+        let mut list = SubscriptionList {
+            subscriptions: std::mem::take(&mut page.data),
+            total_cost: page.total_cost,
+            max_total_cost: page.max_total_cost,
+        };
+
+        while let Some(cursor) = page.pagination.cursor.take() {
+            page = self.list_subscriptions_page(Some(&cursor), true).await?;
+            list.subscriptions.append(&mut page.data);
+        }
+
+        Ok(list)
+    }
+
+    /// One page of the subscriptions. `retry_unauthorized` gets a new token and tries
+    /// once more if the current one is rejected.
+    async fn list_subscriptions_page(
+        &self,
+        cursor: Option<&str>,
+        retry_unauthorized: bool,
+    ) -> Result<SubscriptionListResponse> {
+        let url = match cursor {
+            Some(cursor) => format!("{}?after={}", EVENTSUB_API_URL, cursor),
+            None => EVENTSUB_API_URL.to_string(),
+        };
+
+        let access_token = self.subscription_token().await?;
+
+        let response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 && retry_unauthorized {
+                self.renew_subscription_token().await?;
+                return Box::pin(self.list_subscriptions_page(cursor, false)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::SubscriptionError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        Ok(response.json::<SubscriptionListResponse>().await?)
+    }
+
+    /// Delete a subscription by ID. `retry_unauthorized` gets a new token and tries
+    /// once more if the current one is rejected.
+    pub async fn delete_subscription(&self, id: &str, retry_unauthorized: bool) -> Result<()> {
+        let url = format!("{}?id={}", EVENTSUB_API_URL, id);
+        let access_token = self.subscription_token().await?;
+
+        let response = self
+            .client
+            .delete(&url)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .header("Client-Id", auth::CLIENT_ID)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 401 && retry_unauthorized {
+                self.renew_subscription_token().await?;
+                return Box::pin(self.delete_subscription(id, false)).await;
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(TwitchError::SubscriptionError(format!(
+                "HTTP {}: {}",
+                status, error_text
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Delete websocket subscriptions left behind by sessions that are no longer connected,
+    /// so repeated reconnects don't eat into the cost budget.
+    /// Returns the number of deleted subscriptions.
+    pub async fn delete_stale_subscriptions(&self, session_id: &str) -> Result<usize> {
+        let list = self.list_subscriptions().await?;
+        let mut deleted = 0;
+
+        for subscription in list.subscriptions.iter().filter(|s| {
+            s.transport.method == "websocket"
+                && s.status != "enabled"
+                && s.transport.session_id.as_deref() != Some(session_id)
+        }) {
+            match self.delete_subscription(&subscription.id, true).await {
+                Ok(()) => deleted += 1,
+                Err(e) => log::warn!(
+                    "Failed to delete stale subscription {} ({}): {}",
+                    subscription.id,
+                    subscription.subscription_type,
+                    e
+                ),
+            }
+        }
+
+        Ok(deleted)
+    }
+
     /// Subscribe to channel chat messages
     pub async fn subscribe_to_chat_messages(
         &self,
@@ -165,10 +502,7 @@ impl EventSubManager {
                 "broadcaster_user_id": broadcaster_user_id,
                 "user_id": user_id
             }),
-            transport: Transport {
-                method: "websocket".to_string(),
-                session_id: session_id.to_string(),
-            },
+            transport: self.transport(session_id),
         };
 
         self.create_subscription(request).await
@@ -188,10 +522,7 @@ impl EventSubManager {
                 "broadcaster_user_id": broadcaster_user_id,
                 "user_id": user_id
             }),
-            transport: Transport {
-                method: "websocket".to_string(),
-                session_id: session_id.to_string(),
-            },
+            transport: self.transport(session_id),
         };
 
         self.create_subscription(request).await
@@ -211,10 +542,7 @@ impl EventSubManager {
                 "broadcaster_user_id": broadcaster_user_id,
                 "user_id": user_id
             }),
-            transport: Transport {
-                method: "websocket".to_string(),
-                session_id: session_id.to_string(),
-            },
+            transport: self.transport(session_id),
         };
 
         self.create_subscription(request).await
@@ -234,10 +562,7 @@ impl EventSubManager {
                 "broadcaster_user_id": broadcaster_user_id,
                 "user_id": user_id
             }),
-            transport: Transport {
-                method: "websocket".to_string(),
-                session_id: session_id.to_string(),
-            },
+            transport: self.transport(session_id),
         };
 
         self.create_subscription(request).await
@@ -257,10 +582,7 @@ impl EventSubManager {
                 "broadcaster_user_id": broadcaster_user_id,
                 "user_id": user_id
             }),
-            transport: Transport {
-                method: "websocket".to_string(),
-                session_id: session_id.to_string(),
-            },
+            transport: self.transport(session_id),
         };
 
         self.create_subscription(request).await
@@ -278,10 +600,7 @@ impl EventSubManager {
             condition: json!({
                 "broadcaster_user_id": broadcaster_user_id
             }),
-            transport: Transport {
-                method: "websocket".to_string(),
-                session_id: session_id.to_string(),
-            },
+            transport: self.transport(session_id),
         };
 
         self.create_subscription(request).await
@@ -299,93 +618,661 @@ impl EventSubManager {
             condition: json!({
                 "broadcaster_user_id": broadcaster_user_id
             }),
-            transport: Transport {
-                method: "websocket".to_string(),
-                session_id: session_id.to_string(),
-            },
+            transport: self.transport(session_id),
         };
 
         self.create_subscription(request).await
     }
 
-    /// Helper to get required scope for a subscription type
-    fn get_required_scope(subscription_type: &str) -> &'static str {
-        match subscription_type {
-            "channel.chat.message" => "user:read:chat",
-            "channel.chat.message_delete" => "user:read:chat",
-            "channel.chat.clear_user_messages" => "user:read:chat",
-            "channel.chat.clear" => "user:read:chat",
-            "channel.chat_settings.update" => "user:read:chat",
-            "channel.ban" => "channel:moderate or moderator:read:banned_users",
-            "channel.unban" => "channel:moderate or moderator:read:banned_users",
-            _ => "unknown scope",
-        }
+    /// Subscribe to channel poll begin events
+    pub async fn subscribe_to_poll_begin(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.poll.begin".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
     }
 
-    /// Subscribe to an event with error handling
-    async fn subscribe_with_error_handling(
+    /// Subscribe to channel poll progress events
+    pub async fn subscribe_to_poll_progress(
         &self,
-        name: &str,
-        subscription_type: &str,
-        result: Result<SubscriptionResponse>,
-        warnings: &mut Vec<String>,
-    ) -> bool {
-        match result {
-            Ok(_) => {
-                log::info!("✓ Subscribed to {} successfully", name);
-                true
-            }
-            Err(e) => {
-                let error_msg = e.to_string();
-                if error_msg.contains("403") || error_msg.contains("Forbidden") || error_msg.contains("authorization") {
-                    let warning = format!(
-                        "Skipped '{}' - Missing OAuth scope: {}",
-                        name,
-                        Self::get_required_scope(subscription_type)
-                    );
-                    log::warn!("⚠ {}", warning);
-                    warnings.push(warning);
-                } else {
-                    log::error!("✗ Failed to subscribe to {}: {}", name, e);
-                }
-                false
-            }
-        }
+        session_id: &str,
+        broadcaster_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.poll.progress".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
     }
 
-    /// Subscribe to all chat events (continues on errors)
-    /// Returns (success_count, failed_count, warnings)
-    pub async fn subscribe_to_all_events(
+    /// Subscribe to channel poll end events
+    pub async fn subscribe_to_poll_end(
         &self,
         session_id: &str,
         broadcaster_user_id: &str,
-        user_id: &str,
-    ) -> Result<(usize, usize, Vec<String>)> {
-        log::info!("Creating EventSub subscriptions...");
-        let mut success_count = 0;
-        let mut failed_count = 0;
-        let mut warnings = Vec::new();
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.poll.end".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id
+            }),
+            transport: self.transport(session_id),
+        };
 
-        // Subscribe to all chat-related events (don't fail on errors)
-        if self.subscribe_with_error_handling(
-            "chat messages",
-            "channel.chat.message",
-            self.subscribe_to_chat_messages(session_id, broadcaster_user_id, user_id).await,
-            &mut warnings,
-        ).await {
-            success_count += 1;
-        } else {
-            failed_count += 1;
-        }
+        self.create_subscription(request).await
+    }
 
-        if self.subscribe_with_error_handling(
-            "message deletions",
-            "channel.chat.message_delete",
-            self.subscribe_to_message_delete(session_id, broadcaster_user_id, user_id).await,
-            &mut warnings,
-        ).await {
-            success_count += 1;
-        } else {
+    /// Subscribe to channel ad break begin events
+    pub async fn subscribe_to_ad_break_begin(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.ad_break.begin".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to hype train begin events
+    pub async fn subscribe_to_hype_train_begin(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.hype_train.begin".to_string(),
+            version: "2".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to hype train progress events
+    pub async fn subscribe_to_hype_train_progress(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.hype_train.progress".to_string(),
+            version: "2".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to hype train end events
+    pub async fn subscribe_to_hype_train_end(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.hype_train.end".to_string(),
+            version: "2".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to stream online events
+    pub async fn subscribe_to_stream_online(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "stream.online".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to stream offline events
+    pub async fn subscribe_to_stream_offline(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "stream.offline".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to shoutouts sent by the channel
+    pub async fn subscribe_to_shoutout_create(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+        moderator_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.shoutout.create".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id,
+                "moderator_user_id": moderator_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to shoutouts received from other channels
+    pub async fn subscribe_to_shoutout_receive(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+        moderator_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.shoutout.receive".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id,
+                "moderator_user_id": moderator_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to creator goal begin events
+    pub async fn subscribe_to_goal_begin(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.goal.begin".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to creator goal progress events
+    pub async fn subscribe_to_goal_progress(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.goal.progress".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to creator goal end events
+    pub async fn subscribe_to_goal_end(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.goal.end".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to new unban requests
+    pub async fn subscribe_to_unban_request_create(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+        moderator_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.unban_request.create".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id,
+                "moderator_user_id": moderator_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to resolved unban requests
+    pub async fn subscribe_to_unban_request_resolve(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+        moderator_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.unban_request.resolve".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id,
+                "moderator_user_id": moderator_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to shield mode activation
+    pub async fn subscribe_to_shield_mode_begin(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+        moderator_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.shield_mode.begin".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id,
+                "moderator_user_id": moderator_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to shield mode deactivation
+    pub async fn subscribe_to_shield_mode_end(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+        moderator_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.shield_mode.end".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id,
+                "moderator_user_id": moderator_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to whispers sent to the bot account
+    pub async fn subscribe_to_whisper_message(
+        &self,
+        session_id: &str,
+        user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "user.whisper.message".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "user_id": user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to chat notifications (subs, resubs, gifts, raids, announcements)
+    pub async fn subscribe_to_chat_notification(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+        user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.chat.notification".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id,
+                "user_id": user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to Guest Star session starts
+    pub async fn subscribe_to_guest_star_session_begin(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+        moderator_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.guest_star_session.begin".to_string(),
+            version: "beta".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id,
+                "moderator_user_id": moderator_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to Guest Star session ends
+    pub async fn subscribe_to_guest_star_session_end(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+        moderator_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.guest_star_session.end".to_string(),
+            version: "beta".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id,
+                "moderator_user_id": moderator_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to Guest Star guest updates (invites, slot changes, removals)
+    pub async fn subscribe_to_guest_star_guest_update(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+        moderator_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.guest_star_guest.update".to_string(),
+            version: "beta".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id,
+                "moderator_user_id": moderator_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to chat messages from suspicious users
+    pub async fn subscribe_to_suspicious_user_message(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+        moderator_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.suspicious_user.message".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id,
+                "moderator_user_id": moderator_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to suspicious user status changes
+    pub async fn subscribe_to_suspicious_user_update(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+        moderator_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.suspicious_user.update".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id,
+                "moderator_user_id": moderator_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to charity campaign donation events
+    pub async fn subscribe_to_charity_donate(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.charity_campaign.donate".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to charity campaign progress events
+    pub async fn subscribe_to_charity_progress(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.charity_campaign.progress".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Subscribe to channel point reward redemptions
+    pub async fn subscribe_to_channel_points_redemption(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+    ) -> Result<SubscriptionResponse> {
+        let request = SubscriptionRequest {
+            subscription_type: "channel.channel_points_custom_reward_redemption.add".to_string(),
+            version: "1".to_string(),
+            condition: json!({
+                "broadcaster_user_id": broadcaster_user_id
+            }),
+            transport: self.transport(session_id),
+        };
+
+        self.create_subscription(request).await
+    }
+
+    /// Helper to get required scope for a subscription type
+    fn get_required_scope(subscription_type: &str) -> &'static str {
+        match subscription_type {
+            "channel.chat.message" => "user:read:chat",
+            "channel.chat.message_delete" => "user:read:chat",
+            "channel.chat.clear_user_messages" => "user:read:chat",
+            "channel.chat.clear" => "user:read:chat",
+            "channel.chat.notification" => "user:read:chat",
+            "channel.chat_settings.update" => "user:read:chat",
+            "channel.ban" => "channel:moderate or moderator:read:banned_users",
+            "channel.unban" => "channel:moderate or moderator:read:banned_users",
+            "channel.poll.begin" => "channel:read:polls or channel:manage:polls",
+            "channel.poll.progress" => "channel:read:polls or channel:manage:polls",
+            "channel.poll.end" => "channel:read:polls or channel:manage:polls",
+            "channel.ad_break.begin" => "channel:read:ads",
+            "channel.hype_train.begin" => "channel:read:hype_train",
+            "channel.hype_train.progress" => "channel:read:hype_train",
+            "channel.hype_train.end" => "channel:read:hype_train",
+            "stream.online" => "none",
+            "stream.offline" => "none",
+            "channel.shoutout.create" => "moderator:read:shoutouts or moderator:manage:shoutouts",
+            "channel.shoutout.receive" => "moderator:read:shoutouts or moderator:manage:shoutouts",
+            "channel.goal.begin" => "channel:read:goals",
+            "channel.goal.progress" => "channel:read:goals",
+            "channel.goal.end" => "channel:read:goals",
+            "channel.unban_request.create" => {
+                "moderator:read:unban_requests or moderator:manage:unban_requests"
+            }
+            "channel.unban_request.resolve" => {
+                "moderator:read:unban_requests or moderator:manage:unban_requests"
+            }
+            "channel.shield_mode.begin" => {
+                "moderator:read:shield_mode or moderator:manage:shield_mode"
+            }
+            "channel.shield_mode.end" => {
+                "moderator:read:shield_mode or moderator:manage:shield_mode"
+            }
+            "user.whisper.message" => "user:read:whispers or user:manage:whispers",
+            "channel.charity_campaign.donate" | "channel.charity_campaign.progress" => {
+                "channel:read:charity"
+            }
+            "channel.channel_points_custom_reward_redemption.add" => {
+                "channel:read:redemptions or channel:manage:redemptions"
+            }
+            "channel.suspicious_user.message" | "channel.suspicious_user.update" => {
+                "moderator:read:suspicious_users"
+            }
+            "channel.guest_star_session.begin"
+            | "channel.guest_star_session.end"
+            | "channel.guest_star_guest.update" => {
+                "channel:read:guest_star, channel:manage:guest_star, moderator:read:guest_star or moderator:manage:guest_star"
+            }
+            _ => "unknown scope",
+        }
+    }
+
+    /// Subscribe to an event with error handling
+    async fn subscribe_with_error_handling(
+        &self,
+        name: &str,
+        subscription_type: &str,
+        result: Result<SubscriptionResponse>,
+        warnings: &mut Vec<String>,
+    ) -> bool {
+        match result {
+            Ok(_) => {
+                log::info!("✓ Subscribed to {} successfully", name);
+                true
+            }
+            Err(e) => {
+                let error_msg = e.to_string();
+                if error_msg.contains("403") || error_msg.contains("Forbidden") || error_msg.contains("authorization") {
+                    let warning = format!(
+                        "Skipped '{}' - Missing OAuth scope: {}",
+                        name,
+                        Self::get_required_scope(subscription_type)
+                    );
+                    log::warn!("⚠ {}", warning);
+                    warnings.push(warning);
+                } else {
+                    log::error!("✗ Failed to subscribe to {}: {}", name, e);
+                }
+                false
+            }
+        }
+    }
+
+    /// Subscribe to all chat events (continues on errors)
+    /// Returns (success_count, failed_count, warnings, chat_subscribed)
+    pub async fn subscribe_to_all_events(
+        &self,
+        session_id: &str,
+        broadcaster_user_id: &str,
+        user_id: &str,
+    ) -> Result<(usize, usize, Vec<String>, bool)> {
+        log::info!("Creating EventSub subscriptions...");
+        let mut success_count = 0;
+        let mut failed_count = 0;
+        let mut warnings = Vec::new();
+
+        // Subscribe to all chat-related events (don't fail on errors)
+        let chat_subscribed = self.subscribe_with_error_handling(
+            "chat messages",
+            "channel.chat.message",
+            self.subscribe_to_chat_messages(session_id, broadcaster_user_id, user_id).await,
+            &mut warnings,
+        ).await;
+        if chat_subscribed {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "message deletions",
+            "channel.chat.message_delete",
+            self.subscribe_to_message_delete(session_id, broadcaster_user_id, user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
             failed_count += 1;
         }
 
@@ -444,6 +1331,314 @@ impl EventSubManager {
             failed_count += 1;
         }
 
+        if self.subscribe_with_error_handling(
+            "poll starts",
+            "channel.poll.begin",
+            self.subscribe_to_poll_begin(session_id, broadcaster_user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "poll progress",
+            "channel.poll.progress",
+            self.subscribe_to_poll_progress(session_id, broadcaster_user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "poll results",
+            "channel.poll.end",
+            self.subscribe_to_poll_end(session_id, broadcaster_user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "ad breaks",
+            "channel.ad_break.begin",
+            self.subscribe_to_ad_break_begin(session_id, broadcaster_user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "hype train starts",
+            "channel.hype_train.begin",
+            self.subscribe_to_hype_train_begin(session_id, broadcaster_user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "hype train progress",
+            "channel.hype_train.progress",
+            self.subscribe_to_hype_train_progress(session_id, broadcaster_user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "hype train ends",
+            "channel.hype_train.end",
+            self.subscribe_to_hype_train_end(session_id, broadcaster_user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "stream online",
+            "stream.online",
+            self.subscribe_to_stream_online(session_id, broadcaster_user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "stream offline",
+            "stream.offline",
+            self.subscribe_to_stream_offline(session_id, broadcaster_user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "shoutouts sent",
+            "channel.shoutout.create",
+            self.subscribe_to_shoutout_create(session_id, broadcaster_user_id, user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "shoutouts received",
+            "channel.shoutout.receive",
+            self.subscribe_to_shoutout_receive(session_id, broadcaster_user_id, user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "goal begin",
+            "channel.goal.begin",
+            self.subscribe_to_goal_begin(session_id, broadcaster_user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "goal progress",
+            "channel.goal.progress",
+            self.subscribe_to_goal_progress(session_id, broadcaster_user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "goal end",
+            "channel.goal.end",
+            self.subscribe_to_goal_end(session_id, broadcaster_user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "unban requests",
+            "channel.unban_request.create",
+            self.subscribe_to_unban_request_create(session_id, broadcaster_user_id, user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "unban request resolutions",
+            "channel.unban_request.resolve",
+            self.subscribe_to_unban_request_resolve(session_id, broadcaster_user_id, user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "shield mode begin",
+            "channel.shield_mode.begin",
+            self.subscribe_to_shield_mode_begin(session_id, broadcaster_user_id, user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "shield mode end",
+            "channel.shield_mode.end",
+            self.subscribe_to_shield_mode_end(session_id, broadcaster_user_id, user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "chat notifications",
+            "channel.chat.notification",
+            self.subscribe_to_chat_notification(session_id, broadcaster_user_id, user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "whispers",
+            "user.whisper.message",
+            self.subscribe_to_whisper_message(session_id, user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "Guest Star session starts",
+            "channel.guest_star_session.begin",
+            self.subscribe_to_guest_star_session_begin(session_id, broadcaster_user_id, user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "Guest Star session ends",
+            "channel.guest_star_session.end",
+            self.subscribe_to_guest_star_session_end(session_id, broadcaster_user_id, user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "Guest Star guest updates",
+            "channel.guest_star_guest.update",
+            self.subscribe_to_guest_star_guest_update(session_id, broadcaster_user_id, user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "suspicious user messages",
+            "channel.suspicious_user.message",
+            self.subscribe_to_suspicious_user_message(session_id, broadcaster_user_id, user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "suspicious user updates",
+            "channel.suspicious_user.update",
+            self.subscribe_to_suspicious_user_update(session_id, broadcaster_user_id, user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "charity donations",
+            "channel.charity_campaign.donate",
+            self.subscribe_to_charity_donate(session_id, broadcaster_user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "charity progress",
+            "channel.charity_campaign.progress",
+            self.subscribe_to_charity_progress(session_id, broadcaster_user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
+        if self.subscribe_with_error_handling(
+            "channel point redemptions",
+            "channel.channel_points_custom_reward_redemption.add",
+            self.subscribe_to_channel_points_redemption(session_id, broadcaster_user_id).await,
+            &mut warnings,
+        ).await {
+            success_count += 1;
+        } else {
+            failed_count += 1;
+        }
+
         log::info!(
             "Subscriptions complete: {} succeeded, {} failed/skipped",
             success_count,
@@ -465,6 +1660,6 @@ impl EventSubManager {
             );
         }
 
-        Ok((success_count, failed_count, warnings))
+        Ok((success_count, failed_count, warnings, chat_subscribed))
     }
 }
@@ -0,0 +1,260 @@
+use futures_util::{SinkExt, StreamExt};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tokio::time::{sleep, Duration};
+use tokio_tungstenite::{connect_async, tungstenite::Message as WsMessage};
+
+use super::client::TwitchClientEvent;
+use super::error::Result;
+use super::messages::{
+    Badge, ChatMessageEvent, Cheer, Emote, Message, MessageFragment, TwitchEvent,
+};
+
+const IRC_WS_URL: &str = "wss://irc-ws.chat.twitch.tv:443";
+
+/// Anonymous login, the fallback only reads chat. Messages are still sent through Helix.
+const ANONYMOUS_NICK: &str = "justinfan48151";
+
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Broadcaster the fallback connection reads chat for
+#[derive(Debug, Clone)]
+pub struct IrcChannel {
+    pub user_id: String,
+    pub login: String,
+    pub name: String,
+}
+
+/// Read chat over IRC and forward messages as `ChatMessage` events.
+/// Runs until the event receiver is dropped, reconnecting when the connection drops.
+pub async fn run_irc_fallback(channel: IrcChannel, event_tx: mpsc::Sender<TwitchClientEvent>) {
+    loop {
+        tokio::select! {
+            result = read_chat(&channel, &event_tx) => {
+                if let Err(e) = result {
+                    log::warn!("IRC fallback connection failed: {}", e);
+                }
+            }
+            _ = event_tx.closed() => return,
+        }
+
+        if event_tx.is_closed() {
+            return;
+        }
+        sleep(RECONNECT_DELAY).await;
+    }
+}
+
+async fn read_chat(channel: &IrcChannel, event_tx: &mpsc::Sender<TwitchClientEvent>) -> Result<()> {
+    let (ws_stream, _) = connect_async(IRC_WS_URL).await?;
+    let (mut write, mut read) = ws_stream.split();
+
+    for line in [
+        "CAP REQ :twitch.tv/tags twitch.tv/commands".to_string(),
+        format!("NICK {}", ANONYMOUS_NICK),
+        format!("JOIN #{}", channel.login),
+    ] {
+        write.send(WsMessage::Text(line.into())).await?;
+    }
+    log::info!("IRC fallback joined #{}", channel.login);
+
+    while let Some(message) = read.next().await {
+        let text = match message? {
+            WsMessage::Text(text) => text,
+            WsMessage::Close(_) => break,
+            _ => continue,
+        };
+
+        for line in text.lines() {
+            if line.starts_with("PING") {
+                write
+                    .send(WsMessage::Text("PONG :tmi.twitch.tv".into()))
+                    .await?;
+            } else if let Some(msg) = parse_privmsg(line, channel) {
+                let event = TwitchClientEvent::ChatEvent(TwitchEvent::ChatMessage(msg));
+                if event_tx.send(event).await.is_err() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse a tagged `PRIVMSG` line into a chat message event
+fn parse_privmsg(line: &str, channel: &IrcChannel) -> Option<ChatMessageEvent> {
+    let (tags, rest) = match line.strip_prefix('@') {
+        Some(tagged) => tagged.split_once(' ')?,
+        None => ("", line),
+    };
+
+    // :login!login@login.tmi.twitch.tv PRIVMSG #channel :text
+    let (prefix, command) = rest.strip_prefix(':')?.split_once(' ')?;
+    let (_, text) = command.strip_prefix("PRIVMSG ")?.split_once(" :")?;
+    let login = prefix.split('!').next()?.to_string();
+
+    // /me messages are wrapped in CTCP ACTION
+    let text = text
+        .strip_prefix("\u{1}ACTION ")
+        .and_then(|t| t.strip_suffix('\u{1}'))
+        .unwrap_or(text);
+
+    let tags = parse_tags(tags);
+    let tag = |name: &str| tags.get(name).cloned().unwrap_or_default();
+
+    let badges = tag("badges")
+        .split(',')
+        .filter_map(|badge| badge.split_once('/'))
+        .map(|(set_id, id)| Badge {
+            set_id: set_id.to_string(),
+            id: id.to_string(),
+            info: String::new(),
+        })
+        .collect();
+
+    let display_name = tag("display-name");
+    Some(ChatMessageEvent {
+        broadcaster_user_id: channel.user_id.clone(),
+        broadcaster_user_login: channel.login.clone(),
+        broadcaster_user_name: channel.name.clone(),
+        chatter_user_id: tag("user-id"),
+        chatter_user_name: if display_name.is_empty() {
+            login.clone()
+        } else {
+            display_name
+        },
+        chatter_user_login: login,
+        message_id: tag("id"),
+        message: Message {
+            text: text.to_string(),
+            fragments: fragments(text, &tag("emotes")),
+        },
+        color: tag("color"),
+        badges,
+        message_type: "text".to_string(),
+        cheer: tag("bits").parse().ok().map(|bits| Cheer { bits }),
+        reply: None,
+        channel_points_custom_reward_id: tags.get("custom-reward-id").cloned(),
+    })
+}
+
+fn parse_tags(tags: &str) -> HashMap<&str, String> {
+    tags.split(';')
+        .filter_map(|tag| tag.split_once('='))
+        .map(|(key, value)| (key, unescape_tag(value)))
+        .collect()
+}
+
+fn unescape_tag(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('s') => result.push(' '),
+            Some(':') => result.push(';'),
+            Some('r') => result.push('\r'),
+            Some('n') => result.push('\n'),
+            Some(other) => result.push(other),
+            None => {}
+        }
+    }
+    result
+}
+
+/// Split message text into text and emote fragments using the `emotes` tag,
+/// e.g. `25:0-4,12-16/1902:6-10` (character positions, inclusive)
+fn fragments(text: &str, emotes: &str) -> Vec<MessageFragment> {
+    let mut ranges: Vec<(usize, usize, &str)> = emotes
+        .split('/')
+        .filter_map(|emote| emote.split_once(':'))
+        .flat_map(|(id, positions)| {
+            positions.split(',').filter_map(move |range| {
+                let (start, end) = range.split_once('-')?;
+                Some((start.parse().ok()?, end.parse().ok()?, id))
+            })
+        })
+        .collect();
+    ranges.sort_unstable();
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut result = Vec::new();
+    let mut position = 0;
+
+    for (start, end, id) in ranges {
+        if start < position || end >= chars.len() {
+            continue;
+        }
+        if start > position {
+            result.push(text_fragment(chars[position..start].iter().collect()));
+        }
+        result.push(MessageFragment {
+            fragment_type: "emote".to_string(),
+            text: chars[start..=end].iter().collect(),
+            cheermote: None,
+            emote: Some(Emote {
+                id: id.to_string(),
+                emote_set_id: String::new(),
+            }),
+            mention: None,
+        });
+        position = end + 1;
+    }
+
+    if position < chars.len() {
+        result.push(text_fragment(chars[position..].iter().collect()));
+    }
+    result
+}
+
+fn text_fragment(text: String) -> MessageFragment {
+    MessageFragment {
+        fragment_type: "text".to_string(),
+        text,
+        cheermote: None,
+        emote: None,
+        mention: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_privmsg() {
+        let channel = IrcChannel {
+            user_id: "1".to_string(),
+            login: "streamer".to_string(),
+            name: "Streamer".to_string(),
+        };
+        let line = "@badges=moderator/1,subscriber/12;display-name=Viewer;emotes=25:6-10;\
+                    id=abc;user-id=42 :viewer!viewer@viewer.tmi.twitch.tv PRIVMSG #streamer :hello Kappa !sfx";
+
+        let msg = parse_privmsg(line, &channel).unwrap();
+        assert_eq!(msg.chatter_user_login, "viewer");
+        assert_eq!(msg.chatter_user_name, "Viewer");
+        assert_eq!(msg.chatter_user_id, "42");
+        assert_eq!(msg.message_id, "abc");
+        assert_eq!(msg.message.text, "hello Kappa !sfx");
+        assert_eq!(msg.badges.len(), 2);
+        assert_eq!(msg.badges[0].set_id, "moderator");
+
+        let fragments: Vec<(&str, &str)> = msg
+            .message
+            .fragments
+            .iter()
+            .map(|f| (f.fragment_type.as_str(), f.text.as_str()))
+            .collect();
+        assert_eq!(
+            fragments,
+            [("text", "hello "), ("emote", "Kappa"), ("text", " !sfx")]
+        );
+
+        assert!(parse_privmsg(":tmi.twitch.tv 001 justinfan48151 :Welcome", &channel).is_none());
+    }
+}
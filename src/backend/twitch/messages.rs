@@ -239,6 +239,681 @@ pub struct ChannelUnbanEvent {
     pub moderator_user_name: String,
 }
 
+/// A single choice in a channel poll
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PollChoice {
+    pub id: String,
+    pub title: String,
+    /// Not present in channel.poll.begin
+    #[serde(default)]
+    pub bits_votes: u32,
+    /// Not present in channel.poll.begin
+    #[serde(default)]
+    pub channel_points_votes: u32,
+    /// Total votes, including bits and channel points votes
+    #[serde(default)]
+    pub votes: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PollVotingSettings {
+    pub is_enabled: bool,
+    pub amount_per_vote: u32,
+}
+
+/// Channel poll begin event
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PollBeginEvent {
+    pub id: String,
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub title: String,
+    pub choices: Vec<PollChoice>,
+    pub bits_voting: PollVotingSettings,
+    pub channel_points_voting: PollVotingSettings,
+    pub started_at: String,
+    pub ends_at: String,
+}
+
+/// Channel poll progress event (sent when votes change)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PollProgressEvent {
+    pub id: String,
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub title: String,
+    pub choices: Vec<PollChoice>,
+    pub bits_voting: PollVotingSettings,
+    pub channel_points_voting: PollVotingSettings,
+    pub started_at: String,
+    pub ends_at: String,
+}
+
+/// Channel poll end event
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PollEndEvent {
+    pub id: String,
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub title: String,
+    pub choices: Vec<PollChoice>,
+    pub bits_voting: PollVotingSettings,
+    pub channel_points_voting: PollVotingSettings,
+    /// One of "completed", "archived" or "terminated"
+    pub status: String,
+    pub started_at: String,
+    pub ended_at: String,
+}
+
+impl PollEndEvent {
+    /// Choice with the most votes, or `None` if nobody voted or the top choices are tied
+    pub fn winner(&self) -> Option<&PollChoice> {
+        let top = self.choices.iter().max_by_key(|c| c.votes)?;
+        let tied = self.choices.iter().filter(|c| c.votes == top.votes).count() > 1;
+        if top.votes == 0 || tied {
+            None
+        } else {
+            Some(top)
+        }
+    }
+}
+
+/// Channel ad break begin event
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AdBreakBeginEvent {
+    pub duration_seconds: u32,
+    pub started_at: String,
+    pub is_automatic: bool,
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub requester_user_id: String,
+    pub requester_user_login: String,
+    pub requester_user_name: String,
+}
+
+/// A top contributor in a hype train
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HypeTrainContribution {
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    /// One of "bits", "subscription" or "other"
+    #[serde(rename = "type")]
+    pub contribution_type: String,
+    pub total: u64,
+}
+
+/// Hype train begin event
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HypeTrainBeginEvent {
+    pub id: String,
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub level: u32,
+    pub total: u64,
+    pub progress: u64,
+    pub goal: u64,
+    #[serde(default)]
+    pub top_contributions: Vec<HypeTrainContribution>,
+    pub started_at: String,
+    pub expires_at: String,
+}
+
+/// Hype train progress event
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HypeTrainProgressEvent {
+    pub id: String,
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub level: u32,
+    pub total: u64,
+    pub progress: u64,
+    pub goal: u64,
+    #[serde(default)]
+    pub top_contributions: Vec<HypeTrainContribution>,
+    pub started_at: String,
+    pub expires_at: String,
+}
+
+/// Hype train end event
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HypeTrainEndEvent {
+    pub id: String,
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub level: u32,
+    pub total: u64,
+    #[serde(default)]
+    pub top_contributions: Vec<HypeTrainContribution>,
+    pub started_at: String,
+    pub ended_at: String,
+    pub cooldown_ends_at: String,
+}
+
+/// Stream online event
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StreamOnlineEvent {
+    pub id: String,
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    /// One of "live", "playlist", "watch_party", "premiere" or "rerun"
+    #[serde(rename = "type")]
+    pub stream_type: String,
+    pub started_at: String,
+}
+
+/// Stream offline event
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StreamOfflineEvent {
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+}
+
+/// Shoutout sent by the channel to another broadcaster
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ShoutoutCreateEvent {
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub moderator_user_id: String,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+    pub to_broadcaster_user_id: String,
+    pub to_broadcaster_user_login: String,
+    pub to_broadcaster_user_name: String,
+    pub viewer_count: u64,
+    pub started_at: String,
+    pub cooldown_ends_at: String,
+    pub target_cooldown_ends_at: String,
+}
+
+/// Shoutout received from another broadcaster
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ShoutoutReceiveEvent {
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub from_broadcaster_user_id: String,
+    pub from_broadcaster_user_login: String,
+    pub from_broadcaster_user_name: String,
+    pub viewer_count: u64,
+    pub started_at: String,
+}
+
+/// Creator goal begin event
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GoalBeginEvent {
+    pub id: String,
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    /// One of "follow", "subscription", "subscription_count", "new_subscription",
+    /// "new_subscription_count", "new_bit" or "new_cheerer"
+    #[serde(rename = "type")]
+    pub goal_type: String,
+    pub description: String,
+    pub current_amount: i64,
+    pub target_amount: i64,
+    pub started_at: String,
+}
+
+/// Creator goal progress event
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GoalProgressEvent {
+    pub id: String,
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    /// One of "follow", "subscription", "subscription_count", "new_subscription",
+    /// "new_subscription_count", "new_bit" or "new_cheerer"
+    #[serde(rename = "type")]
+    pub goal_type: String,
+    pub description: String,
+    pub current_amount: i64,
+    pub target_amount: i64,
+    pub started_at: String,
+}
+
+/// Creator goal end event
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GoalEndEvent {
+    pub id: String,
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    /// One of "follow", "subscription", "subscription_count", "new_subscription",
+    /// "new_subscription_count", "new_bit" or "new_cheerer"
+    #[serde(rename = "type")]
+    pub goal_type: String,
+    pub description: String,
+    pub current_amount: i64,
+    pub target_amount: i64,
+    pub started_at: String,
+    pub is_achieved: bool,
+    pub ended_at: String,
+}
+
+/// Unban request created by a banned user
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UnbanRequestCreateEvent {
+    pub id: String,
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub text: String,
+    pub created_at: String,
+}
+
+/// Unban request approved, denied or canceled
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UnbanRequestResolveEvent {
+    pub id: String,
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    /// Not present when the user canceled the request
+    pub moderator_user_id: Option<String>,
+    pub moderator_user_login: Option<String>,
+    pub moderator_user_name: Option<String>,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub resolution_text: Option<String>,
+    /// One of "approved", "canceled" or "denied"
+    pub status: String,
+}
+
+/// Shield mode activated by a moderator
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ShieldModeBeginEvent {
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub moderator_user_id: String,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+    pub started_at: String,
+}
+
+/// Shield mode deactivated by a moderator
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ShieldModeEndEvent {
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub moderator_user_id: String,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+    pub ended_at: String,
+}
+
+/// Whisper sent to the bot account
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WhisperMessageEvent {
+    pub from_user_id: String,
+    pub from_user_login: String,
+    pub from_user_name: String,
+    pub to_user_id: String,
+    pub to_user_login: String,
+    pub to_user_name: String,
+    pub whisper_id: String,
+    pub whisper: WhisperText,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WhisperText {
+    pub text: String,
+}
+
+impl From<WhisperMessageEvent> for ChatMessageEvent {
+    /// Treat a whisper like a chat message so it can go through the command pipeline.
+    /// Whispers carry no badges, colors or channel, `message_type` is "whisper".
+    fn from(whisper: WhisperMessageEvent) -> Self {
+        ChatMessageEvent {
+            broadcaster_user_id: String::new(),
+            broadcaster_user_login: String::new(),
+            broadcaster_user_name: String::new(),
+            chatter_user_id: whisper.from_user_id,
+            chatter_user_login: whisper.from_user_login,
+            chatter_user_name: whisper.from_user_name,
+            message_id: whisper.whisper_id,
+            message: Message {
+                fragments: vec![MessageFragment {
+                    fragment_type: "text".to_string(),
+                    text: whisper.whisper.text.clone(),
+                    cheermote: None,
+                    emote: None,
+                    mention: None,
+                }],
+                text: whisper.whisper.text,
+            },
+            color: String::new(),
+            badges: Vec::new(),
+            message_type: "whisper".to_string(),
+            cheer: None,
+            reply: None,
+            channel_points_custom_reward_id: None,
+        }
+    }
+}
+
+/// Sub, resub, gift, raid and announcement notices shown in chat
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ChatNotificationEvent {
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub chatter_user_id: String,
+    pub chatter_user_login: String,
+    pub chatter_user_name: String,
+    /// Anonymous gift subs
+    pub chatter_is_anonymous: bool,
+    pub color: String,
+    pub badges: Vec<Badge>,
+    /// Text Twitch shows for the notice, e.g. "x subscribed at Tier 1."
+    pub system_message: String,
+    pub message_id: String,
+    /// Message the user added, e.g. to a resub
+    pub message: Message,
+    pub notice_type: String,
+    pub sub: Option<SubNotice>,
+    pub resub: Option<ResubNotice>,
+    pub sub_gift: Option<SubGiftNotice>,
+    pub community_sub_gift: Option<CommunitySubGiftNotice>,
+    pub raid: Option<RaidNotice>,
+    pub announcement: Option<AnnouncementNotice>,
+    pub bits_badge_tier: Option<BitsBadgeTierNotice>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SubNotice {
+    /// "1000", "2000" or "3000"
+    pub sub_tier: String,
+    pub is_prime: bool,
+    pub duration_months: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ResubNotice {
+    pub cumulative_months: u32,
+    pub duration_months: u32,
+    pub streak_months: Option<u32>,
+    pub sub_tier: String,
+    pub is_prime: bool,
+    pub is_gift: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SubGiftNotice {
+    pub duration_months: u32,
+    /// Not present for anonymous gifts
+    pub cumulative_total: Option<u32>,
+    pub recipient_user_id: String,
+    pub recipient_user_login: String,
+    pub recipient_user_name: String,
+    pub sub_tier: String,
+    /// Set when the gift is part of a community gift
+    pub community_gift_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommunitySubGiftNotice {
+    pub id: String,
+    pub total: u32,
+    pub sub_tier: String,
+    pub cumulative_total: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RaidNotice {
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub viewer_count: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AnnouncementNotice {
+    pub color: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BitsBadgeTierNotice {
+    pub tier: u32,
+}
+
+/// Chat notice parsed from `notice_type` and its matching payload
+#[derive(Debug, Clone)]
+pub enum ChatNotice {
+    Sub(SubNotice),
+    Resub(ResubNotice),
+    SubGift(SubGiftNotice),
+    CommunitySubGift(CommunitySubGiftNotice),
+    Raid(RaidNotice),
+    Announcement(AnnouncementNotice),
+    BitsBadgeTier(BitsBadgeTierNotice),
+    /// Notice types without a typed payload (e.g. pay_it_forward, charity_donation,
+    /// shared chat notices) or with a missing one
+    Other(String),
+}
+
+impl ChatNotificationEvent {
+    pub fn notice(&self) -> ChatNotice {
+        let notice = match self.notice_type.as_str() {
+            "sub" => self.sub.clone().map(ChatNotice::Sub),
+            "resub" => self.resub.clone().map(ChatNotice::Resub),
+            "sub_gift" => self.sub_gift.clone().map(ChatNotice::SubGift),
+            "community_sub_gift" => self
+                .community_sub_gift
+                .clone()
+                .map(ChatNotice::CommunitySubGift),
+            "raid" => self.raid.clone().map(ChatNotice::Raid),
+            "announcement" => self.announcement.clone().map(ChatNotice::Announcement),
+            "bits_badge_tier" => self.bits_badge_tier.clone().map(ChatNotice::BitsBadgeTier),
+            _ => None,
+        };
+        notice.unwrap_or_else(|| ChatNotice::Other(self.notice_type.clone()))
+    }
+}
+
+/// Guest Star session started
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GuestStarSessionBeginEvent {
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub session_id: String,
+    pub started_at: String,
+}
+
+/// Guest Star session ended
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GuestStarSessionEndEvent {
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub session_id: String,
+    pub started_at: String,
+    pub ended_at: String,
+}
+
+/// Guest invited, moved between slots or removed from a Guest Star session
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GuestStarGuestUpdateEvent {
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub session_id: String,
+    pub moderator_user_name: Option<String>,
+    pub guest_user_name: Option<String>,
+    pub slot_id: Option<String>,
+    /// "invited", "accepted", "ready", "backstage", "live" or "removed"
+    pub state: Option<String>,
+}
+
+/// Chat message from a user flagged as suspicious (monitored or restricted)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SuspiciousUserMessageEvent {
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    /// "none", "active_monitoring" or "restricted"
+    pub low_trust_status: String,
+    /// Why the user was flagged, e.g. "ban_evader_detector" or "shared_channel_ban"
+    #[serde(default)]
+    pub types: Vec<String>,
+    /// "unknown", "possible" or "likely"
+    pub ban_evasion_evaluation: String,
+    pub message: Message,
+}
+
+/// Moderator changed the low trust status of a user
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SuspiciousUserUpdateEvent {
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub moderator_user_id: String,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    /// "none", "active_monitoring" or "restricted"
+    pub low_trust_status: String,
+}
+
+/// Amount of a charity donation or campaign total
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CharityAmount {
+    /// Amount in the currency's minor unit, e.g. 1050 with 2 decimal places is 10.50
+    pub value: i64,
+    pub decimal_places: u32,
+    /// ISO 4217 currency code, e.g. "USD"
+    pub currency: String,
+}
+
+impl CharityAmount {
+    /// Amount in the currency's major unit
+    pub fn amount(&self) -> f64 {
+        self.value as f64 / 10f64.powi(self.decimal_places as i32)
+    }
+}
+
+impl std::fmt::Display for CharityAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:.*} {}",
+            self.decimal_places as usize,
+            self.amount(),
+            self.currency
+        )
+    }
+}
+
+/// A viewer donated to the channel's charity campaign
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CharityDonateEvent {
+    pub id: String,
+    pub campaign_id: String,
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub charity_name: String,
+    pub amount: CharityAmount,
+}
+
+/// Charity campaign total changed
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CharityProgressEvent {
+    pub id: String,
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub charity_name: String,
+    pub current_amount: CharityAmount,
+    pub target_amount: CharityAmount,
+}
+
+/// A viewer redeemed a channel point reward
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ChannelPointsRedemptionEvent {
+    pub id: String,
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    /// Text the viewer entered, empty for rewards without user input
+    #[serde(default)]
+    pub user_input: String,
+    /// "unfulfilled", "fulfilled" or "canceled"
+    pub status: String,
+    pub reward: RedeemedReward,
+    pub redeemed_at: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RedeemedReward {
+    pub id: String,
+    pub title: String,
+    pub cost: u64,
+    #[serde(default)]
+    pub prompt: String,
+}
+
+impl From<ChannelPointsRedemptionEvent> for ChatMessageEvent {
+    /// Treat a redemption like a chat message with the viewer's input, so it can run the
+    /// command bound to the reward. `message_type` is "channel_points_redemption".
+    fn from(redemption: ChannelPointsRedemptionEvent) -> Self {
+        ChatMessageEvent {
+            broadcaster_user_id: redemption.broadcaster_user_id,
+            broadcaster_user_login: redemption.broadcaster_user_login,
+            broadcaster_user_name: redemption.broadcaster_user_name,
+            chatter_user_id: redemption.user_id,
+            chatter_user_login: redemption.user_login,
+            chatter_user_name: redemption.user_name,
+            message_id: redemption.id,
+            message: Message {
+                fragments: vec![MessageFragment {
+                    fragment_type: "text".to_string(),
+                    text: redemption.user_input.clone(),
+                    cheermote: None,
+                    emote: None,
+                    mention: None,
+                }],
+                text: redemption.user_input,
+            },
+            color: String::new(),
+            badges: Vec::new(),
+            message_type: "channel_points_redemption".to_string(),
+            cheer: None,
+            reply: None,
+            channel_points_custom_reward_id: Some(redemption.reward.id),
+        }
+    }
+}
+
 /// Events that can be received from Twitch
 #[derive(Debug, Clone)]
 pub enum TwitchEvent {
@@ -249,4 +924,32 @@ pub enum TwitchEvent {
     ChatSettingsUpdate(ChatSettingsUpdateEvent),
     ChannelBan(ChannelBanEvent),
     ChannelUnban(ChannelUnbanEvent),
+    PollBegin(PollBeginEvent),
+    PollProgress(PollProgressEvent),
+    PollEnd(PollEndEvent),
+    AdBreakBegin(AdBreakBeginEvent),
+    HypeTrainBegin(HypeTrainBeginEvent),
+    HypeTrainProgress(HypeTrainProgressEvent),
+    HypeTrainEnd(HypeTrainEndEvent),
+    StreamOnline(StreamOnlineEvent),
+    StreamOffline(StreamOfflineEvent),
+    ShoutoutCreate(ShoutoutCreateEvent),
+    ShoutoutReceive(ShoutoutReceiveEvent),
+    GoalBegin(GoalBeginEvent),
+    GoalProgress(GoalProgressEvent),
+    GoalEnd(GoalEndEvent),
+    UnbanRequestCreate(UnbanRequestCreateEvent),
+    UnbanRequestResolve(UnbanRequestResolveEvent),
+    ShieldModeBegin(ShieldModeBeginEvent),
+    ShieldModeEnd(ShieldModeEndEvent),
+    WhisperMessage(WhisperMessageEvent),
+    ChatNotification(ChatNotificationEvent),
+    GuestStarSessionBegin(GuestStarSessionBeginEvent),
+    GuestStarSessionEnd(GuestStarSessionEndEvent),
+    GuestStarGuestUpdate(GuestStarGuestUpdateEvent),
+    SuspiciousUserMessage(SuspiciousUserMessageEvent),
+    SuspiciousUserUpdate(SuspiciousUserUpdateEvent),
+    CharityDonate(CharityDonateEvent),
+    CharityProgress(CharityProgressEvent),
+    ChannelPointsRedemption(ChannelPointsRedemptionEvent),
 }
@@ -45,15 +45,31 @@ mod auth;
 mod client;
 mod error;
 mod eventsub;
+mod irc;
 mod messages;
+mod rate_limit;
 mod websocket;
 
 // Re-export public types
 pub use auth::{refresh_access_token, validate_token, TokenResponse, CLIENT_ID};
-pub use client::{TwitchClient, TwitchClientEvent, TwitchConfig};
+pub use api::{
+    AdSchedule, AutomodSettings, ChannelInformation, ClipData, CustomRewardData,
+    CustomRewardSettings, GuestStarSession, UnbanRequestData,
+};
+pub use client::{ChatTransport, TwitchClient, TwitchClientEvent, TwitchConfig};
 pub use error::{Result, TwitchError};
+pub use eventsub::{EventSubTransport, SubscriptionData, SubscriptionList};
+pub use rate_limit::without_waiting;
 pub use messages::{
     Badge, ChatMessageEvent, TwitchEvent, MessageDeleteEvent,
     ClearUserMessagesEvent, ChatClearEvent, ChatSettingsUpdateEvent,
-    ChannelBanEvent, ChannelUnbanEvent,
+    ChannelBanEvent, ChannelUnbanEvent, PollChoice, PollBeginEvent, PollProgressEvent,
+    PollEndEvent, AdBreakBeginEvent, HypeTrainContribution, HypeTrainBeginEvent,
+    HypeTrainProgressEvent, HypeTrainEndEvent, StreamOnlineEvent, StreamOfflineEvent,
+    ShoutoutCreateEvent, ShoutoutReceiveEvent, GoalBeginEvent, GoalProgressEvent, GoalEndEvent,
+    UnbanRequestCreateEvent, UnbanRequestResolveEvent, ShieldModeBeginEvent, ShieldModeEndEvent,
+    WhisperMessageEvent, ChatNotice, ChatNotificationEvent, GuestStarSessionBeginEvent,
+    GuestStarSessionEndEvent, GuestStarGuestUpdateEvent, SuspiciousUserMessageEvent,
+    SuspiciousUserUpdateEvent, CharityAmount, CharityDonateEvent, CharityProgressEvent,
+    ChannelPointsRedemptionEvent, RedeemedReward,
 };
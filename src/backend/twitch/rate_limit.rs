@@ -0,0 +1,215 @@
+use reqwest::header::HeaderMap;
+use reqwest::{RequestBuilder, Response, StatusCode};
+use std::future::Future;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::error::{Result, TwitchError};
+
+/// Points left in the bucket when requests start waiting for it to refill, so a few
+/// are kept for requests sent from other places with the same token
+const RESERVE: u32 = 5;
+
+/// Times a rate limited request is sent again before the 429 is returned
+const MAX_RETRIES: u32 = 3;
+
+/// Wait before the first retry when Twitch doesn't say when the bucket refills
+const BASE_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Longest a request waits for the bucket, it refills every minute
+const MAX_WAIT: Duration = Duration::from_secs(60);
+
+/// Rate limit shared by all Helix requests made with the bot's token
+static HELIX_RATE_LIMIT: LazyLock<RateLimiter> = LazyLock::new(RateLimiter::default);
+
+tokio::task_local! {
+    /// Set on the event loop, where requests fail instead of waiting for the bucket
+    static NO_WAIT: ();
+}
+
+/// Run `future` with requests that fail right away when the rate limit is reached,
+/// for the event loop which can't stop handling chat for up to a minute.
+/// Tasks spawned from it wait for their turn as usual.
+pub async fn without_waiting<F: Future>(future: F) -> F::Output {
+    NO_WAIT.scope((), future).await
+}
+
+/// Helix token bucket as reported by the `Ratelimit-*` headers of the last response
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Bucket {
+    remaining: Option<u32>,
+    /// Unix timestamp when the bucket is full again
+    reset_at: Option<u64>,
+}
+
+impl Bucket {
+    fn update(&mut self, headers: &HeaderMap) {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok())
+        };
+        if let Some(remaining) = header("ratelimit-remaining") {
+            self.remaining = Some(u32::try_from(remaining).unwrap_or(u32::MAX));
+        }
+        if let Some(reset_at) = header("ratelimit-reset") {
+            self.reset_at = Some(reset_at);
+        }
+    }
+
+    /// How long to wait before sending at `now`, `None` while points are left
+    fn wait_time(&self, now: u64) -> Option<Duration> {
+        let (remaining, reset_at) = (self.remaining?, self.reset_at?);
+        if remaining > RESERVE || reset_at <= now {
+            return None;
+        }
+        Some(Duration::from_secs(reset_at - now).min(MAX_WAIT))
+    }
+
+    /// Wait before retrying a rate limited request, until the bucket refills or
+    /// with exponential backoff if the reset time is unknown
+    fn retry_delay(&self, attempt: u32, now: u64) -> Duration {
+        match self.reset_at {
+            Some(reset_at) if reset_at > now => Duration::from_secs(reset_at - now).min(MAX_WAIT),
+            _ => BASE_BACKOFF * 2u32.pow(attempt),
+        }
+    }
+}
+
+/// Sends Helix requests within the rate limit. Requests wait in order when the
+/// bucket is nearly empty, and ones answered with 429 are retried once it refills.
+/// Inside `without_waiting` they return an error or the 429 instead.
+#[derive(Default)]
+pub struct RateLimiter {
+    bucket: Mutex<Bucket>,
+    /// Held while waiting for the bucket, so waiting requests go out in order
+    queue: tokio::sync::Mutex<()>,
+}
+
+impl RateLimiter {
+    pub async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        let wait = NO_WAIT.try_with(|_| ()).is_err();
+        let mut request = request;
+        let mut attempt = 0;
+        loop {
+            // Requests with a streamed body can't be cloned and are only sent once
+            let retry = request
+                .try_clone()
+                .filter(|_| wait && attempt < MAX_RETRIES);
+
+            if let Err(wait_time) = self.wait_turn(wait).await {
+                return Err(TwitchError::RateLimitExceeded(format!(
+                    "Helix requests are paused for {}s",
+                    wait_time.as_secs()
+                )));
+            }
+            let response = request.send().await?;
+            let bucket = {
+                let mut bucket = self.bucket.lock().unwrap();
+                bucket.update(response.headers());
+                *bucket
+            };
+
+            // A 429 with points left is an endpoint's own limit like the shoutout cooldown
+            let rate_limited = response.status() == StatusCode::TOO_MANY_REQUESTS
+                && bucket.remaining.is_none_or(|remaining| remaining == 0);
+            match retry {
+                Some(next) if rate_limited => {
+                    let delay = bucket.retry_delay(attempt, unix_now());
+                    log::warn!(
+                        "Helix rate limit reached, retrying in {}s",
+                        delay.as_secs_f32()
+                    );
+                    tokio::time::sleep(delay).await;
+                    request = next;
+                    attempt += 1;
+                }
+                _ => return Ok(response),
+            }
+        }
+    }
+
+    /// Wait until the bucket has points left and take one. Without `wait` this
+    /// returns how long the wait would be instead of sleeping.
+    async fn wait_turn(&self, wait: bool) -> std::result::Result<(), Duration> {
+        // Only requests that may sleep queue up, the others never hold the lock long
+        let _turn = if wait {
+            Some(self.queue.lock().await)
+        } else {
+            None
+        };
+        let wait_time = self.bucket.lock().unwrap().wait_time(unix_now());
+        if let Some(wait_time) = wait_time {
+            if !wait {
+                return Err(wait_time);
+            }
+            log::warn!(
+                "Helix rate limit almost reached, waiting {}s",
+                wait_time.as_secs()
+            );
+            tokio::time::sleep(wait_time).await;
+            self.bucket.lock().unwrap().remaining = None;
+        }
+        if let Some(remaining) = self.bucket.lock().unwrap().remaining.as_mut() {
+            *remaining = remaining.saturating_sub(1);
+        }
+        Ok(())
+    }
+}
+
+/// Send a request through the shared Helix rate limit instead of right away
+pub trait SendRateLimited {
+    fn send_rate_limited(self) -> impl Future<Output = Result<Response>> + Send;
+}
+
+impl SendRateLimited for RequestBuilder {
+    fn send_rate_limited(self) -> impl Future<Output = Result<Response>> + Send {
+        HELIX_RATE_LIMIT.send(self)
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_wait_time() {
+        let mut headers = HeaderMap::new();
+        headers.insert("ratelimit-remaining", "3".parse().unwrap());
+        headers.insert("ratelimit-reset", "1030".parse().unwrap());
+        let mut bucket = Bucket::default();
+        assert_eq!(bucket.wait_time(1000), None);
+        assert_eq!(bucket.retry_delay(2, 1000), Duration::from_secs(4));
+
+        bucket.update(&headers);
+        assert_eq!(bucket.wait_time(1000), Some(Duration::from_secs(30)));
+        assert_eq!(bucket.wait_time(1030), None);
+        assert_eq!(bucket.retry_delay(0, 1000), Duration::from_secs(30));
+
+        bucket.remaining = Some(RESERVE + 1);
+        assert_eq!(bucket.wait_time(1000), None);
+    }
+
+    #[tokio::test]
+    async fn test_wait_turn_without_waiting() {
+        let limiter = RateLimiter::default();
+        *limiter.bucket.lock().unwrap() = Bucket {
+            remaining: Some(RESERVE),
+            reset_at: Some(unix_now() + 30),
+        };
+        let wait_time = limiter.wait_turn(false).await.unwrap_err();
+        assert!(wait_time > Duration::from_secs(28) && wait_time <= Duration::from_secs(30));
+
+        limiter.bucket.lock().unwrap().remaining = Some(RESERVE + 1);
+        assert_eq!(limiter.wait_turn(false).await, Ok(()));
+        assert_eq!(limiter.bucket.lock().unwrap().remaining, Some(RESERVE));
+    }
+}
@@ -211,6 +211,118 @@ impl WebSocketHandler {
                 let unban_event = serde_json::from_value(event)?;
                 Ok(TwitchEvent::ChannelUnban(unban_event))
             }
+            "channel.poll.begin" => {
+                let poll_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::PollBegin(poll_event))
+            }
+            "channel.poll.progress" => {
+                let poll_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::PollProgress(poll_event))
+            }
+            "channel.poll.end" => {
+                let poll_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::PollEnd(poll_event))
+            }
+            "channel.ad_break.begin" => {
+                let ad_break_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::AdBreakBegin(ad_break_event))
+            }
+            "channel.hype_train.begin" => {
+                let hype_train_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::HypeTrainBegin(hype_train_event))
+            }
+            "channel.hype_train.progress" => {
+                let hype_train_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::HypeTrainProgress(hype_train_event))
+            }
+            "channel.hype_train.end" => {
+                let hype_train_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::HypeTrainEnd(hype_train_event))
+            }
+            "stream.online" => {
+                let online_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::StreamOnline(online_event))
+            }
+            "stream.offline" => {
+                let offline_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::StreamOffline(offline_event))
+            }
+            "channel.shoutout.create" => {
+                let shoutout_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::ShoutoutCreate(shoutout_event))
+            }
+            "channel.shoutout.receive" => {
+                let shoutout_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::ShoutoutReceive(shoutout_event))
+            }
+            "channel.goal.begin" => {
+                let goal_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::GoalBegin(goal_event))
+            }
+            "channel.goal.progress" => {
+                let goal_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::GoalProgress(goal_event))
+            }
+            "channel.goal.end" => {
+                let goal_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::GoalEnd(goal_event))
+            }
+            "channel.unban_request.create" => {
+                let request_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::UnbanRequestCreate(request_event))
+            }
+            "channel.unban_request.resolve" => {
+                let request_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::UnbanRequestResolve(request_event))
+            }
+            "channel.shield_mode.begin" => {
+                let shield_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::ShieldModeBegin(shield_event))
+            }
+            "channel.shield_mode.end" => {
+                let shield_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::ShieldModeEnd(shield_event))
+            }
+            "channel.chat.notification" => {
+                let notification_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::ChatNotification(notification_event))
+            }
+            "user.whisper.message" => {
+                let whisper_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::WhisperMessage(whisper_event))
+            }
+            "channel.guest_star_session.begin" => {
+                let session_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::GuestStarSessionBegin(session_event))
+            }
+            "channel.guest_star_session.end" => {
+                let session_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::GuestStarSessionEnd(session_event))
+            }
+            "channel.guest_star_guest.update" => {
+                let guest_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::GuestStarGuestUpdate(guest_event))
+            }
+            "channel.suspicious_user.message" => {
+                let suspicious_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::SuspiciousUserMessage(suspicious_event))
+            }
+            "channel.suspicious_user.update" => {
+                let suspicious_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::SuspiciousUserUpdate(suspicious_event))
+            }
+            "channel.charity_campaign.donate" => {
+                let donate_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::CharityDonate(donate_event))
+            }
+            "channel.charity_campaign.progress" => {
+                let progress_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::CharityProgress(progress_event))
+            }
+            "channel.channel_points_custom_reward_redemption.add" => {
+                let redemption_event = serde_json::from_value(event)?;
+                Ok(TwitchEvent::ChannelPointsRedemption(redemption_event))
+            }
             _ => {
                 log::warn!("Unknown subscription type: {}", subscription_type);
                 Err(TwitchError::JsonError(format!(
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{LazyLock, Mutex};
+
+use crate::backend::storage;
+
+const STORAGE_NAME: &str = "users";
+
+/// Longest nickname accepted
+const MAX_NICKNAME_LENGTH: usize = 25;
+
+/// What the bot remembers about chatters, by login
+static USERS: LazyLock<Mutex<BTreeMap<String, UserEntry>>> =
+    LazyLock::new(|| Mutex::new(storage::load(STORAGE_NAME)));
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct UserEntry {
+    /// Used for `{user}` instead of the login, e.g. for names that are hard to pronounce
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+}
+
+/// Check a nickname before it is stored, returns it trimmed. Braces are rejected so a
+/// nickname can't inject placeholders, a leading `/` or `.` so it can't run chat commands.
+pub fn validate_nickname(nickname: &str) -> Result<String, &'static str> {
+    let nickname = nickname.trim();
+    if nickname.is_empty() || nickname.chars().count() > MAX_NICKNAME_LENGTH {
+        return Err("Nicknames can be 1 to 25 characters long");
+    }
+    if nickname.contains(['{', '}']) || nickname.starts_with(['/', '.']) {
+        return Err("Nicknames can't contain { } or start with / or .");
+    }
+    Ok(nickname.to_string())
+}
+
+pub fn nickname(login: &str) -> Option<String> {
+    USERS
+        .lock()
+        .unwrap()
+        .get(&login.to_lowercase())
+        .and_then(|user| user.nickname.clone())
+}
+
+/// Nickname of the user, or the login if they don't have one
+pub fn display_name(login: &str) -> String {
+    nickname(login).unwrap_or_else(|| login.to_string())
+}
+
+/// Set or remove (`None`) a user's nickname and persist the user store
+pub fn set_nickname(login: &str, nickname: Option<String>) {
+    let mut users = USERS.lock().unwrap();
+    let login = login.trim_start_matches('@').to_lowercase();
+    match nickname {
+        Some(nickname) => {
+            users.entry(login).or_default().nickname = Some(nickname);
+        }
+        None => {
+            if let Some(user) = users.get_mut(&login) {
+                user.nickname = None;
+            }
+            users.retain(|_, user| user.nickname.is_some());
+        }
+    }
+    storage::save(STORAGE_NAME, &*users);
+}
+
+/// Logins and their nicknames, sorted by login
+pub fn nicknames() -> Vec<(String, String)> {
+    USERS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter_map(|(login, user)| Some((login.clone(), user.nickname.clone()?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_nickname() {
+        assert_eq!(validate_nickname("  Ksenia "), Ok("Ksenia".to_string()));
+        assert!(validate_nickname("").is_err());
+        assert!(validate_nickname(&"a".repeat(26)).is_err());
+        assert!(validate_nickname("{args}").is_err());
+        assert!(validate_nickname("/ban someone").is_err());
+        assert!(validate_nickname(".me").is_err());
+    }
+}
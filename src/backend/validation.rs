@@ -0,0 +1,233 @@
+use std::collections::HashSet;
+
+use crate::backend::commands::{check_script, CommandRegistry};
+use crate::backend::config::{AppConfig, RewardAction};
+use crate::backend::locale;
+
+/// Keys the config file may have that aren't part of `AppConfig`
+const IGNORED_KEYS: &[&str] = &["schema_version"];
+
+/// Setting that loads but probably doesn't do what was intended
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigWarning {
+    /// Where the setting is, e.g. "config.toml [sfx]"
+    pub location: String,
+    pub message: String,
+}
+
+impl ConfigWarning {
+    fn new(location: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            location: location.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Check config.toml and commands.toml as loaded at startup
+pub fn check_config(config: &AppConfig, commands: &CommandRegistry) -> Vec<ConfigWarning> {
+    let raw = project_root::get_project_root()
+        .ok()
+        .and_then(|root| std::fs::read_to_string(root.join("config.toml")).ok())
+        .and_then(|content| content.parse::<toml::Table>().ok())
+        .unwrap_or_default();
+    let sounds = crate::backend::sfx::Soundlist::names_on_disk();
+    validate(config, &raw, commands, &sounds)
+}
+
+/// Settings that are unknown, out of range or point at things that don't exist.
+/// `raw` is the config file as written, `sounds` the names of the sound files.
+pub fn validate(
+    config: &AppConfig,
+    raw: &toml::Table,
+    commands: &CommandRegistry,
+    sounds: &HashSet<String>,
+) -> Vec<ConfigWarning> {
+    let mut warnings = Vec::new();
+
+    // Compare against the config as it would be saved, which has every known key
+    if let Ok(toml::Value::Table(known)) = toml::Value::try_from(config) {
+        unknown_keys(raw, &known, "", &mut warnings);
+    }
+
+    for (section, volume) in [("sfx", config.sfx.volume), ("tts", config.tts.volume)] {
+        if !(0.0..=1.0).contains(&volume) {
+            warnings.push(ConfigWarning::new(
+                format!("config.toml [{}]", section),
+                format!("volume is {}, it should be between 0 and 1", volume),
+            ));
+        }
+    }
+
+    if let Err(e) = locale::parse_timezone(&config.ui.timezone) {
+        warnings.push(ConfigWarning::new("config.toml [ui]", e));
+    }
+    if locale::Locale::from_tag(&config.ui.locale).is_none() {
+        warnings.push(ConfigWarning::new(
+            "config.toml [ui]",
+            format!("Unknown locale \"{}\"", config.ui.locale),
+        ));
+    }
+
+    let mut rewards: Vec<_> = config.overlay.reward_bindings.iter().collect();
+    rewards.sort_by_key(|(reward, _)| reward.as_str());
+    for (reward, action) in rewards {
+        let location = format!("config.toml [overlay.reward_bindings] \"{}\"", reward);
+        match action {
+            RewardAction::PlaySound(sound) if !sounds.contains(sound) => {
+                warnings.push(ConfigWarning::new(
+                    location,
+                    format!("Plays the sound \"{}\" which isn't in assets/sounds", sound),
+                ));
+            }
+            RewardAction::SpinWheel { items } if items.is_empty() => {
+                warnings.push(ConfigWarning::new(location, "Spins a wheel without items"));
+            }
+            _ => {}
+        }
+    }
+
+    // Commands are matched before sounds, so a sound with the same name never plays
+    let mut shadowed: Vec<&str> = commands
+        .list()
+        .into_iter()
+        .map(|command| command.trigger.as_str())
+        .filter(|trigger| sounds.contains(*trigger))
+        .collect();
+    shadowed.sort();
+    for trigger in shadowed {
+        warnings.push(ConfigWarning::new(
+            format!("commands.toml !{}", trigger),
+            format!(
+                "The sound \"{}\" can't be played, the command has the same name",
+                trigger
+            ),
+        ));
+    }
+
+    for command in commands.list() {
+        for keyword in &command.keywords {
+            if let Err(e) = keyword.validate() {
+                warnings.push(ConfigWarning::new(
+                    format!("commands.toml !{}", command.trigger),
+                    format!("Keyword \"{}\": {}", keyword.pattern, e),
+                ));
+            }
+        }
+        for sound in command.action.sounds() {
+            if !sounds.contains(sound) {
+                warnings.push(ConfigWarning::new(
+                    format!("commands.toml !{}", command.trigger),
+                    format!("Plays the sound \"{}\" which isn't in assets/sounds", sound),
+                ));
+            }
+        }
+        for source in command.action.scripts() {
+            if let Err(e) = check_script(source) {
+                warnings.push(ConfigWarning::new(
+                    format!("commands.toml !{}", command.trigger),
+                    format!("Script: {}", e),
+                ));
+            }
+        }
+        for condition in command.action.conditions() {
+            if let Err(e) = condition.validate() {
+                warnings.push(ConfigWarning::new(
+                    format!("commands.toml !{}", command.trigger),
+                    format!("Condition: {}", e),
+                ));
+            }
+        }
+        let allowed = &config.run_process.allowed_programs;
+        for program in command.action.programs() {
+            if !allowed.iter().any(|allowed| allowed == program) {
+                warnings.push(ConfigWarning::new(
+                    format!("commands.toml !{}", command.trigger),
+                    format!(
+                        "Runs \"{}\" which isn't in [run_process] allowed_programs",
+                        program
+                    ),
+                ));
+            }
+        }
+    }
+
+    if config.remote.enabled {
+        if config.remote.password.is_empty() {
+            warnings.push(ConfigWarning::new(
+                "config.toml [remote]",
+                "The moderator dashboard needs a password and won't start without one",
+            ));
+        }
+        if config.overlay.enabled && config.remote.port == config.overlay.port {
+            warnings.push(ConfigWarning::new(
+                "config.toml [remote]",
+                format!("port {} is already used by the overlay", config.remote.port),
+            ));
+        }
+    }
+
+    warnings
+}
+
+/// Warn about keys in `raw` that aren't in `known`, e.g. typos or removed settings
+fn unknown_keys(
+    raw: &toml::Table,
+    known: &toml::Table,
+    path: &str,
+    warnings: &mut Vec<ConfigWarning>,
+) {
+    for (key, value) in raw {
+        if path.is_empty() && IGNORED_KEYS.contains(&key.as_str()) {
+            continue;
+        }
+        let location = if path.is_empty() {
+            "config.toml".to_string()
+        } else {
+            format!("config.toml [{}]", path)
+        };
+        match known.get(key) {
+            None => warnings.push(ConfigWarning::new(
+                location,
+                format!("Unknown setting \"{}\" is ignored", key),
+            )),
+            Some(toml::Value::Table(known_table)) => {
+                if let toml::Value::Table(raw_table) = value {
+                    let path = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", path, key)
+                    };
+                    unknown_keys(raw_table, known_table, &path, warnings);
+                }
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_keys() {
+        let known: toml::Table = "schema = 1\n[sfx]\nvolume = 1.0\n".parse().unwrap();
+        let raw: toml::Table =
+            "schema_version = 1\nvolum = 2\n[sfx]\nvolume = 0.5\nenabeld = true\n"
+                .parse()
+                .unwrap();
+        let mut warnings = Vec::new();
+        unknown_keys(&raw, &known, "", &mut warnings);
+        assert_eq!(
+            warnings,
+            vec![
+                ConfigWarning::new(
+                    "config.toml [sfx]",
+                    "Unknown setting \"enabeld\" is ignored"
+                ),
+                ConfigWarning::new("config.toml", "Unknown setting \"volum\" is ignored"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{LazyLock, Mutex};
+
+use crate::backend::storage;
+
+const STORAGE_NAME: &str = "wheel_history";
+
+/// Oldest spins are dropped past this many
+const MAX_SPINS: usize = 5000;
+
+/// Every wheel spin, oldest first
+pub static WHEEL_HISTORY: LazyLock<Mutex<Vec<WheelSpin>>> =
+    LazyLock::new(|| Mutex::new(storage::load(STORAGE_NAME)));
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WheelSpin {
+    /// Time of the spin in the configured timezone (YYYY-MM-DD HH:MM:SS)
+    pub timestamp: String,
+    pub wheel: String,
+    pub result: String,
+    /// User who triggered the spin, if it wasn't started from the UI
+    pub user: Option<String>,
+    /// Action executed for the result
+    pub action: String,
+    /// Number of segments on the wheel, 0 if unknown
+    #[serde(default)]
+    pub segments: usize,
+}
+
+/// How often a segment was hit on one wheel
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentStats {
+    pub wheel: String,
+    pub result: String,
+    pub hits: u64,
+    /// Share of the wheel's spins that landed on this segment
+    pub share: f32,
+    /// Share a fair wheel would give the segment, if the segment count is known
+    pub expected_share: Option<f32>,
+}
+
+/// Save a spin and persist the history
+pub fn record_spin(spin: WheelSpin) {
+    let mut history = WHEEL_HISTORY.lock().unwrap();
+    history.push(spin);
+    if history.len() > MAX_SPINS {
+        let excess = history.len() - MAX_SPINS;
+        history.drain(..excess);
+    }
+    storage::save(STORAGE_NAME, &*history);
+}
+
+/// Hits per wheel and segment, sorted by wheel and then most hits first
+pub fn segment_stats(spins: &[WheelSpin]) -> Vec<SegmentStats> {
+    let mut wheels: BTreeMap<&str, (u64, usize, BTreeMap<&str, u64>)> = BTreeMap::new();
+    for spin in spins {
+        let (total, segments, hits) = wheels.entry(&spin.wheel).or_default();
+        *total += 1;
+        *segments = (*segments).max(spin.segments);
+        *hits.entry(&spin.result).or_default() += 1;
+    }
+
+    let mut stats = Vec::new();
+    for (wheel, (total, segments, hits)) in wheels {
+        let mut wheel_stats: Vec<SegmentStats> = hits
+            .into_iter()
+            .map(|(result, hits)| SegmentStats {
+                wheel: wheel.to_string(),
+                result: result.to_string(),
+                hits,
+                share: hits as f32 / total as f32,
+                expected_share: (segments > 0).then(|| 1.0 / segments as f32),
+            })
+            .collect();
+        wheel_stats.sort_by_key(|s| std::cmp::Reverse(s.hits));
+        stats.extend(wheel_stats);
+    }
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spin(wheel: &str, result: &str, segments: usize) -> WheelSpin {
+        WheelSpin {
+            timestamp: String::new(),
+            wheel: wheel.to_string(),
+            result: result.to_string(),
+            user: None,
+            action: "nothing".to_string(),
+            segments,
+        }
+    }
+
+    #[test]
+    fn test_segment_stats() {
+        let spins = [
+            spin("Prizes", "A", 4),
+            spin("Prizes", "B", 4),
+            spin("Prizes", "B", 4),
+            spin("Prizes", "B", 0),
+            spin("Bans", "Timeout", 0),
+        ];
+        let stats = segment_stats(&spins);
+
+        let summary: Vec<(&str, &str, u64)> = stats
+            .iter()
+            .map(|s| (s.wheel.as_str(), s.result.as_str(), s.hits))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("Bans", "Timeout", 1),
+                ("Prizes", "B", 3),
+                ("Prizes", "A", 1)
+            ]
+        );
+        assert_eq!(stats[1].share, 0.75);
+        assert_eq!(stats[1].expected_share, Some(0.25));
+        assert_eq!(stats[0].expected_share, None);
+    }
+}
@@ -1,45 +1,74 @@
 use crate::audio::AudioPlaybackSender;
-use crate::backend::commands::{CommandExecutor, CommandParser, CommandRegistry, CommandResult};
+use crate::backend::chat_history::{self, ChatMessage};
+use crate::backend::commands::{
+    cache_created_at, cache_followed_at, cached_created_at, cached_followed_at,
+    cancel_automod_restore, check_clip_command, check_command, check_snooze_ad_command,
+    clear_queued_commands, death_counter_name, describe_automod, fetch_pronouns,
+    format_clip_message, format_deaths, format_followage, format_next_ad, format_uptime,
+    list_commands, parse_automod_command, parse_blocked_term_command, parse_chat_mode_command,
+    parse_death_command, parse_followage_command, parse_giveaway_command, parse_guest_star_command,
+    parse_moderation_command, parse_moderator_command, parse_nick_command, parse_nuke_command,
+    parse_poll_command, parse_prediction_command, parse_quote_command, parse_raid_command,
+    parse_shield_command, parse_stream_info_command, prefetch_json_placeholders, queue_command,
+    record_command_usage, reset_clip_cooldown, schedule_automod_restore, take_due_automod_restore,
+    take_ready_commands, AutomodCommand, BlockedTermCommand, CommandExecutor, CommandExport,
+    CommandParser, CommandRegistry, CommandResult, CommandWorkers, FailureFeedback, FinishedWork,
+    GuestStarCommand, ImportMode, ModerationCommand, ModeratorCommand, NickCommand, PollCommand,
+    PredictionCommand, ProcessRequest, QueueRejection, QuoteCommand, RaidCommand, ShoutoutTarget,
+    StreamInfoCommand, UserInfo, ACCOUNT_AGE_PLACEHOLDER, ACTION_SEPARATOR, ADD_QUOTE_COMMAND,
+    AUTOMOD_COMMAND, BAN_COMMAND, BLOCK_TERM_COMMAND, CHAT_MODE_COMMANDS, CLIP_COMMAND,
+    COMMANDS_COMMAND, COMMANDS_EXPORT_FILE, DEATHS_COMMAND, DEATHS_WIDGET, DEATH_ADD_COMMAND,
+    DEL_QUOTE_COMMAND, FOLLOWAGE_COMMAND, FOLLOW_AGE_PLACEHOLDER, GIVEAWAY_COMMAND,
+    GUEST_STAR_COMMAND, MOD_COMMAND, NICK_COMMAND, NUKE_COMMAND, OUTCOME_COMMAND, POLL_COMMAND,
+    PREDICTION_COMMAND, PRONOUNS_PLACEHOLDER, QUOTE_COMMAND, RAID_COMMAND, RESET_DEATHS_COMMAND,
+    SHIELD_COMMAND, SNOOZE_AD_COMMAND, STREAM_INFO_COMMANDS, TIMEOUT_COMMAND, UNBAN_COMMAND,
+    UNBLOCK_TERM_COMMAND, UNMOD_COMMAND, UPTIME_COMMAND,
+};
 use crate::backend::config::AppConfig;
+use crate::backend::engagement::{EngagementAction, SilenceDetector};
+use crate::backend::events::{self, BotEvent};
+use crate::backend::firsts;
+use crate::backend::link_requests;
+use crate::backend::quotes;
+use crate::backend::roles::{UserIdentity, UserRole};
+use crate::backend::stream_state::{active_features, shield_mode_restricts};
+use crate::backend::timers::{self, TimerScheduler};
 use crate::backend::tts::{
     LanguageConfig, TTSAudioChunk, TTSQueue, TTSQueueItem, TTSRequest, TTSService,
 };
-use crate::backend::twitch::{TwitchClient, TwitchClientEvent, TwitchConfig};
+use crate::backend::twitch::{
+    without_waiting, ChatTransport, TwitchClient, TwitchClientEvent, TwitchConfig,
+};
+use crate::backend::users;
 use crate::ui::{
-    BackendToFrontendMessage, ChatbotConfig, Config, FrontendToBackendMessage, LogLevel,
-    TTSQueueItemUI,
+    BackendToFrontendMessage, ChatbotConfig, Config, EventSubSubscriptionUI,
+    EventSubSubscriptionsUI, FrontendToBackendMessage, GuestStarGuestUI, LogLevel,
+    SuspiciousUserUI, TTSQueueItemUI, UnbanRequestUI,
 };
 use log::{error, info};
-use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct ChatMessage {
-    pub message_id: String,
-    pub message_text: String,
-    pub badges: Vec<String>,
-    pub username: String,
-    pub user_id: String,
-    pub color: String,
-}
-
-impl From<crate::backend::twitch::ChatMessageEvent> for ChatMessage {
-    fn from(msg: crate::backend::twitch::ChatMessageEvent) -> Self {
-        let badges = msg
-            .badges
-            .into_iter()
-            .map(|badge| format!("{}-{}", badge.set_id, badge.id))
-            .collect();
+/// Per-stream state, reset when the stream goes live
+#[derive(Default)]
+struct StreamSession {
+    live: bool,
+    chatters: HashSet<String>,
+    message_count: u64,
+    /// Roles last seen in chat by user ID, kept across streams. Whispers have no badges.
+    identities: HashMap<String, UserIdentity>,
+    silence: SilenceDetector,
+    timers: TimerScheduler,
+}
 
-        ChatMessage {
-            message_id: msg.message_id,
-            message_text: msg.message.text,
-            badges,
-            username: msg.chatter_user_login,
-            user_id: msg.chatter_user_id,
-            color: msg.color,
-        }
+impl StreamSession {
+    fn record_message(&mut self, user_login: &str) {
+        self.message_count += 1;
+        self.chatters.insert(user_login.to_string());
+        self.silence.record_message(Instant::now());
+        self.timers.record_message();
     }
 }
 
@@ -51,10 +80,16 @@ pub async fn handle_twitch_messages(
     tts_queue: TTSQueue,
     tts_service: Arc<TTSService>,
     language_config: Arc<RwLock<LanguageConfig>>,
+    overlay_ws_state: crate::backend::overlay::WebSocketState,
     welcome_message: Option<String>,
+    mut request_rx: tokio::sync::mpsc::Receiver<TwitchRequest>,
 ) {
-    // TODO: add messages to local db
-    let mut messages: Vec<ChatMessage> = Vec::new();
+    let mut session = StreamSession::default();
+    firsts::reset();
+    clear_queued_commands();
+    command_registry.write().await.reset_stream_uses();
+    // Commands waiting for external data, canceled when the bot disconnects
+    let mut command_workers = CommandWorkers::default();
     let command_parser = CommandParser::with_default_prefix();
 
     // Create event channel
@@ -77,6 +112,34 @@ pub async fn handle_twitch_messages(
                 ))
                 .await;
 
+            // Load BTTV/FFZ/7TV emotes for emote stats in the background
+            if let Some(broadcaster_id) = client.broadcaster_user_id().cloned() {
+                let backend_tx_emotes = backend_tx.clone();
+                tokio::spawn(async move {
+                    let count =
+                        crate::backend::emotes::load_third_party_emotes(&broadcaster_id).await;
+                    let _ = backend_tx_emotes
+                        .send(BackendToFrontendMessage::CreateLog(
+                            LogLevel::INFO,
+                            format!("Loaded {} third-party emotes", count),
+                        ))
+                        .await;
+                });
+            }
+
+            load_pending_unban_requests(&client, &backend_tx).await;
+            load_shield_mode_status(&client, &backend_tx).await;
+            load_eventsub_subscriptions(&client, &backend_tx).await;
+            load_guest_star_session(&client, &backend_tx).await;
+            load_blocked_terms(&client, &backend_tx).await;
+            load_stream_info(&client, &backend_tx).await;
+            if crate::backend::config::load_config()
+                .rewards
+                .sync_on_connect
+            {
+                sync_channel_rewards(&client, &backend_tx).await;
+            }
+
             // Send welcome message if configured
             if let Some(ref msg) = welcome_message {
                 send_welcome_message(&mut client, msg, &backend_tx).await;
@@ -98,477 +161,3774 @@ pub async fn handle_twitch_messages(
         }
     }
 
-    // Handle incoming events
-    while let Some(event) = rx.recv().await {
-        handle_twitch_event(
-            event,
-            &mut messages,
-            &backend_tx,
-            &mut client,
-            &audio_tx,
-            &command_registry,
-            &command_parser,
-            &tts_queue,
-            &tts_service,
-            &language_config,
-        )
-        .await;
+    let mut engagement_timer = tokio::time::interval(ENGAGEMENT_CHECK_INTERVAL);
+    let mut audience_timer = tokio::time::interval(AUDIENCE_POLL_INTERVAL);
+    // The audience is polled in the background, the requests can take a while near
+    // the rate limit. A poll still running when the next one is due is left alone.
+    let mut audience_poll: Option<tokio::task::JoinHandle<()>> = None;
+    let mut automod_timer = tokio::time::interval(AUTOMOD_RESTORE_CHECK_INTERVAL);
+    let mut timers_timer = tokio::time::interval(TIMERS_CHECK_INTERVAL);
+    let mut queue_timer = tokio::time::interval(COMMAND_QUEUE_CHECK_INTERVAL);
+
+    // Handle incoming events and requests from the UI
+    loop {
+        tokio::select! {
+            event = rx.recv() => {
+                let Some(event) = event else {
+                    break;
+                };
+                handle_twitch_event(
+                    event,
+                    &mut session,
+                    &mut command_workers,
+                    &backend_tx,
+                    &mut client,
+                    &audio_tx,
+                    &command_registry,
+                    &command_parser,
+                    &tts_queue,
+                    &tts_service,
+                    &language_config,
+                    &overlay_ws_state,
+                )
+                .await;
+            }
+            Some(request) = request_rx.recv() => {
+                handle_twitch_request(request, &client, &backend_tx).await;
+            }
+            finished = command_workers.next_finished() => match finished {
+                FinishedWork::Fetched(context, true) => {
+                    handle_command(
+                        context,
+                        &mut command_workers,
+                        &command_registry,
+                        &mut client,
+                        &backend_tx,
+                        &audio_tx,
+                        &overlay_ws_state,
+                        &tts_queue,
+                        &tts_service,
+                    )
+                    .await;
+                }
+                FinishedWork::Fetched(context, false) => {
+                    let _ = backend_tx
+                        .send(BackendToFrontendMessage::CreateLog(
+                            LogLevel::WARN,
+                            format!(
+                                "!{} timed out waiting for external data",
+                                context.command_name
+                            ),
+                        ))
+                        .await;
+                }
+                FinishedWork::Process(context, request, result) => {
+                    reply_process_output(&context, &request, result, &mut client, &backend_tx)
+                        .await;
+                }
+            },
+            _ = engagement_timer.tick() => {
+                run_engagement_check(&mut session, &client, &backend_tx).await;
+            }
+            _ = audience_timer.tick() => {
+                if audience_poll.as_ref().is_none_or(|poll| poll.is_finished()) {
+                    audience_poll = Some(spawn_audience_poll(&client, &backend_tx));
+                }
+            }
+            _ = automod_timer.tick() => {
+                restore_automod_settings(&client, &backend_tx).await;
+            }
+            _ = timers_timer.tick() => {
+                run_timers(&mut session, &client, &backend_tx).await;
+            }
+            _ = queue_timer.tick() => {
+                for context in take_ready_commands() {
+                    dispatch_command(
+                        context,
+                        &mut command_workers,
+                        &command_registry,
+                        &mut client,
+                        &backend_tx,
+                        &audio_tx,
+                        &overlay_ws_state,
+                        &tts_queue,
+                        &tts_service,
+                    )
+                    .await;
+                }
+            }
+        }
+    }
+
+    if let Some(poll) = audience_poll {
+        poll.abort();
     }
 }
 
-async fn send_welcome_message(
-    client: &mut TwitchClient,
-    msg: &str,
+/// How often commands waiting for a cooldown are checked
+const COMMAND_QUEUE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How often the viewer count and chatters list are refreshed
+const AUDIENCE_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Run `poll_audience` and `poll_ad_schedule` off the event loop, they send their
+/// results to the UI when they're done
+fn spawn_audience_poll(
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> tokio::task::JoinHandle<()> {
+    let client = client.detached();
+    let backend_tx = backend_tx.clone();
+    tokio::spawn(async move {
+        poll_audience(&client, &backend_tx).await;
+        poll_ad_schedule(&client, &backend_tx).await;
+    })
+}
+
+/// Refresh the viewer count, chatters, uptime and follower count for `!viewers`,
+/// command variables and the HOME tab.
+/// Chatters need moderator:read:chatters, without it only the viewer count is shown.
+async fn poll_audience(
+    client: &TwitchClient,
     backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
 ) {
-    if !msg.trim().is_empty() {
-        info!("Attempting to send welcome message: {}", msg);
+    let previous = crate::backend::audience::current().unwrap_or_default();
+    let (viewers, started_at) = match client.get_stream().await {
+        Ok(stream) => (
+            stream.as_ref().map(|stream| stream.viewer_count),
+            stream.map(|stream| stream.started_at),
+        ),
+        Err(e) => {
+            log::debug!("Failed to load viewer count: {}", e);
+            (previous.viewers, previous.started_at)
+        }
+    };
+    let chatters = client.get_chatters().await.unwrap_or_else(|e| {
+        log::debug!("Failed to load chatters: {}", e);
+        Vec::new()
+    });
+    let followers = match client.get_follower_count().await {
+        Ok(followers) => Some(followers),
+        Err(e) => {
+            log::debug!("Failed to load follower count: {}", e);
+            previous.followers
+        }
+    };
 
-        // Wait a moment for subscriptions to settle
-        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+    let audience = crate::backend::audience::Audience {
+        viewers,
+        chatters,
+        started_at,
+        followers,
+    };
+    crate::backend::audience::update(Some(audience.clone()));
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::AudienceUpdated(Some(audience)))
+        .await;
+}
 
-        match client.send_message(msg).await {
-            Ok(_) => {
-                info!("Welcome message sent successfully");
-                let _ = backend_tx
-                    .send(BackendToFrontendMessage::CreateLog(
-                        LogLevel::INFO,
-                        format!("✓ Sent welcome message: {}", msg),
-                    ))
-                    .await;
-            }
-            Err(e) => {
-                error!("Failed to send welcome message: {}", e);
-                let error_str = e.to_string();
+/// Refresh the next ad break shown in the HOME tab. Needs channel:read:ads on the
+/// broadcaster's token, without it nothing is shown.
+async fn poll_ad_schedule(
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let schedule = client
+        .get_ad_schedule()
+        .await
+        .map_err(|e| log::debug!("Failed to load ad schedule: {}", e))
+        .ok();
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::AdScheduleUpdated(schedule))
+        .await;
+}
 
-                let user_msg = if error_str.contains("403") || error_str.contains("Forbidden") {
-                    format!("❌ Cannot send welcome message - Missing OAuth scope 'user:write:chat'. Please re-authorize with write permissions.")
-                } else {
-                    format!("❌ Failed to send welcome message: {}", e)
-                };
+/// Snooze the next ad and show the new schedule, returns it formatted for chat
+async fn snooze_next_ad(
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> Result<String, String> {
+    let schedule = client.snooze_next_ad().await.map_err(|e| e.to_string())?;
+    let message = format_next_ad(&schedule, chrono::Utc::now());
+    // The snooze response has no ad duration, reload the full schedule
+    poll_ad_schedule(client, backend_tx).await;
+    Ok(message)
+}
 
-                let _ = backend_tx
-                    .send(BackendToFrontendMessage::CreateLog(
-                        LogLevel::ERROR,
-                        user_msg,
-                    ))
-                    .await;
-            }
-        }
+/// `!snoozead` - push the next ad break back
+async fn run_snooze_ad_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> CommandResult {
+    if let Err(result) = check_snooze_ad_command(context) {
+        return result;
+    }
+
+    match snooze_next_ad(client, backend_tx).await {
+        Ok(message) => CommandResult::Success(Some(format!(
+            "reply:{}:Ad snoozed. {}",
+            context.message_id(),
+            message
+        ))),
+        Err(e) => CommandResult::Error(format!("Failed to snooze the next ad: {}", e)),
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-async fn handle_twitch_event(
-    event: TwitchClientEvent,
-    messages: &mut Vec<ChatMessage>,
-    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
-    client: &mut TwitchClient,
-    audio_tx: &AudioPlaybackSender,
-    command_registry: &Arc<RwLock<CommandRegistry>>,
-    command_parser: &CommandParser,
-    tts_queue: &TTSQueue,
-    tts_service: &Arc<TTSService>,
-    language_config: &Arc<RwLock<LanguageConfig>>,
-) {
-    match event {
-        TwitchClientEvent::Connected => {
-            let _ = backend_tx
-                .send(BackendToFrontendMessage::CreateLog(
-                    LogLevel::INFO,
-                    "EventSub ready - listening for chat events".to_string(),
-                ))
-                .await;
+/// How often to check whether a temporary AutoMod level ran out
+const AUTOMOD_RESTORE_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+/// `!automod [level] [minutes]` - show or change the AutoMod level
+async fn run_automod_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+) -> CommandResult {
+    let command = match parse_automod_command(context) {
+        Ok(command) => command,
+        Err(result) => return result,
+    };
+
+    let result = match command {
+        AutomodCommand::Show => client
+            .get_automod_settings()
+            .await
+            .map(|settings| describe_automod(&settings)),
+        AutomodCommand::SetLevel { level, minutes } => {
+            set_automod_level(level, minutes, client).await
+        }
+    };
+    match result {
+        Ok(message) => {
+            CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), message)))
         }
+        Err(e) => CommandResult::Error(format!("Failed to change AutoMod: {}", e)),
+    }
+}
 
-        TwitchClientEvent::ChatEvent(chat_event) => match chat_event {
-            crate::backend::twitch::TwitchEvent::ChatMessage(msg) => {
-                let chat_message: ChatMessage = msg.clone().into();
+/// Set the overall AutoMod level, and put the current settings back after `minutes`
+async fn set_automod_level(
+    level: u8,
+    minutes: Option<u64>,
+    client: &TwitchClient,
+) -> crate::backend::twitch::Result<String> {
+    let previous = client.get_automod_settings().await?;
+    let settings = crate::backend::twitch::AutomodSettings {
+        overall_level: Some(level),
+        ..previous.clone()
+    };
+    let settings = client.update_automod_settings(&settings).await?;
 
-                // Check if message is a TTS command
-                if handle_tts_command(&msg, tts_queue, tts_service, language_config, backend_tx)
-                    .await
-                {
-                    messages.push(chat_message);
-                    return;
-                }
+    Ok(match minutes {
+        Some(minutes) => {
+            schedule_automod_restore(previous, Duration::from_secs(minutes * 60));
+            format!(
+                "{} for the next {} min",
+                describe_automod(&settings),
+                minutes
+            )
+        }
+        None => {
+            cancel_automod_restore();
+            describe_automod(&settings)
+        }
+    })
+}
 
-                // Check if message is a command
-                if let Some(context) = command_parser.parse(msg.clone()) {
-                    handle_command(context, command_registry, client, backend_tx, audio_tx).await;
-                }
+/// Put the AutoMod settings back once a temporary level ran out
+async fn restore_automod_settings(
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let Some(previous) = take_due_automod_restore() else {
+        return;
+    };
+    let log = match client.update_automod_settings(&previous).await {
+        Ok(settings) => (
+            LogLevel::INFO,
+            format!(
+                "Temporary AutoMod level ended, {}",
+                describe_automod(&settings)
+            ),
+        ),
+        Err(e) => (
+            LogLevel::ERROR,
+            format!("Failed to restore the AutoMod level: {}", e),
+        ),
+    };
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(log.0, log.1))
+        .await;
+}
 
-                messages.push(chat_message);
-            }
+/// How often to check whether chat has gone quiet
+const ENGAGEMENT_CHECK_INTERVAL: Duration = Duration::from_secs(30);
 
-            crate::backend::twitch::TwitchEvent::MessageDelete(delete) => {
-                info!(
-                    "Message {} from {} was deleted",
-                    delete.message_id, delete.target_user_name
-                );
-            }
+/// Prompt chat with a question or a quick poll when it has been quiet for too long
+async fn run_engagement_check(
+    session: &mut StreamSession,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let config = crate::backend::config::load_config().engagement;
+    let Some(action) = session.silence.check(&config, session.live, Instant::now()) else {
+        return;
+    };
 
-            crate::backend::twitch::TwitchEvent::ClearUserMessages(clear) => {
-                info!(
-                    "Messages from {} were cleared (ban/timeout)",
-                    clear.target_user_name
-                );
-            }
+    let (result, description) = match action {
+        EngagementAction::Question(prompt) => (
+            client.send_message(&prompt).await,
+            format!("asked: {}", prompt),
+        ),
+        EngagementAction::Poll(poll) => (
+            client
+                .create_poll(&poll.title, &poll.choices, config.poll_duration)
+                .await
+                .map(|_| ()),
+            format!("started poll: {}", poll.title),
+        ),
+    };
 
-            crate::backend::twitch::TwitchEvent::ChatClear(clear) => {
-                info!(
-                    "Chat was cleared in {}'s channel",
-                    clear.broadcaster_user_name
-                );
-            }
+    let log = match result {
+        Ok(()) => (LogLevel::INFO, format!("Chat went quiet, {}", description)),
+        Err(e) => (
+            LogLevel::ERROR,
+            format!("Failed to prompt quiet chat ({}): {}", description, e),
+        ),
+    };
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(log.0, log.1))
+        .await;
+}
 
-            crate::backend::twitch::TwitchEvent::ChatSettingsUpdate(settings) => {
-                info!(
-                    "Chat settings updated: slow_mode={}, sub_only={}",
-                    settings.slow_mode, settings.subscriber_mode
-                );
-            }
+/// How often to check whether a timed message is due
+const TIMERS_CHECK_INTERVAL: Duration = Duration::from_secs(30);
 
-            crate::backend::twitch::TwitchEvent::ChannelBan(ban) => {
-                handle_ban_event(&ban, backend_tx).await;
-            }
+/// Post the next timed message once its interval passed and chat was active enough
+async fn run_timers(
+    session: &mut StreamSession,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let timers = timers::list();
+    let Some(message) = session.timers.check(&timers, session.live, Instant::now()) else {
+        return;
+    };
 
-            crate::backend::twitch::TwitchEvent::ChannelUnban(unban) => {
-                info!(
-                    "✅ {} was unbanned by {}",
-                    unban.user_name, unban.moderator_user_name
-                );
+    if let Err(e) = client.send_message(&message).await {
+        let _ = backend_tx
+            .send(BackendToFrontendMessage::CreateLog(
+                LogLevel::ERROR,
+                format!("Failed to send timed message: {}", e),
+            ))
+            .await;
+    }
+}
+
+/// Requests from the UI that need the connected Twitch client
+#[derive(Debug)]
+pub enum TwitchRequest {
+    ResolveUnbanRequest {
+        id: String,
+        user_name: String,
+        approve: bool,
+        resolution_text: String,
+    },
+    ListEventSubSubscriptions,
+    GuestStar(GuestStarCommand),
+    LoadGuestStarSession,
+    BlockedTerm(BlockedTermCommand),
+    LoadBlockedTerms,
+    UpdateStreamInfo {
+        title: Option<String>,
+        game: Option<String>,
+    },
+    LoadStreamInfo,
+    RemoteAction(crate::backend::remote::RemoteAction),
+    SetShieldMode(bool),
+    SyncRewards,
+    SnoozeNextAd,
+}
 
+async fn handle_twitch_request(
+    request: TwitchRequest,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    match request {
+        TwitchRequest::ResolveUnbanRequest {
+            id,
+            user_name,
+            approve,
+            resolution_text,
+        } => match client
+            .resolve_unban_request(&id, approve, &resolution_text)
+            .await
+        {
+            Ok(()) => {
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::UnbanRequestResolved(id))
+                    .await;
                 let _ = backend_tx
                     .send(BackendToFrontendMessage::CreateLog(
                         LogLevel::INFO,
                         format!(
-                            "{} was unbanned by {}",
-                            unban.user_name, unban.moderator_user_name
+                            "{} unban request from {}",
+                            if approve { "Approved" } else { "Denied" },
+                            user_name
                         ),
                     ))
                     .await;
             }
+            Err(e) => {
+                error!("Failed to resolve unban request {}: {}", id, e);
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::ERROR,
+                        format!("Failed to resolve unban request from {}: {}", user_name, e),
+                    ))
+                    .await;
+            }
         },
-
-        TwitchClientEvent::TokensRefreshed(access_token, refresh_token) => {
-            // Load current config
-            let mut current_config = crate::backend::config::load_config();
-
-            // Update tokens
-            current_config.chatbot.auth_token = access_token;
-            current_config.chatbot.refresh_token = refresh_token;
-
-            // Save updated config
-            crate::backend::config::save_config(&current_config);
-        }
-
-        TwitchClientEvent::Disconnected => {
-            let _ = backend_tx
-                .send(BackendToFrontendMessage::ConnectionFailure(
-                    "Disconnected".to_string(),
-                ))
-                .await;
-            let _ = backend_tx
-                .send(BackendToFrontendMessage::CreateLog(
-                    LogLevel::ERROR,
-                    "Disconnected from Twitch".to_string(),
-                ))
-                .await;
+        TwitchRequest::ListEventSubSubscriptions => {
+            load_eventsub_subscriptions(client, backend_tx).await;
         }
-
-        TwitchClientEvent::Warning(w) => {
-            let _ = backend_tx
-                .send(BackendToFrontendMessage::CreateLog(LogLevel::WARN, w))
-                .await;
+        TwitchRequest::GuestStar(command) => {
+            match execute_guest_star_command(&command, client).await {
+                Ok(()) => {
+                    let _ = backend_tx
+                        .send(BackendToFrontendMessage::CreateLog(
+                            LogLevel::INFO,
+                            command.describe(),
+                        ))
+                        .await;
+                }
+                Err(e) => {
+                    error!("Guest Star action failed: {}", e);
+                    let _ = backend_tx
+                        .send(BackendToFrontendMessage::CreateLog(
+                            LogLevel::ERROR,
+                            format!("Guest Star action failed: {}", e),
+                        ))
+                        .await;
+                }
+            }
+            load_guest_star_session(client, backend_tx).await;
         }
-
-        TwitchClientEvent::Error(e) => {
+        TwitchRequest::LoadGuestStarSession => {
+            load_guest_star_session(client, backend_tx).await;
+        }
+        TwitchRequest::BlockedTerm(command) => {
+            let log = match execute_blocked_term_command(&command, client).await {
+                Ok(()) => (LogLevel::INFO, command.describe()),
+                Err(e) => {
+                    error!("Failed to change blocked terms: {}", e);
+                    (
+                        LogLevel::ERROR,
+                        format!("Failed to change blocked terms: {}", e),
+                    )
+                }
+            };
             let _ = backend_tx
-                .send(BackendToFrontendMessage::CreateLog(
+                .send(BackendToFrontendMessage::CreateLog(log.0, log.1))
+                .await;
+            load_blocked_terms(client, backend_tx).await;
+        }
+        TwitchRequest::LoadBlockedTerms => {
+            load_blocked_terms(client, backend_tx).await;
+        }
+        TwitchRequest::UpdateStreamInfo { title, game } => {
+            match client
+                .update_channel_information(title.as_deref(), game.as_deref())
+                .await
+            {
+                Ok(_) => {
+                    let _ = backend_tx
+                        .send(BackendToFrontendMessage::CreateLog(
+                            LogLevel::INFO,
+                            "Stream info updated".to_string(),
+                        ))
+                        .await;
+                }
+                Err(e) => {
+                    error!("Failed to update stream info: {}", e);
+                    let _ = backend_tx
+                        .send(BackendToFrontendMessage::CreateLog(
+                            LogLevel::ERROR,
+                            format!("Failed to update stream info: {}", e),
+                        ))
+                        .await;
+                }
+            }
+            load_stream_info(client, backend_tx).await;
+        }
+        TwitchRequest::LoadStreamInfo => {
+            load_stream_info(client, backend_tx).await;
+        }
+        TwitchRequest::RemoteAction(action) => {
+            run_remote_action(action, client, backend_tx).await;
+        }
+        TwitchRequest::SetShieldMode(active) => {
+            if let Err(e) = update_shield_mode(active, client, backend_tx).await {
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::ERROR,
+                        format!("Failed to change shield mode: {}", e),
+                    ))
+                    .await;
+            }
+        }
+        TwitchRequest::SyncRewards => {
+            sync_channel_rewards(client, backend_tx).await;
+        }
+        TwitchRequest::SnoozeNextAd => {
+            let log = match snooze_next_ad(client, backend_tx).await {
+                Ok(message) => (LogLevel::INFO, format!("Snoozed the next ad. {}", message)),
+                Err(e) => (
                     LogLevel::ERROR,
-                    format!("Twitch error: {}", e),
-                ))
+                    format!("Failed to snooze the next ad: {}", e),
+                ),
+            };
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::CreateLog(log.0, log.1))
                 .await;
         }
     }
 }
 
-async fn handle_tts_command(
-    msg: &crate::backend::twitch::ChatMessageEvent,
-    tts_queue: &TTSQueue,
-    tts_service: &Arc<TTSService>,
-    language_config: &Arc<RwLock<LanguageConfig>>,
+/// Bring the channel point rewards created by the bot in line with `[rewards]`
+async fn sync_channel_rewards(
+    client: &TwitchClient,
     backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
-) -> bool {
-    let message_text = msg.message.text.trim().to_lowercase();
-    if message_text.starts_with('!') && message_text.len() > 1 {
-        let parts: Vec<&str> = message_text.splitn(2, ' ').collect();
-        if parts.len() == 2 {
-            let potential_lang_code = &parts[0][1..]; // Remove the '!' prefix
-            let tts_text = parts[1];
-
-            // Check if this is a valid language code
-            let lang_config = language_config.read().await;
-            if let Some(language) = lang_config.get_language(potential_lang_code) {
-                if language.enabled {
-                    // Check TTS config and permissions
-                    let config = crate::backend::config::load_config();
-                    if config.tts.enabled {
-                        // Check user permissions
-                        let has_permission = msg.badges.iter().any(|badge| {
-                            (badge.set_id == "subscriber" || badge.set_id == "founder")
-                                && config.tts.permited_roles.subs
-                                || badge.set_id == "vip" && config.tts.permited_roles.vips
-                                || badge.set_id == "moderator" && config.tts.permited_roles.mods
-                                || badge.set_id == "broadcaster"
-                        });
-
-                        if !has_permission {
-                            return true;
-                        }
-                        if tts_queue.is_user_ignored(&msg.chatter_user_login).await {
-                            return true;
-                        }
+) {
+    let config = crate::backend::config::load_config().rewards;
+    let log = match apply_reward_changes(&config.rewards, client).await {
+        Ok(0) => (
+            LogLevel::INFO,
+            "Channel point rewards are up to date".to_string(),
+        ),
+        Ok(count) => (
+            LogLevel::INFO,
+            format!("Synced channel point rewards, {} changed", count),
+        ),
+        Err(e) => (
+            LogLevel::ERROR,
+            format!("Failed to sync channel point rewards: {}", e),
+        ),
+    };
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(log.0, log.1))
+        .await;
+}
 
-                        let tts_request = TTSRequest {
-                            id: msg.message_id.clone(),
-                            username: msg.chatter_user_login.clone(),
-                            language: potential_lang_code.to_string(),
-                            text: tts_text.to_string(),
-                            timestamp: chrono::Utc::now(),
-                        };
+/// Create, update and delete rewards, returns the number of changes
+async fn apply_reward_changes(
+    rewards: &[crate::backend::rewards::ManagedReward],
+    client: &TwitchClient,
+) -> crate::backend::twitch::Result<usize> {
+    use crate::backend::rewards::RewardChange;
 
-                        // Generate TTS files asynchronously
-                        spawn_tts_generation(
-                            tts_request,
-                            tts_service.clone(),
-                            tts_queue.clone(),
-                            backend_tx.clone(),
-                        );
-                    }
-                }
-                // If it's a valid language code, don't process as regular command
-                return true;
+    let existing = client.get_managed_rewards().await?;
+    let changes = crate::backend::rewards::plan_sync(rewards, &existing);
+    for change in &changes {
+        match change {
+            RewardChange::Create(settings) => {
+                client.create_reward(settings).await?;
+                info!("Created channel point reward \"{}\"", settings.title);
+            }
+            RewardChange::Update { id, settings } => {
+                client.update_reward(id, settings).await?;
+                info!("Updated channel point reward \"{}\"", settings.title);
+            }
+            RewardChange::Delete { id, title } => {
+                client.delete_reward(id).await?;
+                info!("Deleted channel point reward \"{}\"", title);
             }
         }
     }
-    false
+    Ok(changes.len())
 }
 
-fn spawn_tts_generation(
-    tts_request: TTSRequest,
-    tts_service: Arc<TTSService>,
-    tts_queue: TTSQueue,
-    backend_tx: tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+/// Send the message of a moderator dashboard button
+async fn run_remote_action(
+    action: crate::backend::remote::RemoteAction,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
 ) {
-    tokio::spawn(async move {
-        // Split text into chunks
-        let text_chunks = tts_service.split_text(&tts_request.text);
-        let chunk_count = text_chunks.len();
+    let result = if action.announce.is_empty() {
+        client.send_message(&action.message).await
+    } else {
+        client
+            .send_announcement(&action.message, &action.announce)
+            .await
+    };
+    let log = match result {
+        Ok(()) => (
+            LogLevel::INFO,
+            format!("Ran \"{}\" from the moderator dashboard", action.label),
+        ),
+        Err(e) => (
+            LogLevel::ERROR,
+            format!("Failed to run \"{}\": {}", action.label, e),
+        ),
+    };
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(log.0, log.1))
+        .await;
+}
 
-        // Process each chunk as a separate queue item
-        for (chunk_index, text_chunk) in text_chunks.into_iter().enumerate() {
-            // Create unique ID for this chunk
-            let chunk_id = if chunk_count > 1 {
-                format!("{}-{}", tts_request.id, chunk_index)
-            } else {
-                tts_request.id.clone()
-            };
+/// Show unban requests that were created while the bot was offline
+async fn load_pending_unban_requests(
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    match client.get_pending_unban_requests().await {
+        Ok(requests) => {
+            let requests = requests
+                .into_iter()
+                .map(|request| UnbanRequestUI {
+                    id: request.id,
+                    user_name: request.user_name,
+                    text: request.text,
+                    created_at: request.created_at,
+                    resolution_text: String::new(),
+                })
+                .collect();
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::UnbanRequestsLoaded(requests))
+                .await;
+        }
+        // Missing moderator:read:unban_requests scope, unban requests are optional
+        Err(e) => log::debug!("Failed to load unban requests: {}", e),
+    }
+}
 
-            // Fetch audio for this chunk
-            match tts_service
-                .fetch_tts_audio(&text_chunk, &tts_request.language)
-                .await
-            {
-                Ok(audio_data) => {
-                    let chunk_request = TTSRequest {
-                        id: chunk_id,
-                        username: tts_request.username.clone(),
-                        language: tts_request.language.clone(),
-                        text: text_chunk,
-                        timestamp: tts_request.timestamp,
-                    };
+/// Turn shield mode on or off and show the new state right away, without waiting for
+/// the shield mode event. Returns whether shield mode is active.
+async fn update_shield_mode(
+    active: bool,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> Result<bool, String> {
+    let active = client
+        .set_shield_mode(active)
+        .await
+        .map_err(|e| e.to_string())?;
+    crate::backend::stream_state::set_shield_mode(active);
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::ShieldModeChanged(active))
+        .await;
+    Ok(active)
+}
 
-                    let queue_item = TTSQueueItem {
-                        request: chunk_request,
-                        audio_chunks: vec![TTSAudioChunk { audio_data }],
-                    };
+/// Shield mode events only report changes, so read the state it's in when connecting
+async fn load_shield_mode_status(
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    match client.get_shield_mode_status().await {
+        Ok(active) => {
+            crate::backend::stream_state::set_shield_mode(active);
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::ShieldModeChanged(active))
+                .await;
+        }
+        // Missing moderator:read:shield_mode scope, shield mode status is optional
+        Err(e) => log::debug!("Failed to load shield mode status: {}", e),
+    }
+}
 
-                    tts_queue.add(queue_item).await;
+async fn load_eventsub_subscriptions(
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    match client.list_eventsub_subscriptions().await {
+        Ok(list) => {
+            let subscriptions = EventSubSubscriptionsUI {
+                subscriptions: list
+                    .subscriptions
+                    .into_iter()
+                    .map(|subscription| EventSubSubscriptionUI {
+                        subscription_type: subscription.subscription_type,
+                        status: subscription.status,
+                        cost: subscription.cost,
+                    })
+                    .collect(),
+                total_cost: list.total_cost,
+                max_total_cost: list.max_total_cost,
+            };
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::EventSubSubscriptionsLoaded(
+                    subscriptions,
+                ))
+                .await;
+        }
+        Err(e) => {
+            error!("Failed to list EventSub subscriptions: {}", e);
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::CreateLog(
+                    LogLevel::ERROR,
+                    format!("Failed to list EventSub subscriptions: {}", e),
+                ))
+                .await;
+        }
+    }
+}
 
-                    // Send updated queue to frontend (including currently playing)
-                    let queue_items = tts_queue.get_all_with_current().await;
-                    let ui_queue: Vec<TTSQueueItemUI> = queue_items
-                        .into_iter()
-                        .map(|item| TTSQueueItemUI {
-                            id: item.request.id,
-                            username: item.request.username,
-                            text: item.request.text,
-                            language: item.request.language,
-                        })
-                        .collect();
-                    let _ = backend_tx
-                        .send(BackendToFrontendMessage::TTSQueueUpdated(ui_queue))
-                        .await;
-                }
-                Err(e) => {
-                    error!(
-                        "Failed to fetch TTS audio for chunk {}/{}: {}",
-                        chunk_index + 1,
-                        chunk_count,
-                        e
-                    );
-                    let _ = backend_tx
-                        .send(BackendToFrontendMessage::CreateLog(
-                            LogLevel::ERROR,
-                            format!("Failed to generate TTS chunk: {}", e),
-                        ))
-                        .await;
-                }
-            }
+/// Readable low trust status of a suspicious user
+fn low_trust_label(status: &str) -> &str {
+    match status {
+        "active_monitoring" => "monitored",
+        "restricted" => "restricted",
+        "none" => "not suspicious",
+        other => other,
+    }
+}
+
+/// Show the running Guest Star session in the MODERATION tab
+async fn load_guest_star_session(
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    match client.get_guest_star_session().await {
+        Ok(session) => {
+            let guests = session.map(|session| {
+                session
+                    .guests
+                    .into_iter()
+                    .map(|guest| GuestStarGuestUI {
+                        slot_id: guest.slot_id,
+                        user_name: guest.user_display_name,
+                        is_live: guest.is_live,
+                        muted: !guest.audio_settings.is_host_enabled,
+                    })
+                    .collect()
+            });
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::GuestStarSessionLoaded(guests))
+                .await;
         }
-    });
+        // Missing guest star scope, Guest Star is optional
+        Err(e) => log::debug!("Failed to load Guest Star session: {}", e),
+    }
 }
 
-async fn handle_command(
-    context: crate::backend::commands::CommandContext,
-    command_registry: &Arc<RwLock<CommandRegistry>>,
-    client: &mut TwitchClient,
+/// Show the channel's blocked terms in the MODERATION tab
+async fn load_blocked_terms(
+    client: &TwitchClient,
     backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
-    audio_tx: &AudioPlaybackSender,
 ) {
-    // Lock the registry and execute command
-    let result = {
-        let mut registry = command_registry.write().await;
-        let mut executor = CommandExecutor::new(registry.clone());
-        let result = executor.execute(&context);
+    match client.get_blocked_terms().await {
+        Ok(terms) => {
+            let terms = terms.into_iter().map(|term| term.text).collect();
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::BlockedTermsLoaded(terms))
+                .await;
+        }
+        // Missing blocked terms scope, blocked terms are optional
+        Err(e) => log::debug!("Failed to load blocked terms: {}", e),
+    }
+}
 
-        // Update cooldowns in the shared registry
-        *registry = executor.registry().clone();
-        result
+async fn execute_blocked_term_command(
+    command: &BlockedTermCommand,
+    client: &TwitchClient,
+) -> crate::backend::twitch::Result<()> {
+    match command {
+        BlockedTermCommand::Add(text) => client.add_blocked_term(text).await.map(|_| ()),
+        BlockedTermCommand::Remove(text) => client.remove_blocked_term(text).await,
+    }
+}
+
+/// `!blockterm` and `!unblockterm` - manage blocked terms from chat
+async fn run_blocked_term_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> CommandResult {
+    let command = match parse_blocked_term_command(context) {
+        Ok(command) => command,
+        Err(result) => return result,
     };
 
-    match result {
-        CommandResult::Success(Some(action)) => {
-            handle_command_action(action, client, backend_tx).await;
-        }
-        CommandResult::Success(None) => {}
-        CommandResult::Error(e) => {
+    match execute_blocked_term_command(&command, client).await {
+        Ok(()) => {
             let _ = backend_tx
                 .send(BackendToFrontendMessage::CreateLog(
-                    LogLevel::ERROR,
-                    format!("Command error: {}", e),
+                    LogLevel::INFO,
+                    format!("{} (by {})", command.describe(), context.username()),
                 ))
                 .await;
+            load_blocked_terms(client, backend_tx).await;
+            CommandResult::Success(Some(format!(
+                "reply:{}:{}",
+                context.message_id(),
+                command.describe()
+            )))
+        }
+        Err(e) => CommandResult::Error(format!("Failed to change blocked terms: {}", e)),
+    }
+}
+
+/// Show the current stream title and category in the Settings tab
+async fn load_stream_info(
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    match client.get_channel_information().await {
+        Ok(info) => {
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::StreamInfoLoaded {
+                    title: info.title,
+                    game: info.game_name,
+                })
+                .await;
+        }
+        Err(e) => log::debug!("Failed to load stream info: {}", e),
+    }
+}
+
+async fn execute_guest_star_command(
+    command: &GuestStarCommand,
+    client: &TwitchClient,
+) -> crate::backend::twitch::Result<()> {
+    match command {
+        GuestStarCommand::Invite { user } => client.invite_guest(user).await,
+        GuestStarCommand::AssignSlot { user, slot_id } => {
+            client.assign_guest_slot(user, slot_id).await
+        }
+        GuestStarCommand::SetAudio { slot_id, enabled } => {
+            client.set_guest_slot_audio(slot_id, *enabled).await
+        }
+    }
+}
+
+/// `!gueststar` - manage the Guest Star session from chat
+async fn run_guest_star_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> CommandResult {
+    let command = match parse_guest_star_command(context) {
+        Ok(command) => command,
+        Err(result) => return result,
+    };
+
+    match execute_guest_star_command(&command, client).await {
+        Ok(()) => {
+            load_guest_star_session(client, backend_tx).await;
+            CommandResult::Success(Some(format!(
+                "reply:{}:{}",
+                context.message_id(),
+                command.describe()
+            )))
+        }
+        Err(e) => CommandResult::Error(format!("Guest Star action failed: {}", e)),
+    }
+}
+
+async fn run_stream_info_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> CommandResult {
+    let command = match parse_stream_info_command(context) {
+        Ok(command) => command,
+        Err(result) => return result,
+    };
+
+    let response = match command {
+        StreamInfoCommand::Show { game } => match client.get_channel_information().await {
+            Ok(info) if game && info.game_name.is_empty() => "No category set".to_string(),
+            Ok(info) if game => format!("Category: {}", info.game_name),
+            Ok(info) => format!("Title: {}", info.title),
+            Err(e) => return CommandResult::Error(format!("Failed to load stream info: {}", e)),
+        },
+        StreamInfoCommand::SetTitle(title) => {
+            match client.update_channel_information(Some(&title), None).await {
+                Ok(_) => format!("Title changed to: {}", title),
+                Err(e) => return CommandResult::Error(format!("Failed to change title: {}", e)),
+            }
+        }
+        StreamInfoCommand::SetGame(name) => {
+            match client.update_channel_information(None, Some(&name)).await {
+                Ok(game) => format!("Category changed to {}", game.unwrap_or(name)),
+                Err(e) => return CommandResult::Error(format!("Failed to change category: {}", e)),
+            }
+        }
+    };
+
+    load_stream_info(client, backend_tx).await;
+    CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), response)))
+}
+
+async fn run_clip_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> CommandResult {
+    let config = crate::backend::config::load_config().clip;
+    if let Err(result) = check_clip_command(context, &config) {
+        return result;
+    }
+
+    match client.create_clip(config.has_delay).await {
+        Ok(clip) => {
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::CreateLog(
+                    LogLevel::INFO,
+                    format!(
+                        "{} created a clip: {} (edit: {})",
+                        context.username(),
+                        clip.url(),
+                        clip.edit_url
+                    ),
+                ))
+                .await;
+            let message = format_clip_message(
+                &config.message,
+                &context.display_name(),
+                &clip.url(),
+                &clip.edit_url,
+            );
+            CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), message)))
+        }
+        Err(e) => {
+            reset_clip_cooldown();
+            CommandResult::Error(format!("Failed to create clip: {}", e))
+        }
+    }
+}
+
+/// `!uptime` - how long the stream has been live
+async fn run_uptime_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+) -> CommandResult {
+    match client.get_stream().await {
+        Ok(stream) => {
+            let started_at = stream.map(|stream| stream.started_at);
+            let message = format_uptime(started_at, chrono::Utc::now());
+            CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), message)))
+        }
+        Err(e) => CommandResult::Error(format!("Failed to get stream uptime: {}", e)),
+    }
+}
+
+/// `!followage [user]` - how long the user (or whoever asked) has followed the channel
+async fn run_followage_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+) -> CommandResult {
+    let user_login = match parse_followage_command(context) {
+        Ok(user_login) => user_login,
+        Err(result) => return result,
+    };
+
+    let result = match &user_login {
+        Some(user_login) => client.get_followed_at_by_login(user_login).await,
+        None => client
+            .get_followed_at(context.user_id())
+            .await
+            .map(|followed_at| (context.display_name(), followed_at)),
+    };
+    match result {
+        Ok((name, followed_at)) => {
+            let message = format_followage(&name, followed_at, chrono::Utc::now());
+            CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), message)))
+        }
+        Err(e) => CommandResult::Error(format!("Failed to look up followage: {}", e)),
+    }
+}
+
+async fn run_poll_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+) -> CommandResult {
+    let command = match parse_poll_command(context) {
+        Ok(command) => command,
+        Err(result) => return result,
+    };
+
+    let response = match command {
+        PollCommand::Start {
+            title,
+            choices,
+            duration,
+        } => match client.create_poll(&title, &choices, duration).await {
+            Ok(_) => format!("Poll started: {} ({}s)", title, duration),
+            Err(e) => return CommandResult::Error(format!("Failed to start poll: {}", e)),
+        },
+        PollCommand::End => match client.end_poll().await {
+            Ok(poll) => format!("Ended poll: {}", poll.title),
+            Err(e) => return CommandResult::Error(format!("Failed to end poll: {}", e)),
+        },
+    };
+
+    CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), response)))
+}
+
+async fn run_prediction_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+) -> CommandResult {
+    let command = match parse_prediction_command(context) {
+        Ok(command) => command,
+        Err(result) => return result,
+    };
+
+    let result = match command {
+        PredictionCommand::Start {
+            title,
+            outcomes,
+            window,
+        } => client
+            .create_prediction(&title, &outcomes, window)
+            .await
+            .map(|_| format!("Prediction started: {} ({}s)", title, window)),
+        PredictionCommand::Lock => client
+            .end_prediction("LOCKED")
+            .await
+            .map(|prediction| format!("Predictions are locked: {}", prediction.title)),
+        PredictionCommand::Cancel => client.end_prediction("CANCELED").await.map(|prediction| {
+            format!(
+                "Prediction canceled, points were refunded: {}",
+                prediction.title
+            )
+        }),
+        PredictionCommand::Resolve(outcome) => client
+            .resolve_prediction(&outcome)
+            .await
+            .map(|(prediction, winner)| format!("{} - \"{}\" wins!", prediction.title, winner)),
+    };
+
+    match result {
+        Ok(response) => {
+            CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), response)))
+        }
+        Err(e) => CommandResult::Error(format!("Prediction failed: {}", e)),
+    }
+}
+
+/// `!raid <channel>` only names the channel, the raid starts with `!raid confirm`
+async fn run_raid_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> CommandResult {
+    let command = match parse_raid_command(context) {
+        Ok(command) => command,
+        Err(result) => return result,
+    };
+
+    let response = match command {
+        RaidCommand::Prepare(channel) => {
+            crate::backend::commands::prepare_raid(&channel);
+            format!(
+                "Raid {}? Type !raid confirm within 30 seconds to start",
+                channel
+            )
+        }
+        RaidCommand::Confirm => {
+            let Some(channel) = crate::backend::commands::take_pending_raid() else {
+                return CommandResult::Success(Some(format!(
+                    "reply:{}:No raid to confirm, use !raid <channel> first",
+                    context.message_id()
+                )));
+            };
+            match client.start_raid(&channel).await {
+                Ok(raid) => {
+                    let mature = if raid.is_mature {
+                        " (mature channel)"
+                    } else {
+                        ""
+                    };
+                    let _ = backend_tx
+                        .send(BackendToFrontendMessage::CreateLog(
+                            LogLevel::INFO,
+                            format!("Raid to {}{} is counting down", channel, mature),
+                        ))
+                        .await;
+                    format!("Raiding {} in 90 seconds, !raid cancel to stop", channel)
+                }
+                Err(e) => {
+                    return CommandResult::Error(format!("Failed to raid {}: {}", channel, e))
+                }
+            }
+        }
+        RaidCommand::Cancel => {
+            if let Some(channel) = crate::backend::commands::take_pending_raid() {
+                format!("Raid to {} dropped", channel)
+            } else {
+                match client.cancel_raid().await {
+                    Ok(()) => {
+                        let _ = backend_tx
+                            .send(BackendToFrontendMessage::CreateLog(
+                                LogLevel::INFO,
+                                "Raid canceled".to_string(),
+                            ))
+                            .await;
+                        "Raid canceled".to_string()
+                    }
+                    Err(e) => return CommandResult::Error(format!("Failed to cancel raid: {}", e)),
+                }
+            }
+        }
+    };
+
+    CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), response)))
+}
+
+async fn run_moderation_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> CommandResult {
+    let command = match parse_moderation_command(context) {
+        Ok(command) => command,
+        Err(result) => return result,
+    };
+
+    let result = match &command {
+        ModerationCommand::Ban { login, reason } => client
+            .ban_user_by_login(login, None, reason)
+            .await
+            .map(|name| format!("{} was banned", name)),
+        ModerationCommand::Timeout {
+            login,
+            seconds,
+            reason,
+        } => client
+            .ban_user_by_login(login, Some(*seconds), reason)
+            .await
+            .map(|name| format!("{} was timed out for {}s", name, seconds)),
+        ModerationCommand::Unban { login } => client
+            .unban_user_by_login(login)
+            .await
+            .map(|name| format!("{} was unbanned", name)),
+    };
+    let response = match result {
+        Ok(response) => response,
+        Err(e) => {
+            return CommandResult::Error(format!(
+                "Failed to {} {}: {}",
+                context.command_name,
+                command.login(),
+                e
+            ))
+        }
+    };
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(
+            LogLevel::INFO,
+            format!("{} (by {})", response, context.display_name()),
+        ))
+        .await;
+
+    CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), response)))
+}
+
+/// `!nuke <phrase> [minutes]` - delete the recent messages containing a phrase
+async fn run_nuke_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> CommandResult {
+    let nuke = match parse_nuke_command(context) {
+        Ok(nuke) => nuke,
+        Err(result) => return result,
+    };
+
+    let messages = chat_history::recent();
+    let targets = nuke.targets(&messages);
+    let mut deleted = 0;
+    let mut users: Vec<&str> = Vec::new();
+    for message in &targets {
+        match client.delete_message(&message.message_id).await {
+            Ok(()) => deleted += 1,
+            Err(e) => log::warn!("Failed to delete message {}: {}", message.message_id, e),
+        }
+        if !users.contains(&message.user_id.as_str()) {
+            users.push(&message.user_id);
+        }
+    }
+
+    let mut timed_out = 0;
+    if let Some(minutes) = nuke.timeout_minutes {
+        let reason = format!("Nuked: {}", nuke.phrase);
+        for user_id in &users {
+            match client.timeout_user(user_id, minutes * 60, &reason).await {
+                Ok(()) => timed_out += 1,
+                Err(e) => log::warn!("Failed to time out user {}: {}", user_id, e),
+            }
+        }
+    }
+    for message in &targets {
+        chat_history::remove_message(&message.message_id);
+    }
+    send_chat_history(backend_tx).await;
+
+    let response = match nuke.timeout_minutes {
+        Some(minutes) => format!(
+            "Nuked {} messages with \"{}\", {} users timed out for {} min",
+            deleted, nuke.phrase, timed_out, minutes
+        ),
+        None => format!("Nuked {} messages with \"{}\"", deleted, nuke.phrase),
+    };
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(
+            LogLevel::INFO,
+            format!("{} (by {})", response, context.display_name()),
+        ))
+        .await;
+
+    CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), response)))
+}
+
+async fn run_moderator_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> CommandResult {
+    let command = match parse_moderator_command(context) {
+        Ok(command) => command,
+        Err(result) => return result,
+    };
+
+    let response = match command {
+        ModeratorCommand::Add(login) => match client.add_moderator(&login).await {
+            Ok(name) => format!("{} is now a moderator", name),
+            Err(e) => return CommandResult::Error(format!("Failed to mod {}: {}", login, e)),
+        },
+        ModeratorCommand::Remove(login) => match client.remove_moderator(&login).await {
+            Ok(name) => format!("{} is no longer a moderator", name),
+            Err(e) => return CommandResult::Error(format!("Failed to unmod {}: {}", login, e)),
+        },
+    };
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(
+            LogLevel::INFO,
+            response.clone(),
+        ))
+        .await;
+
+    CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), response)))
+}
+
+async fn run_shield_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> CommandResult {
+    let active = match parse_shield_command(context) {
+        Ok(active) => active,
+        Err(result) => return result,
+    };
+
+    match update_shield_mode(active, client, backend_tx).await {
+        Ok(true) => CommandResult::Success(Some(format!(
+            "reply:{}:Shield mode is on",
+            context.message_id()
+        ))),
+        Ok(false) => CommandResult::Success(Some(format!(
+            "reply:{}:Shield mode is off",
+            context.message_id()
+        ))),
+        Err(e) => CommandResult::Error(format!("Failed to change shield mode: {}", e)),
+    }
+}
+
+async fn run_chat_mode_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> CommandResult {
+    let command = match parse_chat_mode_command(context) {
+        Ok(command) => command,
+        Err(result) => return result,
+    };
+
+    if let Err(e) = client.update_chat_settings(command.settings()).await {
+        return CommandResult::Error(format!("Failed to change chat mode: {}", e));
+    }
+
+    let response = command.describe();
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(
+            LogLevel::INFO,
+            format!("{} (set by {})", response, context.username()),
+        ))
+        .await;
+    CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), response)))
+}
+
+/// Start or end giveaway mode, which keeps entry keywords from playing sounds or TTS
+async fn run_giveaway_command(
+    context: &crate::backend::commands::CommandContext,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> CommandResult {
+    let active = match parse_giveaway_command(context) {
+        Ok(active) => active,
+        Err(result) => return result,
+    };
+    let state = if active { "on" } else { "off" };
+    let reply = |message: String| {
+        CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), message)))
+    };
+
+    if !crate::backend::commands::set_giveaway_active(active) {
+        return reply(format!("Giveaway mode is already {}", state));
+    }
+
+    let config = crate::backend::config::load_config().giveaway;
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(
+            LogLevel::INFO,
+            format!(
+                "Giveaway mode {} ({} muted for sounds and TTS)",
+                state,
+                config.entry_keywords.join(", ")
+            ),
+        ))
+        .await;
+
+    match config.format_start_message() {
+        Some(message) if active => CommandResult::Success(Some(format!("send:{}", message))),
+        _ => reply(format!("Giveaway mode is {}", state)),
+    }
+}
+
+/// Show, set or clear a nickname with `!nick`
+async fn run_nick_command(
+    context: &crate::backend::commands::CommandContext,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> CommandResult {
+    let command = match parse_nick_command(context) {
+        Ok(command) => command,
+        Err(result) => return result,
+    };
+
+    let response = match command {
+        NickCommand::Show(login) => match users::nickname(&login) {
+            Some(nickname) => format!("{} goes by {}", login, nickname),
+            None => format!("{} has no nickname", login),
+        },
+        NickCommand::Set(login, nickname) => {
+            users::set_nickname(&login, Some(nickname.clone()));
+            send_nicknames_updated(backend_tx).await;
+            format!("{} now goes by {}", login, nickname)
+        }
+        NickCommand::Clear(login) => {
+            users::set_nickname(&login, None);
+            send_nicknames_updated(backend_tx).await;
+            format!("Removed the nickname of {}", login)
+        }
+    };
+    CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), response)))
+}
+
+async fn send_nicknames_updated(backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>) {
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::NicknamesUpdated(
+            users::nicknames(),
+        ))
+        .await;
+}
+
+/// `!death+`, `!deaths` and `!resetdeaths`, counted for the current category
+async fn run_death_command(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+    overlay_ws_state: &crate::backend::overlay::WebSocketState,
+) -> CommandResult {
+    let op = match parse_death_command(context) {
+        Ok(op) => op,
+        Err(result) => return result,
+    };
+    let game = match client.get_channel_information().await {
+        Ok(info) => info.game_name,
+        Err(e) => return CommandResult::Error(format!("Failed to load the category: {}", e)),
+    };
+
+    let counter = death_counter_name(&game);
+    let deaths = match op {
+        Some(op) => {
+            let deaths = crate::backend::counters::update(&counter, op);
+            overlay_ws_state
+                .broadcast(crate::backend::overlay::OverlayEvent::TextWidget {
+                    widget: DEATHS_WIDGET.to_string(),
+                    text: format!("Deaths: {}", deaths),
+                })
+                .await;
+            deaths
+        }
+        None => crate::backend::counters::get(&counter),
+    };
+    let message = format_deaths(&game, deaths);
+    CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), message)))
+}
+
+/// `!quote [id]`, `!addquote <text>` and `!delquote <id>`
+async fn run_quote_command(
+    context: &crate::backend::commands::CommandContext,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> CommandResult {
+    let command = match parse_quote_command(context) {
+        Ok(command) => command,
+        Err(result) => return result,
+    };
+
+    let response = match command {
+        QuoteCommand::Random => match quotes::random() {
+            Some(quote) => quote.format(),
+            None => "There are no quotes yet".to_string(),
+        },
+        QuoteCommand::Show(id) => match quotes::get(id) {
+            Some(quote) => quote.format(),
+            None => format!("There is no quote #{}", id),
+        },
+        QuoteCommand::Add(text) => {
+            let quote = quotes::add(&text, context.username());
+            send_quotes_updated(backend_tx).await;
+            format!("Added quote #{}", quote.id)
+        }
+        QuoteCommand::Delete(id) => match quotes::remove(id) {
+            Some(_) => {
+                send_quotes_updated(backend_tx).await;
+                format!("Deleted quote #{}", id)
+            }
+            None => format!("There is no quote #{}", id),
+        },
+    };
+    CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), response)))
+}
+
+async fn send_quotes_updated(backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>) {
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::QuotesUpdated(quotes::list()))
+        .await;
+}
+
+/// Queue a link from a link-only request command, anything else is rejected
+async fn run_link_request_command(
+    context: &crate::backend::commands::CommandContext,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> CommandResult {
+    let config = crate::backend::config::load_config().link_requests;
+    let Some(command) = config.command(&context.command_name) else {
+        return CommandResult::NotFound;
+    };
+    if !command.permission.has_permission(context.identity()) {
+        return CommandResult::PermissionDenied;
+    }
+
+    let reply = |message: String| {
+        CommandResult::Success(Some(format!("reply:{}:{}", context.message_id(), message)))
+    };
+
+    let link = context
+        .args
+        .first()
+        .and_then(|arg| link_requests::parse_link(arg, &command.sources));
+    let Some(link) = link else {
+        let sources: Vec<String> = command.sources.iter().map(|s| s.to_string()).collect();
+        return reply(
+            context
+                .replace_placeholders(&command.rejected_message)
+                .replace("{sources}", &sources.join("/")),
+        );
+    };
+
+    let (title, duration_seconds) =
+        link_requests::fetch_metadata(&link, &config.youtube_api_key).await;
+    let request = link_requests::add_request(link_requests::LinkRequest {
+        id: 0,
+        command: context.command_name.clone(),
+        user: context.username().to_string(),
+        source: link.source,
+        url: link.url,
+        title,
+        duration_seconds,
+        approved: false,
+        timestamp: crate::backend::locale::now()
+            .format(crate::backend::locale::STORAGE_FORMAT)
+            .to_string(),
+    });
+
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(
+            LogLevel::INFO,
+            format!(
+                "{} requested {} ({})",
+                request.user, request.title, request.url
+            ),
+        ))
+        .await;
+
+    reply(
+        context
+            .replace_placeholders(&command.accepted_message)
+            .replace("{title}", &request.title),
+    )
+}
+
+/// Send the buffered chat messages, replacing the UI's chat panel
+async fn send_chat_history(backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>) {
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::ChatHistoryLoaded(
+            chat_history::recent(),
+        ))
+        .await;
+}
+
+async fn send_welcome_message(
+    client: &mut TwitchClient,
+    msg: &str,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    if !msg.trim().is_empty() {
+        info!("Attempting to send welcome message: {}", msg);
+
+        // Wait a moment for subscriptions to settle
+        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+
+        match client.send_message(msg).await {
+            Ok(_) => {
+                info!("Welcome message sent successfully");
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        format!("✓ Sent welcome message: {}", msg),
+                    ))
+                    .await;
+            }
+            Err(e) => {
+                error!("Failed to send welcome message: {}", e);
+                let error_str = e.to_string();
+
+                let user_msg = if error_str.contains("403") || error_str.contains("Forbidden") {
+                    format!("❌ Cannot send welcome message - Missing OAuth scope 'user:write:chat'. Please re-authorize with write permissions.")
+                } else {
+                    format!("❌ Failed to send welcome message: {}", e)
+                };
+
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::ERROR,
+                        user_msg,
+                    ))
+                    .await;
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn handle_twitch_event(
+    event: TwitchClientEvent,
+    session: &mut StreamSession,
+    command_workers: &mut CommandWorkers,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+    client: &mut TwitchClient,
+    audio_tx: &AudioPlaybackSender,
+    command_registry: &Arc<RwLock<CommandRegistry>>,
+    command_parser: &CommandParser,
+    tts_queue: &TTSQueue,
+    tts_service: &Arc<TTSService>,
+    language_config: &Arc<RwLock<LanguageConfig>>,
+    overlay_ws_state: &crate::backend::overlay::WebSocketState,
+) {
+    match event {
+        TwitchClientEvent::Connected => {
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::CreateLog(
+                    LogLevel::INFO,
+                    "EventSub ready - listening for chat events".to_string(),
+                ))
+                .await;
+        }
+
+        TwitchClientEvent::ChatEvent(chat_event) => match chat_event {
+            crate::backend::twitch::TwitchEvent::ChatMessage(msg) => {
+                let chat_message: ChatMessage = msg.clone().into();
+                chat_history::push(chat_message.clone());
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::ChatMessageReceived(chat_message))
+                    .await;
+                events::publish(BotEvent::ChatMessage(Box::new(msg.clone())));
+
+                session.record_message(&msg.chatter_user_login);
+                // The broadcaster and the bot don't compete for first
+                if msg.chatter_user_id != msg.broadcaster_user_id
+                    && client.bot_user_id() != Some(&msg.chatter_user_id)
+                {
+                    if let Some(firsts) = firsts::claim(&msg.chatter_user_login) {
+                        announce_first_chatter(&msg.chatter_user_name, firsts, client, backend_tx)
+                            .await;
+                    }
+                }
+
+                let identity = UserIdentity::from_badges(&msg.badges);
+                session
+                    .identities
+                    .insert(msg.chatter_user_id.clone(), identity);
+
+                // Check if message is a TTS command
+                if handle_tts_command(
+                    &msg,
+                    &identity,
+                    tts_queue,
+                    tts_service,
+                    language_config,
+                    backend_tx,
+                )
+                .await
+                {
+                    return;
+                }
+
+                // Check if message is a command or contains a command keyword
+                let context = match command_parser.parse(msg.clone(), identity) {
+                    Some(context) => Some(context),
+                    // Skip the bot's own messages, its replies could contain the keyword again
+                    None if client.bot_user_id() != Some(&msg.chatter_user_id) => {
+                        let registry = command_registry.read().await;
+                        command_parser.parse_keyword(msg.clone(), identity, &registry)
+                    }
+                    None => None,
+                };
+                if let Some(context) = context {
+                    dispatch_command(
+                        context,
+                        command_workers,
+                        command_registry,
+                        client,
+                        backend_tx,
+                        audio_tx,
+                        overlay_ws_state,
+                        tts_queue,
+                        tts_service,
+                    )
+                    .await;
+                }
+            }
+
+            crate::backend::twitch::TwitchEvent::MessageDelete(delete) => {
+                info!(
+                    "Message {} from {} was deleted",
+                    delete.message_id, delete.target_user_name
+                );
+                purge_tts_for_message(&delete.message_id, tts_queue, tts_service, backend_tx).await;
+                chat_history::remove_message(&delete.message_id);
+                send_chat_history(backend_tx).await;
+            }
+
+            crate::backend::twitch::TwitchEvent::ClearUserMessages(clear) => {
+                info!(
+                    "Messages from {} were cleared (ban/timeout)",
+                    clear.target_user_name
+                );
+                purge_tts_for_user(&clear.target_user_login, tts_queue, tts_service, backend_tx)
+                    .await;
+                chat_history::remove_user_messages(&clear.target_user_login);
+                send_chat_history(backend_tx).await;
+            }
+
+            crate::backend::twitch::TwitchEvent::ChatClear(clear) => {
+                info!(
+                    "Chat was cleared in {}'s channel",
+                    clear.broadcaster_user_name
+                );
+                chat_history::clear();
+                send_chat_history(backend_tx).await;
+            }
+
+            crate::backend::twitch::TwitchEvent::ChatSettingsUpdate(settings) => {
+                info!(
+                    "Chat settings updated: slow_mode={}, sub_only={}",
+                    settings.slow_mode, settings.subscriber_mode
+                );
+            }
+
+            crate::backend::twitch::TwitchEvent::ChannelBan(ban) => {
+                purge_tts_for_user(&ban.user_login, tts_queue, tts_service, backend_tx).await;
+                handle_ban_event(&ban, backend_tx).await;
+            }
+
+            crate::backend::twitch::TwitchEvent::ChannelUnban(unban) => {
+                info!(
+                    "✅ {} was unbanned by {}",
+                    unban.user_name, unban.moderator_user_name
+                );
+
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        format!(
+                            "{} was unbanned by {}",
+                            unban.user_name, unban.moderator_user_name
+                        ),
+                    ))
+                    .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::PollBegin(poll) => {
+                info!("📊 Poll started: {}", poll.title);
+
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        format!("Poll started: {}", poll.title),
+                    ))
+                    .await;
+
+                broadcast_alert(
+                    overlay_ws_state,
+                    crate::backend::overlay::OverlayEvent::Poll {
+                        poll_id: poll.id,
+                        title: poll.title,
+                        status: "active".to_string(),
+                        choices: serde_json::to_value(&poll.choices).unwrap_or_default(),
+                        ends_at: Some(poll.ends_at),
+                    },
+                )
+                .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::PollProgress(poll) => {
+                broadcast_alert(
+                    overlay_ws_state,
+                    crate::backend::overlay::OverlayEvent::Poll {
+                        poll_id: poll.id,
+                        title: poll.title,
+                        status: "active".to_string(),
+                        choices: serde_json::to_value(&poll.choices).unwrap_or_default(),
+                        ends_at: Some(poll.ends_at),
+                    },
+                )
+                .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::PollEnd(poll) => {
+                handle_poll_end_event(&poll, client, backend_tx, overlay_ws_state).await;
+            }
+
+            crate::backend::twitch::TwitchEvent::StreamOnline(online) => {
+                info!("🔴 Stream went live ({})", online.stream_type);
+
+                // Start a fresh per-stream session
+                *session = StreamSession {
+                    live: true,
+                    identities: std::mem::take(&mut session.identities),
+                    ..Default::default()
+                };
+                firsts::reset();
+                command_registry.write().await.reset_stream_uses();
+                events::publish(BotEvent::StreamOnline);
+
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        format!("Stream is now live ({})", online.stream_type),
+                    ))
+                    .await;
+
+                let config = crate::backend::config::load_config();
+                if config.chatbot.welcome_on_stream_online {
+                    send_welcome_message(client, &config.chatbot.welcome_message, backend_tx).await;
+                }
+            }
+
+            crate::backend::twitch::TwitchEvent::StreamOffline(_) => {
+                info!("⚫ Stream went offline");
+                session.live = false;
+                events::publish(BotEvent::StreamOffline);
+
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        format!(
+                            "Stream is now offline - {} message(s) from {} chatter(s)",
+                            session.message_count,
+                            session.chatters.len()
+                        ),
+                    ))
+                    .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::UnbanRequestCreate(request) => {
+                info!(
+                    "🙏 {} requested an unban: {}",
+                    request.user_name, request.text
+                );
+
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        format!("New unban request from {}", request.user_name),
+                    ))
+                    .await;
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::UnbanRequestCreated(
+                        UnbanRequestUI {
+                            id: request.id,
+                            user_name: request.user_name,
+                            text: request.text,
+                            created_at: request.created_at,
+                            resolution_text: String::new(),
+                        },
+                    ))
+                    .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::UnbanRequestResolve(request) => {
+                info!(
+                    "Unban request from {} {}",
+                    request.user_name, request.status
+                );
+
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        match request.moderator_user_name {
+                            Some(moderator) => format!(
+                                "Unban request from {} {} by {}",
+                                request.user_name, request.status, moderator
+                            ),
+                            None => format!(
+                                "Unban request from {} {}",
+                                request.user_name, request.status
+                            ),
+                        },
+                    ))
+                    .await;
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::UnbanRequestResolved(request.id))
+                    .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::ChatNotification(notification) => {
+                handle_chat_notification(&notification, backend_tx, overlay_ws_state).await;
+            }
+
+            crate::backend::twitch::TwitchEvent::WhisperMessage(whisper) => {
+                info!(
+                    "Whisper from {}: {}",
+                    whisper.from_user_name, whisper.whisper.text
+                );
+
+                let config = crate::backend::config::load_config();
+                if !config.chatbot.whisper_commands {
+                    return;
+                }
+
+                let identity = session
+                    .identities
+                    .get(&whisper.from_user_id)
+                    .copied()
+                    .unwrap_or_default();
+                if let Some(context) = command_parser.parse(whisper.into(), identity) {
+                    dispatch_command(
+                        context,
+                        command_workers,
+                        command_registry,
+                        client,
+                        backend_tx,
+                        audio_tx,
+                        overlay_ws_state,
+                        tts_queue,
+                        tts_service,
+                    )
+                    .await;
+                }
+            }
+
+            crate::backend::twitch::TwitchEvent::ShieldModeBegin(shield) => {
+                info!("🛡 Shield mode activated by {}", shield.moderator_user_name);
+                crate::backend::stream_state::set_shield_mode(true);
+
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::WARN,
+                        format!("Shield mode activated by {}", shield.moderator_user_name),
+                    ))
+                    .await;
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::ShieldModeChanged(true))
+                    .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::ShieldModeEnd(shield) => {
+                info!(
+                    "🛡 Shield mode deactivated by {}",
+                    shield.moderator_user_name
+                );
+                crate::backend::stream_state::set_shield_mode(false);
+
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        format!("Shield mode deactivated by {}", shield.moderator_user_name),
+                    ))
+                    .await;
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::ShieldModeChanged(false))
+                    .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::GuestStarSessionBegin(_) => {
+                info!("⭐ Guest Star session started");
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        "Guest Star session started".to_string(),
+                    ))
+                    .await;
+                load_guest_star_session(client, backend_tx).await;
+            }
+
+            crate::backend::twitch::TwitchEvent::GuestStarSessionEnd(_) => {
+                info!("⭐ Guest Star session ended");
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        "Guest Star session ended".to_string(),
+                    ))
+                    .await;
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::GuestStarSessionLoaded(None))
+                    .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::GuestStarGuestUpdate(update) => {
+                let guest = update.guest_user_name.as_deref().unwrap_or("Guest");
+                let state = update.state.as_deref().unwrap_or("updated");
+                let message = match &update.slot_id {
+                    Some(slot_id) => format!("Guest Star: {} {} (slot {})", guest, state, slot_id),
+                    None => format!("Guest Star: {} {}", guest, state),
+                };
+                info!("⭐ {}", message);
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(LogLevel::INFO, message))
+                    .await;
+                load_guest_star_session(client, backend_tx).await;
+            }
+
+            crate::backend::twitch::TwitchEvent::SuspiciousUserMessage(suspicious) => {
+                let mut details = vec![low_trust_label(&suspicious.low_trust_status).to_string()];
+                if matches!(
+                    suspicious.ban_evasion_evaluation.as_str(),
+                    "possible" | "likely"
+                ) {
+                    details.push(format!("{} ban evader", suspicious.ban_evasion_evaluation));
+                }
+                info!(
+                    "🚩 Suspicious user {} ({}): {}",
+                    suspicious.user_name,
+                    details.join(", "),
+                    suspicious.message.text
+                );
+
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::WARN,
+                        format!(
+                            "Suspicious user {} ({}): {}",
+                            suspicious.user_name,
+                            details.join(", "),
+                            suspicious.message.text
+                        ),
+                    ))
+                    .await;
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::SuspiciousUserUpdated(
+                        SuspiciousUserUI {
+                            user_id: suspicious.user_id,
+                            user_name: suspicious.user_name,
+                            low_trust_status: suspicious.low_trust_status,
+                            reasons: suspicious.types.join(", "),
+                            last_message: suspicious.message.text,
+                        },
+                    ))
+                    .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::SuspiciousUserUpdate(update) => {
+                info!(
+                    "🚩 {} marked {} as {}",
+                    update.moderator_user_name,
+                    update.user_name,
+                    low_trust_label(&update.low_trust_status)
+                );
+
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        format!(
+                            "{} marked {} as {}",
+                            update.moderator_user_name,
+                            update.user_name,
+                            low_trust_label(&update.low_trust_status)
+                        ),
+                    ))
+                    .await;
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::SuspiciousUserUpdated(
+                        SuspiciousUserUI {
+                            user_id: update.user_id,
+                            user_name: update.user_name,
+                            low_trust_status: update.low_trust_status,
+                            reasons: String::new(),
+                            last_message: String::new(),
+                        },
+                    ))
+                    .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::CharityDonate(donation) => {
+                handle_charity_donate_event(&donation, client, tts_queue, tts_service, backend_tx)
+                    .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::CharityProgress(progress) => {
+                log::debug!(
+                    "Charity campaign for {} at {}/{}",
+                    progress.charity_name,
+                    progress.current_amount,
+                    progress.target_amount
+                );
+
+                // Like goal bars, the charity bar stays on screen even while alerts are disabled
+                overlay_ws_state
+                    .broadcast(crate::backend::overlay::OverlayEvent::Charity {
+                        charity_name: progress.charity_name,
+                        current: progress.current_amount.amount(),
+                        target: progress.target_amount.amount(),
+                        currency: progress.current_amount.currency,
+                    })
+                    .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::ChannelPointsRedemption(redemption) => {
+                info!(
+                    "🎁 {} redeemed {}",
+                    redemption.user_name, redemption.reward.title
+                );
+
+                let identity = session
+                    .identities
+                    .get(&redemption.user_id)
+                    .copied()
+                    .unwrap_or_default();
+                let context = {
+                    let registry = command_registry.read().await;
+                    command_parser.parse_redemption(redemption.into(), identity, &registry)
+                };
+                if let Some(context) = context {
+                    dispatch_command(
+                        context,
+                        command_workers,
+                        command_registry,
+                        client,
+                        backend_tx,
+                        audio_tx,
+                        overlay_ws_state,
+                        tts_queue,
+                        tts_service,
+                    )
+                    .await;
+                }
+            }
+
+            crate::backend::twitch::TwitchEvent::ShoutoutCreate(shoutout) => {
+                crate::backend::commands::record_shoutout(&shoutout.to_broadcaster_user_login);
+                info!(
+                    "📣 {} shouted out {}",
+                    shoutout.moderator_user_name, shoutout.to_broadcaster_user_name
+                );
+
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        format!(
+                            "{} gave a shoutout to {}",
+                            shoutout.moderator_user_name, shoutout.to_broadcaster_user_name
+                        ),
+                    ))
+                    .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::ShoutoutReceive(shoutout) => {
+                handle_shoutout_receive_event(&shoutout, client, backend_tx).await;
+            }
+
+            crate::backend::twitch::TwitchEvent::GoalBegin(goal) => {
+                info!(
+                    "🎯 {} goal started: {}/{}",
+                    goal.goal_type, goal.current_amount, goal.target_amount
+                );
+
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        format!(
+                            "Started {} goal: {}/{}",
+                            goal.goal_type, goal.current_amount, goal.target_amount
+                        ),
+                    ))
+                    .await;
+
+                broadcast_goal(
+                    overlay_ws_state,
+                    goal.id,
+                    goal.goal_type,
+                    goal.description,
+                    "active",
+                    goal.current_amount,
+                    goal.target_amount,
+                )
+                .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::GoalProgress(goal) => {
+                broadcast_goal(
+                    overlay_ws_state,
+                    goal.id,
+                    goal.goal_type,
+                    goal.description,
+                    "active",
+                    goal.current_amount,
+                    goal.target_amount,
+                )
+                .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::GoalEnd(goal) => {
+                info!(
+                    "🎯 {} goal ended at {}/{} (achieved: {})",
+                    goal.goal_type, goal.current_amount, goal.target_amount, goal.is_achieved
+                );
+
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        if goal.is_achieved {
+                            format!(
+                                "{} goal achieved: {}/{}",
+                                goal.goal_type, goal.current_amount, goal.target_amount
+                            )
+                        } else {
+                            format!(
+                                "{} goal ended at {}/{}",
+                                goal.goal_type, goal.current_amount, goal.target_amount
+                            )
+                        },
+                    ))
+                    .await;
+
+                broadcast_goal(
+                    overlay_ws_state,
+                    goal.id,
+                    goal.goal_type,
+                    goal.description,
+                    if goal.is_achieved {
+                        "achieved"
+                    } else {
+                        "ended"
+                    },
+                    goal.current_amount,
+                    goal.target_amount,
+                )
+                .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::AdBreakBegin(ad_break) => {
+                handle_ad_break_event(&ad_break, client, audio_tx, backend_tx, overlay_ws_state)
+                    .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::HypeTrainBegin(train) => {
+                info!("🚂 Hype train started at level {}", train.level);
+
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        format!("Hype train started (level {})", train.level),
+                    ))
+                    .await;
+
+                broadcast_alert(
+                    overlay_ws_state,
+                    crate::backend::overlay::OverlayEvent::HypeTrain {
+                        status: "begin".to_string(),
+                        level: train.level,
+                        total: train.total,
+                        progress: train.progress,
+                        goal: train.goal,
+                        expires_at: train.expires_at,
+                    },
+                )
+                .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::HypeTrainProgress(train) => {
+                broadcast_alert(
+                    overlay_ws_state,
+                    crate::backend::overlay::OverlayEvent::HypeTrain {
+                        status: "progress".to_string(),
+                        level: train.level,
+                        total: train.total,
+                        progress: train.progress,
+                        goal: train.goal,
+                        expires_at: train.expires_at,
+                    },
+                )
+                .await;
+            }
+
+            crate::backend::twitch::TwitchEvent::HypeTrainEnd(train) => {
+                info!(
+                    "🚂 Hype train ended at level {} ({} total)",
+                    train.level, train.total
+                );
+
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        format!(
+                            "Hype train ended at level {} ({} total)",
+                            train.level, train.total
+                        ),
+                    ))
+                    .await;
+
+                broadcast_alert(
+                    overlay_ws_state,
+                    crate::backend::overlay::OverlayEvent::HypeTrain {
+                        status: "end".to_string(),
+                        level: train.level,
+                        total: train.total,
+                        progress: 0,
+                        goal: 0,
+                        expires_at: train.cooldown_ends_at,
+                    },
+                )
+                .await;
+            }
+        },
+
+        TwitchClientEvent::TokensRefreshed(access_token, refresh_token) => {
+            // Load current config
+            let mut current_config = crate::backend::config::load_config();
+
+            // Update tokens
+            current_config.chatbot.auth_token = access_token;
+            current_config.chatbot.refresh_token = refresh_token;
+
+            // Save updated config
+            crate::backend::config::save_config(&current_config);
+        }
+
+        TwitchClientEvent::Disconnected => {
+            crate::backend::stream_state::set_shield_mode(false);
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::ConnectionFailure(
+                    "Disconnected".to_string(),
+                ))
+                .await;
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::CreateLog(
+                    LogLevel::ERROR,
+                    "Disconnected from Twitch".to_string(),
+                ))
+                .await;
+        }
+
+        TwitchClientEvent::TransportChanged(transport) => {
+            if transport == ChatTransport::Irc {
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::WARN,
+                        "Reading chat over IRC fallback - commands and sounds keep working, other events are unavailable".to_string(),
+                    ))
+                    .await;
+            }
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::ChatTransportChanged(
+                    transport.label().to_string(),
+                ))
+                .await;
+        }
+
+        TwitchClientEvent::Warning(w) => {
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::CreateLog(LogLevel::WARN, w))
+                .await;
+        }
+
+        TwitchClientEvent::Error(e) => {
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::CreateLog(
+                    LogLevel::ERROR,
+                    format!("Twitch error: {}", e),
+                ))
+                .await;
+        }
+    }
+}
+
+async fn handle_tts_command(
+    msg: &crate::backend::twitch::ChatMessageEvent,
+    identity: &UserIdentity,
+    tts_queue: &TTSQueue,
+    tts_service: &Arc<TTSService>,
+    language_config: &Arc<RwLock<LanguageConfig>>,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) -> bool {
+    let message_text = msg.message.text.trim().to_lowercase();
+    if message_text.starts_with('!') && message_text.len() > 1 {
+        let parts: Vec<&str> = message_text.splitn(2, ' ').collect();
+        if parts.len() == 2 {
+            let potential_lang_code = &parts[0][1..]; // Remove the '!' prefix
+            let tts_text = parts[1];
+
+            // Entries like "!enter" go to commands, not TTS, while a giveaway runs
+            if crate::backend::commands::giveaway_active()
+                && crate::backend::config::load_config()
+                    .giveaway
+                    .suppresses(potential_lang_code)
+            {
+                return false;
+            }
+
+            // Check if this is a valid language code
+            let lang_config = language_config.read().await;
+            if let Some(language) = lang_config.get_language(potential_lang_code) {
+                if language.enabled {
+                    // Check TTS config and permissions
+                    let config = crate::backend::config::load_config();
+                    if config.tts.enabled && active_features(&config).tts {
+                        // Check user permissions, only moderators while shield mode restricts TTS
+                        let has_permission = identity.is_permitted(
+                            &config.tts.permited_roles,
+                            shield_mode_restricts(&config),
+                        );
+
+                        if !has_permission {
+                            return true;
+                        }
+                        if tts_queue.is_user_ignored(&msg.chatter_user_login).await {
+                            return true;
+                        }
+
+                        let tts_request = TTSRequest {
+                            id: msg.message_id.clone(),
+                            username: msg.chatter_user_login.clone(),
+                            language: potential_lang_code.to_string(),
+                            text: tts_text.to_string(),
+                            timestamp: chrono::Utc::now(),
+                        };
+
+                        events::publish(BotEvent::TtsRequested {
+                            user: tts_request.username.clone(),
+                            language: tts_request.language.clone(),
+                            text: tts_request.text.clone(),
+                        });
+
+                        // Generate TTS files asynchronously
+                        spawn_tts_generation(
+                            tts_request,
+                            tts_service.clone(),
+                            tts_queue.clone(),
+                            backend_tx.clone(),
+                        );
+                    }
+                }
+                // If it's a valid language code, don't process as regular command
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn spawn_tts_generation(
+    tts_request: TTSRequest,
+    tts_service: Arc<TTSService>,
+    tts_queue: TTSQueue,
+    backend_tx: tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let request_id = tts_request.id.clone();
+    let username = tts_request.username.clone();
+    let service = tts_service.clone();
+
+    service.track_generation(request_id, username, async move {
+        // Limit how many requests hit the TTS backend at once
+        let Some(_permit) = tts_service.acquire_generation_permit().await else {
+            tts_service.finish_generation(&tts_request.id);
+            return;
+        };
+
+        // Split text into chunks
+        let text_chunks = tts_service.split_text(&tts_request.text);
+        let chunk_count = text_chunks.len();
+
+        // Process each chunk as a separate queue item
+        for (chunk_index, text_chunk) in text_chunks.into_iter().enumerate() {
+            // Create unique ID for this chunk
+            let chunk_id = if chunk_count > 1 {
+                format!("{}-{}", tts_request.id, chunk_index)
+            } else {
+                tts_request.id.clone()
+            };
+
+            // Fetch audio for this chunk
+            match tts_service
+                .fetch_tts_audio_with_timeout(&text_chunk, &tts_request.language)
+                .await
+            {
+                Ok(audio_data) => {
+                    let chunk_request = TTSRequest {
+                        id: chunk_id,
+                        username: tts_request.username.clone(),
+                        language: tts_request.language.clone(),
+                        text: text_chunk,
+                        timestamp: tts_request.timestamp,
+                    };
+
+                    let queue_item = TTSQueueItem {
+                        request: chunk_request,
+                        audio_chunks: vec![TTSAudioChunk { audio_data }],
+                    };
+
+                    tts_queue.add(queue_item).await;
+
+                    // Send updated queue to frontend (including currently playing)
+                    let queue_items = tts_queue.get_all_with_current().await;
+                    let ui_queue: Vec<TTSQueueItemUI> = queue_items
+                        .into_iter()
+                        .map(|item| TTSQueueItemUI {
+                            id: item.request.id,
+                            username: item.request.username,
+                            text: item.request.text,
+                            language: item.request.language,
+                        })
+                        .collect();
+                    let _ = backend_tx
+                        .send(BackendToFrontendMessage::TTSQueueUpdated(ui_queue))
+                        .await;
+                }
+                Err(e) => {
+                    error!(
+                        "Failed to fetch TTS audio for chunk {}/{}: {}",
+                        chunk_index + 1,
+                        chunk_count,
+                        e
+                    );
+                    let _ = backend_tx
+                        .send(BackendToFrontendMessage::CreateLog(
+                            LogLevel::ERROR,
+                            format!("Failed to generate TTS chunk: {}", e),
+                        ))
+                        .await;
+                }
+            }
+        }
+
+        tts_service.finish_generation(&tts_request.id);
+    });
+}
+
+/// Drop pending and queued TTS for a deleted chat message
+async fn purge_tts_for_message(
+    message_id: &str,
+    tts_queue: &TTSQueue,
+    tts_service: &Arc<TTSService>,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let cancelled = tts_service.cancel_generation(message_id);
+    let removed = tts_queue.purge_message(message_id).await;
+
+    if cancelled || removed > 0 {
+        info!("Removed TTS for deleted message {}", message_id);
+        send_tts_queue(tts_queue, backend_tx).await;
+    }
+}
+
+/// Drop pending and queued TTS for a banned, timed out or cleared user
+async fn purge_tts_for_user(
+    username: &str,
+    tts_queue: &TTSQueue,
+    tts_service: &Arc<TTSService>,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let cancelled = tts_service.cancel_user_generations(username);
+    let removed = tts_queue.purge_user(username).await;
+
+    if cancelled + removed > 0 {
+        let _ = backend_tx
+            .send(BackendToFrontendMessage::CreateLog(
+                LogLevel::INFO,
+                format!(
+                    "Removed {} pending TTS message(s) from {}",
+                    cancelled + removed,
+                    username
+                ),
+            ))
+            .await;
+        send_tts_queue(tts_queue, backend_tx).await;
+    }
+}
+
+/// Run a command, or hand it to the workers first if it needs external data
+#[allow(clippy::too_many_arguments)]
+async fn dispatch_command(
+    context: crate::backend::commands::CommandContext,
+    command_workers: &mut CommandWorkers,
+    command_registry: &Arc<RwLock<CommandRegistry>>,
+    client: &mut TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+    audio_tx: &AudioPlaybackSender,
+    overlay_ws_state: &crate::backend::overlay::WebSocketState,
+    tts_queue: &TTSQueue,
+    tts_service: &Arc<TTSService>,
+) {
+    // Commands the user can't run now go straight to `handle_command`, which answers
+    // them without fetching anything. Follower-only commands need the follow date
+    // for that, it's cached for `handle_command`.
+    let context = resolve_follow_date(context, command_registry, client).await;
+    let global_cooldown = crate::backend::config::load_config()
+        .command_cooldown
+        .global_cooldown;
+    let pending = check_command(&*command_registry.read().await, &context, global_cooldown)
+        .ok()
+        .and_then(|command| {
+            let templates: Vec<String> = command
+                .action
+                .templates()
+                .into_iter()
+                .map(str::to_string)
+                .collect();
+            let fetches = templates.iter().any(|template| {
+                crate::backend::commands::needs_prefetch(template)
+                    || UserInfo::is_used_in(template)
+                    || ShoutoutTarget::is_used_in(template)
+            });
+            fetches.then(|| (templates, Duration::from_secs(command.timeout)))
+        });
+
+    let Some((templates, timeout)) = pending else {
+        handle_command(
+            context,
+            command_workers,
+            command_registry,
+            client,
+            backend_tx,
+            audio_tx,
+            overlay_ws_state,
+            tts_queue,
+            tts_service,
+        )
+        .await;
+        return;
+    };
+    let command = format!("!{} from {}", context.command_name, context.username());
+    let helix = client.detached();
+    let fetch = |context| async move {
+        for template in &templates {
+            prefetch_json_placeholders(template).await;
+        }
+        let context = resolve_shoutout_target(context, &templates, &helix).await;
+        resolve_user_info(context, &templates, &helix).await
+    };
+    if !command_workers.spawn(context, fetch, timeout) {
+        let _ = backend_tx
+            .send(BackendToFrontendMessage::CreateLog(
+                LogLevel::WARN,
+                format!(
+                    "Too many commands waiting for external data, {} was dropped",
+                    command
+                ),
+            ))
+            .await;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn handle_command(
+    context: crate::backend::commands::CommandContext,
+    command_workers: &mut CommandWorkers,
+    command_registry: &Arc<RwLock<CommandRegistry>>,
+    client: &mut TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+    audio_tx: &AudioPlaybackSender,
+    overlay_ws_state: &crate::backend::overlay::WebSocketState,
+    tts_queue: &TTSQueue,
+    tts_service: &Arc<TTSService>,
+) {
+    let context = resolve_follow_date(context, command_registry, client).await;
+
+    let cooldown_config = crate::backend::config::load_config().command_cooldown;
+
+    // Lock the registry and execute command
+    let (result, cooldowns) = {
+        let mut registry = command_registry.write().await;
+        let mut executor = CommandExecutor::new(registry.clone())
+            .with_global_cooldown(cooldown_config.global_cooldown);
+        let result = executor.execute(&context);
+
+        // Update cooldowns in the shared registry
+        *registry = executor.registry().clone();
+        let cooldowns = (matches!(result, CommandResult::Success(_))
+            && registry
+                .has_lasting_cooldown(&context.command_name, cooldown_config.global_cooldown))
+        .then(|| registry.cooldowns().clone());
+        (result, cooldowns)
+    };
+
+    // Saved once the registry is unlocked, so other commands don't wait on the disk
+    if let Some(cooldowns) = cooldowns {
+        crate::backend::config::save_cooldowns(&cooldowns);
+    }
+
+    // Keywords only run their own command and stay quiet on cooldown or without
+    // permission, otherwise every matching message would get an answer
+    if context.keyword && !matches!(result, CommandResult::Success(_) | CommandResult::Error(_)) {
+        return;
+    }
+
+    // Fall back to Guest Star management, viewer-owned command management and
+    // built-in commands when no custom command matches. Managing commands only counts
+    // as a use when it changed them.
+    let mut counts_as_use = true;
+    let result = match result {
+        CommandResult::NotFound if context.command_name == GUEST_STAR_COMMAND => {
+            run_guest_star_command(&context, client, backend_tx).await
+        }
+        CommandResult::NotFound
+            if context.command_name == BLOCK_TERM_COMMAND
+                || context.command_name == UNBLOCK_TERM_COMMAND =>
+        {
+            run_blocked_term_command(&context, client, backend_tx).await
+        }
+        CommandResult::NotFound
+            if STREAM_INFO_COMMANDS.contains(&context.command_name.as_str()) =>
+        {
+            run_stream_info_command(&context, client, backend_tx).await
+        }
+        CommandResult::NotFound if context.command_name == CLIP_COMMAND => {
+            run_clip_command(&context, client, backend_tx).await
+        }
+        CommandResult::NotFound if context.command_name == POLL_COMMAND => {
+            run_poll_command(&context, client).await
+        }
+        CommandResult::NotFound
+            if context.command_name == PREDICTION_COMMAND
+                || context.command_name == OUTCOME_COMMAND =>
+        {
+            run_prediction_command(&context, client).await
+        }
+        CommandResult::NotFound if context.command_name == RAID_COMMAND => {
+            run_raid_command(&context, client, backend_tx).await
+        }
+        CommandResult::NotFound
+            if context.command_name == BAN_COMMAND
+                || context.command_name == TIMEOUT_COMMAND
+                || context.command_name == UNBAN_COMMAND =>
+        {
+            run_moderation_command(&context, client, backend_tx).await
+        }
+        CommandResult::NotFound if context.command_name == NUKE_COMMAND => {
+            run_nuke_command(&context, client, backend_tx).await
+        }
+        CommandResult::NotFound
+            if context.command_name == MOD_COMMAND || context.command_name == UNMOD_COMMAND =>
+        {
+            run_moderator_command(&context, client, backend_tx).await
+        }
+        CommandResult::NotFound if context.command_name == SHIELD_COMMAND => {
+            run_shield_command(&context, client, backend_tx).await
+        }
+        CommandResult::NotFound if CHAT_MODE_COMMANDS.contains(&context.command_name.as_str()) => {
+            run_chat_mode_command(&context, client, backend_tx).await
+        }
+        CommandResult::NotFound if context.command_name == GIVEAWAY_COMMAND => {
+            run_giveaway_command(&context, backend_tx).await
+        }
+        CommandResult::NotFound if context.command_name == NICK_COMMAND => {
+            run_nick_command(&context, backend_tx).await
+        }
+        CommandResult::NotFound
+            if [QUOTE_COMMAND, ADD_QUOTE_COMMAND, DEL_QUOTE_COMMAND]
+                .contains(&context.command_name.as_str()) =>
+        {
+            run_quote_command(&context, backend_tx).await
+        }
+        CommandResult::NotFound if context.command_name == UPTIME_COMMAND => {
+            run_uptime_command(&context, client).await
+        }
+        CommandResult::NotFound if context.command_name == AUTOMOD_COMMAND => {
+            run_automod_command(&context, client).await
+        }
+        CommandResult::NotFound if context.command_name == SNOOZE_AD_COMMAND => {
+            run_snooze_ad_command(&context, client, backend_tx).await
+        }
+        CommandResult::NotFound if context.command_name == FOLLOWAGE_COMMAND => {
+            run_followage_command(&context, client).await
+        }
+        CommandResult::NotFound
+            if [DEATH_ADD_COMMAND, DEATHS_COMMAND, RESET_DEATHS_COMMAND]
+                .contains(&context.command_name.as_str()) =>
+        {
+            run_death_command(&context, client, overlay_ws_state).await
+        }
+        CommandResult::NotFound if context.command_name == COMMANDS_COMMAND => {
+            list_commands(&context, &*command_registry.read().await)
+        }
+        CommandResult::NotFound
+            if crate::backend::config::load_config()
+                .link_requests
+                .command(&context.command_name)
+                .is_some() =>
+        {
+            run_link_request_command(&context, backend_tx).await
+        }
+        CommandResult::NotFound => {
+            let config = crate::backend::config::load_config();
+            let mut registry = command_registry.write().await;
+            match crate::backend::commands::execute_user_command(
+                &context,
+                &mut registry,
+                &config.user_commands,
+            )
+            .map(|result| {
+                let changed = matches!(result, CommandResult::Success(_));
+                (result, changed)
+            })
+            .or_else(|| crate::backend::commands::execute_add_command(&context, &mut registry))
+            .or_else(|| crate::backend::commands::execute_edit_command(&context, &mut registry))
+            {
+                Some((result, changed)) => {
+                    if changed {
+                        crate::backend::config::save_commands(&registry);
+                        send_commands_updated(&registry, backend_tx);
+                    }
+                    counts_as_use = changed;
+                    result
+                }
+                None => crate::backend::commands::execute_builtin(&context)
+                    .unwrap_or(CommandResult::NotFound),
+            }
+        }
+        other => other,
+    };
+
+    if counts_as_use && matches!(result, CommandResult::Success(_)) {
+        events::publish(BotEvent::CommandExecuted {
+            command: context.command_name.clone(),
+            user: context.username().to_string(),
+        });
+        let usage = record_command_usage(&context.command_name);
+        let _ = backend_tx.try_send(BackendToFrontendMessage::CommandUsageUpdated(usage));
+    }
+
+    match result {
+        CommandResult::Success(Some(actions)) => {
+            // Commands running several actions have an output for each of them
+            for (idx, action) in actions.split(ACTION_SEPARATOR).enumerate() {
+                if let Some(sound) = action.strip_prefix("sound:") {
+                    play_command_sound(sound, audio_tx, backend_tx).await;
+                } else if let Some(speech) = action.strip_prefix("tts:") {
+                    let id = format!("{}-{}", context.message_id(), idx);
+                    speak_command_tts(&context, id, speech, tts_queue, tts_service, backend_tx)
+                        .await;
+                } else if let Some(request) = action.strip_prefix("process:") {
+                    start_command_process(&context, request, command_workers, backend_tx).await;
+                } else if context.is_whisper() {
+                    whisper_command_action(&context, action, client, backend_tx).await;
+                } else if context.is_redemption() {
+                    handle_command_action(redemption_action(action), client, backend_tx).await;
+                } else {
+                    handle_command_action(action.to_string(), client, backend_tx).await;
+                }
+            }
+        }
+        CommandResult::Success(None) => {}
+        CommandResult::Error(e) => {
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::CreateLog(
+                    LogLevel::ERROR,
+                    format!("Command error: {}", e),
+                ))
+                .await;
+        }
+        CommandResult::NotFound => {
+            handle_sound_file(&context, audio_tx);
         }
-        CommandResult::NotFound => {
-            handle_sound_file(&context, audio_tx);
-        }
         CommandResult::PermissionDenied => {
+            let command = find_command(&context, command_registry).await;
+            let feedback = command
+                .as_ref()
+                .map(|c| c.failure_feedback.clone())
+                .unwrap_or_default();
+
+            if feedback == FailureFeedback::Log {
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::WARN,
+                        format!(
+                            "User {} tried to use command !{} without permission",
+                            context.username(),
+                            context.command_name
+                        ),
+                    ))
+                    .await;
+            }
+
+            let response = command.and_then(|c| c.permission_denied_response(&context));
+            let default = format!("You don't have permission to use !{}", context.command_name);
+            report_command_failure(&context, feedback, response, default, client, backend_tx).await;
+        }
+        CommandResult::OnCooldown(remaining) if cooldown_config.queue => {
+            let command = format!("!{} from {}", context.command_name, context.username());
+            let queued = queue_command(context, remaining, cooldown_config.max_queued);
+            let (level, message) = match queued {
+                Ok(()) => (
+                    LogLevel::INFO,
+                    format!("{} is on cooldown, it runs in {}s", command, remaining),
+                ),
+                Err(QueueRejection::AlreadyQueued) => (
+                    LogLevel::INFO,
+                    format!("{} is already waiting for the cooldown", command),
+                ),
+                Err(QueueRejection::Full) => (
+                    LogLevel::WARN,
+                    format!(
+                        "Too many commands waiting for a cooldown, {} was dropped",
+                        command
+                    ),
+                ),
+            };
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::CreateLog(level, message))
+                .await;
+        }
+        CommandResult::OnCooldown(remaining) => {
+            let command = find_command(&context, command_registry).await;
+            let feedback = command
+                .as_ref()
+                .map(|c| c.failure_feedback.clone())
+                .unwrap_or_default();
+
+            let response = command.and_then(|c| c.cooldown_response(&context, remaining));
+            let default = format!(
+                "!{} is on cooldown, try again in {}s",
+                context.command_name, remaining
+            );
+            report_command_failure(&context, feedback, response, default, client, backend_tx).await;
+        }
+        CommandResult::LimitReached(uses) => {
+            let feedback = find_command(&context, command_registry)
+                .await
+                .map(|c| c.failure_feedback)
+                .unwrap_or_default();
+            let times = match uses {
+                1 => "once".to_string(),
+                uses => format!("{} times", uses),
+            };
+            if feedback == FailureFeedback::Log {
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        format!(
+                            "User {} already used !{} {} this stream",
+                            context.username(),
+                            context.command_name,
+                            times
+                        ),
+                    ))
+                    .await;
+            }
+
+            let default = format!("You can use !{} {} per stream", context.command_name, times);
+            report_command_failure(&context, feedback, None, default, client, backend_tx).await;
+        }
+    }
+}
+
+/// Look up when the user followed the channel if the command is follower-only.
+/// Needs moderator:read:followers, users count as not following without it.
+async fn resolve_follow_date(
+    mut context: crate::backend::commands::CommandContext,
+    command_registry: &Arc<RwLock<CommandRegistry>>,
+    client: &TwitchClient,
+) -> crate::backend::commands::CommandContext {
+    let needs_follow_date = command_registry
+        .read()
+        .await
+        .get(&context.command_name)
+        .is_some_and(|command| {
+            command.permission.needs_follow_date()
+                && command.user_override(context.username()).is_none()
+        });
+    if !needs_follow_date || context.identity().role() >= UserRole::Moderator {
+        return context;
+    }
+
+    context.followed_at = lookup_followed_at(&context, client).await.unwrap_or(None);
+    context
+}
+
+/// Follow date of the user who ran the command, from the cache if it was looked up
+/// recently. `None` if the lookup failed.
+async fn lookup_followed_at(
+    context: &crate::backend::commands::CommandContext,
+    client: &TwitchClient,
+) -> Option<Option<chrono::DateTime<chrono::Utc>>> {
+    if let Some(followed_at) = cached_followed_at(context.user_id()) {
+        return Some(followed_at);
+    }
+    match client.get_followed_at(context.user_id()).await {
+        Ok(followed_at) => {
+            cache_followed_at(context.user_id(), followed_at);
+            Some(followed_at)
+        }
+        Err(e) => {
+            log::warn!(
+                "Failed to look up whether {} follows: {}",
+                context.username(),
+                e
+            );
+            None
+        }
+    }
+}
+
+/// Look up the pronouns, account age and follow date of the user if the command
+/// uses `{pronouns}`, `{accountage}` or `{followage}`. Only the used ones are fetched.
+/// Runs in the command workers, after checking that the user may run the command.
+async fn resolve_user_info(
+    mut context: crate::backend::commands::CommandContext,
+    templates: &[String],
+    client: &TwitchClient,
+) -> crate::backend::commands::CommandContext {
+    let templates = templates.join("\n");
+    if !UserInfo::is_used_in(&templates) {
+        return context;
+    }
+
+    let mut info = UserInfo::default();
+    if templates.contains(PRONOUNS_PLACEHOLDER) {
+        info.pronouns = fetch_pronouns(context.username()).await;
+    }
+    if templates.contains(ACCOUNT_AGE_PLACEHOLDER) {
+        info.created_at = match cached_created_at(context.user_id()) {
+            Some(created_at) => created_at,
+            None => {
+                let created_at = client
+                    .get_account_created_at(context.username())
+                    .await
+                    .map_err(|e| {
+                        log::warn!(
+                            "Failed to look up the account age of {}: {}",
+                            context.username(),
+                            e
+                        )
+                    })
+                    .ok();
+                cache_created_at(context.user_id(), created_at);
+                created_at
+            }
+        };
+    }
+    if templates.contains(FOLLOW_AGE_PLACEHOLDER) {
+        info.followed_at = match context.followed_at {
+            Some(followed_at) => Some(Some(followed_at)),
+            None => lookup_followed_at(&context, client).await,
+        };
+    }
+    context.user_info = Some(info);
+    context
+}
+
+/// Look up the game and a clip of the channel in the first argument if the command
+/// uses `{target_game}` or `{target_clip}`. Placeholders fall back when it fails.
+/// Runs in the command workers, after checking that the user may run the command.
+async fn resolve_shoutout_target(
+    mut context: crate::backend::commands::CommandContext,
+    templates: &[String],
+    client: &TwitchClient,
+) -> crate::backend::commands::CommandContext {
+    if !templates
+        .iter()
+        .any(|template| ShoutoutTarget::is_used_in(template))
+    {
+        return context;
+    }
+
+    let login = context
+        .args
+        .first()
+        .map(|arg| arg.trim_start_matches('@').to_lowercase())
+        .unwrap_or_default();
+    if let Some(target) = ShoutoutTarget::cached(&login) {
+        context.shoutout_target = Some(target);
+        return context;
+    }
+
+    let mut target = ShoutoutTarget {
+        login,
+        ..Default::default()
+    };
+    if !target.login.is_empty() {
+        match client.get_shoutout_info(&target.login).await {
+            Ok((game, clip)) => {
+                target.game = Some(game);
+                target.clip = clip;
+            }
+            Err(e) => log::warn!("Failed to look up channel info of {}: {}", target.login, e),
+        }
+        target.cache();
+    }
+    context.shoutout_target = Some(target);
+    context
+}
+
+async fn find_command(
+    context: &crate::backend::commands::CommandContext,
+    command_registry: &Arc<RwLock<CommandRegistry>>,
+) -> Option<crate::backend::commands::Command> {
+    command_registry
+        .read()
+        .await
+        .get(&context.command_name)
+        .cloned()
+}
+
+/// Tell the user why a command didn't run. Custom responses are posted in chat,
+/// whisper mode falls back to the default text when no custom response is set.
+/// Whispered commands always get their response as a whisper.
+async fn report_command_failure(
+    context: &crate::backend::commands::CommandContext,
+    feedback: FailureFeedback,
+    response: Option<String>,
+    default: String,
+    client: &mut TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    match feedback {
+        FailureFeedback::Silent => {}
+        FailureFeedback::Whisper => {
+            let message = response.unwrap_or(default);
+            whisper_user(context, &message, client, backend_tx).await;
+        }
+        FailureFeedback::Log => {
+            if let Some(response) = response {
+                if context.is_whisper() {
+                    whisper_user(context, &response, client, backend_tx).await;
+                    return;
+                }
+                if context.is_redemption() {
+                    handle_command_action(format!("send:{}", response), client, backend_tx).await;
+                    return;
+                }
+                let action = format!("reply:{}:{}", context.message_id(), response);
+                handle_command_action(action, client, backend_tx).await;
+            }
+        }
+    }
+}
+
+/// Redemptions have no chat message to reply to, their replies are sent as messages
+fn redemption_action(action: &str) -> String {
+    match action
+        .strip_prefix("reply:")
+        .and_then(|reply| reply.split_once(':'))
+    {
+        Some((_, message)) => format!("send:{}", message),
+        None => action.to_string(),
+    }
+}
+
+async fn whisper_user(
+    context: &crate::backend::commands::CommandContext,
+    message: &str,
+    client: &mut TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    if let Err(e) = client.send_whisper(context.user_id(), message).await {
+        error!("Failed to whisper {}: {}", context.username(), e);
+        let _ = backend_tx
+            .send(BackendToFrontendMessage::CreateLog(
+                LogLevel::ERROR,
+                format!("Failed to whisper {}: {}", context.username(), e),
+            ))
+            .await;
+    }
+}
+
+/// Answer a whispered command with a whisper instead of posting in chat
+async fn whisper_command_action(
+    context: &crate::backend::commands::CommandContext,
+    action: &str,
+    client: &mut TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    if let Some(login) = action.strip_prefix("shoutout:") {
+        send_shoutout(login, client, backend_tx).await;
+        return;
+    }
+
+    let message = action.strip_prefix("send:").or_else(|| {
+        action
+            .strip_prefix("reply:")
+            .or_else(|| action.strip_prefix("announce:"))
+            .or_else(|| action.strip_prefix("whisper:"))
+            .and_then(|reply| reply.split_once(':'))
+            .map(|(_, message)| message)
+    });
+
+    if let Some(message) = message {
+        whisper_user(context, message, client, backend_tx).await;
+    }
+}
+
+/// Read out `<language>:<text>` from a command with TTS, if TTS is enabled
+async fn speak_command_tts(
+    context: &crate::backend::commands::CommandContext,
+    id: String,
+    speech: &str,
+    tts_queue: &TTSQueue,
+    tts_service: &Arc<TTSService>,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let Some((language, text)) = speech.split_once(':') else {
+        return;
+    };
+    let config = crate::backend::config::load_config();
+    if text.trim().is_empty() || !config.tts.enabled || !active_features(&config).tts {
+        return;
+    }
+    if tts_queue.is_user_ignored(context.username()).await {
+        return;
+    }
+
+    let tts_request = TTSRequest {
+        id,
+        username: context.username().to_string(),
+        language: language.to_string(),
+        text: text.to_string(),
+        timestamp: chrono::Utc::now(),
+    };
+
+    events::publish(BotEvent::TtsRequested {
+        user: tts_request.username.clone(),
+        language: tts_request.language.clone(),
+        text: tts_request.text.clone(),
+    });
+
+    spawn_tts_generation(
+        tts_request,
+        tts_service.clone(),
+        tts_queue.clone(),
+        backend_tx.clone(),
+    );
+}
+
+async fn handle_command_action(
+    action: String,
+    client: &mut TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    if let Some(send_msg) = action.strip_prefix("send:") {
+        if let Err(e) = client.send_message(send_msg).await {
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::CreateLog(
+                    LogLevel::ERROR,
+                    format!("Failed to send message: {}", e),
+                ))
+                .await;
+        }
+    } else if let Some(reply_parts) = action.strip_prefix("reply:") {
+        let parts: Vec<&str> = reply_parts.splitn(2, ':').collect();
+        if parts.len() == 2 {
+            let message_id = parts[0];
+            let reply_msg = parts[1];
+            if let Err(e) = client.reply_to_message(reply_msg, message_id).await {
+                error!("Failed to reply: {}", e);
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::ERROR,
+                        format!("Failed to reply: {}", e),
+                    ))
+                    .await;
+            }
+        }
+    } else if let Some(announcement) = action.strip_prefix("announce:") {
+        if let Some((color, message)) = announcement.split_once(':') {
+            if let Err(e) = client.send_announcement(message, color).await {
+                error!("Failed to send announcement: {}", e);
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::ERROR,
+                        format!("Failed to send announcement: {}", e),
+                    ))
+                    .await;
+            }
+        }
+    } else if let Some(login) = action.strip_prefix("shoutout:") {
+        send_shoutout(login, client, backend_tx).await;
+    } else if let Some(whisper) = action.strip_prefix("whisper:") {
+        if let Some((user_id, message)) = whisper.split_once(':') {
+            if let Err(e) = client.send_whisper(user_id, message).await {
+                error!("Failed to send whisper: {}", e);
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::ERROR,
+                        format!("Failed to send whisper: {}", e),
+                    ))
+                    .await;
+            }
+        }
+    }
+}
+
+/// Send a Twitch shoutout unless it would hit Twitch's shoutout rate limits
+async fn send_shoutout(
+    login: &str,
+    client: &TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    if let Some(remaining) = crate::backend::commands::shoutout_cooldown(login) {
+        let _ = backend_tx
+            .send(BackendToFrontendMessage::CreateLog(
+                LogLevel::WARN,
+                format!(
+                    "Shoutout to {} is on cooldown for {} more seconds",
+                    login, remaining
+                ),
+            ))
+            .await;
+        return;
+    }
+
+    match client.send_shoutout(login).await {
+        Ok(()) => crate::backend::commands::record_shoutout(login),
+        Err(e) => {
+            error!("Failed to send shoutout: {}", e);
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::CreateLog(
+                    LogLevel::ERROR,
+                    format!("Failed to shout out {}: {}", login, e),
+                ))
+                .await;
+        }
+    }
+}
+
+/// Play the sound of a PlaySound command action. The command's own permission
+/// applies instead of the SFX roles.
+async fn play_command_sound(
+    sound: &str,
+    audio_tx: &AudioPlaybackSender,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let Some(sound_file) = crate::backend::sfx::Soundlist::file_name(sound) else {
+        let _ = backend_tx
+            .send(BackendToFrontendMessage::CreateLog(
+                LogLevel::WARN,
+                format!("Command sound \"{}\" isn't in assets/sounds", sound),
+            ))
+            .await;
+        return;
+    };
+
+    let config = crate::backend::config::load_config();
+    if config.sfx.enabled && !crate::backend::sfx::is_paused() && active_features(&config).sfx {
+        let _ = audio_tx.send_sound(sound_file, config.sfx.volume as f32);
+        events::publish(BotEvent::SoundPlayed(sound.to_string()));
+    }
+}
+
+/// Start the program of a RunProcess command action in the background if
+/// `[run_process]` allows it, `reply_process_output` handles it once it's done
+async fn start_command_process(
+    context: &crate::backend::commands::CommandContext,
+    request: &str,
+    command_workers: &mut CommandWorkers,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let config = crate::backend::config::load_config().run_process;
+    let request = match serde_json::from_str::<ProcessRequest>(request) {
+        Ok(request) => request,
+        Err(e) => {
+            error!("Invalid program request: {}", e);
+            return;
+        }
+    };
+
+    let result = config
+        .check(&request.program, context.identity())
+        .and_then(|()| {
+            let timeout = Duration::from_secs(config.timeout);
+            if command_workers.spawn_process(context.clone(), request, timeout) {
+                Ok(())
+            } else {
+                Err("too many programs are running".to_string())
+            }
+        });
+    if let Err(e) = result {
+        let _ = backend_tx
+            .send(BackendToFrontendMessage::CreateLog(
+                LogLevel::WARN,
+                format!("!{} didn't run a program: {}", context.command_name, e),
+            ))
+            .await;
+    }
+}
+
+/// Reply with the output of a command's program when the action asks for it
+async fn reply_process_output(
+    context: &crate::backend::commands::CommandContext,
+    request: &ProcessRequest,
+    result: Result<String, String>,
+    client: &mut TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let output = match result {
+        Ok(output) => output,
+        Err(e) => {
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::CreateLog(
+                    LogLevel::WARN,
+                    format!("!{} didn't run a program: {}", context.command_name, e),
+                ))
+                .await;
+            return;
+        }
+    };
+
+    if !request.reply || output.is_empty() {
+        return;
+    }
+    if context.is_whisper() {
+        whisper_user(context, &output, client, backend_tx).await;
+    } else if context.is_redemption() {
+        handle_command_action(format!("send:{}", output), client, backend_tx).await;
+    } else {
+        let action = format!("reply:{}:{}", context.message_id(), output);
+        handle_command_action(action, client, backend_tx).await;
+    }
+}
+
+fn handle_sound_file(
+    context: &crate::backend::commands::CommandContext,
+    audio_tx: &AudioPlaybackSender,
+) {
+    // Check if there's a sound file with this name
+    if let Some(sound_file) = crate::backend::sfx::Soundlist::file_name(&context.command_name) {
+        // Check if user has permission to play sounds
+        let config = crate::backend::config::load_config();
+        let has_permission = context
+            .identity()
+            .is_permitted(&config.sfx.permited_roles, shield_mode_restricts(&config));
+
+        if has_permission
+            && config.sfx.enabled
+            && !crate::backend::sfx::is_paused()
+            && active_features(&config).sfx
+            && !config.giveaway.suppresses(&context.command_name)
+        {
+            // Play the sound with volume from sfx config
+            let _ = audio_tx.send_sound(sound_file, config.sfx.volume as f32);
+            events::publish(BotEvent::SoundPlayed(context.command_name.clone()));
+        }
+    }
+}
+
+async fn handle_ban_event(
+    ban: &crate::backend::twitch::ChannelBanEvent,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let ban_type = if ban.is_permanent {
+        "permanently banned"
+    } else {
+        "timed out"
+    };
+    let duration_info = if let Some(ref ends_at) = ban.ends_at {
+        format!(" (until {})", ends_at)
+    } else {
+        String::new()
+    };
+
+    info!(
+        "🔨 {} was {} by {}: {}{}",
+        ban.user_name, ban_type, ban.moderator_user_name, ban.reason, duration_info
+    );
+
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(
+            LogLevel::WARN,
+            format!(
+                "{} was {} by {}: {}{}",
+                ban.user_name, ban_type, ban.moderator_user_name, ban.reason, duration_info
+            ),
+        ))
+        .await;
+}
+
+/// Send an overlay alert unless alerts are disabled for the current stream state
+async fn broadcast_alert(
+    overlay_ws_state: &crate::backend::overlay::WebSocketState,
+    event: crate::backend::overlay::OverlayEvent,
+) {
+    let config = crate::backend::config::load_config();
+    if active_features(&config).alerts {
+        events::publish(BotEvent::Alert(event.clone()));
+        overlay_ws_state.broadcast(event).await;
+    }
+}
+
+/// Goal bars stay on screen, so they are kept in sync even while alerts are disabled
+async fn broadcast_goal(
+    overlay_ws_state: &crate::backend::overlay::WebSocketState,
+    goal_id: String,
+    goal_type: String,
+    description: String,
+    status: &str,
+    current: i64,
+    target: i64,
+) {
+    overlay_ws_state
+        .broadcast(crate::backend::overlay::OverlayEvent::Goal {
+            goal_id,
+            goal_type,
+            description,
+            status: status.to_string(),
+            current,
+            target,
+        })
+        .await;
+}
+
+/// Log a received shoutout and thank the channel if a thanks message is configured
+async fn handle_shoutout_receive_event(
+    shoutout: &crate::backend::twitch::ShoutoutReceiveEvent,
+    client: &mut TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    info!(
+        "📣 Received a shoutout from {} ({} viewers)",
+        shoutout.from_broadcaster_user_name, shoutout.viewer_count
+    );
+
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(
+            LogLevel::INFO,
+            format!(
+                "{} gave you a shoutout to {} viewers",
+                shoutout.from_broadcaster_user_name, shoutout.viewer_count
+            ),
+        ))
+        .await;
+
+    let thanks = crate::backend::config::load_config()
+        .chatbot
+        .shoutout_thanks_message;
+    if thanks.trim().is_empty() {
+        return;
+    }
+
+    let message = thanks.replace("{user}", &shoutout.from_broadcaster_user_name);
+    if let Err(e) = client.send_message(&message).await {
+        error!("Failed to send shoutout thanks: {}", e);
+        let _ = backend_tx
+            .send(BackendToFrontendMessage::CreateLog(
+                LogLevel::ERROR,
+                format!("Failed to send shoutout thanks: {}", e),
+            ))
+            .await;
+    }
+}
+
+/// Log the first chatter and greet them with the configured message
+async fn announce_first_chatter(
+    user_name: &str,
+    firsts: u64,
+    client: &mut TwitchClient,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(
+            LogLevel::INFO,
+            format!(
+                "First chatter of the stream: {} ({} firsts)",
+                user_name, firsts
+            ),
+        ))
+        .await;
+
+    let greeting = crate::backend::config::load_config()
+        .chatbot
+        .first_chatter_message;
+    if greeting.trim().is_empty() {
+        return;
+    }
+
+    let message = greeting
+        .replace("{user}", user_name)
+        .replace("{count}", &firsts.to_string());
+    if let Err(e) = client.send_message(&message).await {
+        error!("Failed to send first chatter greeting: {}", e);
+        let _ = backend_tx
+            .send(BackendToFrontendMessage::CreateLog(
+                LogLevel::ERROR,
+                format!("Failed to send first chatter greeting: {}", e),
+            ))
+            .await;
+    }
+}
+
+/// Log a charity donation, thank the donor in chat and read it out with TTS
+async fn handle_charity_donate_event(
+    donation: &crate::backend::twitch::CharityDonateEvent,
+    client: &mut TwitchClient,
+    tts_queue: &TTSQueue,
+    tts_service: &Arc<TTSService>,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let config = crate::backend::config::load_config();
+    let fill = |template: &str| {
+        template
+            .replace("{user}", &donation.user_name)
+            .replace("{amount}", &donation.amount.to_string())
+            .replace("{charity}", &donation.charity_name)
+    };
+
+    info!(
+        "💝 {} donated {} to {}",
+        donation.user_name, donation.amount, donation.charity_name
+    );
+
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(
+            LogLevel::INFO,
+            format!(
+                "{} donated {} to {}",
+                donation.user_name, donation.amount, donation.charity_name
+            ),
+        ))
+        .await;
+
+    if !config.charity.thanks_message.trim().is_empty() {
+        if let Err(e) = client
+            .send_message(&fill(&config.charity.thanks_message))
+            .await
+        {
+            error!("Failed to send charity thanks: {}", e);
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::CreateLog(
+                    LogLevel::ERROR,
+                    format!("Failed to send charity thanks: {}", e),
+                ))
+                .await;
+        }
+    }
+
+    let read_out = config.charity.tts
+        && config.tts.enabled
+        && active_features(&config).tts
+        && donation.amount.amount() >= config.charity.tts_min_amount
+        && !config.charity.tts_message.trim().is_empty()
+        && !tts_queue.is_user_ignored(&donation.user_login).await;
+    if !read_out {
+        return;
+    }
+
+    let tts_request = TTSRequest {
+        id: donation.id.clone(),
+        username: donation.user_login.clone(),
+        language: config.charity.tts_language.clone(),
+        text: fill(&config.charity.tts_message),
+        timestamp: chrono::Utc::now(),
+    };
+
+    events::publish(BotEvent::TtsRequested {
+        user: tts_request.username.clone(),
+        language: tts_request.language.clone(),
+        text: tts_request.text.clone(),
+    });
+
+    spawn_tts_generation(
+        tts_request,
+        tts_service.clone(),
+        tts_queue.clone(),
+        backend_tx.clone(),
+    );
+}
+
+async fn handle_ad_break_event(
+    ad_break: &crate::backend::twitch::AdBreakBeginEvent,
+    client: &mut TwitchClient,
+    audio_tx: &AudioPlaybackSender,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+    overlay_ws_state: &crate::backend::overlay::WebSocketState,
+) {
+    let config = crate::backend::config::load_config();
+    let kind = if ad_break.is_automatic {
+        "Automatic ad break"
+    } else {
+        "Ad break"
+    };
+
+    info!("📺 {} started ({}s)", kind, ad_break.duration_seconds);
+
+    let log_message = if config.ads.pause_playback {
+        audio_tx.hold().hold_for(std::time::Duration::from_secs(
+            ad_break.duration_seconds as u64,
+        ));
+        format!(
+            "{} started ({}s) - holding SFX/TTS until it ends",
+            kind, ad_break.duration_seconds
+        )
+    } else {
+        format!("{} started ({}s)", kind, ad_break.duration_seconds)
+    };
+
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(
+            LogLevel::INFO,
+            log_message,
+        ))
+        .await;
+
+    if !config.ads.chat_message.trim().is_empty() {
+        let message = config
+            .ads
+            .chat_message
+            .replace("{duration}", &ad_break.duration_seconds.to_string());
+
+        if let Err(e) = client.send_message(&message).await {
+            error!("Failed to send ad break message: {}", e);
             let _ = backend_tx
                 .send(BackendToFrontendMessage::CreateLog(
-                    LogLevel::WARN,
-                    format!(
-                        "User {} tried to use command !{} without permission",
-                        context.username(),
-                        context.command_name
-                    ),
+                    LogLevel::ERROR,
+                    format!("Failed to send ad break message: {}", e),
                 ))
                 .await;
         }
-        CommandResult::OnCooldown(_remaining) => {}
     }
+
+    broadcast_alert(
+        overlay_ws_state,
+        crate::backend::overlay::OverlayEvent::AdBreak {
+            duration_seconds: ad_break.duration_seconds,
+            started_at: ad_break.started_at.clone(),
+        },
+    )
+    .await;
 }
 
-async fn handle_command_action(
-    action: String,
+async fn handle_poll_end_event(
+    poll: &crate::backend::twitch::PollEndEvent,
     client: &mut TwitchClient,
     backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+    overlay_ws_state: &crate::backend::overlay::WebSocketState,
 ) {
-    if let Some(send_msg) = action.strip_prefix("send:") {
-        if let Err(e) = client.send_message(send_msg).await {
-            let _ = backend_tx
-                .send(BackendToFrontendMessage::CreateLog(
-                    LogLevel::ERROR,
-                    format!("Failed to send message: {}", e),
-                ))
-                .await;
-        }
-    } else if let Some(reply_parts) = action.strip_prefix("reply:") {
-        let parts: Vec<&str> = reply_parts.splitn(2, ':').collect();
-        if parts.len() == 2 {
-            let message_id = parts[0];
-            let reply_msg = parts[1];
-            if let Err(e) = client.reply_to_message(reply_msg, message_id).await {
-                error!("Failed to reply: {}", e);
-                let _ = backend_tx
-                    .send(BackendToFrontendMessage::CreateLog(
-                        LogLevel::ERROR,
-                        format!("Failed to reply: {}", e),
-                    ))
-                    .await;
-            }
-        }
-    }
-}
+    // Archived polls were already reported when they completed or were terminated
+    if poll.status != "archived" {
+        let total_votes: u32 = poll.choices.iter().map(|c| c.votes).sum();
+        let outcome = match poll.winner() {
+            Some(choice) => format!(
+                "\"{}\" won with {}/{} votes",
+                choice.title, choice.votes, total_votes
+            ),
+            None if total_votes == 0 => "no votes".to_string(),
+            None => format!("tie with {} votes", total_votes),
+        };
 
-fn handle_sound_file(
-    context: &crate::backend::commands::CommandContext,
-    audio_tx: &AudioPlaybackSender,
-) {
-    // Check if there's a sound file with this name
-    let sound_format = crate::backend::sfx::Soundlist::get_format();
-    let sound_path = format!("./assets/sounds/{}.{}", context.command_name, sound_format);
+        info!("📊 Poll {} ({}): {}", poll.status, poll.title, outcome);
 
-    if std::path::Path::new(&sound_path).exists() {
-        // Check if user has permission to play sounds
-        let config = crate::backend::config::load_config();
-        let has_permission = context.badges().iter().any(|badge| {
-            (badge.set_id == "subscriber" || badge.set_id == "founder")
-                && config.sfx.permited_roles.subs
-                || badge.set_id == "vip" && config.sfx.permited_roles.vips
-                || badge.set_id == "moderator" && config.sfx.permited_roles.mods
-                || badge.set_id == "broadcaster"
-        });
+        let _ = backend_tx
+            .send(BackendToFrontendMessage::CreateLog(
+                LogLevel::INFO,
+                format!("Poll {}: {} - {}", poll.status, poll.title, outcome),
+            ))
+            .await;
 
-        if has_permission && config.sfx.enabled {
-            // Play the sound with volume from sfx config
-            let sound_file = format!("{}.{}", context.command_name, sound_format);
-            let _ = audio_tx.send_sound(sound_file, config.sfx.volume as f32);
+        let results_message = crate::backend::config::load_config().poll.results_message;
+        if !results_message.trim().is_empty() {
+            let message = results_message
+                .replace("{title}", &poll.title)
+                .replace("{outcome}", &outcome);
+            if let Err(e) = client.send_message(&message).await {
+                error!("Failed to send poll results: {}", e);
+            }
         }
     }
+
+    broadcast_alert(
+        overlay_ws_state,
+        crate::backend::overlay::OverlayEvent::Poll {
+            poll_id: poll.id.clone(),
+            title: poll.title.clone(),
+            status: poll.status.clone(),
+            choices: serde_json::to_value(&poll.choices).unwrap_or_default(),
+            ends_at: None,
+        },
+    )
+    .await;
 }
 
-async fn handle_ban_event(
-    ban: &crate::backend::twitch::ChannelBanEvent,
+/// Log chat notices and show subs, gift subs and raids as overlay alerts
+async fn handle_chat_notification(
+    notification: &crate::backend::twitch::ChatNotificationEvent,
     backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+    overlay_ws_state: &crate::backend::overlay::WebSocketState,
 ) {
-    let ban_type = if ban.is_permanent {
-        "permanently banned"
+    use crate::backend::twitch::ChatNotice;
+
+    info!(
+        "🔔 {}: {}",
+        notification.notice_type, notification.system_message
+    );
+
+    let user_name = if notification.chatter_is_anonymous {
+        "Anonymous".to_string()
     } else {
-        "timed out"
+        notification.chatter_user_name.clone()
     };
-    let duration_info = if let Some(ref ends_at) = ban.ends_at {
-        format!(" (until {})", ends_at)
-    } else {
-        String::new()
+
+    let alert_user = match notification.notice() {
+        ChatNotice::Sub(_)
+        | ChatNotice::Resub(_)
+        | ChatNotice::CommunitySubGift(_)
+        | ChatNotice::BitsBadgeTier(_) => Some(user_name),
+        // Gifts that are part of a community gift are covered by its own notice
+        ChatNotice::SubGift(gift) => gift.community_gift_id.is_none().then_some(user_name),
+        ChatNotice::Raid(raid) => Some(raid.user_name),
+        ChatNotice::Announcement(_) | ChatNotice::Other(_) => None,
     };
 
-    info!(
-        "🔨 {} was {} by {}: {}{}",
-        ban.user_name, ban_type, ban.moderator_user_name, ban.reason, duration_info
-    );
+    if !notification.system_message.is_empty() {
+        let _ = backend_tx
+            .send(BackendToFrontendMessage::CreateLog(
+                LogLevel::INFO,
+                notification.system_message.clone(),
+            ))
+            .await;
+    }
 
-    let _ = backend_tx
-        .send(BackendToFrontendMessage::CreateLog(
-            LogLevel::WARN,
-            format!(
-                "{} was {} by {}: {}{}",
-                ban.user_name, ban_type, ban.moderator_user_name, ban.reason, duration_info
-            ),
-        ))
+    if let Some(user_name) = alert_user {
+        broadcast_alert(
+            overlay_ws_state,
+            crate::backend::overlay::OverlayEvent::ChatNotice {
+                notice_type: notification.notice_type.clone(),
+                user_name,
+                message: notification.system_message.clone(),
+            },
+        )
         .await;
+    }
 }
 
 pub async fn handle_frontend_to_backend_messages(
@@ -583,6 +3943,8 @@ pub async fn handle_frontend_to_backend_messages(
 ) {
     // Store the handle to the twitch message handler task so we can abort it on disconnect
     let mut twitch_task_handle: Option<tokio::task::JoinHandle<()>> = None;
+    // Sends requests to the running twitch task, `None` while disconnected
+    let mut twitch_request_tx: Option<tokio::sync::mpsc::Sender<TwitchRequest>> = None;
     while let Some(message) = backend_rx.recv().await {
         match message {
             FrontendToBackendMessage::AddTTSLang(lang_code) => {
@@ -597,18 +3959,47 @@ pub async fn handle_frontend_to_backend_messages(
             FrontendToBackendMessage::UpdateSfxConfig(config) => {
                 update_sfx_config(config, &backend_tx);
             }
+            FrontendToBackendMessage::RescanSounds => {
+                rescan_sounds(&backend_tx).await;
+            }
+            FrontendToBackendMessage::RetryAudioDevice => {
+                let _ = audio_tx.retry_device();
+            }
+            FrontendToBackendMessage::SetNickname { login, nickname } => {
+                users::set_nickname(&login, nickname);
+                send_nicknames_updated(&backend_tx).await;
+            }
+            FrontendToBackendMessage::AddQuote(text) => {
+                quotes::add(
+                    &text,
+                    &crate::backend::config::load_config().chatbot.channel_name,
+                );
+                send_quotes_updated(&backend_tx).await;
+            }
+            FrontendToBackendMessage::DeleteQuote(id) => {
+                quotes::remove(id);
+                send_quotes_updated(&backend_tx).await;
+            }
+            FrontendToBackendMessage::SetTimers(list) => {
+                timers::set_timers(list);
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::TimersUpdated(timers::list()))
+                    .await;
+            }
             FrontendToBackendMessage::UpdateConfig(config) => {
                 update_chatbot_config(config, &backend_tx);
             }
             FrontendToBackendMessage::ConnectToChat(_channel_name) => {
                 connect_to_chat(
                     &mut twitch_task_handle,
+                    &mut twitch_request_tx,
                     &backend_tx,
                     &audio_tx,
                     &command_registry,
                     &tts_queue,
                     &tts_service,
                     &language_config,
+                    &overlay_ws_state,
                 )
                 .await;
             }
@@ -624,6 +4015,15 @@ pub async fn handle_frontend_to_backend_messages(
             FrontendToBackendMessage::ToggleCommand(trigger, enabled) => {
                 toggle_command(trigger, enabled, &command_registry, &backend_tx).await;
             }
+            FrontendToBackendMessage::ToggleCommandGroup(group, enabled) => {
+                toggle_command_group(group, enabled, &command_registry, &backend_tx).await;
+            }
+            FrontendToBackendMessage::ExportCommands => {
+                export_commands(&command_registry, &backend_tx).await;
+            }
+            FrontendToBackendMessage::ImportCommands { path, mode } => {
+                import_commands(&path, mode, &command_registry, &backend_tx).await;
+            }
             FrontendToBackendMessage::GetTTSQueue => {
                 send_tts_queue(&tts_queue, &backend_tx).await;
             }
@@ -633,8 +4033,78 @@ pub async fn handle_frontend_to_backend_messages(
             FrontendToBackendMessage::SkipCurrentTTS => {
                 skip_current_tts(&tts_queue, &backend_tx).await;
             }
+            FrontendToBackendMessage::SetSfxPaused(paused) => {
+                crate::backend::sfx::set_paused(paused);
+                let status = if paused { "paused" } else { "resumed" };
+                let _ = backend_tx
+                    .send(BackendToFrontendMessage::CreateLog(
+                        LogLevel::INFO,
+                        format!("Sound effects {}", status),
+                    ))
+                    .await;
+            }
+            FrontendToBackendMessage::RunRemoteAction(action) => {
+                let request = TwitchRequest::RemoteAction(action);
+                send_twitch_request(&twitch_request_tx, request, &backend_tx).await;
+            }
+            FrontendToBackendMessage::SetShieldMode(active) => {
+                let request = TwitchRequest::SetShieldMode(active);
+                send_twitch_request(&twitch_request_tx, request, &backend_tx).await;
+            }
             FrontendToBackendMessage::DisconnectFromChat(_channel_name) => {
                 disconnect_from_chat(&mut twitch_task_handle, &backend_tx);
+                remove_temporary_commands(&command_registry, &backend_tx).await;
+                twitch_request_tx = None;
+            }
+            FrontendToBackendMessage::ResolveUnbanRequest {
+                id,
+                user_name,
+                approve,
+                resolution_text,
+            } => {
+                let request = TwitchRequest::ResolveUnbanRequest {
+                    id,
+                    user_name,
+                    approve,
+                    resolution_text,
+                };
+                send_twitch_request(&twitch_request_tx, request, &backend_tx).await;
+            }
+            FrontendToBackendMessage::RefreshEventSubSubscriptions => {
+                let request = TwitchRequest::ListEventSubSubscriptions;
+                send_twitch_request(&twitch_request_tx, request, &backend_tx).await;
+            }
+            FrontendToBackendMessage::GuestStar(command) => {
+                let request = TwitchRequest::GuestStar(command);
+                send_twitch_request(&twitch_request_tx, request, &backend_tx).await;
+            }
+            FrontendToBackendMessage::RefreshGuestStarSession => {
+                let request = TwitchRequest::LoadGuestStarSession;
+                send_twitch_request(&twitch_request_tx, request, &backend_tx).await;
+            }
+            FrontendToBackendMessage::BlockedTerm(command) => {
+                let request = TwitchRequest::BlockedTerm(command);
+                send_twitch_request(&twitch_request_tx, request, &backend_tx).await;
+            }
+            FrontendToBackendMessage::RefreshBlockedTerms => {
+                let request = TwitchRequest::LoadBlockedTerms;
+                send_twitch_request(&twitch_request_tx, request, &backend_tx).await;
+            }
+            FrontendToBackendMessage::UpdateStreamInfo { title, game } => {
+                let request = TwitchRequest::UpdateStreamInfo { title, game };
+                send_twitch_request(&twitch_request_tx, request, &backend_tx).await;
+            }
+            FrontendToBackendMessage::RefreshStreamInfo => {
+                let request = TwitchRequest::LoadStreamInfo;
+                send_twitch_request(&twitch_request_tx, request, &backend_tx).await;
+            }
+            FrontendToBackendMessage::SnoozeNextAd => {
+                send_twitch_request(&twitch_request_tx, TwitchRequest::SnoozeNextAd, &backend_tx)
+                    .await;
+            }
+            FrontendToBackendMessage::SyncRewards => {
+                send_twitch_request(&twitch_request_tx, TwitchRequest::SyncRewards, &backend_tx)
+                    .await;
             }
             FrontendToBackendMessage::EnableOverlay => {
                 handle_enable_overlay(&backend_tx, &overlay_ws_state).await;
@@ -645,9 +4115,27 @@ pub async fn handle_frontend_to_backend_messages(
             FrontendToBackendMessage::TestOverlayWheel => {
                 handle_test_overlay_wheel(&overlay_ws_state, &backend_tx).await;
             }
+            FrontendToBackendMessage::ExportObsScenes => {
+                export_obs_scenes(&backend_tx);
+            }
             FrontendToBackendMessage::UpdateUIConfig(theme_name) => {
                 handle_update_ui_config(theme_name, &backend_tx).await;
             }
+            FrontendToBackendMessage::UpdateRegion { timezone, locale } => {
+                handle_update_region(timezone, locale, &backend_tx).await;
+            }
+            FrontendToBackendMessage::SetEditorPin(pin_hash) => {
+                handle_set_editor_pin(pin_hash, &backend_tx).await;
+            }
+            FrontendToBackendMessage::GetChatHistory => {
+                send_chat_history(&backend_tx).await;
+            }
+            FrontendToBackendMessage::SetEditorMode(locked) => {
+                handle_set_editor_mode(locked, &backend_tx).await;
+            }
+            FrontendToBackendMessage::SetStreamState(state) => {
+                handle_set_stream_state(state, &overlay_ws_state, &backend_tx).await;
+            }
         }
     }
 }
@@ -712,11 +4200,8 @@ fn update_tts_config(
 ) {
     let current_config: AppConfig = crate::backend::config::load_config();
     crate::backend::config::save_config(&AppConfig {
-        ui: current_config.ui,
-        chatbot: current_config.chatbot,
-        sfx: current_config.sfx,
         tts: config,
-        overlay: current_config.overlay,
+        ..current_config
     });
     let _ = backend_tx.try_send(BackendToFrontendMessage::CreateLog(
         LogLevel::INFO,
@@ -730,11 +4215,8 @@ fn update_sfx_config(
 ) {
     let current_config: AppConfig = crate::backend::config::load_config();
     crate::backend::config::save_config(&AppConfig {
-        ui: current_config.ui,
-        chatbot: current_config.chatbot,
         sfx: config,
-        tts: current_config.tts,
-        overlay: current_config.overlay,
+        ..current_config
     });
     let _ = backend_tx.try_send(BackendToFrontendMessage::CreateLog(
         LogLevel::INFO,
@@ -742,17 +4224,35 @@ fn update_sfx_config(
     ));
 }
 
+async fn rescan_sounds(backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>) {
+    let log = match crate::backend::sfx::Soundlist::rescan() {
+        Ok(count) => {
+            let _ = backend_tx
+                .send(BackendToFrontendMessage::SFXListUpdated)
+                .await;
+            (
+                LogLevel::INFO,
+                format!("Sounds folder rescanned, {} sounds found", count),
+            )
+        }
+        Err(e) => (
+            LogLevel::ERROR,
+            format!("Failed to rescan sounds folder: {}", e),
+        ),
+    };
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(log.0, log.1))
+        .await;
+}
+
 fn update_chatbot_config(
     config: ChatbotConfig,
     backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
 ) {
     let current_config: AppConfig = crate::backend::config::load_config();
     crate::backend::config::save_config(&AppConfig {
-        ui: current_config.ui,
         chatbot: config,
-        sfx: current_config.sfx,
-        tts: current_config.tts,
-        overlay: current_config.overlay,
+        ..current_config
     });
     let _ = backend_tx.try_send(BackendToFrontendMessage::CreateLog(
         LogLevel::INFO,
@@ -760,6 +4260,37 @@ fn update_chatbot_config(
     ));
 }
 
+async fn handle_set_stream_state(
+    state: crate::backend::stream_state::StreamState,
+    overlay_ws_state: &crate::backend::overlay::WebSocketState,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let mut current_config: AppConfig = crate::backend::config::load_config();
+    current_config.stream_states.current = state;
+    crate::backend::config::save_config(&current_config);
+
+    let features = active_features(&current_config);
+    overlay_ws_state
+        .broadcast(crate::backend::overlay::OverlayEvent::StreamState {
+            state: state.label().to_string(),
+            scene: features.overlay_scene.clone(),
+        })
+        .await;
+
+    let _ = backend_tx
+        .send(BackendToFrontendMessage::CreateLog(
+            LogLevel::INFO,
+            format!(
+                "Stream state set to {} (TTS: {}, SFX: {}, alerts: {})",
+                state.label(),
+                if features.tts { "on" } else { "off" },
+                if features.sfx { "on" } else { "off" },
+                if features.alerts { "on" } else { "off" },
+            ),
+        ))
+        .await;
+}
+
 async fn handle_update_ui_config(
     theme_name: String,
     backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
@@ -775,15 +4306,99 @@ async fn handle_update_ui_config(
     let _ = backend_tx.try_send(BackendToFrontendMessage::UIConfigUpdated);
 }
 
+async fn handle_update_region(
+    timezone: String,
+    locale: String,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let mut current_config: AppConfig = crate::backend::config::load_config();
+    current_config.ui.timezone = timezone.clone();
+    current_config.ui.locale = locale.clone();
+    crate::backend::config::save_config(&current_config);
+
+    let timezone = if timezone.is_empty() {
+        "system timezone".to_string()
+    } else {
+        timezone
+    };
+    let _ = backend_tx.try_send(BackendToFrontendMessage::CreateLog(
+        LogLevel::INFO,
+        format!(
+            "Time and number format changed to {} ({})",
+            locale, timezone
+        ),
+    ));
+}
+
+async fn handle_set_editor_pin(
+    pin_hash: String,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let mut current_config: AppConfig = crate::backend::config::load_config();
+    let message = if pin_hash.is_empty() {
+        current_config.ui.editor_mode = false;
+        "Editor mode PIN removed"
+    } else {
+        "Editor mode PIN set"
+    };
+    current_config.ui.editor_pin_hash = pin_hash;
+    crate::backend::config::save_config(&current_config);
+
+    let _ = backend_tx.try_send(BackendToFrontendMessage::CreateLog(
+        LogLevel::INFO,
+        message.to_string(),
+    ));
+}
+
+async fn handle_set_editor_mode(
+    locked: bool,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let mut current_config: AppConfig = crate::backend::config::load_config();
+    current_config.ui.editor_mode = locked;
+    crate::backend::config::save_config(&current_config);
+
+    let message = if locked {
+        "Switched to editor mode"
+    } else {
+        "Editor mode unlocked"
+    };
+    let _ = backend_tx.try_send(BackendToFrontendMessage::CreateLog(
+        LogLevel::INFO,
+        message.to_string(),
+    ));
+}
+
+async fn send_twitch_request(
+    twitch_request_tx: &Option<tokio::sync::mpsc::Sender<TwitchRequest>>,
+    request: TwitchRequest,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let sent = match twitch_request_tx {
+        Some(tx) => tx.send(request).await.is_ok(),
+        None => false,
+    };
+    if !sent {
+        let _ = backend_tx
+            .send(BackendToFrontendMessage::CreateLog(
+                LogLevel::WARN,
+                "Not connected to Twitch".to_string(),
+            ))
+            .await;
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn connect_to_chat(
     twitch_task_handle: &mut Option<tokio::task::JoinHandle<()>>,
+    twitch_request_tx: &mut Option<tokio::sync::mpsc::Sender<TwitchRequest>>,
     backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
     audio_tx: &AudioPlaybackSender,
     command_registry: &Arc<RwLock<CommandRegistry>>,
     tts_queue: &TTSQueue,
     tts_service: &Arc<TTSService>,
     language_config: &Arc<RwLock<LanguageConfig>>,
+    overlay_ws_state: &crate::backend::overlay::WebSocketState,
 ) {
     // Abort any existing connection first
     if let Some(handle) = twitch_task_handle.take() {
@@ -800,10 +4415,14 @@ async fn connect_to_chat(
         channel_name: config.chatbot.channel_name.clone(),
         auth_token: config.chatbot.auth_token.clone(),
         refresh_token: config.chatbot.refresh_token.clone(),
+        irc_fallback: config.chatbot.irc_fallback,
+        eventsub_transport: config.chatbot.eventsub_transport.clone(),
     };
 
-    // Get welcome message if configured
-    let welcome_message = if config.chatbot.welcome_message.trim().is_empty() {
+    // Get welcome message if configured to be sent on connect
+    let welcome_message = if config.chatbot.welcome_message.trim().is_empty()
+        || config.chatbot.welcome_on_stream_online
+    {
         None
     } else {
         Some(config.chatbot.welcome_message.clone())
@@ -815,10 +4434,14 @@ async fn connect_to_chat(
     let tts_queue_clone = tts_queue.clone();
     let tts_service_clone = tts_service.clone();
     let language_config_clone = language_config.clone();
+    let overlay_ws_clone = overlay_ws_state.clone();
+    let (request_tx, request_rx) = tokio::sync::mpsc::channel(16);
+    *twitch_request_tx = Some(request_tx);
 
     // Spawn the twitch handler task and store the handle
     let handle = tokio::spawn(async move {
-        handle_twitch_messages(
+        // Helix calls on the event loop fail instead of waiting for the rate limit
+        without_waiting(handle_twitch_messages(
             twitch_config,
             backend_tx_clone,
             audio_tx_clone,
@@ -826,8 +4449,10 @@ async fn connect_to_chat(
             tts_queue_clone,
             tts_service_clone,
             language_config_clone,
+            overlay_ws_clone,
             welcome_message,
-        )
+            request_rx,
+        ))
         .await;
     });
     *twitch_task_handle = Some(handle);
@@ -847,12 +4472,12 @@ async fn add_command(
         let mut registry = command_registry.write().await;
         registry.register(command);
         crate::backend::config::save_commands(&registry);
+        send_commands_updated(&registry, backend_tx);
     }
     let _ = backend_tx.try_send(BackendToFrontendMessage::CreateLog(
         LogLevel::INFO,
         "Command added".to_string(),
     ));
-    let _ = backend_tx.try_send(BackendToFrontendMessage::CommandsUpdated);
 }
 
 async fn remove_command(
@@ -864,29 +4489,45 @@ async fn remove_command(
         let mut registry = command_registry.write().await;
         registry.unregister(&trigger);
         crate::backend::config::save_commands(&registry);
+        send_commands_updated(&registry, backend_tx);
     }
     let _ = backend_tx.try_send(BackendToFrontendMessage::CreateLog(
         LogLevel::INFO,
         format!("Command '{}' removed", trigger),
     ));
-    let _ = backend_tx.try_send(BackendToFrontendMessage::CommandsUpdated);
 }
 
 async fn update_command(
-    command: crate::backend::commands::Command,
+    mut command: crate::backend::commands::Command,
     command_registry: &Arc<RwLock<CommandRegistry>>,
     backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
 ) {
     {
         let mut registry = command_registry.write().await;
+        // Ownership and approval only change through chat, not by editing the command
+        if let Some(existing) = registry.get(&command.trigger) {
+            command.owner = existing.owner.clone();
+            command.pending_approval = existing.pending_approval;
+        }
         registry.register(command);
         crate::backend::config::save_commands(&registry);
+        send_commands_updated(&registry, backend_tx);
     }
     let _ = backend_tx.try_send(BackendToFrontendMessage::CreateLog(
         LogLevel::INFO,
         "Command updated".to_string(),
     ));
-    let _ = backend_tx.try_send(BackendToFrontendMessage::CommandsUpdated);
+}
+
+/// Send the current command list to the UI, sorted by trigger
+fn send_commands_updated(
+    registry: &CommandRegistry,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let mut commands: Vec<crate::backend::commands::Command> =
+        registry.list().into_iter().cloned().collect();
+    commands.sort_by(|a, b| a.trigger.cmp(&b.trigger));
+    let _ = backend_tx.try_send(BackendToFrontendMessage::CommandsUpdated(commands));
 }
 
 async fn toggle_command(
@@ -899,6 +4540,7 @@ async fn toggle_command(
     if let Some(cmd) = registry.get_mut(&trigger) {
         cmd.enabled = enabled;
         crate::backend::config::save_commands(&registry);
+        send_commands_updated(&registry, backend_tx);
         let _ = backend_tx.try_send(BackendToFrontendMessage::CreateLog(
             LogLevel::INFO,
             format!(
@@ -910,6 +4552,92 @@ async fn toggle_command(
     }
 }
 
+async fn toggle_command_group(
+    group: String,
+    enabled: bool,
+    command_registry: &Arc<RwLock<CommandRegistry>>,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let mut registry = command_registry.write().await;
+    let changed = registry.set_group_enabled(&group, enabled);
+    if changed > 0 {
+        crate::backend::config::save_commands(&registry);
+    }
+    send_commands_updated(&registry, backend_tx);
+    let _ = backend_tx.try_send(BackendToFrontendMessage::CreateLog(
+        LogLevel::INFO,
+        format!(
+            "Command group '{}' {} ({} commands changed)",
+            group,
+            if enabled { "enabled" } else { "disabled" },
+            changed
+        ),
+    ));
+}
+
+/// Write all saved commands to commands-export.json next to commands.toml
+async fn export_commands(
+    command_registry: &Arc<RwLock<CommandRegistry>>,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let export = CommandExport::from_registry(&*command_registry.read().await);
+    let result = (|| -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+        let path = project_root::get_project_root()?.join(COMMANDS_EXPORT_FILE);
+        std::fs::write(&path, export.encode(&path)?)?;
+        Ok(path)
+    })();
+
+    let log = match result {
+        Ok(path) => (
+            LogLevel::INFO,
+            format!(
+                "Exported {} command(s) to {}",
+                export.commands.len(),
+                path.display()
+            ),
+        ),
+        Err(e) => (LogLevel::ERROR, format!("Failed to export commands: {}", e)),
+    };
+    let _ = backend_tx.try_send(BackendToFrontendMessage::CreateLog(log.0, log.1));
+}
+
+/// Load commands from a file written by `export_commands`, a relative path is
+/// read from the project root
+async fn import_commands(
+    path: &str,
+    mode: ImportMode,
+    command_registry: &Arc<RwLock<CommandRegistry>>,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let export = (|| -> Result<CommandExport, Box<dyn std::error::Error>> {
+        let path = project_root::get_project_root()?.join(path.trim());
+        let content = std::fs::read_to_string(&path)?;
+        Ok(CommandExport::decode(&path, &content)?)
+    })()
+    .map_err(|e| e.to_string());
+
+    let log = match export {
+        Ok(export) => {
+            let mut registry = command_registry.write().await;
+            let summary = export.import_into(&mut registry, mode);
+            crate::backend::config::save_commands(&registry);
+            send_commands_updated(&registry, backend_tx);
+            (
+                LogLevel::INFO,
+                format!(
+                    "Imported commands from {}: {} added, {} updated, {} removed",
+                    path, summary.added, summary.updated, summary.removed
+                ),
+            )
+        }
+        Err(e) => (
+            LogLevel::ERROR,
+            format!("Failed to import commands from {}: {}", path, e),
+        ),
+    };
+    let _ = backend_tx.try_send(BackendToFrontendMessage::CreateLog(log.0, log.1));
+}
+
 async fn send_tts_queue(
     tts_queue: &TTSQueue,
     backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
@@ -943,6 +4671,8 @@ async fn skip_tts_message(
     if is_current {
         // Skip currently playing
         tts_queue.skip_current().await;
+    } else {
+        tts_queue.remove(&message_id).await;
     }
 
     // Send updated queue
@@ -959,6 +4689,24 @@ async fn skip_current_tts(
     send_tts_queue(tts_queue, backend_tx).await;
 }
 
+/// Drop the commands that were only added for this stream
+async fn remove_temporary_commands(
+    command_registry: &Arc<RwLock<CommandRegistry>>,
+    backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
+) {
+    let mut registry = command_registry.write().await;
+    let removed = registry.remove_temporary();
+    if removed.is_empty() {
+        return;
+    }
+
+    send_commands_updated(&registry, backend_tx);
+    let _ = backend_tx.try_send(BackendToFrontendMessage::CreateLog(
+        LogLevel::INFO,
+        format!("Removed temporary commands: !{}", removed.join(", !")),
+    ));
+}
+
 fn disconnect_from_chat(
     twitch_task_handle: &mut Option<tokio::task::JoinHandle<()>>,
     backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
@@ -966,6 +4714,10 @@ fn disconnect_from_chat(
     // Abort the twitch message handler task if it's running
     if let Some(handle) = twitch_task_handle.take() {
         handle.abort();
+        crate::backend::stats::flush();
+        crate::backend::audience::update(None);
+        let _ = backend_tx.try_send(BackendToFrontendMessage::AudienceUpdated(None));
+        let _ = backend_tx.try_send(BackendToFrontendMessage::AdScheduleUpdated(None));
         let _ = backend_tx.try_send(BackendToFrontendMessage::CreateLog(
             LogLevel::INFO,
             "Disconnected from Twitch".to_string(),
@@ -980,6 +4732,37 @@ fn disconnect_from_chat(
 
 // Overlay handler functions
 
+/// Write an OBS scene collection with browser sources for the overlay and custom widgets
+fn export_obs_scenes(backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>) {
+    use crate::backend::overlay::obs_export;
+
+    let result = (|| -> Result<(std::path::PathBuf, usize), Box<dyn std::error::Error>> {
+        let project_root = project_root::get_project_root()?;
+        let port = crate::backend::config::load_config().overlay.port;
+        let sources = obs_export::browser_sources(&project_root.join("assets/overlay"), port);
+        let path = project_root.join(obs_export::EXPORT_FILE);
+        let collection = obs_export::scene_collection(&sources);
+        std::fs::write(&path, serde_json::to_string_pretty(&collection)?)?;
+        Ok((path, sources.len()))
+    })();
+
+    let log = match result {
+        Ok((path, count)) => (
+            LogLevel::INFO,
+            format!(
+                "Exported {} overlay source(s) to {}, import it in OBS with Scene Collection > Import",
+                count,
+                path.display()
+            ),
+        ),
+        Err(e) => (
+            LogLevel::ERROR,
+            format!("Failed to export OBS scene collection: {}", e),
+        ),
+    };
+    let _ = backend_tx.try_send(BackendToFrontendMessage::CreateLog(log.0, log.1));
+}
+
 async fn handle_enable_overlay(
     backend_tx: &tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
     _overlay_ws_state: &crate::backend::overlay::WebSocketState,
@@ -1029,6 +4812,7 @@ async fn handle_test_overlay_wheel(
     let event = OverlayEvent::TriggerAction {
         action_type: "spin_wheel".to_string(),
         data: serde_json::json!({
+            "name": "Test wheel",
             "items": test_items
         }),
     };
@@ -1047,12 +4831,32 @@ pub async fn handle_overlay_client_messages(
     backend_tx: tokio::sync::mpsc::Sender<BackendToFrontendMessage>,
 ) {
     use crate::backend::overlay::websocket::OverlayClientMessage;
+    use crate::backend::wheel_history::{self, WheelSpin};
 
     while let Some(message) = rx.recv().await {
         match message {
-            OverlayClientMessage::WheelResult { result, action } => {
+            OverlayClientMessage::WheelResult {
+                result,
+                action,
+                wheel,
+                user,
+                segments,
+            } => {
                 log::info!("Wheel result received: {} with action: {:?}", result, action);
 
+                wheel_history::record_spin(WheelSpin {
+                    timestamp: crate::backend::locale::now()
+                        .format(crate::backend::locale::STORAGE_FORMAT)
+                        .to_string(),
+                    wheel: wheel.unwrap_or_else(|| "Wheel".to_string()),
+                    result: result.clone(),
+                    user,
+                    action: action
+                        .as_ref()
+                        .map_or_else(|| "nothing".to_string(), |a| a.to_string()),
+                    segments,
+                });
+
                 if let Some(wheel_action) = action {
                     handle_wheel_action(wheel_action, &backend_tx).await;
                 }
@@ -8,7 +8,7 @@ pub mod backend;
 pub mod handlers;
 pub mod ui;
 
-use audio::{audio_playback_task, tts_player_task, AudioPlaybackSender};
+use audio::{audio_playback_task, tts_player_task, AudioPlaybackSender, PlaybackHold};
 
 const WINDOW_WIDTH: f32 = 800.0;
 const WINDOW_HEIGHT: f32 = 600.0;
@@ -26,6 +26,13 @@ async fn main() {
     };
     let config = backend::config::load_config();
     let command_registry = backend::config::load_commands();
+    let config_warnings = backend::validation::check_config(&config, &command_registry);
+    for warning in &config_warnings {
+        log::warn!("{}: {}", warning.location, warning.message);
+    }
+    let _ = backend_tx.try_send(ui::BackendToFrontendMessage::ConfigWarnings(
+        config_warnings,
+    ));
 
     // Initialize SoundsManager to start file watching
     // Spawn it in a task to keep it alive for the entire application lifetime
@@ -44,18 +51,23 @@ async fn main() {
         });
     });
 
+    // Record chat stats from the internal event bus
+    tokio::spawn(backend::stats::run_recorder(backend::events::subscribe()));
+
     // Wrap command registry in Arc<RwLock> for sharing across tasks
     let shared_registry = Arc::new(RwLock::new(command_registry));
 
     // Create audio playback channel and spawn dedicated audio task in a blocking thread
     // This solves the OutputStream Send issue on macOS by creating OutputStream in a dedicated thread
-    let (audio_tx, audio_rx) = std::sync::mpsc::channel::<audio::AudioPlaybackRequest>();
-    let audio_tx = AudioPlaybackSender(audio_tx);
+    let (audio_tx, audio_rx) = std::sync::mpsc::channel::<audio::AudioMessage>();
+    // Shared hold used to pause SFX/TTS playback (e.g. during ad breaks)
+    let playback_hold = PlaybackHold::new();
+    let audio_tx = AudioPlaybackSender::new(audio_tx, playback_hold.clone());
+    let playback_hold_for_audio = playback_hold.clone();
+    let backend_tx_for_audio = backend_tx.clone();
     std::thread::spawn(move || {
-        // Create the OutputStream inside the thread to avoid Send issues on macOS
-        let stream = rodio::OutputStreamBuilder::open_default_stream()
-            .expect("Failed to open default audio stream");
-        audio_playback_task(audio_rx, stream);
+        // The OutputStream is created inside the thread to avoid Send issues on macOS
+        audio_playback_task(audio_rx, playback_hold_for_audio, backend_tx_for_audio);
     });
 
     // Initialize TTS system
@@ -67,7 +79,7 @@ async fn main() {
     let tts_queue_for_player = tts_queue.clone();
     let backend_tx_for_player = backend_tx.clone();
     tokio::spawn(async move {
-        tts_player_task(tts_queue_for_player, backend_tx_for_player).await;
+        tts_player_task(tts_queue_for_player, backend_tx_for_player, playback_hold).await;
     });
 
     // Initialize overlay server if enabled
@@ -88,6 +100,34 @@ async fn main() {
         });
     }
 
+    // Serve the moderator dashboard if enabled
+    if config.remote.enabled {
+        let remote = config.remote.clone();
+        let tts_queue = tts_queue.clone();
+        let frontend_tx = frontend_tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) = backend::remote::start_remote_server(
+                remote.port,
+                remote.password,
+                tts_queue,
+                frontend_tx,
+            )
+            .await
+            {
+                log::error!("Failed to start moderator dashboard: {}", e);
+            }
+        });
+    }
+
+    // Connect to OBS to track the current scene for scene rules
+    if config.obs.enabled {
+        let backend_tx_obs = backend_tx.clone();
+        let ws_state = overlay_ws_state.clone();
+        tokio::spawn(async move {
+            backend::obs::run_obs_client(backend_tx_obs, ws_state).await;
+        });
+    }
+
     // Spawn task to handle messages from overlay clients
     let backend_tx_overlay = backend_tx.clone();
     tokio::spawn(async move {
@@ -154,8 +194,14 @@ async fn main() {
                 commands,
                 config.overlay.enabled,
                 config.overlay.port,
+                config.stream_states.current,
+                ui::EditorLock::new(config.ui.editor_pin_hash, config.ui.editor_mode),
+                config.ui.log_history_size,
             )))
         }),
     )
     .map_err(|e| log::error!("Error: {:?}", e));
+
+    // Persist chat stats recorded since the last save
+    backend::stats::flush();
 }
@@ -1,5 +1,10 @@
-use super::{Chatbot, EditingCommand};
-use crate::backend::commands::{Command, CommandAction, CommandPermission};
+use super::{Chatbot, EditingAction, EditingCommand};
+use crate::backend::commands::{
+    by_popularity, check_script, command_groups, Command, CommandAction, CommandPermission,
+    FailureFeedback, ImportMode, KeywordTrigger, COMMANDS_EXPORT_FILE,
+};
+use crate::backend::counters::CounterOp;
+use crate::backend::sfx::FILES;
 use crate::ui::FrontendToBackendMessage;
 use egui::{ScrollArea, Ui};
 
@@ -26,9 +31,53 @@ impl Chatbot {
                     }
                 });
             });
+            ui.group(|ui| {
+                ui.heading("Import / Export");
+                ui.horizontal(|ui| {
+                    if ui
+                        .button("Export commands")
+                        .on_hover_text(format!("Write all commands to {}", COMMANDS_EXPORT_FILE))
+                        .clicked()
+                    {
+                        let _ = self
+                            .frontend_tx
+                            .try_send(FrontendToBackendMessage::ExportCommands);
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("File:");
+                    ui.text_edit_singleline(&mut self.command_import_path)
+                        .on_hover_text("JSON or TOML export, relative to the bot's folder");
+                    let mut mode = None;
+                    if ui
+                        .button("Import (merge)")
+                        .on_hover_text("Add the commands, replacing ones with the same name")
+                        .clicked()
+                    {
+                        mode = Some(ImportMode::Merge);
+                    }
+                    if ui
+                        .button("Import (replace)")
+                        .on_hover_text("Remove all saved commands first")
+                        .clicked()
+                    {
+                        mode = Some(ImportMode::Replace);
+                    }
+                    if let Some(mode) = mode {
+                        let _ =
+                            self.frontend_tx
+                                .try_send(FrontendToBackendMessage::ImportCommands {
+                                    path: self.command_import_path.clone(),
+                                    mode,
+                                });
+                    }
+                });
+            });
             ui.separator();
         }
 
+        self.show_command_groups(ui);
+
         // Commands list
         ui.heading("Registered Commands");
 
@@ -42,7 +91,13 @@ impl Chatbot {
                     let mut command_to_toggle: Option<(String, bool)> = None;
                     let mut command_to_edit: Option<usize> = None;
 
-                    for (idx, command) in self.commands.iter().enumerate() {
+                    // Most used commands first
+                    let triggers: Vec<&str> =
+                        self.commands.iter().map(|c| c.trigger.as_str()).collect();
+                    let order = by_popularity(&triggers, &self.command_usage);
+
+                    for idx in order {
+                        let command = &self.commands[idx];
                         ui.group(|ui| {
                             ui.horizontal(|ui| {
                                 ui.vertical(|ui| {
@@ -58,10 +113,53 @@ impl Chatbot {
                                         }
                                     ));
                                     ui.label(format!("Action: {}", Self::format_action(&command.action)));
+                                    if !command.keywords.is_empty() {
+                                        let keywords: Vec<String> = command
+                                            .keywords
+                                            .iter()
+                                            .map(|keyword| {
+                                                let off = if keyword.enabled { "" } else { " (off)" };
+                                                format!("\"{}\"{}", keyword.pattern, off)
+                                            })
+                                            .collect();
+                                        ui.label(format!("Keywords: {}", keywords.join(", ")));
+                                    }
                                     ui.label(format!(
-                                        "Status: {}",
-                                        if command.enabled { "Enabled" } else { "Disabled" }
+                                        "Status: {}{}",
+                                        if command.enabled { "Enabled" } else { "Disabled" },
+                                        if command.temporary { " (this stream only)" } else { "" }
                                     ));
+                                    let usage = self
+                                        .command_usage
+                                        .get(&command.trigger.to_lowercase())
+                                        .cloned()
+                                        .unwrap_or_default();
+                                    let region = crate::backend::locale::region();
+                                    ui.label(match usage.last_used {
+                                        Some(last_used) => format!(
+                                            "Used: {} times, last {}",
+                                            region.format_number(usage.count),
+                                            region.format_datetime(last_used)
+                                        ),
+                                        None => "Used: never".to_string(),
+                                    });
+                                    if let Some(group) = &command.group {
+                                        ui.label(format!("Group: {}", group));
+                                    }
+                                    if let Some(reward_id) = &command.reward_id {
+                                        ui.label(format!("Channel point reward: {}", reward_id));
+                                    }
+                                    if let Some(owner) = &command.owner {
+                                        ui.label(format!(
+                                            "Owner: {}{}",
+                                            owner,
+                                            if command.pending_approval {
+                                                " (waiting for approval)"
+                                            } else {
+                                                ""
+                                            }
+                                        ));
+                                    }
                                 });
 
                                 ui.with_layout(
@@ -111,9 +209,26 @@ impl Chatbot {
             CommandAction::TextToSpeech { message } => format!("TTS: {}", message),
             CommandAction::SendMessage { message } => format!("Send: {}", message),
             CommandAction::Reply { message } => format!("Reply: {}", message),
+            CommandAction::RandomReply { messages } => {
+                format!("Random reply ({} responses)", messages.len())
+            }
+            CommandAction::Announce { message, color } => {
+                format!("Announce ({}): {}", color, message)
+            }
+            CommandAction::Shoutout { user } => format!("Shoutout: {}", user),
+            CommandAction::Whisper { message } => format!("Whisper: {}", message),
+            CommandAction::PlaySound { sound } => format!("Sound: {}", sound),
+            CommandAction::Counter { name, op } => format!("Counter: {} ({:?})", name, op),
+            CommandAction::RunProcess { program, .. } => format!("Run: {}", program),
+            CommandAction::Script { source } => {
+                format!("Script ({} lines)", source.lines().count())
+            }
             CommandAction::Multiple { actions } => {
                 format!("Multiple actions ({})", actions.len())
             }
+            CommandAction::If {
+                then, otherwise, ..
+            } => format!("If ({} / {} actions)", then.len(), otherwise.len()),
         }
     }
 
@@ -144,8 +259,8 @@ impl Chatbot {
             "so".to_string(),
             "Shoutout another streamer".to_string(),
             CommandPermission::Moderator,
-            CommandAction::SendMessage {
-                message: "Check out {args} at https://twitch.tv/{args}".to_string(),
+            CommandAction::Shoutout {
+                user: "{args}".to_string(),
             },
         );
 
@@ -177,6 +292,55 @@ impl Chatbot {
         }
     }
 
+    /// Enable/disable buttons for each command group
+    fn show_command_groups(&mut self, ui: &mut Ui) {
+        let groups = command_groups(&self.commands);
+        if groups.is_empty() {
+            return;
+        }
+
+        ui.heading("Command Groups");
+        let mut group_to_toggle = None;
+        for group in groups {
+            let (enabled, total) = self
+                .commands
+                .iter()
+                .filter(|c| c.in_group(&group))
+                .fold((0, 0), |(enabled, total), c| {
+                    (enabled + usize::from(c.enabled), total + 1)
+                });
+            ui.horizontal(|ui| {
+                ui.label(format!("{} ({}/{} enabled)", group, enabled, total));
+                if ui.button("Enable all").clicked() {
+                    group_to_toggle = Some((group.clone(), true));
+                }
+                if ui.button("Disable all").clicked() {
+                    group_to_toggle = Some((group.clone(), false));
+                }
+            });
+        }
+        if let Some((group, enabled)) = group_to_toggle {
+            self.toggle_command_group(&group, enabled);
+        }
+        ui.separator();
+    }
+
+    fn toggle_command_group(&mut self, group: &str, enabled: bool) {
+        let _ = self
+            .frontend_tx
+            .try_send(FrontendToBackendMessage::ToggleCommandGroup(
+                group.to_string(),
+                enabled,
+            ));
+
+        // Update local state
+        for command in &mut self.commands {
+            if command.in_group(group) {
+                command.enabled = enabled;
+            }
+        }
+    }
+
     fn toggle_command(&mut self, trigger: &str, enabled: bool) {
         let _ = self
             .frontend_tx
@@ -197,19 +361,35 @@ impl Chatbot {
             trigger: String::new(),
             description: String::new(),
             permission: 0, // Everyone
+            follower_minutes: 0,
             cooldown: "0".to_string(),
-            action_type: 0, // Reply
-            action_param: String::new(),
+            mods_bypass_cooldown: true,
+            failure_feedback: FailureFeedback::Log,
+            cooldown_message: String::new(),
+            permission_denied_message: String::new(),
+            actions: vec![EditingAction::new()],
+            temporary: false,
+            timeout: Command::DEFAULT_TIMEOUT,
+            keywords: Vec::new(),
+            required_args: "0".to_string(),
+            max_uses_per_stream: "0".to_string(),
+            usage: String::new(),
+            group: String::new(),
+            allowed_users: String::new(),
+            denied_users: String::new(),
+            reward_id: String::new(),
+            owner: None,
+            pending_approval: false,
         });
     }
 
     fn start_editing_command(&mut self, idx: usize) {
         if let Some(command) = self.commands.get(idx) {
-            let (action_type, action_param) = match &command.action {
-                CommandAction::Reply { message } => (0, message.clone()),
-                CommandAction::SendMessage { message } => (1, message.clone()),
-                CommandAction::TextToSpeech { message } => (2, message.clone()),
-                CommandAction::Multiple { .. } => (0, String::new()), // Default to Reply for complex actions
+            let actions = match &command.action {
+                CommandAction::Multiple { actions } if !actions.is_empty() => {
+                    actions.iter().map(EditingAction::from_action).collect()
+                }
+                action => vec![EditingAction::from_action(action)],
             };
 
             let permission = match command.permission {
@@ -218,6 +398,11 @@ impl Chatbot {
                 CommandPermission::Vip => 2,
                 CommandPermission::Moderator => 3,
                 CommandPermission::Broadcaster => 4,
+                CommandPermission::Follower { .. } => 5,
+            };
+            let follower_minutes = match command.permission {
+                CommandPermission::Follower { min_minutes } => min_minutes,
+                _ => 0,
             };
 
             self.editing_command = Some(EditingCommand {
@@ -225,9 +410,28 @@ impl Chatbot {
                 trigger: command.trigger.clone(),
                 description: command.description.clone(),
                 permission,
+                follower_minutes,
                 cooldown: command.cooldown.to_string(),
-                action_type,
-                action_param,
+                mods_bypass_cooldown: command.mods_bypass_cooldown,
+                failure_feedback: command.failure_feedback.clone(),
+                cooldown_message: command.cooldown_message.clone().unwrap_or_default(),
+                permission_denied_message: command
+                    .permission_denied_message
+                    .clone()
+                    .unwrap_or_default(),
+                actions,
+                temporary: command.temporary,
+                timeout: command.timeout,
+                keywords: command.keywords.clone(),
+                required_args: command.required_args.to_string(),
+                max_uses_per_stream: command.max_uses_per_stream.to_string(),
+                usage: command.usage.clone().unwrap_or_default(),
+                group: command.group.clone().unwrap_or_default(),
+                allowed_users: command.allowed_users.join(", "),
+                denied_users: command.denied_users.join(", "),
+                reward_id: command.reward_id.clone().unwrap_or_default(),
+                owner: command.owner.clone(),
+                pending_approval: command.pending_approval,
             });
         }
     }
@@ -265,31 +469,151 @@ impl Chatbot {
                             ui.selectable_value(&mut editing.permission, 2, "VIP");
                             ui.selectable_value(&mut editing.permission, 3, "Moderator");
                             ui.selectable_value(&mut editing.permission, 4, "Broadcaster");
+                            ui.selectable_value(&mut editing.permission, 5, "Follower");
                         });
+                    if editing.permission == 5 {
+                        ui.label("for at least");
+                        ui.add(egui::DragValue::new(&mut editing.follower_minutes).suffix(" min"));
+                    }
                 });
 
                 ui.horizontal(|ui| {
                     ui.label("Cooldown (seconds):");
                     ui.text_edit_singleline(&mut editing.cooldown);
                 });
+                ui.horizontal(|ui| {
+                    ui.label("Uses per user per stream:");
+                    ui.add(egui::TextEdit::singleline(&mut editing.max_uses_per_stream).desired_width(40.0))
+                        .on_hover_text("0 = no limit, counted since the stream went live or the bot connected");
+                });
+                ui.checkbox(
+                    &mut editing.mods_bypass_cooldown,
+                    "Moderators and broadcaster ignore cooldown",
+                );
+                ui.checkbox(&mut editing.temporary, "This stream only")
+                    .on_hover_text("Removed when the bot disconnects, never saved to commands.toml");
 
                 ui.horizontal(|ui| {
-                    ui.label("Action Type:");
-                    egui::ComboBox::from_id_salt("action_type_combo")
-                        .selected_text(Self::action_type_name(editing.action_type))
+                    ui.label("On cooldown / no permission:");
+                    egui::ComboBox::from_id_salt("failure_feedback_combo")
+                        .selected_text(Self::failure_feedback_name(&editing.failure_feedback))
                         .show_ui(ui, |ui| {
-                            ui.selectable_value(&mut editing.action_type, 0, "Reply");
-                            ui.selectable_value(&mut editing.action_type, 1, "Send Message");
-                            ui.selectable_value(&mut editing.action_type, 2, "Text-to-Speech");
+                            for feedback in [
+                                FailureFeedback::Log,
+                                FailureFeedback::Silent,
+                                FailureFeedback::Whisper,
+                            ] {
+                                let name = Self::failure_feedback_name(&feedback);
+                                ui.selectable_value(&mut editing.failure_feedback, feedback, name);
+                            }
                         });
                 });
 
                 ui.horizontal(|ui| {
-                    ui.label(Self::action_param_label(editing.action_type));
-                    ui.text_edit_singleline(&mut editing.action_param);
+                    ui.label("Cooldown response:");
+                    ui.text_edit_singleline(&mut editing.cooldown_message);
+                });
+                ui.horizontal(|ui| {
+                    ui.label("No permission response:");
+                    ui.text_edit_singleline(&mut editing.permission_denied_message);
+                });
+                ui.label("(Optional, leave empty to stay quiet. {remaining} is the cooldown left in seconds)");
+
+                ui.horizontal(|ui| {
+                    ui.label("Always allowed:");
+                    ui.text_edit_singleline(&mut editing.allowed_users)
+                        .on_hover_text("Logins that can use the command whatever the permission, comma separated");
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Never allowed:");
+                    ui.text_edit_singleline(&mut editing.denied_users)
+                        .on_hover_text("Logins that can never use the command, even mods, comma separated");
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Required arguments:");
+                    ui.add(egui::TextEdit::singleline(&mut editing.required_args).desired_width(40.0));
+                    ui.label("Usage:");
+                    ui.text_edit_singleline(&mut editing.usage)
+                        .on_hover_text("Reply when arguments are missing, leave empty to list them");
                 });
 
-                ui.label("Available placeholders: {user}, {userid}, {args}, {command}");
+                ui.horizontal(|ui| {
+                    ui.label("Group:");
+                    ui.text_edit_singleline(&mut editing.group)
+                        .on_hover_text("Commands in a group can be enabled or disabled together");
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Channel point reward ID:");
+                    ui.text_edit_singleline(&mut editing.reward_id).on_hover_text(
+                        "Run the command when this reward is redeemed instead of from chat, \
+                         the viewer's text becomes {args}",
+                    );
+                });
+
+                ui.label("Actions (run in order):");
+                let count = editing.actions.len();
+                let mut step_to_move = None;
+                let mut step_to_remove = None;
+                for (idx, action) in editing.actions.iter_mut().enumerate() {
+                    ui.group(|ui| {
+                        if count > 1 {
+                            ui.horizontal(|ui| {
+                                ui.label(format!("Step {}", idx + 1));
+                                if ui.add_enabled(idx > 0, egui::Button::new("Up")).clicked() {
+                                    step_to_move = Some((idx, idx - 1));
+                                }
+                                if ui.add_enabled(idx + 1 < count, egui::Button::new("Down")).clicked() {
+                                    step_to_move = Some((idx, idx + 1));
+                                }
+                                if ui.button("Remove").clicked() {
+                                    step_to_remove = Some(idx);
+                                }
+                            });
+                        }
+                        Self::show_action_editor(ui, action, idx);
+                    });
+                }
+                if let Some((from, to)) = step_to_move {
+                    editing.actions.swap(from, to);
+                }
+                if let Some(idx) = step_to_remove {
+                    editing.actions.remove(idx);
+                }
+                if ui.button("Add action").clicked() {
+                    editing.actions.push(EditingAction::new());
+                }
+
+                ui.label("Keywords (run the command when a message contains them, without !):");
+                let mut keyword_to_remove = None;
+                for (idx, keyword) in editing.keywords.iter_mut().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut keyword.enabled, "")
+                            .on_hover_text("Turn the keyword off without removing it");
+                        ui.text_edit_singleline(&mut keyword.pattern);
+                        ui.checkbox(&mut keyword.regex, "Regex")
+                            .on_hover_text("Match a regex instead of plain text, its groups become {args}");
+                        if ui.button("Remove").clicked() {
+                            keyword_to_remove = Some(idx);
+                        }
+                        if let Err(e) = keyword.validate() {
+                            ui.colored_label(egui::Color32::from_rgb(255, 50, 0), e);
+                        }
+                    });
+                }
+                if let Some(idx) = keyword_to_remove {
+                    editing.keywords.remove(idx);
+                }
+                if ui.button("Add keyword").clicked() {
+                    editing
+                        .keywords
+                        .push(KeywordTrigger::new(String::new(), false));
+                }
+
+                ui.label(
+                    "Available placeholders: {user}, {userid}, {args}, {1}, {2|default}, {args:rest}, {command}, {count}, {count:<name>}, {json:<url>:<pointer>}, {target_game}, {target_clip}, {first_chatter}, {pronouns}, {accountage}, {followage}, $(random 1-100), $(time), $(uptime), $(channel), $(followcount), $(viewers), $(touser)",
+                );
 
                 ui.horizontal(|ui| {
                     if ui.button("Save").clicked() {
@@ -311,6 +635,117 @@ impl Chatbot {
         }
     }
 
+    /// Type and settings of one action in the command editor
+    fn show_action_editor(ui: &mut Ui, action: &mut EditingAction, idx: usize) {
+        if let Some(unsupported) = &action.unsupported {
+            ui.label(format!(
+                "{} (edit it in commands.toml, it's kept as it is)",
+                Self::format_action(unsupported)
+            ));
+            return;
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("Action Type:");
+            egui::ComboBox::from_id_salt(("action_type_combo", idx))
+                .selected_text(Self::action_type_name(action.action_type))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut action.action_type, 0, "Reply");
+                    ui.selectable_value(&mut action.action_type, 1, "Send Message");
+                    ui.selectable_value(&mut action.action_type, 2, "Text-to-Speech");
+                    ui.selectable_value(&mut action.action_type, 3, "Announcement");
+                    ui.selectable_value(&mut action.action_type, 4, "Shoutout");
+                    ui.selectable_value(&mut action.action_type, 5, "Whisper");
+                    ui.selectable_value(&mut action.action_type, 6, "Play Sound");
+                    ui.selectable_value(&mut action.action_type, 7, "Counter");
+                    ui.selectable_value(&mut action.action_type, 8, "Random Reply");
+                    ui.selectable_value(&mut action.action_type, 9, "Run Program");
+                    ui.selectable_value(&mut action.action_type, 10, "Script");
+                });
+        });
+
+        if action.action_type == 3 {
+            ui.horizontal(|ui| {
+                ui.label("Color:");
+                egui::ComboBox::from_id_salt(("announcement_color_combo", idx))
+                    .selected_text(action.announcement_color.as_str())
+                    .show_ui(ui, |ui| {
+                        for color in ["primary", "blue", "green", "orange", "purple"] {
+                            ui.selectable_value(
+                                &mut action.announcement_color,
+                                color.to_string(),
+                                color,
+                            );
+                        }
+                    });
+            });
+        }
+
+        if action.action_type == 7 {
+            ui.horizontal(|ui| {
+                ui.label("Change:");
+                egui::ComboBox::from_id_salt(("counter_op_combo", idx))
+                    .selected_text(format!("{:?}", action.counter_op))
+                    .show_ui(ui, |ui| {
+                        for op in [CounterOp::Increment, CounterOp::Decrement, CounterOp::Reset] {
+                            ui.selectable_value(&mut action.counter_op, op, format!("{:?}", op));
+                        }
+                    });
+            });
+        }
+
+        ui.horizontal(|ui| {
+            ui.label(Self::action_param_label(action.action_type));
+            if action.action_type == 6 {
+                let mut sounds: Vec<String> = FILES.lock().unwrap().iter().cloned().collect();
+                sounds.sort();
+                egui::ComboBox::from_id_salt(("sound_combo", idx))
+                    .selected_text(action.action_param.as_str())
+                    .show_ui(ui, |ui| {
+                        for sound in sounds {
+                            let label = sound.clone();
+                            ui.selectable_value(&mut action.action_param, sound, label);
+                        }
+                    });
+            } else if action.action_type == 10 {
+                ui.add(
+                    egui::TextEdit::multiline(&mut action.action_param)
+                        .code_editor()
+                        .desired_rows(6),
+                );
+            } else if action.action_type == 8 {
+                ui.text_edit_multiline(&mut action.action_param)
+                    .on_hover_text("One response per line");
+            } else {
+                ui.text_edit_singleline(&mut action.action_param);
+            }
+        });
+
+        if action.action_type == 10 {
+            ui.label(
+                "Scripts see user, login, user_id, command, args, badges, is_mod, is_vip, \
+                 is_sub and is_broadcaster, and can call send(text), reply(text), \
+                 play_sound(name), tts(text) or tts(language, text) and random(min, max). \
+                 A value left at the end is sent as a reply.",
+            );
+            if let Err(e) = check_script(&action.action_param) {
+                ui.colored_label(egui::Color32::from_rgb(255, 50, 0), e);
+            }
+        }
+
+        if action.action_type == 9 {
+            ui.horizontal(|ui| {
+                ui.label("Arguments (one per line):");
+                ui.text_edit_multiline(&mut action.process_args)
+                    .on_hover_text("Passed to the program as they are, placeholders work");
+            });
+            ui.checkbox(&mut action.process_reply, "Reply with the program's output");
+            ui.label(
+                "The program has to be listed in [run_process] allowed_programs of config.toml",
+            );
+        }
+    }
+
     fn permission_name(idx: usize) -> &'static str {
         match idx {
             0 => "Everyone",
@@ -318,15 +753,32 @@ impl Chatbot {
             2 => "VIP",
             3 => "Moderator",
             4 => "Broadcaster",
+            5 => "Follower",
             _ => "Unknown",
         }
     }
 
+    fn failure_feedback_name(feedback: &FailureFeedback) -> &'static str {
+        match feedback {
+            FailureFeedback::Log => "Log",
+            FailureFeedback::Silent => "Ignore silently",
+            FailureFeedback::Whisper => "Whisper the user",
+        }
+    }
+
     fn action_type_name(idx: usize) -> &'static str {
         match idx {
             0 => "Reply",
             1 => "Send Message",
             2 => "Text-to-Speech",
+            3 => "Announcement",
+            4 => "Shoutout",
+            5 => "Whisper",
+            6 => "Play Sound",
+            7 => "Counter",
+            8 => "Random Reply",
+            9 => "Run Program",
+            10 => "Script",
             _ => "Unknown",
         }
     }
@@ -336,6 +788,14 @@ impl Chatbot {
             0 => "Reply message:",
             1 => "Message:",
             2 => "TTS message:",
+            3 => "Announcement:",
+            4 => "Channel:",
+            5 => "Whisper message:",
+            6 => "Sound:",
+            7 => "Counter name:",
+            8 => "Responses (one per line):",
+            9 => "Program:",
+            10 => "Script (Rhai):",
             _ => "Parameter:",
         }
     }
@@ -353,50 +813,197 @@ impl Chatbot {
                 2 => CommandPermission::Vip,
                 3 => CommandPermission::Moderator,
                 4 => CommandPermission::Broadcaster,
+                5 => CommandPermission::Follower {
+                    min_minutes: editing.follower_minutes,
+                },
                 _ => CommandPermission::Everyone,
             };
 
-            let action = match editing.action_type {
-                0 => CommandAction::Reply {
-                    message: editing.action_param,
-                },
-                1 => CommandAction::SendMessage {
-                    message: editing.action_param,
-                },
-                2 => CommandAction::TextToSpeech {
-                    message: editing.action_param,
-                },
-                _ => CommandAction::Reply {
-                    message: editing.action_param,
-                },
+            let mut actions: Vec<CommandAction> = editing
+                .actions
+                .into_iter()
+                .map(EditingAction::into_action)
+                .collect();
+            let action = if actions.len() == 1 {
+                actions.remove(0)
+            } else {
+                CommandAction::Multiple { actions }
             };
 
             let cooldown = editing.cooldown.parse::<u64>().unwrap_or(0);
-
-            let command = Command::new(
+            let required_args = editing.required_args.trim().parse::<usize>().unwrap_or(0);
+            let max_uses_per_stream = editing
+                .max_uses_per_stream
+                .trim()
+                .parse::<usize>()
+                .unwrap_or(0);
+
+            let mut command = Command::new(
                 editing.trigger.clone(),
                 editing.description.clone(),
                 permission,
                 action,
             )
-            .with_cooldown(cooldown);
-
-            // If we're editing an existing command, remove the old one first
-            if !editing.original_trigger.is_empty() {
-                let _ = self
-                    .frontend_tx
-                    .try_send(FrontendToBackendMessage::RemoveCommand(
-                        editing.original_trigger.clone(),
-                    ));
-                self.commands
-                    .retain(|c| c.trigger != editing.original_trigger);
+            .with_cooldown(cooldown)
+            .with_mods_bypass_cooldown(editing.mods_bypass_cooldown)
+            .with_failure_feedback(editing.failure_feedback)
+            .with_failure_messages(editing.cooldown_message, editing.permission_denied_message)
+            .with_temporary(editing.temporary)
+            .with_timeout(editing.timeout)
+            .with_usage(required_args, editing.usage)
+            .with_max_uses_per_stream(max_uses_per_stream)
+            .with_group(editing.group)
+            .with_reward_id(editing.reward_id)
+            .with_user_lists(
+                editing.allowed_users.split(',').map(String::from).collect(),
+                editing.denied_users.split(',').map(String::from).collect(),
+            )
+            .with_keywords(
+                editing
+                    .keywords
+                    .into_iter()
+                    .filter(|keyword| !keyword.pattern.trim().is_empty())
+                    .map(|keyword| {
+                        // Built again so a changed pattern isn't matched with the old regex
+                        let mut trigger = KeywordTrigger::new(keyword.pattern, keyword.regex);
+                        trigger.enabled = keyword.enabled;
+                        trigger
+                    })
+                    .collect(),
+            );
+
+            command.owner = editing.owner;
+            command.pending_approval = editing.pending_approval;
+
+            let message = if editing.original_trigger == editing.trigger {
+                FrontendToBackendMessage::UpdateCommand(command.clone())
+            } else {
+                // A renamed command is removed under its old trigger and added again
+                if !editing.original_trigger.is_empty() {
+                    let _ = self
+                        .frontend_tx
+                        .try_send(FrontendToBackendMessage::RemoveCommand(
+                            editing.original_trigger.clone(),
+                        ));
+                }
+                FrontendToBackendMessage::AddCommand(command.clone())
+            };
+            let _ = self.frontend_tx.try_send(message);
+            self.commands
+                .retain(|c| c.trigger != editing.original_trigger);
+            self.commands.push(command);
+        }
+    }
+}
+
+impl EditingAction {
+    /// Empty reply, the first action of a new command
+    fn new() -> Self {
+        Self {
+            action_type: 0, // Reply
+            action_param: String::new(),
+            announcement_color: "primary".to_string(),
+            counter_op: CounterOp::Increment,
+            process_args: String::new(),
+            process_reply: false,
+            unsupported: None,
+        }
+    }
+
+    fn from_action(action: &CommandAction) -> Self {
+        let mut editing = Self::new();
+        let (action_type, action_param) = match action {
+            CommandAction::Reply { message } => (0, message.clone()),
+            CommandAction::SendMessage { message } => (1, message.clone()),
+            CommandAction::TextToSpeech { message } => (2, message.clone()),
+            CommandAction::Announce { message, color } => {
+                editing.announcement_color = color.clone();
+                (3, message.clone())
+            }
+            CommandAction::Shoutout { user } => (4, user.clone()),
+            CommandAction::Whisper { message } => (5, message.clone()),
+            CommandAction::PlaySound { sound } => (6, sound.clone()),
+            CommandAction::Counter { name, op } => {
+                editing.counter_op = *op;
+                (7, name.clone())
+            }
+            CommandAction::RandomReply { messages } => (8, messages.join("\n")),
+            CommandAction::RunProcess {
+                program,
+                args,
+                reply,
+            } => {
+                editing.process_args = args.join("\n");
+                editing.process_reply = *reply;
+                (9, program.clone())
+            }
+            CommandAction::Script { source } => (10, source.clone()),
+            CommandAction::Multiple { .. } | CommandAction::If { .. } => {
+                editing.unsupported = Some(action.clone());
+                (0, String::new())
             }
+        };
+        editing.action_type = action_type;
+        editing.action_param = action_param;
+        editing
+    }
 
-            // Add the new/updated command
-            let _ = self
-                .frontend_tx
-                .try_send(FrontendToBackendMessage::AddCommand(command.clone()));
-            self.commands.push(command);
+    fn into_action(self) -> CommandAction {
+        if let Some(action) = self.unsupported {
+            return action;
+        }
+        match self.action_type {
+            0 => CommandAction::Reply {
+                message: self.action_param,
+            },
+            1 => CommandAction::SendMessage {
+                message: self.action_param,
+            },
+            2 => CommandAction::TextToSpeech {
+                message: self.action_param,
+            },
+            3 => CommandAction::Announce {
+                message: self.action_param,
+                color: self.announcement_color,
+            },
+            4 => CommandAction::Shoutout {
+                user: self.action_param,
+            },
+            5 => CommandAction::Whisper {
+                message: self.action_param,
+            },
+            6 => CommandAction::PlaySound {
+                sound: self.action_param,
+            },
+            7 => CommandAction::Counter {
+                name: self.action_param,
+                op: self.counter_op,
+            },
+            8 => CommandAction::RandomReply {
+                messages: self
+                    .action_param
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            },
+            9 => CommandAction::RunProcess {
+                program: self.action_param.trim().to_string(),
+                args: self
+                    .process_args
+                    .lines()
+                    .filter(|line| !line.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+                reply: self.process_reply,
+            },
+            10 => CommandAction::Script {
+                source: self.action_param,
+            },
+            _ => CommandAction::Reply {
+                message: self.action_param,
+            },
         }
     }
 }
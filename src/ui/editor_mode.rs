@@ -0,0 +1,130 @@
+use sha2::{Digest, Sha256};
+
+use super::{Chatbot, FrontendToBackendMessage, LogLevel, LogMessage, Section};
+use crate::backend::locale;
+
+/// PIN lock for handing the bot to a moderator or second operator. In editor mode
+/// only the home, sound, TTS and moderation tabs are available, so auth tokens,
+/// commands and other settings can't be seen or changed until the PIN is entered.
+pub struct EditorLock {
+    /// SHA-256 of the PIN, empty if no PIN is set
+    pin_hash: String,
+    pub locked: bool,
+    /// PIN typed into the unlock or settings field
+    pin_input: String,
+}
+
+impl EditorLock {
+    pub fn new(pin_hash: String, locked: bool) -> Self {
+        // Without a PIN there is no way to unlock, so don't start locked
+        let locked = locked && !pin_hash.is_empty();
+        Self {
+            pin_hash,
+            locked,
+            pin_input: String::new(),
+        }
+    }
+
+    pub fn has_pin(&self) -> bool {
+        !self.pin_hash.is_empty()
+    }
+
+    /// Tabs a moderator may use in editor mode
+    fn allows(&self, section: &Section) -> bool {
+        !self.locked
+            || matches!(
+                section,
+                Section::Home | Section::Sfx | Section::Tts | Section::Moderation
+            )
+    }
+}
+
+pub fn hash_pin(pin: &str) -> String {
+    Sha256::digest(pin.trim().as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+impl Chatbot {
+    pub(super) fn section_allowed(&self, section: &Section) -> bool {
+        self.editor_lock.allows(section)
+    }
+
+    /// Lock/unlock controls in the top bar
+    pub fn show_editor_lock(&mut self, ui: &mut egui::Ui) {
+        if !self.editor_lock.has_pin() {
+            return;
+        }
+
+        if !self.editor_lock.locked {
+            if ui.button("Editor mode").clicked() {
+                self.set_editor_mode(true);
+            }
+            return;
+        }
+
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut self.editor_lock.pin_input)
+                .password(true)
+                .hint_text("PIN")
+                .desired_width(60.0),
+        );
+        let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+        if ui.button("Unlock").clicked() || submitted {
+            if hash_pin(&self.editor_lock.pin_input) == self.editor_lock.pin_hash {
+                self.set_editor_mode(false);
+            } else {
+                self.log_messages.push(LogMessage {
+                    message: "Wrong editor mode PIN".to_string(),
+                    timestamp: locale::timestamp(),
+                    log_level: LogLevel::WARN,
+                });
+            }
+            self.editor_lock.pin_input.clear();
+        }
+        ui.label(egui::RichText::new("EDITOR MODE").strong());
+    }
+
+    /// PIN setup in the Settings tab
+    pub fn show_editor_mode_settings(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Editor mode");
+        ui.label("(Lets a moderator use the TTS queue, sounds and moderation tools without seeing auth tokens or changing settings. Unlocking needs the PIN)");
+        ui.horizontal(|ui| {
+            ui.label("PIN:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.editor_lock.pin_input)
+                    .password(true)
+                    .desired_width(100.0),
+            );
+            if ui.button("Set PIN").clicked() && !self.editor_lock.pin_input.trim().is_empty() {
+                let pin_hash = hash_pin(&self.editor_lock.pin_input);
+                self.editor_lock.pin_input.clear();
+                self.set_editor_pin(pin_hash);
+            }
+            if self.editor_lock.has_pin() && ui.button("Remove PIN").clicked() {
+                self.set_editor_pin(String::new());
+            }
+        });
+        if self.editor_lock.has_pin() {
+            ui.label("Use the \"Editor mode\" button in the top bar to lock the bot");
+        }
+    }
+
+    fn set_editor_pin(&mut self, pin_hash: String) {
+        self.editor_lock.pin_hash = pin_hash.clone();
+        let _ = self
+            .frontend_tx
+            .try_send(FrontendToBackendMessage::SetEditorPin(pin_hash));
+    }
+
+    fn set_editor_mode(&mut self, locked: bool) {
+        self.editor_lock.locked = locked;
+        if !self.section_allowed(&self.selected_section) {
+            self.selected_section = Section::Home;
+        }
+        let _ = self
+            .frontend_tx
+            .try_send(FrontendToBackendMessage::SetEditorMode(locked));
+    }
+}
@@ -1,6 +1,13 @@
 use egui::Color32;
 
 use super::{Chatbot, FrontendToBackendMessage, LogLevel, LogMessage};
+use crate::backend::link_requests::{self, LINK_REQUESTS};
+use crate::backend::locale;
+use crate::backend::stats::{StatsCounters, STATS};
+use crate::backend::stream_state::StreamState;
+
+/// Number of emotes shown in the dashboard chart
+const TOP_EMOTES_SHOWN: usize = 10;
 
 impl Chatbot {
     pub fn show_home(&mut self, ui: &mut egui::Ui) {
@@ -18,7 +25,7 @@ impl Chatbot {
                     if self.config.auth_token == "" {
                         self.log_messages.push(LogMessage {
                             message: "Tried to connect to the chat without auth token".to_string(),
-                            timestamp: chrono::Local::now().to_string(),
+                            timestamp: locale::timestamp(),
                             log_level: LogLevel::ERROR,
                         });
                         return;
@@ -42,6 +49,69 @@ impl Chatbot {
                     self.labels.bot_status = "Disconnected".to_string();
                 }
             }
+
+            ui.separator();
+            ui.label("Stream state:");
+            for state in StreamState::ALL {
+                if ui
+                    .selectable_label(self.stream_state == state, state.label())
+                    .clicked()
+                    && self.stream_state != state
+                {
+                    self.stream_state = state;
+                    let _ = self
+                        .frontend_tx
+                        .try_send(FrontendToBackendMessage::SetStreamState(state));
+                }
+            }
+        });
+        if let Some(schedule) = &self.ad_schedule {
+            ui.horizontal(|ui| {
+                ui.label(crate::backend::commands::format_next_ad(
+                    schedule,
+                    chrono::Utc::now(),
+                ));
+                if ui
+                    .add_enabled(
+                        schedule.next_ad_at.is_some() && schedule.snooze_count > 0,
+                        egui::Button::new("Snooze next ad"),
+                    )
+                    .clicked()
+                {
+                    let _ = self
+                        .frontend_tx
+                        .try_send(FrontendToBackendMessage::SnoozeNextAd);
+                }
+            });
+        }
+        ui.separator();
+        let chat_open = egui::CollapsingHeader::new("Chat")
+            .show(ui, |ui| self.show_chat(ui))
+            .body_returned
+            .is_some();
+        if chat_open && !self.chat_open {
+            let _ = self
+                .frontend_tx
+                .try_send(FrontendToBackendMessage::GetChatHistory);
+        }
+        self.chat_open = chat_open;
+        let chatters_title = match &self.audience {
+            Some(audience) => format!("Audience: {}", audience.describe()),
+            None => "Audience".to_string(),
+        };
+        egui::CollapsingHeader::new(chatters_title)
+            .id_salt("viewers")
+            .show(ui, |ui| {
+                self.show_chatters(ui);
+            });
+        egui::CollapsingHeader::new("Top emotes this stream").show(ui, |ui| {
+            self.show_top_emotes(ui);
+        });
+        egui::CollapsingHeader::new("Requests").show(ui, |ui| {
+            self.show_link_requests(ui);
+        });
+        egui::CollapsingHeader::new("EventSub subscriptions").show(ui, |ui| {
+            self.show_eventsub_subscriptions(ui);
         });
         ui.separator();
         ui.heading(egui::widget_text::RichText::new("Bot logs").color(Color32::WHITE));
@@ -63,4 +133,157 @@ impl Chatbot {
                 }
             });
     }
+
+    fn show_chatters(&self, ui: &mut egui::Ui) {
+        let Some(audience) = &self.audience else {
+            ui.label("Connect to see viewers and chatters");
+            return;
+        };
+        if audience.chatters.is_empty() {
+            ui.label("No chatters (reading chatters needs moderator:read:chatters)");
+            return;
+        }
+
+        egui::ScrollArea::vertical()
+            .id_salt("chatters")
+            .max_height(150.0)
+            .show(ui, |ui| {
+                ui.horizontal_wrapped(|ui| {
+                    for chatter in &audience.chatters {
+                        ui.label(chatter);
+                    }
+                });
+            });
+    }
+
+    fn show_chat(&self, ui: &mut egui::Ui) {
+        if self.chat_messages.is_empty() {
+            ui.label("No messages yet");
+            return;
+        }
+
+        egui::ScrollArea::vertical()
+            .id_salt("chat_history")
+            .max_height(200.0)
+            .stick_to_bottom(true)
+            .show(ui, |ui| {
+                for message in &self.chat_messages {
+                    ui.horizontal_wrapped(|ui| {
+                        ui.label(&message.timestamp);
+                        let color = Color32::from_hex(&message.color).unwrap_or(Color32::WHITE);
+                        ui.label(
+                            egui::widget_text::RichText::new(&message.username)
+                                .strong()
+                                .color(color),
+                        );
+                        ui.label(&message.message_text);
+                    });
+                }
+            });
+    }
+
+    fn show_top_emotes(&self, ui: &mut egui::Ui) {
+        let stats = STATS.lock().unwrap();
+        let top = StatsCounters::top(&stats.session.emotes, TOP_EMOTES_SHOWN);
+        let Some(&(_, max)) = top.first() else {
+            ui.label("No emotes used yet");
+            return;
+        };
+
+        egui::Grid::new("top_emotes").num_columns(2).show(ui, |ui| {
+            for (emote, count) in top {
+                ui.label(emote);
+                ui.add(
+                    egui::ProgressBar::new(count as f32 / max as f32)
+                        .desired_width(200.0)
+                        .text(locale::format_number(count)),
+                );
+                ui.end_row();
+            }
+        });
+    }
+
+    fn show_link_requests(&self, ui: &mut egui::Ui) {
+        let requests = LINK_REQUESTS.lock().unwrap().clone();
+        if requests.is_empty() {
+            ui.label("No requests");
+            return;
+        }
+
+        let mut approve = None;
+        let mut remove = None;
+        egui::Grid::new("link_requests")
+            .num_columns(5)
+            .striped(true)
+            .show(ui, |ui| {
+                for request in &requests {
+                    ui.label(format!("!{}", request.command));
+                    ui.label(&request.user);
+                    let title = match request.duration_seconds {
+                        Some(seconds) => format!(
+                            "{} ({})",
+                            request.title,
+                            link_requests::format_duration(seconds)
+                        ),
+                        None => request.title.clone(),
+                    };
+                    ui.hyperlink_to(title, &request.url)
+                        .on_hover_text(format!("{}: {}", request.source, request.url));
+                    if request.approved {
+                        ui.label("Approved");
+                        if ui.button("Done").clicked() {
+                            remove = Some(request.id);
+                        }
+                    } else {
+                        if ui.button("Approve").clicked() {
+                            approve = Some(request.id);
+                        }
+                        if ui.button("Reject").clicked() {
+                            remove = Some(request.id);
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+
+        if let Some(id) = approve {
+            link_requests::approve_request(id);
+        }
+        if let Some(id) = remove {
+            link_requests::remove_request(id);
+        }
+    }
+
+    fn show_eventsub_subscriptions(&self, ui: &mut egui::Ui) {
+        let Some(list) = &self.eventsub_subscriptions else {
+            ui.label("Not connected");
+            return;
+        };
+
+        ui.horizontal(|ui| {
+            ui.label(format!(
+                "{} subscriptions, cost {} / {}",
+                list.subscriptions.len(),
+                list.total_cost,
+                list.max_total_cost
+            ));
+            if ui.button("Refresh").clicked() {
+                let _ = self
+                    .frontend_tx
+                    .try_send(FrontendToBackendMessage::RefreshEventSubSubscriptions);
+            }
+        });
+
+        egui::Grid::new("eventsub_subscriptions")
+            .num_columns(3)
+            .striped(true)
+            .show(ui, |ui| {
+                for subscription in &list.subscriptions {
+                    ui.label(&subscription.subscription_type);
+                    ui.label(&subscription.status);
+                    ui.label(subscription.cost.to_string());
+                    ui.end_row();
+                }
+            });
+    }
 }
@@ -2,20 +2,31 @@ use egui::{CentralPanel, Color32, TopBottomPanel};
 use serde::{Deserialize, Serialize};
 
 pub mod commands;
+pub mod editor_mode;
 pub mod home;
+pub mod moderation;
 pub mod overlay;
+pub mod quotes;
 pub mod settings;
 pub mod sfx;
 pub mod theme;
+pub mod timers;
 pub mod tts;
 
+pub use editor_mode::EditorLock;
 pub use theme::ThemeKind;
 
+/// Storage name of the bot log lines that no longer fit in memory
+const LOG_ARCHIVE_NAME: &str = "log_archive";
+
 enum Section {
     Home,
     Sfx,
     Tts,
     Commands,
+    Timers,
+    Quotes,
+    Moderation,
     Overlay,
     Settings,
 }
@@ -25,6 +36,16 @@ pub enum FrontendToBackendMessage {
     AddTTSLang(String),
     UpdateConfig(ChatbotConfig),
     UpdateSfxConfig(Config),
+    RetryAudioDevice,
+    SetNickname {
+        login: String,
+        nickname: Option<String>, // None removes the nickname
+    },
+    RescanSounds,
+    /// Replace all timed messages
+    SetTimers(Vec<crate::backend::timers::Timer>),
+    AddQuote(String),
+    DeleteQuote(u64),
     UpdateTTSConfig(Config),
     ConnectToChat(String),
     DisconnectFromChat(String),
@@ -32,15 +53,58 @@ pub enum FrontendToBackendMessage {
     RemoveCommand(String),
     UpdateCommand(crate::backend::commands::Command),
     ToggleCommand(String, bool),
+    /// Enable or disable every command of a group
+    ToggleCommandGroup(String, bool),
+    /// Write all commands to commands-export.json
+    ExportCommands,
+    /// Load commands from an exported file, the path is relative to the project root
+    ImportCommands {
+        path: String,
+        mode: crate::backend::commands::ImportMode,
+    },
     GetTTSQueue,
     SkipTTSMessage(String), // Skip by message ID
     SkipCurrentTTS,
+    SetSfxPaused(bool),
+    /// Button of the moderator dashboard
+    RunRemoteAction(crate::backend::remote::RemoteAction),
     // Overlay messages
     EnableOverlay,
     DisableOverlay,
     TestOverlayWheel,
+    ExportObsScenes,
     // UI messages
     UpdateUIConfig(String), // theme name
+    UpdateRegion {
+        timezone: String,
+        locale: String,
+    },
+    SetEditorPin(String), // PIN hash, empty to remove the PIN
+    SetEditorMode(bool),
+    GetChatHistory,
+    SetStreamState(crate::backend::stream_state::StreamState),
+    // Moderation messages
+    ResolveUnbanRequest {
+        id: String,
+        user_name: String,
+        approve: bool,
+        resolution_text: String,
+    },
+    RefreshEventSubSubscriptions,
+    GuestStar(crate::backend::commands::GuestStarCommand),
+    RefreshGuestStarSession,
+    BlockedTerm(crate::backend::commands::BlockedTermCommand),
+    RefreshBlockedTerms,
+    SetShieldMode(bool),
+    /// Change the stream title and/or category (looked up by name)
+    UpdateStreamInfo {
+        title: Option<String>,
+        game: Option<String>,
+    },
+    RefreshStreamInfo,
+    SnoozeNextAd,
+    /// Create, update and delete the bot's channel point rewards to match config.toml
+    SyncRewards,
 }
 
 #[derive(Debug, Clone)]
@@ -51,19 +115,86 @@ pub struct TTSQueueItemUI {
     pub language: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct UnbanRequestUI {
+    pub id: String,
+    pub user_name: String,
+    pub text: String,
+    pub created_at: String,
+    /// Optional message to the user, edited in the UI
+    pub resolution_text: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct SuspiciousUserUI {
+    pub user_id: String,
+    pub user_name: String,
+    /// "none", "active_monitoring" or "restricted"
+    pub low_trust_status: String,
+    /// Why Twitch flagged the user, empty when only the status changed
+    pub reasons: String,
+    /// Latest message, empty when only the status changed
+    pub last_message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct GuestStarGuestUI {
+    pub slot_id: String,
+    pub user_name: String,
+    pub is_live: bool,
+    pub muted: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct EventSubSubscriptionUI {
+    pub subscription_type: String,
+    pub status: String,
+    pub cost: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct EventSubSubscriptionsUI {
+    pub subscriptions: Vec<EventSubSubscriptionUI>,
+    pub total_cost: u32,
+    pub max_total_cost: u32,
+}
+
 #[derive(Debug)]
 pub enum BackendToFrontendMessage {
     ConnectionSuccess(String),
     ConnectionFailure(String),
+    ChatTransportChanged(String),
+    ShieldModeChanged(bool),
+    EventSubSubscriptionsLoaded(EventSubSubscriptionsUI),
+    GuestStarSessionLoaded(Option<Vec<GuestStarGuestUI>>), // None when no session is running
+    StreamInfoLoaded {
+        title: String,
+        game: String,
+    },
     TTSLangListUpdated(Vec<crate::backend::tts::Language>),
     SFXListUpdated,
-    ChatMessageReceived(String),
+    AudioDeviceChanged(Option<String>), // None once an output device is open, else the error
+    ConfigWarnings(Vec<crate::backend::validation::ConfigWarning>),
+    ChatMessageReceived(crate::backend::chat_history::ChatMessage),
+    ChatHistoryLoaded(Vec<crate::backend::chat_history::ChatMessage>),
     CreateLog(LogLevel, String),
     CommandExecuted(String, String), // (command_name, result)
-    CommandsUpdated,
+    CommandsUpdated(Vec<crate::backend::commands::Command>),
+    CommandUsageUpdated(std::collections::HashMap<String, crate::backend::commands::CommandUsage>),
     TTSQueueUpdated(Vec<TTSQueueItemUI>),
     // Overlay messages
     OverlayStatusChanged(bool), // enabled/disabled
+    // Moderation messages
+    UnbanRequestsLoaded(Vec<UnbanRequestUI>),
+    UnbanRequestCreated(UnbanRequestUI),
+    UnbanRequestResolved(String), // request ID
+    SuspiciousUserUpdated(SuspiciousUserUI),
+    BlockedTermsLoaded(Vec<String>),
+    NicknamesUpdated(Vec<(String, String)>), // (login, nickname)
+    TimersUpdated(Vec<crate::backend::timers::Timer>),
+    QuotesUpdated(Vec<crate::backend::quotes::Quote>),
+    AudienceUpdated(Option<crate::backend::audience::Audience>), // None while disconnected
+    AdScheduleUpdated(Option<crate::backend::twitch::AdSchedule>), // None while unknown
     // UI messages
     UIConfigUpdated,
 }
@@ -85,9 +216,12 @@ pub struct PermitedRoles {
 struct ChatbotUILabels {
     bot_status: String,
     connect_button: String,
+    /// Transport chat is read over, empty while disconnected
+    chat_transport: String,
+    shield_mode: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub enum LogLevel {
     INFO,
     WARN,
@@ -103,6 +237,7 @@ impl LogLevel {
         }
     }
 }
+#[derive(Serialize)]
 struct LogMessage {
     message: String,
     timestamp: String,
@@ -116,6 +251,32 @@ pub struct ChatbotConfig {
     pub sound_format: crate::backend::sfx::Format,
     #[serde(default)]
     pub welcome_message: String,
+    /// Send the welcome message on stream.online instead of when the bot connects
+    #[serde(default)]
+    pub welcome_on_stream_online: bool,
+    /// Sent when another channel shouts us out, `{user}` is replaced with their name
+    #[serde(default)]
+    pub shoutout_thanks_message: String,
+    /// Greets the first chatter of the stream, `{user}` is their name and `{count}`
+    /// how many streams they were first in
+    #[serde(default)]
+    pub first_chatter_message: String,
+    /// Read chat over IRC when EventSub chat messages are unavailable
+    #[serde(default = "default_irc_fallback")]
+    pub irc_fallback: bool,
+    /// Only moderators can use TTS and sounds while shield mode is active
+    #[serde(default)]
+    pub shield_mode_mods_only: bool,
+    /// Run commands whispered to the bot, responses are whispered back
+    #[serde(default)]
+    pub whisper_commands: bool,
+    /// Where EventSub subscriptions deliver events, only set in config.toml
+    #[serde(default)]
+    pub eventsub_transport: crate::backend::twitch::EventSubTransport,
+}
+
+fn default_irc_fallback() -> bool {
+    true
 }
 
 pub struct Chatbot {
@@ -124,16 +285,63 @@ pub struct Chatbot {
     frontend_tx: tokio::sync::mpsc::Sender<FrontendToBackendMessage>,
     frontend_rx: tokio::sync::mpsc::Receiver<BackendToFrontendMessage>,
     labels: ChatbotUILabels,
-    log_messages: Vec<LogMessage>,
+    log_messages: crate::backend::archive::ArchivedBuffer<LogMessage>,
+    /// Recent chat, refilled from the backend's history when the chat panel opens
+    chat_messages: std::collections::VecDeque<crate::backend::chat_history::ChatMessage>,
+    chat_open: bool,
     sfx_config: Config,
     tts_config: Config,
     tts_languages: Vec<crate::backend::tts::Language>,
     tts_queue: Vec<TTSQueueItemUI>,
     commands: Vec<crate::backend::commands::Command>,
+    /// How often each command was used, by trigger
+    command_usage: std::collections::HashMap<String, crate::backend::commands::CommandUsage>,
     editing_command: Option<EditingCommand>,
+    /// File the Import buttons of the COMMANDS tab read
+    command_import_path: String,
+    unban_requests: Vec<UnbanRequestUI>,
+    /// Users flagged as suspicious since the bot connected
+    suspicious_users: Vec<SuspiciousUserUI>,
+    /// Loaded after connecting
+    eventsub_subscriptions: Option<EventSubSubscriptionsUI>,
+    /// Guests of the running Guest Star session
+    guest_star_guests: Option<Vec<GuestStarGuestUI>>,
+    /// Login typed into the Guest Star guest field
+    guest_star_login: String,
+    guest_star_slot: u32,
+    /// Blocked terms of the channel, loaded after connecting
+    blocked_terms: Vec<String>,
+    /// Term typed into the blocked terms field
+    blocked_term_input: String,
+    /// Viewer count and chatters, refreshed every minute while connected
+    audience: Option<crate::backend::audience::Audience>,
+    /// Next ad break, refreshed with the audience. Needs channel:read:ads.
+    ad_schedule: Option<crate::backend::twitch::AdSchedule>,
+    /// Nicknames by login, used for `{user}` in responses
+    nicknames: Vec<(String, String)>,
+    /// Timed messages as edited in the TIMERS tab, saved with the Save button
+    timers: Vec<crate::backend::timers::Timer>,
+    quotes: Vec<crate::backend::quotes::Quote>,
+    /// Text typed into the QUOTES tab for a new quote
+    new_quote: String,
+    /// Login and nickname typed into the nickname fields
+    nickname_login: String,
+    nickname_input: String,
+    /// Stream title and category being edited in the Settings tab
+    stream_title: String,
+    stream_game: String,
+    /// Why no audio output device could be opened, sounds and TTS are muted while set
+    audio_error: Option<String>,
+    /// Problems found in the configuration at startup, cleared when dismissed
+    config_warnings: Vec<crate::backend::validation::ConfigWarning>,
     overlay_enabled: bool,
     overlay_port: u16,
     current_theme: ThemeKind,
+    /// Timezone name and locale being edited in the Settings tab
+    timezone: String,
+    locale: crate::backend::locale::Locale,
+    editor_lock: EditorLock,
+    stream_state: crate::backend::stream_state::StreamState,
 }
 
 pub struct EditingCommand {
@@ -141,9 +349,54 @@ pub struct EditingCommand {
     pub trigger: String,
     pub description: String,
     pub permission: usize, // Index into permission options
+    /// Minutes a user must have followed for, used by the follower permission
+    pub follower_minutes: u64,
     pub cooldown: String,
+    pub mods_bypass_cooldown: bool,
+    pub failure_feedback: crate::backend::commands::FailureFeedback,
+    pub cooldown_message: String,
+    pub permission_denied_message: String,
+    /// Actions run in order, more than one is saved as `Multiple`
+    pub actions: Vec<EditingAction>,
+    /// Only keep the command for the current stream
+    pub temporary: bool,
+    /// Seconds to wait for external data, only set in commands.toml
+    pub timeout: u64,
+    /// Keyword triggers that run the command without the ! prefix
+    pub keywords: Vec<crate::backend::commands::KeywordTrigger>,
+    pub required_args: String,
+    /// Times each user can run the command per stream, 0 for no limit
+    pub max_uses_per_stream: String,
+    /// Reply when arguments are missing
+    pub usage: String,
+    /// Group to enable or disable together, empty for none
+    pub group: String,
+    /// Logins that can always use the command, comma separated
+    pub allowed_users: String,
+    /// Logins that can never use the command, comma separated
+    pub denied_users: String,
+    /// Channel point reward running the command instead of chat, empty for none
+    pub reward_id: String,
+    /// Viewer who added the command with !mycmd, kept so editing doesn't take it away
+    pub owner: Option<String>,
+    /// Viewer command not approved yet, editing doesn't approve it
+    pub pending_approval: bool,
+}
+
+/// One action of the command being edited
+pub struct EditingAction {
     pub action_type: usize, // Index into action type options
     pub action_param: String,
+    /// Announcement color, used by the announcement action
+    pub announcement_color: String,
+    /// Change made by the counter action
+    pub counter_op: crate::backend::counters::CounterOp,
+    /// Arguments of the program action, one per line
+    pub process_args: String,
+    /// Reply with the program's output
+    pub process_reply: bool,
+    /// Action the editor can't show, like `If`, saved as it is
+    pub unsupported: Option<crate::backend::commands::CommandAction>,
 }
 
 impl Chatbot {
@@ -159,6 +412,9 @@ impl Chatbot {
         commands: Vec<crate::backend::commands::Command>,
         overlay_enabled: bool,
         overlay_port: u16,
+        stream_state: crate::backend::stream_state::StreamState,
+        editor_lock: EditorLock,
+        log_history_size: usize,
     ) -> Self {
         // Apply the theme to the egui context
         theme::apply_theme(&cc.egui_ctx, theme);
@@ -171,17 +427,53 @@ impl Chatbot {
             labels: ChatbotUILabels {
                 bot_status: "Disconnected".to_string(),
                 connect_button: "Connect".to_string(),
+                chat_transport: String::new(),
+                shield_mode: false,
             },
-            log_messages: Vec::new(),
+            log_messages: crate::backend::archive::ArchivedBuffer::new(
+                LOG_ARCHIVE_NAME,
+                log_history_size,
+            ),
+            chat_messages: std::collections::VecDeque::new(),
+            chat_open: false,
             sfx_config,
             tts_config,
             tts_languages,
             tts_queue: Vec::new(),
             commands,
+            command_usage: crate::backend::commands::command_usage(),
             editing_command: None,
+            command_import_path: crate::backend::commands::COMMANDS_EXPORT_FILE.to_string(),
+            unban_requests: Vec::new(),
+            suspicious_users: Vec::new(),
+            eventsub_subscriptions: None,
+            guest_star_guests: None,
+            guest_star_login: String::new(),
+            guest_star_slot: 1,
+            blocked_terms: Vec::new(),
+            blocked_term_input: String::new(),
+            audience: None,
+            ad_schedule: None,
+            nicknames: crate::backend::users::nicknames(),
+            timers: crate::backend::timers::list(),
+            quotes: crate::backend::quotes::list(),
+            new_quote: String::new(),
+            nickname_login: String::new(),
+            nickname_input: String::new(),
+            stream_title: String::new(),
+            stream_game: String::new(),
+            audio_error: None,
+            config_warnings: Vec::new(),
             overlay_enabled,
             overlay_port,
             current_theme: theme,
+            timezone: crate::backend::locale::region()
+                .timezone
+                .map(|tz| tz.name().to_string())
+                .unwrap_or_default(),
+            locale: crate::backend::locale::region().locale,
+            editor_lock,
+            stream_state,
         }
     }
 }
@@ -241,38 +533,82 @@ impl eframe::App for Chatbot {
                             }
 
                             // COMMANDS button
-                            let commands_btn = if matches!(self.selected_section, Section::Commands)
-                            {
-                                egui::Button::new(egui::RichText::new("COMMANDS").strong())
-                                    .fill(Color32::from_rgb(60, 60, 80))
-                            } else {
-                                egui::Button::new("COMMANDS")
-                            };
-                            if ui.add_sized([95.0, 30.0], commands_btn).clicked() {
-                                self.selected_section = Section::Commands;
+                            if self.section_allowed(&Section::Commands) {
+                                let commands_btn = if matches!(self.selected_section, Section::Commands)
+                                {
+                                    egui::Button::new(egui::RichText::new("COMMANDS").strong())
+                                        .fill(Color32::from_rgb(60, 60, 80))
+                                } else {
+                                    egui::Button::new("COMMANDS")
+                                };
+                                if ui.add_sized([95.0, 30.0], commands_btn).clicked() {
+                                    self.selected_section = Section::Commands;
+                                }
+                            }
+
+                            // TIMERS button
+                            if self.section_allowed(&Section::Timers) {
+                                let timers_btn = if matches!(self.selected_section, Section::Timers) {
+                                    egui::Button::new(egui::RichText::new("TIMERS").strong())
+                                        .fill(Color32::from_rgb(60, 60, 80))
+                                } else {
+                                    egui::Button::new("TIMERS")
+                                };
+                                if ui.add_sized([80.0, 30.0], timers_btn).clicked() {
+                                    self.selected_section = Section::Timers;
+                                }
+                            }
+
+                            // QUOTES button
+                            if self.section_allowed(&Section::Quotes) {
+                                let quotes_btn = if matches!(self.selected_section, Section::Quotes) {
+                                    egui::Button::new(egui::RichText::new("QUOTES").strong())
+                                        .fill(Color32::from_rgb(60, 60, 80))
+                                } else {
+                                    egui::Button::new("QUOTES")
+                                };
+                                if ui.add_sized([80.0, 30.0], quotes_btn).clicked() {
+                                    self.selected_section = Section::Quotes;
+                                }
+                            }
+
+                            // MODERATION button
+                            let moderation_btn =
+                                if matches!(self.selected_section, Section::Moderation) {
+                                    egui::Button::new(egui::RichText::new("MODERATION").strong())
+                                        .fill(Color32::from_rgb(60, 60, 80))
+                                } else {
+                                    egui::Button::new("MODERATION")
+                                };
+                            if ui.add_sized([110.0, 30.0], moderation_btn).clicked() {
+                                self.selected_section = Section::Moderation;
                             }
 
                             // OVERLAY button
-                            let overlay_btn = if matches!(self.selected_section, Section::Overlay) {
-                                egui::Button::new(egui::RichText::new("OVERLAY").strong())
-                                    .fill(Color32::from_rgb(60, 60, 80))
-                            } else {
-                                egui::Button::new("OVERLAY")
-                            };
-                            if ui.add_sized([85.0, 30.0], overlay_btn).clicked() {
-                                self.selected_section = Section::Overlay;
+                            if self.section_allowed(&Section::Overlay) {
+                                let overlay_btn = if matches!(self.selected_section, Section::Overlay) {
+                                    egui::Button::new(egui::RichText::new("OVERLAY").strong())
+                                        .fill(Color32::from_rgb(60, 60, 80))
+                                } else {
+                                    egui::Button::new("OVERLAY")
+                                };
+                                if ui.add_sized([85.0, 30.0], overlay_btn).clicked() {
+                                    self.selected_section = Section::Overlay;
+                                }
                             }
 
                             // SETTINGS button
-                            let settings_btn = if matches!(self.selected_section, Section::Settings)
-                            {
-                                egui::Button::new(egui::RichText::new("SETTINGS").strong())
-                                    .fill(Color32::from_rgb(60, 60, 80))
-                            } else {
-                                egui::Button::new("SETTINGS")
-                            };
-                            if ui.add_sized([90.0, 30.0], settings_btn).clicked() {
-                                self.selected_section = Section::Settings;
+                            if self.section_allowed(&Section::Settings) {
+                                let settings_btn = if matches!(self.selected_section, Section::Settings)
+                                {
+                                    egui::Button::new(egui::RichText::new("SETTINGS").strong())
+                                        .fill(Color32::from_rgb(60, 60, 80))
+                                } else {
+                                    egui::Button::new("SETTINGS")
+                                };
+                                if ui.add_sized([90.0, 30.0], settings_btn).clicked() {
+                                    self.selected_section = Section::Settings;
+                                }
                             }
                         });
                     },
@@ -280,13 +616,70 @@ impl eframe::App for Chatbot {
 
                 // Right section: Status or empty space for balance
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    self.show_editor_lock(ui);
                     ui.label(format!("Status: {}", self.labels.bot_status));
+                    if !self.labels.chat_transport.is_empty() {
+                        ui.label(format!("Chat: {}", self.labels.chat_transport));
+                    }
+                    if self.labels.shield_mode {
+                        ui.label(
+                            egui::RichText::new("SHIELD MODE")
+                                .strong()
+                                .color(Color32::from_rgb(255, 80, 80)),
+                        );
+                    }
                 });
             });
 
             ui.add_space(5.0);
         });
 
+        if let Some(error) = &self.audio_error {
+            TopBottomPanel::top("audio_banner").show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "No audio output device, sounds and TTS are disabled ({})",
+                            error
+                        ))
+                        .color(LogLevel::WARN.color()),
+                    );
+                    if ui.button("Retry audio init").clicked() {
+                        let _ = self
+                            .frontend_tx
+                            .try_send(FrontendToBackendMessage::RetryAudioDevice);
+                    }
+                });
+            });
+        }
+
+        if !self.config_warnings.is_empty() {
+            TopBottomPanel::top("config_warnings").show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{} problem(s) found in the configuration",
+                            self.config_warnings.len()
+                        ))
+                        .color(LogLevel::WARN.color()),
+                    );
+                    if ui.button("Dismiss").clicked() {
+                        self.config_warnings.clear();
+                    }
+                });
+                egui::CollapsingHeader::new("Details")
+                    .id_salt("config_warnings_details")
+                    .show(ui, |ui| {
+                        for warning in &self.config_warnings {
+                            ui.horizontal_wrapped(|ui| {
+                                ui.label(egui::RichText::new(&warning.location).strong());
+                                ui.label(&warning.message);
+                            });
+                        }
+                    });
+            });
+        }
+
         TopBottomPanel::bottom("bottom_panel").show(ctx, |ui| {
             ui.vertical_centered(|ui| {
                 ui.label(format!("Version: {}", env!("CARGO_PKG_VERSION")));
@@ -299,9 +692,15 @@ impl eframe::App for Chatbot {
             Section::Sfx => self.show_sfx(ui),
             Section::Tts => self.show_tts(ui),
             Section::Commands => self.show_commands(ui),
+            Section::Timers => self.show_timers(ui),
+            Section::Quotes => self.show_quotes(ui),
+            Section::Moderation => self.show_moderation(ui),
             Section::Overlay => self.show_overlay(ui),
             Section::Settings => self.show_settings(ui),
         });
+        if !matches!(self.selected_section, Section::Home) {
+            self.chat_open = false;
+        }
 
         while let Ok(message) = self.frontend_rx.try_recv() {
             match message {
@@ -312,23 +711,63 @@ impl eframe::App for Chatbot {
                 BackendToFrontendMessage::ConnectionFailure(response) => {
                     self.labels.bot_status = response;
                     self.labels.connect_button = "Connect".to_string();
+                    self.labels.chat_transport.clear();
+                    self.labels.shield_mode = false;
+                    self.eventsub_subscriptions = None;
+                }
+                BackendToFrontendMessage::ChatTransportChanged(transport) => {
+                    self.labels.chat_transport = transport;
+                }
+                BackendToFrontendMessage::ShieldModeChanged(active) => {
+                    self.labels.shield_mode = active;
+                }
+                BackendToFrontendMessage::EventSubSubscriptionsLoaded(subscriptions) => {
+                    self.eventsub_subscriptions = Some(subscriptions);
+                }
+                BackendToFrontendMessage::GuestStarSessionLoaded(guests) => {
+                    self.guest_star_guests = guests;
+                }
+                BackendToFrontendMessage::BlockedTermsLoaded(terms) => {
+                    self.blocked_terms = terms;
+                }
+                BackendToFrontendMessage::StreamInfoLoaded { title, game } => {
+                    self.stream_title = title;
+                    self.stream_game = game;
                 }
                 BackendToFrontendMessage::CreateLog(level, message) => {
                     self.log_messages.push(LogMessage {
                         message,
-                        timestamp: chrono::Local::now().to_string(),
+                        timestamp: crate::backend::locale::timestamp(),
                         log_level: level,
                     });
                 }
                 BackendToFrontendMessage::CommandExecuted(command, result) => {
                     self.log_messages.push(LogMessage {
                         message: format!("Command '{}' executed: {}", command, result),
-                        timestamp: chrono::Local::now().to_string(),
+                        timestamp: crate::backend::locale::timestamp(),
                         log_level: LogLevel::INFO,
                     });
                 }
-                BackendToFrontendMessage::CommandsUpdated => {
-                    // Command list will be updated on the backend
+                BackendToFrontendMessage::UnbanRequestsLoaded(requests) => {
+                    self.unban_requests = requests;
+                }
+                BackendToFrontendMessage::UnbanRequestCreated(request) => {
+                    if !self.unban_requests.iter().any(|r| r.id == request.id) {
+                        self.unban_requests.push(request);
+                    }
+                }
+                BackendToFrontendMessage::UnbanRequestResolved(id) => {
+                    self.unban_requests.retain(|r| r.id != id);
+                }
+                BackendToFrontendMessage::SuspiciousUserUpdated(user) => {
+                    self.update_suspicious_user(user);
+                }
+                BackendToFrontendMessage::CommandsUpdated(commands) => {
+                    // Editing keeps working on its own copy of the command
+                    self.commands = commands;
+                }
+                BackendToFrontendMessage::CommandUsageUpdated(usage) => {
+                    self.command_usage = usage;
                 }
                 BackendToFrontendMessage::TTSLangListUpdated(updated_langs) => {
                     // Update TTS languages with the new list from backend
@@ -341,8 +780,35 @@ impl eframe::App for Chatbot {
                     // Sound list has been updated by the file watcher
                     // The UI will automatically reflect changes since it reads from FILES every frame
                 }
-                BackendToFrontendMessage::ChatMessageReceived(_) => {
-                    // Chat message received
+                BackendToFrontendMessage::AdScheduleUpdated(schedule) => {
+                    self.ad_schedule = schedule;
+                }
+                BackendToFrontendMessage::AudienceUpdated(audience) => {
+                    self.audience = audience;
+                }
+                BackendToFrontendMessage::NicknamesUpdated(nicknames) => {
+                    self.nicknames = nicknames;
+                }
+                BackendToFrontendMessage::QuotesUpdated(quotes) => {
+                    self.quotes = quotes;
+                }
+                BackendToFrontendMessage::TimersUpdated(timers) => {
+                    self.timers = timers;
+                }
+                BackendToFrontendMessage::AudioDeviceChanged(error) => {
+                    self.audio_error = error;
+                }
+                BackendToFrontendMessage::ConfigWarnings(warnings) => {
+                    self.config_warnings = warnings;
+                }
+                BackendToFrontendMessage::ChatMessageReceived(message) => {
+                    if self.chat_messages.len() >= crate::backend::chat_history::capacity() {
+                        self.chat_messages.pop_front();
+                    }
+                    self.chat_messages.push_back(message);
+                }
+                BackendToFrontendMessage::ChatHistoryLoaded(messages) => {
+                    self.chat_messages = messages.into();
                 }
                 BackendToFrontendMessage::OverlayStatusChanged(enabled) => {
                     self.overlay_enabled = enabled;
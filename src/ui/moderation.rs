@@ -0,0 +1,341 @@
+use egui::{ScrollArea, Ui};
+
+use super::{Chatbot, FrontendToBackendMessage, SuspiciousUserUI};
+use crate::backend::commands::{BlockedTermCommand, GuestStarCommand, BLOCK_TERM_COMMAND};
+use crate::backend::users::validate_nickname;
+
+impl Chatbot {
+    pub fn show_moderation(&mut self, ui: &mut Ui) {
+        ui.heading("Shield Mode");
+        ui.separator();
+        self.show_shield_mode(ui);
+        ui.add_space(10.0);
+
+        ui.heading("Guest Star");
+        ui.separator();
+        self.show_guest_star(ui);
+        ui.add_space(10.0);
+
+        ui.heading("Suspicious Users");
+        ui.separator();
+        self.show_suspicious_users(ui);
+        ui.add_space(10.0);
+
+        ui.heading("Blocked Terms");
+        ui.separator();
+        self.show_blocked_terms(ui);
+        ui.add_space(10.0);
+
+        ui.heading("Nicknames");
+        ui.separator();
+        self.show_nicknames(ui);
+        ui.add_space(10.0);
+
+        ui.heading("Unban Requests");
+        ui.separator();
+
+        if self.unban_requests.is_empty() {
+            ui.label("No pending unban requests");
+            return;
+        }
+
+        let mut resolved: Option<(usize, bool)> = None;
+
+        ScrollArea::vertical()
+            .auto_shrink([false; 2])
+            .show(ui, |ui| {
+                for (idx, request) in self.unban_requests.iter_mut().enumerate() {
+                    ui.group(|ui| {
+                        ui.label(egui::RichText::new(&request.user_name).strong());
+                        ui.label(format!("Requested: {}", request.created_at));
+                        ui.label(&request.text);
+
+                        ui.horizontal(|ui| {
+                            ui.label("Response:");
+                            ui.text_edit_singleline(&mut request.resolution_text);
+                        });
+
+                        ui.horizontal(|ui| {
+                            if ui.button("Approve").clicked() {
+                                resolved = Some((idx, true));
+                            }
+                            if ui.button("Deny").clicked() {
+                                resolved = Some((idx, false));
+                            }
+                        });
+                    });
+
+                    ui.add_space(5.0);
+                }
+            });
+
+        // The request is removed once the backend confirms it was resolved
+        if let Some((idx, approve)) = resolved {
+            let request = &self.unban_requests[idx];
+            let _ = self
+                .frontend_tx
+                .try_send(FrontendToBackendMessage::ResolveUnbanRequest {
+                    id: request.id.clone(),
+                    user_name: request.user_name.clone(),
+                    approve,
+                    resolution_text: request.resolution_text.clone(),
+                });
+        }
+    }
+
+    /// Add or update a flagged user, users no longer suspicious are removed
+    pub(super) fn update_suspicious_user(&mut self, user: SuspiciousUserUI) {
+        if user.low_trust_status == "none" {
+            self.suspicious_users.retain(|u| u.user_id != user.user_id);
+            return;
+        }
+
+        match self
+            .suspicious_users
+            .iter_mut()
+            .find(|u| u.user_id == user.user_id)
+        {
+            Some(existing) => {
+                existing.low_trust_status = user.low_trust_status;
+                if !user.reasons.is_empty() {
+                    existing.reasons = user.reasons;
+                }
+                if !user.last_message.is_empty() {
+                    existing.last_message = user.last_message;
+                }
+            }
+            None => self.suspicious_users.push(user),
+        }
+    }
+
+    /// Emergency switch for raids, needs moderator:manage:shield_mode
+    fn show_shield_mode(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            let active = self.labels.shield_mode;
+            let status = if active {
+                egui::RichText::new("Active").color(egui::Color32::from_rgb(255, 80, 80))
+            } else {
+                egui::RichText::new("Off")
+            };
+            ui.label(status);
+            let label = if active {
+                "Turn off shield mode"
+            } else {
+                "Turn on shield mode"
+            };
+            if ui.button(label).clicked() {
+                let _ = self
+                    .frontend_tx
+                    .try_send(FrontendToBackendMessage::SetShieldMode(!active));
+            }
+        });
+    }
+
+    fn show_suspicious_users(&mut self, ui: &mut Ui) {
+        if self.suspicious_users.is_empty() {
+            ui.label("No flagged users");
+            return;
+        }
+
+        let mut dismissed: Option<usize> = None;
+        egui::Grid::new("suspicious_users")
+            .num_columns(5)
+            .striped(true)
+            .show(ui, |ui| {
+                for (idx, user) in self.suspicious_users.iter().enumerate() {
+                    ui.label(egui::RichText::new(&user.user_name).strong());
+                    let status = if user.low_trust_status == "restricted" {
+                        egui::RichText::new("Restricted").color(egui::Color32::from_rgb(255, 50, 0))
+                    } else {
+                        egui::RichText::new("Monitored").color(egui::Color32::YELLOW)
+                    };
+                    ui.label(status);
+                    ui.label(&user.reasons);
+                    ui.label(&user.last_message);
+                    if ui.button("Dismiss").clicked() {
+                        dismissed = Some(idx);
+                    }
+                    ui.end_row();
+                }
+            });
+
+        if let Some(idx) = dismissed {
+            self.suspicious_users.remove(idx);
+        }
+    }
+
+    fn show_blocked_terms(&mut self, ui: &mut Ui) {
+        let mut command: Option<BlockedTermCommand> = None;
+
+        ui.horizontal(|ui| {
+            ui.label("Word or phrase:");
+            let response = ui.text_edit_singleline(&mut self.blocked_term_input);
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if ui.button("Block").clicked() || submitted {
+                if let Ok(add) =
+                    BlockedTermCommand::parse(BLOCK_TERM_COMMAND, &self.blocked_term_input)
+                {
+                    command = Some(add);
+                    self.blocked_term_input.clear();
+                }
+            }
+            if ui.button("Refresh").clicked() {
+                let _ = self
+                    .frontend_tx
+                    .try_send(FrontendToBackendMessage::RefreshBlockedTerms);
+            }
+        });
+        ui.label("(2 to 500 characters, * matches any characters)");
+
+        if self.blocked_terms.is_empty() {
+            ui.label("No blocked terms");
+        } else {
+            ScrollArea::vertical()
+                .id_salt("blocked_terms")
+                .max_height(150.0)
+                .show(ui, |ui| {
+                    egui::Grid::new("blocked_terms")
+                        .num_columns(2)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for term in &self.blocked_terms {
+                                ui.label(term);
+                                if ui.button("Remove").clicked() {
+                                    command = Some(BlockedTermCommand::Remove(term.clone()));
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+        }
+
+        // The list is reloaded once the backend made the change
+        if let Some(command) = command {
+            let _ = self
+                .frontend_tx
+                .try_send(FrontendToBackendMessage::BlockedTerm(command));
+        }
+    }
+
+    fn show_nicknames(&mut self, ui: &mut Ui) {
+        let mut change: Option<(String, Option<String>)> = None;
+        let login = self
+            .nickname_login
+            .trim()
+            .trim_start_matches('@')
+            .to_lowercase();
+        let nickname = validate_nickname(&self.nickname_input);
+
+        ui.horizontal(|ui| {
+            ui.label("User:");
+            ui.add(egui::TextEdit::singleline(&mut self.nickname_login).desired_width(120.0));
+            ui.label("Nickname:");
+            ui.add(egui::TextEdit::singleline(&mut self.nickname_input).desired_width(120.0));
+            let can_set = !login.is_empty() && nickname.is_ok();
+            if ui.add_enabled(can_set, egui::Button::new("Set")).clicked() {
+                change = Some((login.clone(), nickname.clone().ok()));
+                self.nickname_login.clear();
+                self.nickname_input.clear();
+            }
+        });
+        if let Err(e) = &nickname {
+            if !self.nickname_input.is_empty() {
+                ui.label(*e);
+            }
+        }
+        ui.label("(Used for {user} in responses. Chatters can set their own with !nick)");
+
+        if self.nicknames.is_empty() {
+            ui.label("No nicknames");
+        } else {
+            ScrollArea::vertical()
+                .id_salt("nicknames")
+                .max_height(150.0)
+                .show(ui, |ui| {
+                    egui::Grid::new("nicknames")
+                        .num_columns(3)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for (login, nickname) in &self.nicknames {
+                                ui.label(login);
+                                ui.label(nickname);
+                                if ui.button("Remove").clicked() {
+                                    change = Some((login.clone(), None));
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+        }
+
+        if let Some((login, nickname)) = change {
+            let _ = self
+                .frontend_tx
+                .try_send(FrontendToBackendMessage::SetNickname { login, nickname });
+        }
+    }
+
+    fn show_guest_star(&mut self, ui: &mut Ui) {
+        let mut command: Option<GuestStarCommand> = None;
+        let user = self
+            .guest_star_login
+            .trim()
+            .trim_start_matches('@')
+            .to_lowercase();
+
+        ui.horizontal(|ui| {
+            ui.label("Guest:");
+            ui.text_edit_singleline(&mut self.guest_star_login);
+            if ui.button("Invite").clicked() && !user.is_empty() {
+                command = Some(GuestStarCommand::Invite { user: user.clone() });
+            }
+
+            ui.label("Slot:");
+            ui.add(egui::DragValue::new(&mut self.guest_star_slot).range(1..=5));
+            if ui.button("Assign").clicked() && !user.is_empty() {
+                command = Some(GuestStarCommand::AssignSlot {
+                    user: user.clone(),
+                    slot_id: self.guest_star_slot.to_string(),
+                });
+            }
+
+            if ui.button("Refresh").clicked() {
+                let _ = self
+                    .frontend_tx
+                    .try_send(FrontendToBackendMessage::RefreshGuestStarSession);
+            }
+        });
+
+        match &self.guest_star_guests {
+            None => {
+                ui.label("No Guest Star session running");
+            }
+            Some(guests) => {
+                egui::Grid::new("guest_star_guests")
+                    .num_columns(4)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for guest in guests {
+                            ui.label(format!("Slot {}", guest.slot_id));
+                            ui.label(&guest.user_name);
+                            ui.label(if guest.is_live { "Live" } else { "Backstage" });
+                            let label = if guest.muted { "Unmute" } else { "Mute" };
+                            if ui.button(label).clicked() {
+                                command = Some(GuestStarCommand::SetAudio {
+                                    slot_id: guest.slot_id.clone(),
+                                    enabled: guest.muted,
+                                });
+                            }
+                            ui.end_row();
+                        }
+                    });
+            }
+        }
+
+        if let Some(command) = command {
+            let _ = self
+                .frontend_tx
+                .try_send(FrontendToBackendMessage::GuestStar(command));
+        }
+    }
+}
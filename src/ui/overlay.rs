@@ -1,4 +1,6 @@
 use super::Chatbot;
+use crate::backend::locale;
+use crate::backend::wheel_history::{segment_stats, WHEEL_HISTORY};
 use egui::{Button, Color32, RichText, Ui};
 
 impl Chatbot {
@@ -98,6 +100,20 @@ impl Chatbot {
                                 .italics()
                                 .color(Color32::GRAY),
                         );
+
+                        ui.add_space(5.0);
+                        if ui
+                            .button("Export OBS scene collection")
+                            .on_hover_text(
+                                "Write browser sources for the overlay and custom widgets \
+                                 to yambot-obs-scenes.json",
+                            )
+                            .clicked()
+                        {
+                            let _ = self
+                                .frontend_tx
+                                .try_send(super::FrontendToBackendMessage::ExportObsScenes);
+                        }
                     });
                 });
 
@@ -144,5 +160,82 @@ impl Chatbot {
                     });
                 });
             });
+
+        ui.add_space(10.0);
+        egui::CollapsingHeader::new("Wheel statistics").show(ui, |ui| {
+            self.show_wheel_stats(ui);
+        });
+        egui::CollapsingHeader::new("Wheel history").show(ui, |ui| {
+            self.show_wheel_history(ui);
+        });
+    }
+
+    fn show_wheel_stats(&self, ui: &mut Ui) {
+        let stats = segment_stats(&WHEEL_HISTORY.lock().unwrap());
+        if stats.is_empty() {
+            ui.label("No spins yet");
+            return;
+        }
+
+        egui::Grid::new("wheel_stats")
+            .num_columns(4)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(RichText::new("Wheel").strong());
+                ui.label(RichText::new("Segment").strong());
+                ui.label(RichText::new("Hits").strong());
+                ui.label(RichText::new("Share (fair)").strong());
+                ui.end_row();
+
+                for segment in &stats {
+                    ui.label(&segment.wheel);
+                    ui.label(&segment.result);
+                    ui.label(locale::format_number(segment.hits));
+                    let percent = |share: f32| {
+                        format!("{}%", locale::format_decimal(share as f64 * 100.0, 1))
+                    };
+                    let share = match segment.expected_share {
+                        Some(expected) => {
+                            format!("{} ({})", percent(segment.share), percent(expected))
+                        }
+                        None => percent(segment.share),
+                    };
+                    ui.label(share);
+                    ui.end_row();
+                }
+            });
+    }
+
+    fn show_wheel_history(&self, ui: &mut Ui) {
+        let history = WHEEL_HISTORY.lock().unwrap();
+        if history.is_empty() {
+            ui.label("No spins yet");
+            return;
+        }
+
+        egui::ScrollArea::vertical()
+            .max_height(300.0)
+            .show(ui, |ui| {
+                egui::Grid::new("wheel_history")
+                    .num_columns(5)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.label(RichText::new("Time").strong());
+                        ui.label(RichText::new("Wheel").strong());
+                        ui.label(RichText::new("Result").strong());
+                        ui.label(RichText::new("User").strong());
+                        ui.label(RichText::new("Action").strong());
+                        ui.end_row();
+
+                        for spin in history.iter().rev() {
+                            ui.label(locale::format_stored_timestamp(&spin.timestamp));
+                            ui.label(&spin.wheel);
+                            ui.label(&spin.result);
+                            ui.label(spin.user.as_deref().unwrap_or("-"));
+                            ui.label(&spin.action);
+                            ui.end_row();
+                        }
+                    });
+            });
     }
 }
@@ -0,0 +1,68 @@
+use super::Chatbot;
+use crate::backend::quotes::validate_quote;
+use crate::ui::FrontendToBackendMessage;
+use egui::{ScrollArea, Ui};
+
+impl Chatbot {
+    pub fn show_quotes(&mut self, ui: &mut Ui) {
+        ui.heading("Quotes");
+        ui.label(
+            "Viewers get a random quote with !quote or a specific one with !quote <id>. \
+             Moderators add quotes with !addquote <text> and delete them with !delquote <id>.",
+        );
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            ui.label("New quote:");
+            ui.text_edit_singleline(&mut self.new_quote);
+            let error = validate_quote(&self.new_quote).err();
+            if ui
+                .add_enabled(error.is_none(), egui::Button::new("Add"))
+                .clicked()
+            {
+                let _ = self
+                    .frontend_tx
+                    .try_send(FrontendToBackendMessage::AddQuote(self.new_quote.clone()));
+                self.new_quote.clear();
+            }
+            if let Some(error) = error.filter(|_| !self.new_quote.is_empty()) {
+                ui.colored_label(egui::Color32::from_rgb(255, 50, 0), error);
+            }
+        });
+        ui.add_space(5.0);
+
+        ScrollArea::vertical()
+            .auto_shrink([false; 2])
+            .show(ui, |ui| {
+                if self.quotes.is_empty() {
+                    ui.label("No quotes yet.");
+                }
+
+                let mut quote_to_delete = None;
+                for quote in self.quotes.iter().rev() {
+                    ui.group(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(format!("#{}", quote.id)).strong());
+                            ui.label(&quote.text);
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label(format!(
+                                "Added by {} on {}",
+                                quote.added_by,
+                                quote.added_at.format("%Y-%m-%d")
+                            ));
+                            if ui.button("Delete").clicked() {
+                                quote_to_delete = Some(quote.id);
+                            }
+                        });
+                    });
+                }
+
+                if let Some(id) = quote_to_delete {
+                    let _ = self
+                        .frontend_tx
+                        .try_send(FrontendToBackendMessage::DeleteQuote(id));
+                }
+            });
+    }
+}
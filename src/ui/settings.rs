@@ -1,4 +1,5 @@
-use super::{Chatbot, ChatbotConfig, FrontendToBackendMessage};
+use super::{Chatbot, ChatbotConfig, FrontendToBackendMessage, LogLevel, LogMessage};
+use crate::backend::locale::{self, Locale, Region};
 use crate::backend::sfx::Format;
 
 impl Chatbot {
@@ -39,6 +40,77 @@ impl Chatbot {
                 ui.text_edit_singleline(&mut self.config.welcome_message);
             });
             ui.label("(Optional: Message to send when bot connects. Leave empty to disable)");
+            ui.checkbox(
+                &mut self.config.welcome_on_stream_online,
+                "Send welcome message when the stream goes live instead",
+            );
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                ui.label("Shoutout thanks:");
+                ui.text_edit_singleline(&mut self.config.shoutout_thanks_message);
+            });
+            ui.label("(Optional: Sent when another channel shouts you out, {user} is their name. Leave empty to disable)");
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                ui.label("First chatter greeting:");
+                ui.text_edit_singleline(&mut self.config.first_chatter_message);
+            });
+            ui.label("(Optional: Sent to the first chatter of the stream, {user} is their name and {count} how often they were first. Leave empty to disable)");
+            ui.add_space(10.0);
+            ui.checkbox(
+                &mut self.config.irc_fallback,
+                "Read chat over IRC when EventSub chat messages are unavailable",
+            );
+            ui.checkbox(
+                &mut self.config.shield_mode_mods_only,
+                "Only moderators can use TTS and sounds while shield mode is active",
+            );
+            ui.checkbox(
+                &mut self.config.whisper_commands,
+                "Run commands whispered to the bot (roles are taken from the user's chat badges)",
+            );
+            ui.add_space(10.0);
+
+            ui.separator();
+            ui.add_space(10.0);
+            ui.heading("Stream info");
+            ui.horizontal(|ui| {
+                ui.label("Title:");
+                ui.text_edit_singleline(&mut self.stream_title);
+            });
+            ui.horizontal(|ui| {
+                ui.label("Category:");
+                ui.text_edit_singleline(&mut self.stream_game);
+            });
+            ui.label("(Needs the channel:manage:broadcast scope on the broadcaster's account. Mods can also use !title and !game)");
+            ui.horizontal(|ui| {
+                if ui.button("Update stream info").clicked() {
+                    let game = Some(self.stream_game.trim().to_string()).filter(|g| !g.is_empty());
+                    let _ = self
+                        .frontend_tx
+                        .try_send(FrontendToBackendMessage::UpdateStreamInfo {
+                            title: Some(self.stream_title.clone()),
+                            game,
+                        });
+                }
+                if ui.button("Reload").clicked() {
+                    let _ = self
+                        .frontend_tx
+                        .try_send(FrontendToBackendMessage::RefreshStreamInfo);
+                }
+            });
+            ui.add_space(10.0);
+
+            ui.separator();
+            ui.add_space(10.0);
+            ui.heading("Channel point rewards");
+            ui.label("Creates the rewards listed under [rewards] in config.toml, updates them and deletes the ones that were removed. Rewards created on Twitch are left alone.");
+            ui.label("(Needs the channel:manage:redemptions scope on the broadcaster's account)");
+            if ui.button("Sync rewards").clicked() {
+                let _ = self
+                    .frontend_tx
+                    .try_send(FrontendToBackendMessage::SyncRewards);
+            }
             ui.add_space(10.0);
 
             ui.separator();
@@ -69,6 +141,34 @@ impl Chatbot {
             });
             ui.add_space(10.0);
 
+            ui.separator();
+            ui.add_space(10.0);
+            ui.heading("Time and number format");
+            ui.horizontal(|ui| {
+                ui.label("Timezone:");
+                ui.text_edit_singleline(&mut self.timezone);
+            });
+            ui.label("(IANA name like Europe/Berlin or America/New_York. Leave empty to use the system timezone)");
+            ui.horizontal(|ui| {
+                ui.label("Format:");
+                egui::ComboBox::from_id_salt("locale_selector")
+                    .selected_text(self.locale.display_name())
+                    .show_ui(ui, |ui| {
+                        for locale in Locale::all() {
+                            ui.selectable_value(&mut self.locale, locale, locale.display_name());
+                        }
+                    });
+            });
+            if ui.button("Apply format").clicked() {
+                self.apply_region();
+            }
+            ui.add_space(10.0);
+
+            ui.separator();
+            ui.add_space(10.0);
+            self.show_editor_mode_settings(ui);
+            ui.add_space(10.0);
+
             if ui.button("Save").clicked() {
                 let _ = self
                     .frontend_tx
@@ -78,9 +178,39 @@ impl Chatbot {
                         refresh_token: self.config.refresh_token.clone(),
                         sound_format: self.config.sound_format.clone(),
                         welcome_message: self.config.welcome_message.clone(),
+                        welcome_on_stream_online: self.config.welcome_on_stream_online,
+                        shoutout_thanks_message: self.config.shoutout_thanks_message.clone(),
+                        first_chatter_message: self.config.first_chatter_message.clone(),
+                        irc_fallback: self.config.irc_fallback,
+                        shield_mode_mods_only: self.config.shield_mode_mods_only,
+                        whisper_commands: self.config.whisper_commands,
+                        eventsub_transport: self.config.eventsub_transport.clone(),
                     }))
                     .unwrap();
             }
         });
     }
+
+    /// Use the timezone and locale right away and persist them
+    fn apply_region(&mut self) {
+        match locale::parse_timezone(&self.timezone) {
+            Ok(timezone) => {
+                locale::set_region(Region {
+                    timezone,
+                    locale: self.locale,
+                });
+                let _ = self
+                    .frontend_tx
+                    .try_send(FrontendToBackendMessage::UpdateRegion {
+                        timezone: self.timezone.trim().to_string(),
+                        locale: self.locale.tag().to_string(),
+                    });
+            }
+            Err(e) => self.log_messages.push(LogMessage {
+                message: e,
+                timestamp: locale::timestamp(),
+                log_level: LogLevel::ERROR,
+            }),
+        }
+    }
 }
@@ -24,6 +24,10 @@ impl Chatbot {
                             )
                             .unwrap();
                     }
+                    let mut paused = crate::backend::sfx::is_paused();
+                    if ui.checkbox(&mut paused, "Paused").on_hover_text("Until unpaused or restarted, also set from the moderator dashboard").changed() {
+                        let _ = self.frontend_tx.try_send(super::FrontendToBackendMessage::SetSfxPaused(paused));
+                    }
                 });
                 ui.add_space(10.0);
                 ui.label("SFX volume (0-1 range):");
@@ -71,9 +75,20 @@ impl Chatbot {
             ui.separator();
             ui.vertical(|ui| {
                 ui.set_height(ui.available_height());
-                ui.heading(
-                    egui::widget_text::RichText::new("Available sounds").color(Color32::WHITE)
-                );
+                ui.horizontal(|ui| {
+                    ui.heading(
+                        egui::widget_text::RichText::new("Available sounds").color(Color32::WHITE)
+                    );
+                    if ui
+                        .button("Rescan folder")
+                        .on_hover_text("Read the sounds folder again")
+                        .clicked()
+                    {
+                        let _ = self
+                            .frontend_tx
+                            .try_send(super::FrontendToBackendMessage::RescanSounds);
+                    }
+                });
                 let files = FILES.lock().unwrap();
                 egui::ScrollArea::vertical()
                     .max_height(ui.available_height() - 100.0)
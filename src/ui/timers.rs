@@ -0,0 +1,73 @@
+use super::Chatbot;
+use crate::backend::timers::Timer;
+use crate::ui::FrontendToBackendMessage;
+use egui::{ScrollArea, Ui};
+
+impl Chatbot {
+    pub fn show_timers(&mut self, ui: &mut Ui) {
+        ui.heading("Timed Messages");
+        ui.label(
+            "Posted to chat while the stream is live, once the interval passed and \
+             enough chat messages came in. Only one timer posts at a time.",
+        );
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            if ui.button("Add timer").clicked() {
+                let name = format!("timer{}", self.timers.len() + 1);
+                self.timers.push(Timer::new(name, String::new()));
+            }
+            if ui.button("Save timers").clicked() {
+                let _ = self
+                    .frontend_tx
+                    .try_send(FrontendToBackendMessage::SetTimers(self.timers.clone()));
+            }
+            if self.timers != crate::backend::timers::list() {
+                ui.label("Unsaved changes");
+            }
+        });
+        ui.add_space(5.0);
+
+        ScrollArea::vertical()
+            .auto_shrink([false; 2])
+            .show(ui, |ui| {
+                if self.timers.is_empty() {
+                    ui.label("No timers yet, add one for messages like your Discord link.");
+                }
+
+                let mut timer_to_remove = None;
+                for (idx, timer) in self.timers.iter_mut().enumerate() {
+                    ui.group(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut timer.enabled, "");
+                            ui.label("Name:");
+                            ui.text_edit_singleline(&mut timer.name);
+                            if ui.button("Remove").clicked() {
+                                timer_to_remove = Some(idx);
+                            }
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("Message:");
+                            ui.text_edit_multiline(&mut timer.message);
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("Every");
+                            ui.add(
+                                egui::DragValue::new(&mut timer.interval_minutes)
+                                    .range(1..=1440)
+                                    .suffix(" min"),
+                            );
+                            ui.label("after at least");
+                            ui.add(egui::DragValue::new(&mut timer.min_lines).range(0..=1000));
+                            ui.label("chat messages");
+                        });
+                    });
+                    ui.add_space(5.0);
+                }
+
+                if let Some(idx) = timer_to_remove {
+                    self.timers.remove(idx);
+                }
+            });
+    }
+}